@@ -0,0 +1,54 @@
+//! Compare [SearchFoldTokenFilter] with the equivalent chain of three filters : an
+//! [EnclosedAlphanumericTokenFilter] for width, a [LowerCaser] for case and an
+//! [ASCIIFoldingTokenFilter] for accents.
+//!
+//! Run it with `cargo bench --bench search_fold`.
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use tantivy::tokenizer::{LowerCaser, TextAnalyzer, Token, TokenStream, WhitespaceTokenizer};
+use tantivy_analysis_contrib::commons::{
+    ASCIIFoldingTokenFilter, EnclosedAlphanumericTokenFilter, SearchFoldTokenFilter,
+};
+
+const TEXT: &str = "Le Café de la Gare ＯＰＥＮ Crème Brûlée Œuvre complète Straße München \
+                    ÉTÉ À L'HÔTEL naïve façade Ｔｏｋｙｏ plain ascii words in lowercase";
+
+fn run(analyzer: &mut TextAnalyzer) {
+    let mut token_stream = analyzer.token_stream(black_box(TEXT));
+    while token_stream.advance() {
+        black_box(&token_stream.token().text);
+    }
+}
+
+fn tokens(analyzer: &mut TextAnalyzer) -> Vec<Token> {
+    let mut tokens = vec![];
+    analyzer
+        .token_stream(TEXT)
+        .process(&mut |token: &Token| tokens.push(token.clone()));
+    tokens
+}
+
+fn search_fold(c: &mut Criterion) {
+    let mut chain = TextAnalyzer::builder(WhitespaceTokenizer::default())
+        .filter(EnclosedAlphanumericTokenFilter)
+        .filter(LowerCaser)
+        .filter(ASCIIFoldingTokenFilter::new(false))
+        .build();
+    let mut single = TextAnalyzer::builder(WhitespaceTokenizer::default())
+        .filter(SearchFoldTokenFilter)
+        .build();
+
+    // Both must do the same work
+    assert_eq!(tokens(&mut chain), tokens(&mut single));
+
+    let mut group = c.benchmark_group("search_fold");
+    group.bench_function("EnclosedAlphanumeric + LowerCaser + ASCIIFolding", |b| {
+        b.iter(|| run(&mut chain))
+    });
+    group.bench_function("SearchFold", |b| b.iter(|| run(&mut single)));
+    group.finish();
+}
+
+criterion_group!(benches, search_fold);
+criterion_main!(benches);
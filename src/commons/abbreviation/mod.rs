@@ -0,0 +1,108 @@
+pub use token_filter::AbbreviationExpandTokenFilter;
+use token_stream::AbbreviationExpandTokenStream;
+use wrapper::AbbreviationExpandFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, keep_original: bool) -> Vec<Token> {
+        let filter = AbbreviationExpandTokenFilter::new(
+            [("dr", "doctor"), ("nyc", "new york city"), ("empty", " ")],
+            keep_original,
+        );
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn token(
+        text: &str,
+        offset_from: usize,
+        offset_to: usize,
+        position: usize,
+        position_length: usize,
+    ) -> Token {
+        Token {
+            offset_from,
+            offset_to,
+            position,
+            text: text.to_string(),
+            position_length,
+        }
+    }
+
+    #[test]
+    fn test_single_word() {
+        let result = token_stream_helper("see dr who", false);
+        let expected = vec![
+            token("see", 0, 3, 0, 1),
+            token("doctor", 4, 6, 1, 1),
+            token("who", 7, 10, 2, 1),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_single_word_keep_original() {
+        let result = token_stream_helper("see dr who", true);
+        let expected = vec![
+            token("see", 0, 3, 0, 1),
+            token("dr", 4, 6, 1, 1),
+            token("doctor", 4, 6, 1, 1),
+            token("who", 7, 10, 2, 1),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multi_words() {
+        let result = token_stream_helper("dr in nyc today", false);
+        let expected = vec![
+            token("doctor", 0, 2, 0, 1),
+            token("in", 3, 5, 1, 1),
+            token("new", 6, 9, 2, 1),
+            token("york", 6, 9, 3, 1),
+            token("city", 6, 9, 4, 1),
+            token("today", 10, 15, 5, 1),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multi_words_keep_original() {
+        let result = token_stream_helper("nyc nyc", true);
+        let expected = vec![
+            token("nyc", 0, 3, 0, 3),
+            token("new", 0, 3, 0, 1),
+            token("york", 0, 3, 1, 1),
+            token("city", 0, 3, 2, 1),
+            token("nyc", 4, 7, 3, 3),
+            token("new", 4, 7, 3, 1),
+            token("york", 4, 7, 4, 1),
+            token("city", 4, 7, 5, 1),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_empty_full_form_ignored() {
+        let result = token_stream_helper("empty", false);
+        assert_eq!(result, vec![token("empty", 0, 5, 0, 1)]);
+    }
+}
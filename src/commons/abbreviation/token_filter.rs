@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::AbbreviationExpandFilterWrapper;
+
+/// A token filter that replaces abbreviations with their full form. For example, with
+/// `dr` mapped to `doctor`, the token `dr` becomes `doctor`.
+///
+/// A full form can have several words (`asap` mapped to `as soon as possible`), then the
+/// words are emitted at consecutive positions, as if they were in the text, and positions
+/// of the following tokens are shifted accordingly. All words keep the offsets of the
+/// abbreviation.
+///
+/// If `keep_original` is `true`, the abbreviation is also emitted, before its full
+/// form, at the position of the first word and with a `position_length` that spans all the
+/// words, so phrase queries work with both.
+///
+/// Abbreviations are matched on the exact token's text, so you may want to lowercase
+/// tokens before. Only provide unambiguous abbreviations : `st` can be `street` or `saint`.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::AbbreviationExpandTokenFilter;
+///
+/// let filter = AbbreviationExpandTokenFilter::new([("dr", "doctor"), ("asap", "as soon as possible")], false);
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("call dr asap");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "call".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "doctor".to_string());
+/// assert_eq!(token.position, 1);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "as".to_string());
+/// assert_eq!(token.position, 2);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "soon".to_string());
+/// assert_eq!(token.position, 3);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "as".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "possible".to_string());
+/// assert_eq!(token.position, 5);
+/// assert_eq!(token.offset_from, 8);
+/// assert_eq!(token.offset_to, 12);
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AbbreviationExpandTokenFilter {
+    abbreviations: Arc<FxHashMap<String, Vec<String>>>,
+    keep_original: bool,
+}
+
+impl AbbreviationExpandTokenFilter {
+    /// Construct a new [AbbreviationExpandTokenFilter].
+    /// # Parameters :
+    /// * `abbreviations`: pairs of abbreviation and full form. Words of the full form are
+    ///   separated by whitespaces. Abbreviations with an empty full form are ignored.
+    /// * `keep_original`: also emit the abbreviation
+    pub fn new<A: Into<String>, F: AsRef<str>>(
+        abbreviations: impl IntoIterator<Item = (A, F)>,
+        keep_original: bool,
+    ) -> Self {
+        let abbreviations = abbreviations
+            .into_iter()
+            .filter_map(|(abbreviation, full_form)| {
+                let words: Vec<String> = full_form
+                    .as_ref()
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect();
+                (!words.is_empty()).then(|| (abbreviation.into(), words))
+            })
+            .collect();
+        Self {
+            abbreviations: Arc::new(abbreviations),
+            keep_original,
+        }
+    }
+}
+
+impl TokenFilter for AbbreviationExpandTokenFilter {
+    type Tokenizer<T: Tokenizer> = AbbreviationExpandFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        AbbreviationExpandFilterWrapper::new(token_stream, self.abbreviations, self.keep_original)
+    }
+}
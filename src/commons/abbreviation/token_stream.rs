@@ -0,0 +1,83 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use rustc_hash::FxHashMap;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Debug, Clone)]
+pub struct AbbreviationExpandTokenStream<'a, T> {
+    tail: T,
+    abbreviations: &'a FxHashMap<String, Vec<String>>,
+    keep_original: bool,
+    /// Positions taken by words of previous full forms.
+    position_shift: usize,
+    buffer: VecDeque<Token>,
+    token: Token,
+}
+
+impl<'a, T> AbbreviationExpandTokenStream<'a, T> {
+    pub(crate) fn new(
+        tail: T,
+        abbreviations: &'a FxHashMap<String, Vec<String>>,
+        keep_original: bool,
+    ) -> Self {
+        Self {
+            tail,
+            abbreviations,
+            keep_original,
+            position_shift: 0,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for AbbreviationExpandTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if let Some(token) = self.buffer.pop_front() {
+            self.token = token;
+            return true;
+        }
+
+        if !self.tail.advance() {
+            return false;
+        }
+
+        self.token.clone_from(self.tail.token());
+        self.token.position += self.position_shift;
+
+        let Some(words) = self.abbreviations.get(&self.token.text) else {
+            return true;
+        };
+
+        for (i, word) in words.iter().enumerate() {
+            self.buffer.push_back(Token {
+                offset_from: self.token.offset_from,
+                offset_to: self.token.offset_to,
+                position: self.token.position + i,
+                text: word.clone(),
+                position_length: 1,
+            });
+        }
+        self.position_shift += words.len() - 1;
+
+        if self.keep_original {
+            // The abbreviation spans the positions of its words
+            self.token.position_length = words.len();
+        } else if let Some(token) = self.buffer.pop_front() {
+            self.token = token;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -0,0 +1,43 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::AbbreviationExpandTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct AbbreviationExpandFilterWrapper<T> {
+    abbreviations: Arc<FxHashMap<String, Vec<String>>>,
+    keep_original: bool,
+    inner: T,
+}
+
+impl<T> AbbreviationExpandFilterWrapper<T> {
+    pub(crate) fn new(
+        inner: T,
+        abbreviations: Arc<FxHashMap<String, Vec<String>>>,
+        keep_original: bool,
+    ) -> Self {
+        Self {
+            abbreviations,
+            keep_original,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for AbbreviationExpandFilterWrapper<T> {
+    type TokenStream<'a> = AbbreviationExpandTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        AbbreviationExpandTokenStream::new(
+            self.inner.token_stream(text),
+            &self.abbreviations,
+            self.keep_original,
+        )
+    }
+}
@@ -0,0 +1,517 @@
+//! Configuration of a whole analysis chain : char filters, a tokenizer and token
+//! filters, that is validated and built into a tantivy [TextAnalyzer].
+//!
+//! Steps are given in the order they apply. Char filters must come before the
+//! tokenizer and token filters after it. When there are char filters, the tokenizer
+//! is wrapped into a [CharFilterTokenizer] so that offsets point to the original text.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use tantivy_analysis_contrib::commons::analyzer::{
+//!     AnalyzerStep, CharFilterConfig, FilterConfig, FullAnalyzerConfig, TokenizerConfig,
+//! };
+//!
+//! let config = FullAnalyzerConfig {
+//!     steps: vec![
+//!         AnalyzerStep::CharFilter(CharFilterConfig::HtmlStrip),
+//!         AnalyzerStep::Tokenizer(TokenizerConfig::Whitespace),
+//!         AnalyzerStep::Filter(FilterConfig::LowerCase),
+//!     ],
+//! };
+//! let mut analyzer = config.build()?;
+//! let text = "<b>Hello</b> World";
+//! let mut token_stream = analyzer.token_stream(text);
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "hello".to_string());
+//! assert_eq!(&text[token.offset_from..token.offset_to], "Hello");
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "world".to_string());
+//!
+//! assert_eq!(None, token_stream.next());
+//! #     Ok(())
+//! # }
+//! ```
+
+use std::error::Error as StdError;
+use std::num::NonZeroUsize;
+
+use tantivy::tokenizer::{
+    RawTokenizer, SimpleTokenizer, TextAnalyzer, TextAnalyzerBuilder, Tokenizer,
+    WhitespaceTokenizer,
+};
+use thiserror::Error;
+
+use crate::commons::{
+    ASCIIFoldingTokenFilter, BudgetTokenFilter, CharFilter, CharFilterTokenizer,
+    EdgeNgramTokenFilter, HtmlStripRewriter, KeywordTokenizer, LengthTokenFilter,
+    LowerCaseTokenFilter, MappingRewriter, NGramTokenFilter, PathTokenizer, ReverseTokenFilter,
+    SearchFoldTokenFilter, StopTokenFilter, TrimTokenFilter, UpperCaseTokenFilter,
+    UrlEmailTokenizer,
+};
+
+/// Configuration of a char filter.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CharFilterConfig {
+    /// [MappingRewriter] with these mappings.
+    Mapping(Vec<(String, String)>),
+    /// [HtmlStripRewriter].
+    HtmlStrip,
+}
+
+impl From<&CharFilterConfig> for CharFilter {
+    fn from(config: &CharFilterConfig) -> Self {
+        match config {
+            CharFilterConfig::Mapping(mappings) => MappingRewriter::new(mappings.clone()).into(),
+            CharFilterConfig::HtmlStrip => HtmlStripRewriter.into(),
+        }
+    }
+}
+
+/// Configuration of a tokenizer.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum TokenizerConfig {
+    /// tantivy's [WhitespaceTokenizer].
+    Whitespace,
+    /// tantivy's [SimpleTokenizer].
+    Simple,
+    /// tantivy's [RawTokenizer].
+    Raw,
+    /// [KeywordTokenizer], truncated to `max_length` bytes if any.
+    Keyword {
+        /// Maximum length of the token, in bytes.
+        max_length: Option<usize>,
+    },
+    /// [PathTokenizer].
+    Path(PathTokenizer),
+    /// [UrlEmailTokenizer].
+    UrlEmail,
+}
+
+impl TokenizerConfig {
+    fn builder(&self, char_filters: Vec<CharFilter>) -> TextAnalyzerBuilder {
+        match self {
+            TokenizerConfig::Whitespace => builder(WhitespaceTokenizer::default(), char_filters),
+            TokenizerConfig::Simple => builder(SimpleTokenizer::default(), char_filters),
+            TokenizerConfig::Raw => builder(RawTokenizer::default(), char_filters),
+            TokenizerConfig::Keyword { max_length } => {
+                let tokenizer = match max_length {
+                    Some(max_length) => KeywordTokenizer::with_max_length(*max_length),
+                    None => KeywordTokenizer::default(),
+                };
+                builder(tokenizer, char_filters)
+            }
+            TokenizerConfig::Path(tokenizer) => builder(tokenizer.clone(), char_filters),
+            TokenizerConfig::UrlEmail => builder(UrlEmailTokenizer, char_filters),
+        }
+    }
+}
+
+/// Builder of an analyzer starting with `tokenizer`, wrapped with the char filters
+/// if there are some.
+fn builder<T: Tokenizer>(tokenizer: T, char_filters: Vec<CharFilter>) -> TextAnalyzerBuilder {
+    if char_filters.is_empty() {
+        TextAnalyzer::builder(tokenizer).dynamic()
+    } else {
+        TextAnalyzer::builder(CharFilterTokenizer::new(tokenizer, char_filters)).dynamic()
+    }
+}
+
+/// Configuration of a token filter.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum FilterConfig {
+    /// [LowerCaseTokenFilter].
+    LowerCase,
+    /// [UpperCaseTokenFilter].
+    UpperCase,
+    /// [ASCIIFoldingTokenFilter].
+    AsciiFolding {
+        /// Also emit the original token when it is folded.
+        preserve_original: bool,
+    },
+    /// [SearchFoldTokenFilter].
+    SearchFold,
+    /// [ReverseTokenFilter] without marker.
+    Reverse,
+    /// [TrimTokenFilter].
+    Trim,
+    /// [EdgeNgramTokenFilter].
+    EdgeNgram(EdgeNgramTokenFilter),
+    /// [NGramTokenFilter].
+    Ngram {
+        /// Minimum ngram.
+        min: NonZeroUsize,
+        /// Maximum ngram, unlimited if [None].
+        max: Option<NonZeroUsize>,
+        /// Also emit the original token when it is out of bounds.
+        keep_original: bool,
+    },
+    /// [LengthTokenFilter].
+    Length(LengthTokenFilter),
+    /// [StopTokenFilter].
+    Stop {
+        /// Words to remove.
+        words: Vec<String>,
+        /// Stop words are case-insensitive.
+        ignore_case: bool,
+    },
+    /// [BudgetTokenFilter] of `max_tokens` tokens.
+    Budget {
+        /// Maximum number of tokens.
+        max_tokens: usize,
+    },
+}
+
+impl FilterConfig {
+    fn add_to(
+        &self,
+        builder: TextAnalyzerBuilder,
+    ) -> Result<TextAnalyzerBuilder, Box<dyn StdError + Send + Sync>> {
+        let builder = match self {
+            FilterConfig::LowerCase => builder.filter_dynamic(LowerCaseTokenFilter),
+            FilterConfig::UpperCase => builder.filter_dynamic(UpperCaseTokenFilter),
+            FilterConfig::AsciiFolding { preserve_original } => {
+                builder.filter_dynamic(ASCIIFoldingTokenFilter::new(*preserve_original))
+            }
+            FilterConfig::SearchFold => builder.filter_dynamic(SearchFoldTokenFilter),
+            FilterConfig::Reverse => builder.filter_dynamic(ReverseTokenFilter::default()),
+            FilterConfig::Trim => builder.filter_dynamic(TrimTokenFilter),
+            FilterConfig::EdgeNgram(filter) => builder.filter_dynamic(*filter),
+            FilterConfig::Ngram {
+                min,
+                max,
+                keep_original,
+            } => builder.filter_dynamic(NGramTokenFilter::new(*min, *max, *keep_original)?),
+            FilterConfig::Length(filter) => builder.filter_dynamic(*filter),
+            FilterConfig::Stop { words, ignore_case } => builder.filter_dynamic(
+                StopTokenFilter::from_iter_string(words.iter().cloned(), *ignore_case)?,
+            ),
+            FilterConfig::Budget { max_tokens } => {
+                builder.filter_dynamic(BudgetTokenFilter::new(*max_tokens))
+            }
+        };
+        Ok(builder)
+    }
+}
+
+/// A step of the analysis chain.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum AnalyzerStep {
+    /// A char filter, applied on the text before the tokenizer.
+    CharFilter(CharFilterConfig),
+    /// The tokenizer.
+    Tokenizer(TokenizerConfig),
+    /// A token filter, applied on tokens after the tokenizer.
+    Filter(FilterConfig),
+}
+
+/// Errors of an invalid [FullAnalyzerConfig].
+#[derive(Debug, Error)]
+pub enum AnalyzerConfigError {
+    /// Error raised when a char filter comes after the tokenizer.
+    #[error("Char filter at step {index} must come before the tokenizer")]
+    CharFilterAfterTokenizer {
+        /// Index of the char filter step.
+        index: usize,
+    },
+    /// Error raised when a token filter comes before the tokenizer.
+    #[error("Filter at step {index} must come after the tokenizer")]
+    FilterBeforeTokenizer {
+        /// Index of the token filter step.
+        index: usize,
+    },
+    /// Error raised when there is more than one tokenizer.
+    #[error("Tokenizer at step {index} is not the only one, there is one at step {first}")]
+    SeveralTokenizers {
+        /// Index of the extra tokenizer step.
+        index: usize,
+        /// Index of the first tokenizer step.
+        first: usize,
+    },
+    /// Error raised when there is no tokenizer.
+    #[error("There is no tokenizer")]
+    NoTokenizer,
+    /// Error raised when HTML is stripped after another char filter. Other char
+    /// filters could break the markup, a mapping of `&` would break entities.
+    #[error("HTML strip at step {index} must be the first char filter")]
+    HtmlStripNotFirst {
+        /// Index of the HTML strip step.
+        index: usize,
+    },
+    /// Error raised when a step can't be built from its configuration.
+    #[error("Step {index} is invalid: {source}")]
+    InvalidStep {
+        /// Index of the step.
+        index: usize,
+        /// Error of the component.
+        source: Box<dyn StdError + Send + Sync>,
+    },
+}
+
+/// Configuration of a whole analysis chain, see the [module](self) documentation.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FullAnalyzerConfig {
+    /// Steps of the chain, in order.
+    pub steps: Vec<AnalyzerStep>,
+}
+
+impl FullAnalyzerConfig {
+    /// Validate the configuration and build the [TextAnalyzer].
+    pub fn build(&self) -> Result<TextAnalyzer, AnalyzerConfigError> {
+        let mut char_filters: Vec<CharFilter> = Vec::new();
+        let mut tokenizer: Option<(usize, &TokenizerConfig)> = None;
+        let mut filters: Vec<(usize, &FilterConfig)> = Vec::new();
+        for (index, step) in self.steps.iter().enumerate() {
+            match step {
+                AnalyzerStep::CharFilter(config) => {
+                    if tokenizer.is_some() {
+                        return Err(AnalyzerConfigError::CharFilterAfterTokenizer { index });
+                    }
+                    if matches!(config, CharFilterConfig::HtmlStrip) && !char_filters.is_empty() {
+                        return Err(AnalyzerConfigError::HtmlStripNotFirst { index });
+                    }
+                    char_filters.push(config.into());
+                }
+                AnalyzerStep::Tokenizer(config) => {
+                    if let Some((first, _)) = tokenizer {
+                        return Err(AnalyzerConfigError::SeveralTokenizers { index, first });
+                    }
+                    tokenizer = Some((index, config));
+                }
+                AnalyzerStep::Filter(config) => {
+                    if tokenizer.is_none() {
+                        return Err(AnalyzerConfigError::FilterBeforeTokenizer { index });
+                    }
+                    filters.push((index, config));
+                }
+            }
+        }
+
+        let (_, tokenizer) = tokenizer.ok_or(AnalyzerConfigError::NoTokenizer)?;
+        let mut builder = tokenizer.builder(char_filters);
+        for (index, filter) in filters {
+            builder = filter
+                .add_to(builder)
+                .map_err(|source| AnalyzerConfigError::InvalidStep { index, source })?;
+        }
+
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::Token;
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, config: &FullAnalyzerConfig) -> Vec<Token> {
+        let mut analyzer = config.build().expect("Configuration should be valid");
+        let mut token_stream = analyzer.token_stream(text);
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_full_pipeline() {
+        let config = FullAnalyzerConfig {
+            steps: vec![
+                AnalyzerStep::CharFilter(CharFilterConfig::HtmlStrip),
+                AnalyzerStep::CharFilter(CharFilterConfig::Mapping(vec![(
+                    "&".to_string(),
+                    " and ".to_string(),
+                )])),
+                AnalyzerStep::Tokenizer(TokenizerConfig::Whitespace),
+                AnalyzerStep::Filter(FilterConfig::LowerCase),
+                AnalyzerStep::Filter(FilterConfig::Stop {
+                    words: vec!["the".to_string()],
+                    ignore_case: false,
+                }),
+            ],
+        };
+        let text = "<p>The Salt&amp;<i>Pepper</i></p>";
+        let result = token_stream_helper(text, &config);
+        let expected = vec![
+            Token {
+                offset_from: 7,
+                offset_to: 11,
+                position: 1,
+                text: "salt".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 11,
+                offset_to: 16,
+                position: 2,
+                text: "and".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 19,
+                offset_to: 25,
+                position: 3,
+                text: "pepper".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+        assert_eq!(&text[11..16], "&amp;");
+        assert_eq!(&text[19..25], "Pepper");
+    }
+
+    #[test]
+    fn test_without_char_filter() {
+        let config = FullAnalyzerConfig {
+            steps: vec![
+                AnalyzerStep::Tokenizer(TokenizerConfig::Keyword { max_length: None }),
+                AnalyzerStep::Filter(FilterConfig::Reverse),
+            ],
+        };
+        let result = token_stream_helper("abc", &config);
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 3,
+            position: 0,
+            text: "cba".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_invalid_ordering() {
+        let config = FullAnalyzerConfig {
+            steps: vec![
+                AnalyzerStep::Tokenizer(TokenizerConfig::Whitespace),
+                AnalyzerStep::CharFilter(CharFilterConfig::HtmlStrip),
+            ],
+        };
+        assert!(matches!(
+            config.build(),
+            Err(AnalyzerConfigError::CharFilterAfterTokenizer { index: 1 })
+        ));
+
+        let config = FullAnalyzerConfig {
+            steps: vec![
+                AnalyzerStep::Filter(FilterConfig::LowerCase),
+                AnalyzerStep::Tokenizer(TokenizerConfig::Whitespace),
+            ],
+        };
+        assert!(matches!(
+            config.build(),
+            Err(AnalyzerConfigError::FilterBeforeTokenizer { index: 0 })
+        ));
+
+        let config = FullAnalyzerConfig {
+            steps: vec![
+                AnalyzerStep::Tokenizer(TokenizerConfig::Whitespace),
+                AnalyzerStep::Filter(FilterConfig::LowerCase),
+                AnalyzerStep::Tokenizer(TokenizerConfig::Simple),
+            ],
+        };
+        assert!(matches!(
+            config.build(),
+            Err(AnalyzerConfigError::SeveralTokenizers { index: 2, first: 0 })
+        ));
+
+        let config = FullAnalyzerConfig {
+            steps: vec![AnalyzerStep::CharFilter(CharFilterConfig::HtmlStrip)],
+        };
+        assert!(matches!(
+            config.build(),
+            Err(AnalyzerConfigError::NoTokenizer)
+        ));
+    }
+
+    #[test]
+    fn test_incompatible_steps() {
+        let config = FullAnalyzerConfig {
+            steps: vec![
+                AnalyzerStep::CharFilter(CharFilterConfig::Mapping(vec![(
+                    "&".to_string(),
+                    "and".to_string(),
+                )])),
+                AnalyzerStep::CharFilter(CharFilterConfig::HtmlStrip),
+                AnalyzerStep::Tokenizer(TokenizerConfig::Whitespace),
+            ],
+        };
+        assert!(matches!(
+            config.build(),
+            Err(AnalyzerConfigError::HtmlStripNotFirst { index: 1 })
+        ));
+
+        let config = FullAnalyzerConfig {
+            steps: vec![
+                AnalyzerStep::Tokenizer(TokenizerConfig::Whitespace),
+                AnalyzerStep::Filter(FilterConfig::Ngram {
+                    min: NonZeroUsize::new(3).unwrap(),
+                    max: NonZeroUsize::new(2),
+                    keep_original: false,
+                }),
+            ],
+        };
+        let error = config
+            .build()
+            .err()
+            .expect("Configuration should be invalid");
+        assert!(matches!(
+            error,
+            AnalyzerConfigError::InvalidStep { index: 1, .. }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Step 1 is invalid: Maximum '2' must be greater or equals to minimum '3' or should be 'None'"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() {
+        let config: FullAnalyzerConfig = serde_json::from_str(
+            r#"{"steps": [
+                {"char_filter": "html_strip"},
+                {"tokenizer": "whitespace"},
+                {"filter": {"ascii_folding": {"preserve_original": false}}}
+            ]}"#,
+        )
+        .expect("Configuration should deserialize");
+        let result = token_stream_helper("<b>Café</b>", &config);
+        let expected = vec![Token {
+            offset_from: 3,
+            offset_to: 8,
+            position: 0,
+            text: "Cafe".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+}
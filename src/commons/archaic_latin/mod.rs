@@ -0,0 +1,100 @@
+pub use token_filter::{ArchaicLatinTokenFilter, ArchaicLatinTokenFilterBuilder};
+use token_stream::ArchaicLatinTokenStream;
+use wrapper::ArchaicLatinFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, filter: ArchaicLatinTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn texts(text: &str, filter: ArchaicLatinTokenFilter) -> Vec<String> {
+        token_stream_helper(text, filter)
+            .into_iter()
+            .map(|token| token.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_long_s() {
+        let result = token_stream_helper("Congreſs ſhall", ArchaicLatinTokenFilter::default());
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "Congress".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 16,
+                position: 1,
+                text: "shall".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ligatures() {
+        let result = texts("ﬁrſt eﬀect ﬅyle ꝏk", ArchaicLatinTokenFilter::default());
+        assert_eq!(result, vec!["first", "effect", "style", "ook"]);
+
+        // Modern ligatures are left untouched
+        let result = texts("cœur æther", ArchaicLatinTokenFilter::default());
+        assert_eq!(result, vec!["cœur", "æther"]);
+    }
+
+    #[test]
+    fn test_r_rotunda_and_superscript() {
+        let result = texts("oꝛdo yᵉ muͤde", ArchaicLatinTokenFilter::default());
+        assert_eq!(result, vec!["ordo", "ye", "muede"]);
+    }
+
+    #[test]
+    fn test_ampersand() -> Result<(), Box<dyn std::error::Error>> {
+        let result = texts("this & that &c", ArchaicLatinTokenFilter::default());
+        assert_eq!(result, vec!["this", "&", "that", "&c"]);
+
+        let filter = ArchaicLatinTokenFilterBuilder::default()
+            .ampersand("et".to_string())
+            .build()?;
+        let result = texts("this & that &c", filter);
+        assert_eq!(result, vec!["this", "et", "that", "&c"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_disabled_mappings() -> Result<(), Box<dyn std::error::Error>> {
+        let filter = ArchaicLatinTokenFilterBuilder::default()
+            .long_s(false)
+            .ligatures(false)
+            .build()?;
+        let result = texts("ﬁrſt oꝛdo", filter);
+        assert_eq!(result, vec!["ﬁrſt", "ordo"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::ArchaicLatinFilterWrapper;
+
+/// A token filter that maps archaic forms of the Latin script to their modern
+/// equivalent. It improves search over historical texts, for example OCR'd documents,
+/// where `ſ` (long s) is used instead of `s`.
+///
+/// Mappings are grouped and each group can be enabled with [ArchaicLatinTokenFilterBuilder] :
+/// * `long_s`: long s `ſ` and its variants (`ẛ`, `ẜ`, `ẝ`) become `s`,
+/// * `ligatures`: typographic and medieval ligatures are expanded (`ﬁ` becomes `fi`,
+///   `ﬅ` becomes `st`, `ꜳ` becomes `aa`, `ꝏ` becomes `oo`...). `æ` and `œ` are not
+///   archaic and are left untouched, use an [ASCIIFoldingTokenFilter](crate::commons::ASCIIFoldingTokenFilter)
+///   if needed,
+/// * `r_rotunda`: r rotunda `ꝛ` becomes `r`,
+/// * `superscript_letters`: superscript letters (`ᵉ`) and combining letters written
+///   above another letter (`uͤ`, the old form of `ü`) become regular letters,
+/// * `ampersand`: a token that is only `&` is replaced by the given word (`and`, `et`...).
+///   Ampersands inside tokens (`&c`) are left untouched.
+///
+/// The [Default] implementation enables every group except `ampersand`. Offsets are not
+/// modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::ArchaicLatinTokenFilterBuilder;
+///
+/// let filter = ArchaicLatinTokenFilterBuilder::default()
+///     .ampersand("and".to_string())
+///     .build()?;
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("Congreſs & ﬁrſt");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Congress".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "and".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "first".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Builder)]
+#[builder(setter(into), default)]
+pub struct ArchaicLatinTokenFilter {
+    /// Replace long s `ſ` by `s`.
+    #[builder(default = "true")]
+    pub long_s: bool,
+    /// Expand ligatures : `ﬁ` gives `fi`.
+    #[builder(default = "true")]
+    pub ligatures: bool,
+    /// Replace r rotunda `ꝛ` by `r`.
+    #[builder(default = "true")]
+    pub r_rotunda: bool,
+    /// Replace superscript and combining letters by regular letters : `uͤ` gives `ue`.
+    #[builder(default = "true")]
+    pub superscript_letters: bool,
+    /// Replacement of tokens that are only `&`. [None] leaves them untouched.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub ampersand: Option<String>,
+}
+
+impl Default for ArchaicLatinTokenFilter {
+    /// Construct an [ArchaicLatinTokenFilter] that applies every mapping except the
+    /// replacement of `&`.
+    fn default() -> Self {
+        ArchaicLatinTokenFilter {
+            long_s: true,
+            ligatures: true,
+            r_rotunda: true,
+            superscript_letters: true,
+            ampersand: None,
+        }
+    }
+}
+
+impl TokenFilter for ArchaicLatinTokenFilter {
+    type Tokenizer<T: Tokenizer> = ArchaicLatinFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        ArchaicLatinFilterWrapper::new(tokenizer, self)
+    }
+}
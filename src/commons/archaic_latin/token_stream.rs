@@ -0,0 +1,162 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::ArchaicLatinTokenFilter;
+use crate::commons::map_chars::map_chars;
+
+/// Long s and its variants.
+fn long_s(ch: char) -> Option<&'static str> {
+    match ch {
+        '\u{017F}' | '\u{1E9B}' | '\u{1E9C}' | '\u{1E9D}' => Some("s"),
+        _ => None,
+    }
+}
+
+/// Typographic and medieval ligatures.
+fn ligature(ch: char) -> Option<&'static str> {
+    match ch {
+        '\u{FB00}' => Some("ff"),
+        '\u{FB01}' => Some("fi"),
+        '\u{FB02}' => Some("fl"),
+        '\u{FB03}' => Some("ffi"),
+        '\u{FB04}' => Some("ffl"),
+        // ﬅ is long s with t
+        '\u{FB05}' | '\u{FB06}' => Some("st"),
+        '\u{A732}' => Some("AA"),
+        '\u{A733}' => Some("aa"),
+        '\u{A734}' => Some("AO"),
+        '\u{A735}' => Some("ao"),
+        '\u{A736}' => Some("AU"),
+        '\u{A737}' => Some("au"),
+        '\u{A738}' | '\u{A73A}' => Some("AV"),
+        '\u{A739}' | '\u{A73B}' => Some("av"),
+        '\u{A73C}' => Some("AY"),
+        '\u{A73D}' => Some("ay"),
+        '\u{A74E}' => Some("OO"),
+        '\u{A74F}' => Some("oo"),
+        '\u{A760}' => Some("VY"),
+        '\u{A761}' => Some("vy"),
+        '\u{A728}' => Some("TZ"),
+        '\u{A729}' => Some("tz"),
+        '\u{1D6B}' => Some("ue"),
+        _ => None,
+    }
+}
+
+/// R rotunda.
+fn r_rotunda(ch: char) -> Option<&'static str> {
+    match ch {
+        '\u{A75A}' => Some("R"),
+        '\u{A75B}' => Some("r"),
+        _ => None,
+    }
+}
+
+/// Superscript letters and combining Latin small letters.
+fn superscript_letter(ch: char) -> Option<&'static str> {
+    match ch {
+        // Combining Latin small letters, written above another letter
+        '\u{0363}' => Some("a"),
+        '\u{0364}' => Some("e"),
+        '\u{0365}' => Some("i"),
+        '\u{0366}' => Some("o"),
+        '\u{0367}' => Some("u"),
+        '\u{0368}' => Some("c"),
+        '\u{0369}' => Some("d"),
+        '\u{036A}' => Some("h"),
+        '\u{036B}' => Some("m"),
+        '\u{036C}' => Some("r"),
+        '\u{036D}' => Some("t"),
+        '\u{036E}' => Some("v"),
+        '\u{036F}' => Some("x"),
+        // Modifier letters
+        '\u{1D43}' => Some("a"),
+        '\u{1D47}' => Some("b"),
+        '\u{1D48}' => Some("d"),
+        '\u{1D49}' => Some("e"),
+        '\u{1D4D}' => Some("g"),
+        '\u{02B0}' => Some("h"),
+        '\u{2071}' => Some("i"),
+        '\u{02B2}' => Some("j"),
+        '\u{1D4F}' => Some("k"),
+        '\u{02E1}' => Some("l"),
+        '\u{1D50}' => Some("m"),
+        '\u{207F}' => Some("n"),
+        '\u{1D52}' => Some("o"),
+        '\u{1D56}' => Some("p"),
+        '\u{02B3}' => Some("r"),
+        '\u{02E2}' => Some("s"),
+        '\u{1D57}' => Some("t"),
+        '\u{1D58}' => Some("u"),
+        '\u{1D5B}' => Some("v"),
+        '\u{02B7}' => Some("w"),
+        '\u{02E3}' => Some("x"),
+        '\u{02B8}' => Some("y"),
+        '\u{1DBB}' => Some("z"),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArchaicLatinTokenStream<'a, T> {
+    tail: T,
+    config: &'a ArchaicLatinTokenFilter,
+}
+
+impl<'a, T> ArchaicLatinTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, config: &'a ArchaicLatinTokenFilter) -> Self {
+        Self { tail, config }
+    }
+
+    /// Modern equivalent of a character, according to the enabled mappings.
+    fn map(&self, ch: char) -> Option<&'static str> {
+        let config = self.config;
+        None.or_else(|| if config.long_s { long_s(ch) } else { None })
+            .or_else(|| if config.ligatures { ligature(ch) } else { None })
+            .or_else(|| {
+                if config.r_rotunda {
+                    r_rotunda(ch)
+                } else {
+                    None
+                }
+            })
+            .or_else(|| {
+                if config.superscript_letters {
+                    superscript_letter(ch)
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+impl<T: TokenStream> TokenStream for ArchaicLatinTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        if let Some(ampersand) = &self.config.ampersand {
+            if self.tail.token().text == "&" {
+                self.tail.token_mut().text.clone_from(ampersand);
+                return true;
+            }
+        }
+
+        if let Some(text) = map_chars(&self.tail.token().text, |ch| self.map(ch)) {
+            self.tail.token_mut().text = text;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
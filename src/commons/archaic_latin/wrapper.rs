@@ -0,0 +1,27 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{ArchaicLatinTokenFilter, ArchaicLatinTokenStream};
+
+#[derive(Clone, Debug)]
+pub struct ArchaicLatinFilterWrapper<T> {
+    config: ArchaicLatinTokenFilter,
+    inner: T,
+}
+
+impl<T> ArchaicLatinFilterWrapper<T> {
+    pub(crate) fn new(inner: T, config: ArchaicLatinTokenFilter) -> Self {
+        Self { config, inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for ArchaicLatinFilterWrapper<T> {
+    type TokenStream<'a> = ArchaicLatinTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        ArchaicLatinTokenStream::new(self.inner.token_stream(text), &self.config)
+    }
+}
@@ -0,0 +1,204 @@
+pub(crate) use mapping::fold;
+pub use token_filter::ASCIIFoldingTokenFilter;
+use token_stream::ASCIIFoldingTokenStream;
+use wrapper::ASCIIFoldingFilterWrapper;
+
+mod mapping;
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, preserve_original: bool) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(ASCIIFoldingTokenFilter::new(preserve_original))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_single_char() {
+        let result = token_stream_helper("résumé Ångström naïve Łódź", false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "resume".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 19,
+                position: 1,
+                text: "Angstrom".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 20,
+                offset_to: 26,
+                position: 2,
+                text: "naive".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 27,
+                offset_to: 34,
+                position: 3,
+                text: "Lodz".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_expansion() {
+        let result = token_stream_helper("Straße Æsir œuvre ﬁre ⑽", false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 7,
+                position: 0,
+                text: "Strasse".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 13,
+                position: 1,
+                text: "AEsir".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 20,
+                position: 2,
+                text: "oeuvre".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 21,
+                offset_to: 26,
+                position: 3,
+                text: "fire".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 27,
+                offset_to: 30,
+                position: 4,
+                text: "(10)".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_symbols() {
+        let result = token_stream_helper("«quoted» ｆｕｌｌ x²", false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 10,
+                position: 0,
+                text: "\"quoted\"".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 11,
+                offset_to: 23,
+                position: 1,
+                text: "full".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 24,
+                offset_to: 27,
+                position: 2,
+                text: "x2".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_preserve_original() {
+        let result = token_stream_helper("café plain Æon", true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "cafe".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "café".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 11,
+                position: 1,
+                text: "plain".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 16,
+                position: 2,
+                text: "AEon".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 16,
+                position: 2,
+                text: "Æon".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_not_folded() {
+        let result = token_stream_helper("中文 Ελληνικά", false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "中文".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 23,
+                position: 1,
+                text: "Ελληνικά".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
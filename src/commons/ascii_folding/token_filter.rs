@@ -0,0 +1,72 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::ASCIIFoldingFilterWrapper;
+
+/// This [TokenFilter] converts alphabetic, numeric, and symbolic Unicode characters which are
+/// not in the Basic Latin block into their ASCII equivalents, if one exists. It is an equivalent of
+/// [Lucene's ASCIIFoldingFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/miscellaneous/ASCIIFoldingFilter.html)
+/// that doesn't need ICU.
+///
+/// A character can be expanded into several ones, for example `ß` becomes `ss` and `Æ` becomes
+/// `AE`. Offsets stay those of the original token.
+///
+/// When `preserve_original` is `true`, the original token is also emitted, at the same position,
+/// after the folded one if folding changed it.
+/// ```rust
+/// use tantivy_analysis_contrib::commons::ASCIIFoldingTokenFilter;
+///
+/// let filter = ASCIIFoldingTokenFilter::new(false);
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::ASCIIFoldingTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(ASCIIFoldingTokenFilter::new(true))
+///    .build();
+/// let mut token_stream = tmp.token_stream("Straße café");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Strasse".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Straße".to_string());
+/// assert_eq!(token.position, 0);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "cafe".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "café".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ASCIIFoldingTokenFilter {
+    /// Also emit the original token when folding changed it.
+    pub preserve_original: bool,
+}
+
+impl ASCIIFoldingTokenFilter {
+    /// Get a new token filter.
+    /// # Parameters :
+    /// * preserve_original : also emit the original token, at the same position, if
+    ///   folding changed it
+    pub fn new(preserve_original: bool) -> Self {
+        ASCIIFoldingTokenFilter { preserve_original }
+    }
+}
+
+impl TokenFilter for ASCIIFoldingTokenFilter {
+    type Tokenizer<T: Tokenizer> = ASCIIFoldingFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        ASCIIFoldingFilterWrapper::new(token_stream, self.preserve_original)
+    }
+}
@@ -0,0 +1,55 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::mem;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::mapping::fold_to_ascii;
+
+#[derive(Clone, Debug)]
+pub struct ASCIIFoldingTokenStream<T> {
+    tail: T,
+    preserve_original: bool,
+    original: Option<String>,
+}
+
+impl<T> ASCIIFoldingTokenStream<T> {
+    pub(crate) fn new(tail: T, preserve_original: bool) -> Self {
+        Self {
+            tail,
+            preserve_original,
+            original: None,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for ASCIIFoldingTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if let Some(original) = self.original.take() {
+            self.tail.token_mut().text = original;
+            return true;
+        }
+
+        if !self.tail.advance() {
+            return false;
+        }
+
+        if let Some(folded) = fold_to_ascii(&self.tail.token().text) {
+            let original = mem::replace(&mut self.tail.token_mut().text, folded);
+            if self.preserve_original {
+                self.original = Some(original);
+            }
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
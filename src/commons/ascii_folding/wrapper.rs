@@ -0,0 +1,30 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::ASCIIFoldingTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct ASCIIFoldingFilterWrapper<T> {
+    preserve_original: bool,
+    inner: T,
+}
+
+impl<T> ASCIIFoldingFilterWrapper<T> {
+    pub(crate) fn new(inner: T, preserve_original: bool) -> Self {
+        Self {
+            preserve_original,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for ASCIIFoldingFilterWrapper<T> {
+    type TokenStream<'a> = ASCIIFoldingTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        ASCIIFoldingTokenStream::new(self.inner.token_stream(text), self.preserve_original)
+    }
+}
@@ -0,0 +1,112 @@
+pub use token_filter::BomStripTokenFilter;
+use token_stream::BomStripTokenStream;
+use wrapper::BomStripFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, filter: BomStripTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_leading_bom() {
+        let result = token_stream_helper(
+            "\u{FEFF}first \u{FEFF}second thi\u{FEFF}rd",
+            BomStripTokenFilter::default(),
+        );
+        let expected = vec![
+            Token {
+                offset_from: 3,
+                offset_to: 8,
+                position: 0,
+                text: "first".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 18,
+                position: 1,
+                text: "\u{FEFF}second".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 19,
+                offset_to: 27,
+                position: 2,
+                text: "thi\u{FEFF}rd".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_no_bom() {
+        let result = token_stream_helper("first second", BomStripTokenFilter::default());
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "first".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 12,
+                position: 1,
+                text: "second".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_only_bom() {
+        let result = token_stream_helper("\u{FEFF} first", BomStripTokenFilter::default());
+        let expected = vec![Token {
+            offset_from: 4,
+            offset_to: 9,
+            position: 1,
+            text: "first".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_configured_chars() {
+        let result = token_stream_helper(
+            "\u{200B}\u{FEFF}\u{200B}first",
+            BomStripTokenFilter::new(['\u{FEFF}', '\u{200B}']),
+        );
+        let expected = vec![Token {
+            offset_from: 9,
+            offset_to: 14,
+            position: 0,
+            text: "first".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+}
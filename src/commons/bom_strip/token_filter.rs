@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::BomStripFilterWrapper;
+
+/// Byte order mark, also known as zero width no-break space.
+const BOM: char = '\u{FEFF}';
+
+/// This is a [TokenFilter] that removes a byte order mark at the very start of the
+/// first token. Texts read from UTF-8 files with a BOM start with `U+FEFF`, which is not
+/// a whitespace : without this filter, the first indexed term would not match.
+///
+/// Only the leading characters of the first token of each text are removed, characters
+/// inside tokens and following tokens are left untouched. The characters to remove can
+/// be configured with [new](BomStripTokenFilter::new), for example to also remove a
+/// zero width space (`U+200B`) ; the [Default] implementation only removes `U+FEFF`.
+///
+/// Offsets are adjusted to match the stripped text, unless the token's text doesn't
+/// match its offsets anymore (because a previous filter changed it). If the first token
+/// is empty once stripped, it is removed.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::BomStripTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(BomStripTokenFilter::default())
+///    .build();
+/// let mut token_stream = tmp.token_stream("\u{FEFF}Hello world");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Hello".to_string());
+/// assert_eq!(token.offset_from, 3);
+/// assert_eq!(token.offset_to, 8);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "world".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BomStripTokenFilter {
+    chars: Arc<[char]>,
+}
+
+impl BomStripTokenFilter {
+    /// Construct a [BomStripTokenFilter] that removes the given characters at the start of
+    /// the first token.
+    /// # Parameters :
+    /// * `chars`: characters to remove, in any order
+    pub fn new(chars: impl IntoIterator<Item = char>) -> Self {
+        Self {
+            chars: chars.into_iter().collect(),
+        }
+    }
+}
+
+impl Default for BomStripTokenFilter {
+    /// Construct a [BomStripTokenFilter] that removes the byte order mark `U+FEFF`.
+    fn default() -> Self {
+        Self::new([BOM])
+    }
+}
+
+impl TokenFilter for BomStripTokenFilter {
+    type Tokenizer<T: Tokenizer> = BomStripFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        BomStripFilterWrapper::new(token_stream, self.chars)
+    }
+}
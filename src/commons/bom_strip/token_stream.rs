@@ -0,0 +1,61 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Debug, Clone)]
+pub struct BomStripTokenStream<'a, T> {
+    tail: T,
+    chars: &'a [char],
+    /// Indicates that the next token is the first one.
+    first: bool,
+}
+
+impl<'a, T> BomStripTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, chars: &'a [char]) -> Self {
+        Self {
+            tail,
+            chars,
+            first: true,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for BomStripTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+        if !self.first {
+            return true;
+        }
+        self.first = false;
+
+        let token = self.tail.token_mut();
+        let len = token.text.len();
+        let leading = len - token.text.trim_start_matches(self.chars).len();
+        if leading == 0 {
+            return true;
+        }
+        if leading == len {
+            // Nothing left, the first token is removed
+            return self.tail.advance();
+        }
+
+        // Offsets can only be adjusted if they still match the text
+        if token.offset_to.checked_sub(token.offset_from) == Some(len) {
+            token.offset_from += leading;
+        }
+        token.text.drain(..leading);
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
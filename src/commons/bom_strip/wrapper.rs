@@ -0,0 +1,29 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::BomStripTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct BomStripFilterWrapper<T> {
+    chars: Arc<[char]>,
+    inner: T,
+}
+
+impl<T> BomStripFilterWrapper<T> {
+    pub(crate) fn new(inner: T, chars: Arc<[char]>) -> Self {
+        Self { chars, inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for BomStripFilterWrapper<T> {
+    type TokenStream<'a> = BomStripTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        BomStripTokenStream::new(self.inner.token_stream(text), &self.chars)
+    }
+}
@@ -0,0 +1,113 @@
+pub use token_filter::BoundedDistinctTokenFilter;
+use token_stream::BoundedDistinctTokenStream;
+use wrapper::BoundedDistinctFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, capacity: usize) -> Vec<Token> {
+        let capacity = NonZeroUsize::new(capacity).unwrap();
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(BoundedDistinctTokenFilter::new(capacity))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_dedup_within_window() {
+        let result = token_stream_helper("a b a b c", 2);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 1,
+                position: 0,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 3,
+                position: 1,
+                text: "b".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 9,
+                position: 4,
+                text: "c".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_emitted_again_after_eviction() {
+        // "b" is evicted by "c" because "a" has been used more recently.
+        let result = token_stream_helper("a b a c b", 2);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 1,
+                position: 0,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 3,
+                position: 1,
+                text: "b".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 7,
+                position: 3,
+                text: "c".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 9,
+                position: 4,
+                text: "b".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_capacity_one() {
+        let result = token_stream_helper("a a b a", 1);
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+        assert_eq!(texts, vec!["a", "b", "a"]);
+    }
+
+    #[test]
+    fn test_many_repetitions() {
+        let text = ["a"; 1000].join(" ") + " b a";
+        let result = token_stream_helper(&text, 2);
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+        assert_eq!(texts, vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,69 @@
+use std::num::NonZeroUsize;
+
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::BoundedDistinctFilterWrapper;
+
+/// [TokenFilter] that removes duplicated tokens using a bounded amount of memory.
+///
+/// It remembers the last `capacity` distinct terms it has seen, in a least recently
+/// used set. A token is removed if its text is in this set, and seeing a term again
+/// makes it the most recently used. When a new term doesn't fit in the set, the least
+/// recently used one is forgotten.
+///
+/// Deduplication only happens within this window : a term that has been evicted is
+/// emitted again the next time it appears. Memory doesn't depend on the size of the
+/// text, so it can be used on huge streams.
+///
+/// Removed tokens leave a gap in positions, like stop words.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::num::NonZeroUsize;
+///
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::BoundedDistinctTokenFilter;
+///
+/// let capacity = NonZeroUsize::new(100).expect("Capacity should not be zero");
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(BoundedDistinctTokenFilter::new(capacity))
+///    .build();
+/// let mut token_stream = tmp.token_stream("buy now buy now !");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "buy".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "now".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "!".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BoundedDistinctTokenFilter {
+    capacity: NonZeroUsize,
+}
+
+impl BoundedDistinctTokenFilter {
+    /// Create a new [BoundedDistinctTokenFilter].
+    ///
+    /// # Parameters :
+    /// * capacity : number of distinct terms to remember
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self { capacity }
+    }
+}
+
+impl TokenFilter for BoundedDistinctTokenFilter {
+    type Tokenizer<T: Tokenizer> = BoundedDistinctFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        BoundedDistinctFilterWrapper::new(token_stream, self.capacity)
+    }
+}
@@ -0,0 +1,101 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+
+use rustc_hash::FxHashMap;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+/// Least recently used set of terms.
+///
+/// Each use of a term pushes it, with a new stamp, at the back of `order`. The map
+/// keeps the last stamp of each term, so entries of `order` with an older stamp are
+/// stale and skipped when evicting.
+#[derive(Debug, Clone)]
+struct LruSet {
+    capacity: usize,
+    stamp: u64,
+    terms: FxHashMap<String, u64>,
+    order: VecDeque<(u64, String)>,
+}
+
+impl LruSet {
+    fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity: capacity.get(),
+            stamp: 0,
+            terms: FxHashMap::default(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Mark the term as the most recently used. Returns `true` if it was already in the set.
+    fn touch(&mut self, term: &str) -> bool {
+        self.stamp += 1;
+        let known = match self.terms.get_mut(term) {
+            Some(stamp) => {
+                *stamp = self.stamp;
+                true
+            }
+            None => {
+                self.terms.insert(term.to_string(), self.stamp);
+                false
+            }
+        };
+        self.order.push_back((self.stamp, term.to_string()));
+
+        while self.terms.len() > self.capacity {
+            let Some((stamp, oldest)) = self.order.pop_front() else {
+                break;
+            };
+            if self.terms.get(&oldest) == Some(&stamp) {
+                self.terms.remove(&oldest);
+            }
+        }
+
+        // Repeated terms leave stale entries behind, drop them before they pile up.
+        if self.order.len() > 2 * self.capacity {
+            let terms = &self.terms;
+            self.order
+                .retain(|(stamp, term)| terms.get(term) == Some(stamp));
+        }
+
+        known
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BoundedDistinctTokenStream<T> {
+    tail: T,
+    seen: LruSet,
+}
+
+impl<T> BoundedDistinctTokenStream<T> {
+    pub(crate) fn new(tail: T, capacity: NonZeroUsize) -> Self {
+        Self {
+            tail,
+            seen: LruSet::new(capacity),
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for BoundedDistinctTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            if !self.seen.touch(&self.tail.token().text) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,29 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::num::NonZeroUsize;
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::BoundedDistinctTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct BoundedDistinctFilterWrapper<T> {
+    inner: T,
+    capacity: NonZeroUsize,
+}
+
+impl<T> BoundedDistinctFilterWrapper<T> {
+    pub(crate) fn new(inner: T, capacity: NonZeroUsize) -> Self {
+        Self { inner, capacity }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for BoundedDistinctFilterWrapper<T> {
+    type TokenStream<'a> = BoundedDistinctTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        BoundedDistinctTokenStream::new(self.inner.token_stream(text), self.capacity)
+    }
+}
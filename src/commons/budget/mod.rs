@@ -0,0 +1,125 @@
+pub use token_filter::BudgetTokenFilter;
+use token_filter::OnTruncation;
+use token_stream::BudgetTokenStream;
+use wrapper::BudgetFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use std::sync::{Arc, Mutex};
+
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::NGramTokenFilter;
+
+    fn texts(a: &mut TextAnalyzer, text: &str) -> Vec<String> {
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.text.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn ngram_analyzer(filter: BudgetTokenFilter) -> TextAnalyzer {
+        let ngram = NGramTokenFilter::new(NonZeroUsize::new(1).unwrap(), None, false).unwrap();
+        TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(ngram)
+            .filter(filter)
+            .build()
+    }
+
+    /// Filter that records the texts it truncates.
+    fn reporting(filter: BudgetTokenFilter) -> (BudgetTokenFilter, Arc<Mutex<Vec<String>>>) {
+        let truncated = Arc::new(Mutex::new(vec![]));
+        let report = Arc::clone(&truncated);
+        let filter =
+            filter.on_truncation(move |text| report.lock().unwrap().push(text.to_string()));
+        (filter, truncated)
+    }
+
+    #[test]
+    fn test_within_budget() {
+        let (filter, truncated) = reporting(BudgetTokenFilter::new(6));
+        let mut a = ngram_analyzer(filter);
+
+        // "abc" gives exactly 6 ngrams
+        let result = texts(&mut a, "abc");
+        assert_eq!(result.len(), 6);
+        assert!(truncated.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_max_tokens() {
+        let (filter, truncated) = reporting(BudgetTokenFilter::new(10));
+        let mut a = ngram_analyzer(filter);
+
+        // A long token would give 5050 ngrams
+        let long = "a".repeat(100);
+        let result = texts(&mut a, &long);
+        assert_eq!(result.len(), 10);
+        assert_eq!(*truncated.lock().unwrap(), vec![long.clone()]);
+
+        // Only truncated texts are reported
+        let result = texts(&mut a, "ab");
+        assert_eq!(result, vec!["a", "b", "ab"]);
+        assert_eq!(*truncated.lock().unwrap(), vec![long]);
+    }
+
+    #[test]
+    fn test_max_bytes() {
+        let (filter, truncated) = reporting(BudgetTokenFilter::new(usize::MAX).with_max_bytes(10));
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        // "abc" and "def" use 6 bytes, "ghijk" doesn't fit
+        let result = texts(&mut a, "abc def ghijk l");
+        assert_eq!(result, vec!["abc", "def"]);
+
+        let result = texts(&mut a, "abc def ghij");
+        assert_eq!(result, vec!["abc", "def", "ghij"]);
+        assert_eq!(*truncated.lock().unwrap(), vec!["abc def ghijk l"]);
+    }
+
+    #[test]
+    fn test_analyzer_clones() {
+        let (filter, truncated) = reporting(BudgetTokenFilter::new(2));
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+        let mut b = a.clone();
+
+        // Streams of both clones are consumed at the same time, each one reports
+        // its own text
+        let mut long = a.token_stream("one two three");
+        let mut short = b.token_stream("four five");
+        let mut tokens = vec![];
+        while long.advance() | short.advance() {
+            tokens.push((long.token().text.clone(), short.token().text.clone()));
+        }
+        drop((long, short));
+        assert_eq!(
+            tokens,
+            vec![
+                ("one".to_string(), "four".to_string()),
+                ("two".to_string(), "five".to_string())
+            ]
+        );
+        assert_eq!(*truncated.lock().unwrap(), vec!["one two three"]);
+
+        let handle = std::thread::spawn(move || texts(&mut b, "six seven eight"));
+        assert_eq!(handle.join().unwrap(), vec!["six", "seven"]);
+        assert_eq!(
+            *truncated.lock().unwrap(),
+            vec!["one two three", "six seven eight"]
+        );
+    }
+}
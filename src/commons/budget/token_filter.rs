@@ -0,0 +1,110 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::BudgetFilterWrapper;
+
+/// Function called by a [BudgetTokenFilter] when it cuts off a token stream.
+#[derive(Clone)]
+pub(crate) struct OnTruncation(Arc<dyn Fn(&str) + Send + Sync>);
+
+impl OnTruncation {
+    pub(crate) fn call(&self, text: &str) {
+        (self.0)(text)
+    }
+}
+
+impl Debug for OnTruncation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("OnTruncation")
+    }
+}
+
+/// [TokenFilter] that guards indexing against pathological inputs, such as a huge token
+/// fed into an n-gram expansion. It stops the token stream as soon as the number of
+/// emitted tokens or the total size of their text, in bytes, would exceed the budget.
+///
+/// As token streams are lazy, stopping early also stops the work of the previous
+/// components, so this filter should be the last one of the analysis chain. Unlike the
+/// [LimitTokenCountFilter](crate::commons::LimitTokenCountFilter), it can report each
+/// truncated text with [on_truncation](BudgetTokenFilter::on_truncation), to log or
+/// reject the document.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::sync::{Arc, Mutex};
+///
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::BudgetTokenFilter;
+///
+/// let truncated = Arc::new(Mutex::new(vec![]));
+/// let report = truncated.clone();
+/// let filter = BudgetTokenFilter::new(2)
+///     .on_truncation(move |text| report.lock().unwrap().push(text.to_string()));
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("one two three");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "one".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "two".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// assert_eq!(*truncated.lock().unwrap(), vec!["one two three"]);
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BudgetTokenFilter {
+    max_tokens: usize,
+    max_bytes: Option<usize>,
+    on_truncation: Option<OnTruncation>,
+}
+
+impl BudgetTokenFilter {
+    /// Create a new [BudgetTokenFilter] that emits at most `max_tokens` tokens.
+    pub fn new(max_tokens: usize) -> Self {
+        Self {
+            max_tokens,
+            max_bytes: None,
+            on_truncation: None,
+        }
+    }
+
+    /// Also limit the total size, in bytes, of the emitted tokens' text. A token that
+    /// would exceed it isn't emitted and ends the stream.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Call `on_truncation` with the analyzed text each time a token stream is cut off.
+    /// It's called by the thread that consumes the stream, once per truncated text, so
+    /// clones of the filter used by different threads report their own texts.
+    pub fn on_truncation<F>(mut self, on_truncation: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_truncation = Some(OnTruncation(Arc::new(on_truncation)));
+        self
+    }
+}
+
+impl TokenFilter for BudgetTokenFilter {
+    type Tokenizer<T: Tokenizer> = BudgetFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        BudgetFilterWrapper::new(
+            tokenizer,
+            self.max_tokens,
+            self.max_bytes,
+            self.on_truncation,
+        )
+    }
+}
@@ -0,0 +1,75 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::OnTruncation;
+
+#[derive(Debug, Clone)]
+pub struct BudgetTokenStream<'a, T> {
+    tail: T,
+    /// Number of tokens that can still be emitted
+    tokens: usize,
+    /// Number of bytes that can still be emitted, [None] if there is no limit
+    bytes: Option<usize>,
+    /// Called with the analyzed text if the stream is cut off
+    on_truncation: Option<(&'a OnTruncation, &'a str)>,
+    /// The budget is exhausted
+    done: bool,
+}
+
+impl<'a, T> BudgetTokenStream<'a, T> {
+    pub(crate) fn new(
+        tail: T,
+        tokens: usize,
+        bytes: Option<usize>,
+        on_truncation: Option<(&'a OnTruncation, &'a str)>,
+    ) -> Self {
+        Self {
+            tail,
+            tokens,
+            bytes,
+            on_truncation,
+            done: false,
+        }
+    }
+
+    fn truncate(&mut self) -> bool {
+        self.done = true;
+        if let Some((on_truncation, text)) = self.on_truncation {
+            on_truncation.call(text);
+        }
+        false
+    }
+}
+
+impl<T: TokenStream> TokenStream for BudgetTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if self.done || !self.tail.advance() {
+            return false;
+        }
+
+        // There is one more token, it's truncated if it doesn't fit in the budget
+        if self.tokens == 0 {
+            return self.truncate();
+        }
+        if let Some(bytes) = self.bytes.as_mut() {
+            let length = self.tail.token().text.len();
+            if length > *bytes {
+                return self.truncate();
+            }
+            *bytes -= length;
+        }
+        self.tokens -= 1;
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
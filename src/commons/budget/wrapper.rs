@@ -0,0 +1,46 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{BudgetTokenStream, OnTruncation};
+
+#[derive(Clone, Debug)]
+pub struct BudgetFilterWrapper<T> {
+    max_tokens: usize,
+    max_bytes: Option<usize>,
+    on_truncation: Option<OnTruncation>,
+    inner: T,
+}
+
+impl<T> BudgetFilterWrapper<T> {
+    pub(crate) fn new(
+        inner: T,
+        max_tokens: usize,
+        max_bytes: Option<usize>,
+        on_truncation: Option<OnTruncation>,
+    ) -> Self {
+        Self {
+            max_tokens,
+            max_bytes,
+            on_truncation,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for BudgetFilterWrapper<T> {
+    type TokenStream<'a> = BudgetTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        BudgetTokenStream::new(
+            self.inner.token_stream(text),
+            self.max_tokens,
+            self.max_bytes,
+            self.on_truncation
+                .as_ref()
+                .map(|on_truncation| (on_truncation, text)),
+        )
+    }
+}
@@ -0,0 +1,149 @@
+pub use token_filter::{CapitalizationTokenFilter, CapitalizationTokenFilterBuilder};
+use token_stream::CapitalizationTokenStream;
+use wrapper::CapitalizationFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::build_set;
+
+    fn token_stream_helper(text: &str, filter: CapitalizationTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn raw_texts(text: &str, filter: CapitalizationTokenFilter) -> Vec<String> {
+        let mut a = TextAnalyzer::builder(RawTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut texts = vec![];
+        while let Some(token) = token_stream.next() {
+            texts.push(token.text.clone());
+        }
+        texts
+    }
+
+    #[test]
+    fn test_default() {
+        let result = token_stream_helper("kiTTEN élan", CapitalizationTokenFilter::default());
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "Kitten".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 12,
+                position: 1,
+                text: "Élan".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_words_of_token() {
+        let result = raw_texts("hello  WORLD ", CapitalizationTokenFilter::default());
+        assert_eq!(result, vec!["Hello  World "]);
+    }
+
+    #[test]
+    fn test_on_first_token_only() -> Result<(), Box<dyn std::error::Error>> {
+        let filter = CapitalizationTokenFilterBuilder::default()
+            .on_first_token_only(true)
+            .build()?;
+        let result: Vec<String> = token_stream_helper("the LORD of the Rings", filter)
+            .into_iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(result, vec!["The", "lord", "of", "the", "rings"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_keep_words() -> Result<(), Box<dyn std::error::Error>> {
+        let filter = CapitalizationTokenFilterBuilder::default()
+            .keep_words(build_set(vec!["McDonald", "and", "iPhone"])?)
+            .build()?;
+        let result = raw_texts("mcdonald and McDonald AND iPhone", filter.clone());
+        assert_eq!(result, vec!["Mcdonald and McDonald And iPhone"]);
+
+        // The first letter of the first word is forced
+        let result = raw_texts("iPhone and McDonald", filter);
+        assert_eq!(result, vec!["IPhone and McDonald"]);
+
+        let filter = CapitalizationTokenFilterBuilder::default()
+            .keep_words(build_set(vec!["iPhone"])?)
+            .force_first_letter(false)
+            .build()?;
+        let result = raw_texts("iPhone case", filter);
+        assert_eq!(result, vec!["iPhone Case"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_keep_words_on_first_token_only() -> Result<(), Box<dyn std::error::Error>> {
+        let filter = CapitalizationTokenFilterBuilder::default()
+            .on_first_token_only(true)
+            .keep_words(build_set(vec!["McDonald"])?)
+            .build()?;
+        let result: Vec<String> = token_stream_helper("old McDonald FARM", filter)
+            .into_iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(result, vec!["Old", "McDonald", "farm"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_word_length() -> Result<(), Box<dyn std::error::Error>> {
+        let filter = CapitalizationTokenFilterBuilder::default()
+            .min_word_length(3_usize)
+            .force_first_letter(false)
+            .build()?;
+        let result = raw_texts("of MICE and mE", filter);
+        assert_eq!(result, vec!["of Mice And mE"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_word_count() -> Result<(), Box<dyn std::error::Error>> {
+        let filter = CapitalizationTokenFilterBuilder::default()
+            .max_word_count(2_usize)
+            .build()?;
+        let result = raw_texts("hello world", filter.clone());
+        assert_eq!(result, vec!["Hello World"]);
+
+        let result = raw_texts("hello big world", filter);
+        assert_eq!(result, vec!["hello big world"]);
+
+        Ok(())
+    }
+}
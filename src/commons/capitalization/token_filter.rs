@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use fst::Set;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::CapitalizationFilterWrapper;
+
+/// A token filter that capitalizes words : their first letter is uppercased and the other
+/// letters are lowercased. It is modeled on
+/// [Lucene's CapitalizationFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/miscellaneous/CapitalizationFilter.html)
+/// and is mostly useful to display values, for example facets built with a keyword tokenizer.
+///
+/// Words are the whitespace separated parts of a token, separators are kept as is. They
+/// are processed as follows :
+/// * words in `keep_words` are left untouched (except for `force_first_letter`),
+/// * words shorter than `min_word_length` characters are left untouched,
+/// * the other words are capitalized.
+///
+/// Tokens with more than `max_word_count` words are left untouched.
+///
+/// If `on_first_token_only` is `true`, only the first token of the stream is capitalized,
+/// words of the following tokens are lowercased (keep words are still left untouched).
+///
+/// Use [CapitalizationTokenFilterBuilder] to configure it, the [Default] implementation
+/// capitalizes every word and forces the first letter of keep words.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::{build_set, CapitalizationTokenFilterBuilder};
+///
+/// let filter = CapitalizationTokenFilterBuilder::default()
+///     .keep_words(build_set(vec!["McDonald", "of"])?)
+///     .min_word_length(2_usize)
+///     .build()?;
+/// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("the OLD McDonald of a farm");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "The Old McDonald of a Farm".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Builder)]
+#[builder(setter(into), default)]
+pub struct CapitalizationTokenFilter {
+    /// Only capitalize the first token of the stream, the following ones are lowercased.
+    #[builder(default = "false")]
+    pub on_first_token_only: bool,
+    /// Words that are left untouched.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub keep_words: Option<Arc<Set<Vec<u8>>>>,
+    /// Words with fewer characters are left untouched.
+    #[builder(default = "0")]
+    pub min_word_length: usize,
+    /// Tokens with more words are left untouched. `None` means no limit.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub max_word_count: Option<usize>,
+    /// Uppercase the first letter of the first word of a token even if it is a keep word
+    /// or a word shorter than `min_word_length`.
+    #[builder(default = "true")]
+    pub force_first_letter: bool,
+}
+
+impl Default for CapitalizationTokenFilter {
+    /// Construct a [CapitalizationTokenFilter] that capitalizes every word of every token
+    /// and forces the first letter of the tokens.
+    fn default() -> Self {
+        CapitalizationTokenFilter {
+            on_first_token_only: false,
+            keep_words: None,
+            min_word_length: 0,
+            max_word_count: None,
+            force_first_letter: true,
+        }
+    }
+}
+
+impl TokenFilter for CapitalizationTokenFilter {
+    type Tokenizer<T: Tokenizer> = CapitalizationFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        CapitalizationFilterWrapper::new(tokenizer, self)
+    }
+}
@@ -0,0 +1,119 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::CapitalizationTokenFilter;
+
+/// Uppercase the first letter of the word and lowercase the others if `capitalize`,
+/// otherwise lowercase the whole word.
+fn push_word(result: &mut String, word: &str, capitalize: bool) {
+    let mut chars = word.chars();
+    if capitalize {
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+        }
+    }
+    result.push_str(&chars.as_str().to_lowercase());
+}
+
+/// Uppercase the first letter of the word and keep the others.
+fn push_force_first_letter(result: &mut String, word: &str) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        result.extend(first.to_uppercase());
+    }
+    result.push_str(chars.as_str());
+}
+
+#[derive(Debug)]
+pub struct CapitalizationTokenStream<'a, T> {
+    tail: T,
+    config: &'a CapitalizationTokenFilter,
+    /// Indicates that the first token of the stream has been processed.
+    first_done: bool,
+}
+
+impl<'a, T> CapitalizationTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, config: &'a CapitalizationTokenFilter) -> Self {
+        Self {
+            tail,
+            config,
+            first_done: false,
+        }
+    }
+
+    fn is_keep_word(&self, word: &str) -> bool {
+        self.config
+            .keep_words
+            .as_ref()
+            .is_some_and(|keep_words| keep_words.contains(word))
+    }
+
+    /// Capitalize the words of the text, or lowercase them if `capitalize` is `false`.
+    /// Returns `None` if the text is left untouched.
+    fn capitalize(&self, text: &str, capitalize: bool) -> Option<String> {
+        if let Some(max_word_count) = self.config.max_word_count {
+            if text.split_whitespace().count() > max_word_count {
+                return None;
+            }
+        }
+
+        let mut result = String::with_capacity(text.len());
+        let mut first_word = true;
+        let mut rest = text;
+        while !rest.is_empty() {
+            // Separators are copied as is
+            let start = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let word = &rest[..end];
+            rest = &rest[end..];
+            if word.is_empty() {
+                continue;
+            }
+
+            let untouched = self.is_keep_word(word)
+                || (capitalize && word.chars().count() < self.config.min_word_length);
+            if untouched {
+                if first_word && capitalize && self.config.force_first_letter {
+                    push_force_first_letter(&mut result, word);
+                } else {
+                    result.push_str(word);
+                }
+            } else {
+                push_word(&mut result, word, capitalize);
+            }
+            first_word = false;
+        }
+
+        Some(result)
+    }
+}
+
+impl<T: TokenStream> TokenStream for CapitalizationTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let capitalize = !self.config.on_first_token_only || !self.first_done;
+        self.first_done = true;
+        if let Some(text) = self.capitalize(&self.tail.token().text, capitalize) {
+            self.tail.token_mut().text = text;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,27 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{CapitalizationTokenFilter, CapitalizationTokenStream};
+
+#[derive(Clone, Debug)]
+pub struct CapitalizationFilterWrapper<T> {
+    config: CapitalizationTokenFilter,
+    inner: T,
+}
+
+impl<T> CapitalizationFilterWrapper<T> {
+    pub(crate) fn new(inner: T, config: CapitalizationTokenFilter) -> Self {
+        Self { config, inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for CapitalizationFilterWrapper<T> {
+    type TokenStream<'a> = CapitalizationTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        CapitalizationTokenStream::new(self.inner.token_stream(text), &self.config)
+    }
+}
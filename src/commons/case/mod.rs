@@ -0,0 +1,92 @@
+pub use token_filter::{LowerCaseTokenFilter, UpperCaseTokenFilter};
+use token_stream::CaseTokenStream;
+use wrapper::CaseFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+/// Case to convert tokens to.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Case {
+    Lower,
+    Upper,
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, TokenFilter, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper<F: TokenFilter>(text: &str, filter: F) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn texts(tokens: Vec<Token>) -> Vec<String> {
+        tokens.into_iter().map(|token| token.text).collect()
+    }
+
+    #[test]
+    fn test_lower_german() {
+        let result = token_stream_helper("STRAßE Größe ẞ", LowerCaseTokenFilter);
+        assert_eq!(texts(result), vec!["straße", "größe", "ß"]);
+    }
+
+    #[test]
+    fn test_upper_german() {
+        let result = token_stream_helper("Straße", UpperCaseTokenFilter);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 7,
+            position: 0,
+            text: "STRASSE".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_lower_greek() {
+        // Final sigma depends on its position in the word
+        let result = token_stream_helper("ΟΔΟΣ ΣΟΦΟΣ Σ", LowerCaseTokenFilter);
+        assert_eq!(texts(result), vec!["οδος", "σοφος", "σ"]);
+    }
+
+    #[test]
+    fn test_upper_greek() {
+        let result = token_stream_helper("σοφός", UpperCaseTokenFilter);
+        assert_eq!(texts(result), vec!["ΣΟΦΌΣ"]);
+    }
+
+    #[test]
+    fn test_turkish_is_locale_insensitive() {
+        // Dotted capital I gets a combining dot, dotless i stays dotless,
+        // ASCII I and i are converted as in English.
+        let result = token_stream_helper("İSTANBUL ılık Iğdır", LowerCaseTokenFilter);
+        assert_eq!(texts(result), vec!["i\u{307}stanbul", "ılık", "iğdır"]);
+
+        let result = token_stream_helper("istanbul ılık", UpperCaseTokenFilter);
+        assert_eq!(texts(result), vec!["ISTANBUL", "ILIK"]);
+    }
+
+    #[test]
+    fn test_ascii() {
+        let result = token_stream_helper("Hello WORLD", LowerCaseTokenFilter);
+        assert_eq!(texts(result), vec!["hello", "world"]);
+
+        let result = token_stream_helper("Hello world", UpperCaseTokenFilter);
+        assert_eq!(texts(result), vec!["HELLO", "WORLD"]);
+    }
+}
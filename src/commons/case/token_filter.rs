@@ -0,0 +1,85 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::{Case, CaseFilterWrapper};
+
+/// This [TokenFilter] lowercases tokens using Unicode rules.
+///
+/// It uses [str::to_lowercase], so it handles the Greek final sigma : `ΟΔΟΣ` becomes
+/// `οδος`. It is not language sensitive, `I` always becomes `i`, even for Turkish or
+/// Azeri.
+///
+/// Lowercasing is not case folding, like ICU does (for example with the
+/// `ICUNormalizer2TokenFilter` and the `NFKCCasefold` mode). Case folding is meant to compare strings : `ß` is folded
+/// to `ss` and `ς` to `σ`, while they are kept when lowercasing.
+///
+/// Offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::LowerCaseTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(LowerCaseTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("STRAßE ΟΔΟΣ");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "straße".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "οδος".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LowerCaseTokenFilter;
+
+impl TokenFilter for LowerCaseTokenFilter {
+    type Tokenizer<T: Tokenizer> = CaseFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        CaseFilterWrapper::new(token_stream, Case::Lower)
+    }
+}
+
+/// This [TokenFilter] uppercases tokens using Unicode rules.
+///
+/// It uses [str::to_uppercase], so special casing applies : `ß` becomes `SS`. It is not
+/// language sensitive, `i` always becomes `I`, even for Turkish or Azeri.
+///
+/// Offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::UpperCaseTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(UpperCaseTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("Straße");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "STRASSE".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UpperCaseTokenFilter;
+
+impl TokenFilter for UpperCaseTokenFilter {
+    type Tokenizer<T: Tokenizer> = CaseFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        CaseFilterWrapper::new(token_stream, Case::Upper)
+    }
+}
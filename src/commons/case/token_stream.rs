@@ -0,0 +1,45 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::Case;
+
+#[derive(Debug, Clone)]
+pub struct CaseTokenStream<T> {
+    tail: T,
+    case: Case,
+}
+
+impl<T> CaseTokenStream<T> {
+    pub(crate) fn new(tail: T, case: Case) -> Self {
+        Self { tail, case }
+    }
+}
+
+impl<T: TokenStream> TokenStream for CaseTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let text = &mut self.tail.token_mut().text;
+        // ASCII text can be changed in place
+        match (self.case, text.is_ascii()) {
+            (Case::Lower, true) => text.make_ascii_lowercase(),
+            (Case::Upper, true) => text.make_ascii_uppercase(),
+            (Case::Lower, false) => *text = text.to_lowercase(),
+            (Case::Upper, false) => *text = text.to_uppercase(),
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,27 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{Case, CaseTokenStream};
+
+#[derive(Clone, Debug)]
+pub struct CaseFilterWrapper<T> {
+    inner: T,
+    case: Case,
+}
+
+impl<T> CaseFilterWrapper<T> {
+    pub(crate) fn new(inner: T, case: Case) -> Self {
+        Self { inner, case }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for CaseFilterWrapper<T> {
+    type TokenStream<'a> = CaseTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        CaseTokenStream::new(self.inner.token_stream(text), self.case)
+    }
+}
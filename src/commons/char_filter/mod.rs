@@ -0,0 +1,116 @@
+use token_stream::CharFilterTokenStream;
+pub use tokenizer::{CharFilter, CharFilterTokenizer};
+
+mod token_stream;
+mod tokenizer;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{Token, TokenStream, Tokenizer, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::{HtmlStripRewriter, MappingRewriter};
+
+    fn tokenize_all(
+        text: &str,
+        mut tokenizer: CharFilterTokenizer<WhitespaceTokenizer>,
+    ) -> Vec<Token> {
+        let mut result: Vec<Token> = Vec::new();
+
+        let mut tokenizer = tokenizer.token_stream(text);
+        while tokenizer.advance() {
+            result.push(tokenizer.token().clone());
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_no_char_filter() {
+        let tokenizer = CharFilterTokenizer::new(WhitespaceTokenizer::default(), vec![]);
+        let result = tokenize_all("hello world", tokenizer);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "hello".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 11,
+                position: 1,
+                text: "world".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_chained_char_filters() {
+        let tokenizer = CharFilterTokenizer::new(
+            WhitespaceTokenizer::default(),
+            vec![
+                HtmlStripRewriter.into(),
+                MappingRewriter::new([("&", " and "), ("œ", "oe")]).into(),
+            ],
+        );
+        let text = "<p>Bœuf&amp;<b>frites</b></p>";
+        let result = tokenize_all(text, tokenizer);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 3,
+                offset_to: 8,
+                position: 0,
+                text: "Boeuf".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 13,
+                position: 1,
+                text: "and".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 22,
+                position: 2,
+                text: "frites".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+        assert_eq!(&text[3..8], "Bœuf");
+        assert_eq!(&text[8..13], "&amp;");
+    }
+
+    #[test]
+    fn test_reused_tokenizer() {
+        let mut tokenizer = CharFilterTokenizer::new(
+            WhitespaceTokenizer::default(),
+            vec![MappingRewriter::new([("ß", "ss")]).into()],
+        );
+        let _ = tokenize_all("a ß b", tokenizer.clone());
+
+        let mut token_stream = tokenizer.token_stream("Straße x");
+        assert!(token_stream.advance());
+        assert_eq!(token_stream.token().text, "Strasse");
+        assert_eq!(token_stream.token().offset_to, 7);
+        assert!(token_stream.advance());
+        assert_eq!(token_stream.token().text, "x");
+        assert_eq!(token_stream.token().offset_from, 8);
+        assert!(!token_stream.advance());
+
+        // The text of the previous stream is not kept
+        let mut token_stream = tokenizer.token_stream("ß");
+        assert!(token_stream.advance());
+        assert_eq!(token_stream.token().text, "ss");
+        assert_eq!(token_stream.token().offset_to, 2);
+        assert!(!token_stream.advance());
+    }
+}
@@ -0,0 +1,43 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use crate::commons::OffsetMap;
+
+#[derive(Debug)]
+pub struct CharFilterTokenStream<'a, T> {
+    tail: T,
+    /// Offset maps of the char filters, in the order they were applied
+    offsets: &'a [OffsetMap],
+}
+
+impl<'a, T> CharFilterTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, offsets: &'a [OffsetMap]) -> Self {
+        Self { tail, offsets }
+    }
+}
+
+impl<T: TokenStream> TokenStream for CharFilterTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        // The last char filter rewrote the text given to the tokenizer, its offsets
+        // are corrected first
+        for offsets in self.offsets.iter().rev() {
+            offsets.correct_token(self.tail.token_mut());
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,115 @@
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::CharFilterTokenStream;
+use crate::commons::{HtmlStripRewriter, MappingRewriter, OffsetMap};
+
+/// A rewriter applied on the text before it is tokenized, like Lucene's char filters.
+#[derive(Clone, Debug)]
+pub enum CharFilter {
+    /// Replace sequences of characters, see [MappingRewriter].
+    Mapping(MappingRewriter),
+    /// Remove HTML markup, see [HtmlStripRewriter].
+    HtmlStrip(HtmlStripRewriter),
+}
+
+impl CharFilter {
+    /// Rewrite `text`, see [MappingRewriter::rewrite] and [HtmlStripRewriter::rewrite].
+    pub fn rewrite(&self, text: &str) -> (String, OffsetMap) {
+        match self {
+            CharFilter::Mapping(rewriter) => rewriter.rewrite(text),
+            CharFilter::HtmlStrip(rewriter) => rewriter.rewrite(text),
+        }
+    }
+}
+
+impl From<MappingRewriter> for CharFilter {
+    fn from(rewriter: MappingRewriter) -> Self {
+        CharFilter::Mapping(rewriter)
+    }
+}
+
+impl From<HtmlStripRewriter> for CharFilter {
+    fn from(rewriter: HtmlStripRewriter) -> Self {
+        CharFilter::HtmlStrip(rewriter)
+    }
+}
+
+/// [Tokenizer] that rewrites the text with [CharFilter]s, in order, before giving it
+/// to another tokenizer. Offsets of the tokens are corrected so that they point to the
+/// original text, as if the text was tokenized directly.
+///
+/// As it is a [Tokenizer], it can be the tokenizer of a tantivy `TextAnalyzer` and
+/// char filters are applied when documents are indexed.
+///
+/// # Example
+///
+/// ```rust
+/// use tantivy::tokenizer::{SimpleTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::{CharFilterTokenizer, HtmlStripRewriter, MappingRewriter};
+///
+/// let tokenizer = CharFilterTokenizer::new(
+///     SimpleTokenizer::default(),
+///     vec![
+///         HtmlStripRewriter.into(),
+///         MappingRewriter::new([("&", " and ")]).into(),
+///     ],
+/// );
+/// let mut analyzer = TextAnalyzer::builder(tokenizer).build();
+/// let text = "<b>salt</b>&amp;pepper";
+/// let mut token_stream = analyzer.token_stream(text);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "salt".to_string());
+/// assert_eq!(&text[token.offset_from..token.offset_to], "salt");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "and".to_string());
+/// assert_eq!(&text[token.offset_from..token.offset_to], "&amp;");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "pepper".to_string());
+/// assert_eq!(&text[token.offset_from..token.offset_to], "pepper");
+///
+/// assert_eq!(None, token_stream.next());
+/// ```
+#[derive(Clone, Debug)]
+pub struct CharFilterTokenizer<T> {
+    char_filters: Vec<CharFilter>,
+    inner: T,
+    // Rewritten text of the current token stream, the stream borrows it
+    text: String,
+    // Offset maps of each char filter for the current token stream
+    offsets: Vec<OffsetMap>,
+}
+
+impl<T> CharFilterTokenizer<T> {
+    /// Construct a new [CharFilterTokenizer].
+    /// # Parameters :
+    /// * `inner`: tokenizer of the rewritten text
+    /// * `char_filters`: char filters, applied in order
+    pub fn new(inner: T, char_filters: Vec<CharFilter>) -> Self {
+        Self {
+            char_filters,
+            inner,
+            text: String::new(),
+            offsets: Vec::new(),
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for CharFilterTokenizer<T> {
+    type TokenStream<'a> = CharFilterTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        self.offsets.clear();
+        self.text.clear();
+        self.text.push_str(text);
+        for char_filter in &self.char_filters {
+            let (rewritten, offsets) = char_filter.rewrite(&self.text);
+            self.text = rewritten;
+            self.offsets.push(offsets);
+        }
+
+        CharFilterTokenStream::new(self.inner.token_stream(&self.text), &self.offsets)
+    }
+}
@@ -0,0 +1,175 @@
+use token_stream::CharGroupTokenStream;
+pub use tokenizer::*;
+
+mod token_stream;
+mod tokenizer;
+
+const DEFAULT_SPLIT_CHARS: [char; 4] = [' ', '\t', '\r', '\n'];
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+    use super::*;
+
+    fn tokenize_all(text: &str, mut tokenizer: CharGroupTokenizer) -> Vec<Token> {
+        let mut result: Vec<Token> = Vec::new();
+
+        let mut tokenizer = tokenizer.token_stream(text);
+        while tokenizer.advance() {
+            result.push(tokenizer.token().clone());
+        }
+
+        result
+    }
+
+    fn tokenizer(split_chars: &[char], max_token_length: Option<usize>) -> CharGroupTokenizer {
+        CharGroupTokenizerBuilder::default()
+            .split_chars(split_chars.to_vec())
+            .max_token_length(max_token_length)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_several_delimiters() {
+        let result = tokenize_all("a-b_c/d e", tokenizer(&['-', '_', '/', ' '], None));
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 1,
+                position: 0,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 3,
+                position: 1,
+                text: "b".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 5,
+                position: 2,
+                text: "c".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 7,
+                position: 3,
+                text: "d".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 9,
+                position: 4,
+                text: "e".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_consecutive_delimiters() {
+        let result = tokenize_all("--a--b-", tokenizer(&['-'], None));
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 2,
+                offset_to: 3,
+                position: 0,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 6,
+                position: 1,
+                text: "b".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multibyte() {
+        let result = tokenize_all("café·crème", tokenizer(&['·'], None));
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "café".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 13,
+                position: 1,
+                text: "crème".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_max_token_length() {
+        let result = tokenize_all("abcdéfg-h", tokenizer(&['-'], Some(3)));
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "abc".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 3,
+                offset_to: 7,
+                position: 1,
+                text: "déf".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 8,
+                position: 2,
+                text: "g".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 10,
+                position: 3,
+                text: "h".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_default() {
+        let result = tokenize_all(" a\tb\n", CharGroupTokenizer::default());
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+
+        assert_eq!(texts, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_only_delimiters() {
+        let result = tokenize_all("---", tokenizer(&['-'], None));
+
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct CharGroupTokenStream<'a> {
+    text: &'a str,
+    split_chars: &'a [char],
+    max_token_length: Option<usize>,
+    /// Byte offset where the next token is searched.
+    cursor: usize,
+    token: Token,
+}
+
+impl<'a> CharGroupTokenStream<'a> {
+    pub(crate) fn new(
+        text: &'a str,
+        split_chars: &'a [char],
+        max_token_length: Option<usize>,
+    ) -> Self {
+        Self {
+            text,
+            split_chars,
+            max_token_length,
+            cursor: 0,
+            token: Token::default(),
+        }
+    }
+}
+
+impl TokenStream for CharGroupTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        let rest = &self.text[self.cursor..];
+        let Some(start) = rest.find(|c| !self.split_chars.contains(&c)) else {
+            self.cursor = self.text.len();
+            return false;
+        };
+
+        let mut end = rest.len();
+        for (count, (index, c)) in rest[start..].char_indices().enumerate() {
+            if self.split_chars.contains(&c) || Some(count) == self.max_token_length {
+                end = start + index;
+                break;
+            }
+        }
+
+        self.token.text.clear();
+        self.token.text.push_str(&rest[start..end]);
+        self.token.offset_from = self.cursor + start;
+        self.token.offset_to = self.cursor + end;
+        self.token.position = self.token.position.wrapping_add(1);
+        self.cursor += end;
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -0,0 +1,77 @@
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{CharGroupTokenStream, DEFAULT_SPLIT_CHARS};
+
+/// A [Tokenizer] that splits text on any character of a group. It is an equivalent of
+/// [Lucene's CharGroupTokenizer](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/pattern/CharGroupTokenizer.html).
+///
+/// Unlike the [PathTokenizer](crate::commons::PathTokenizer) that only has one delimiter,
+/// several separators can coexist, which is useful for identifiers or log lines.
+/// Consecutive separators don't produce empty tokens. Tokens longer than
+/// `max_token_length` characters are split into several tokens.
+///
+/// To construct a new [CharGroupTokenizer] you should use the [CharGroupTokenizerBuilder]
+/// or the [Default] implementation, that splits on whitespaces.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::CharGroupTokenizerBuilder;
+///
+/// let tokenizer = CharGroupTokenizerBuilder::default()
+///     .split_chars(vec!['-', '_', '/', ' '])
+///     .build()?;
+///
+/// let mut tmp = TextAnalyzer::builder(tokenizer).build();
+/// let mut token_stream = tmp.token_stream("user_id/2024-01 ok");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "user".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "id".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "2024".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "01".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ok".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Builder)]
+#[builder(setter(into), default)]
+pub struct CharGroupTokenizer {
+    /// Characters to split on.
+    #[builder(default = "DEFAULT_SPLIT_CHARS.to_vec()")]
+    pub split_chars: Vec<char>,
+    /// Maximum length of tokens, in characters. Longer tokens are split.
+    /// If [None], tokens are not limited.
+    pub max_token_length: Option<usize>,
+}
+
+impl Default for CharGroupTokenizer {
+    /// Construct a [CharGroupTokenizer] that splits on whitespaces,
+    /// without limit of length.
+    fn default() -> Self {
+        CharGroupTokenizer {
+            split_chars: DEFAULT_SPLIT_CHARS.to_vec(),
+            max_token_length: None,
+        }
+    }
+}
+
+impl Tokenizer for CharGroupTokenizer {
+    type TokenStream<'a> = CharGroupTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        CharGroupTokenStream::new(text, &self.split_chars, self.max_token_length)
+    }
+}
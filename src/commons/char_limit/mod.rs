@@ -0,0 +1,164 @@
+pub use token_filter::CharLimitTokenFilter;
+use token_stream::CharLimitTokenStream;
+use wrapper::CharLimitFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::LimitTokenCountFilter;
+
+    fn token_stream_helper(text: &str, filter: CharLimitTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(LimitTokenCountFilter::new(5))
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_under_limit() {
+        let result = token_stream_helper("This is a text", CharLimitTokenFilter::new(20, false));
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "This".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 7,
+                position: 1,
+                text: "is".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 9,
+                position: 2,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 14,
+                position: 3,
+                text: "text".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_equals() {
+        let result = token_stream_helper("This is a text", CharLimitTokenFilter::new(11, false));
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "This".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 7,
+                position: 1,
+                text: "is".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 9,
+                position: 2,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 14,
+                position: 3,
+                text: "text".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_long_tokens() {
+        let result = token_stream_helper(
+            "Pneumonoultramicroscopic silicovolcanoconiosis is long",
+            CharLimitTokenFilter::new(40, false),
+        );
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 24,
+            position: 0,
+            text: "Pneumonoultramicroscopic".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_long_tokens_consume_all() {
+        let result = token_stream_helper(
+            "Pneumonoultramicroscopic silicovolcanoconiosis is long",
+            CharLimitTokenFilter::new(40, true),
+        );
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 24,
+            position: 0,
+            text: "Pneumonoultramicroscopic".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_count_chars() {
+        let result = token_stream_helper("été été été", CharLimitTokenFilter::new(6, false));
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "été".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 11,
+                position: 1,
+                text: "été".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_zero() {
+        let result = token_stream_helper("This is a text", CharLimitTokenFilter::new(0, false));
+        let expected: Vec<Token> = vec![];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,70 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::CharLimitFilterWrapper;
+
+/// [TokenFilter] that limits the number of characters emitted. Tokens are emitted
+/// as long as the cumulative number of characters of emitted tokens doesn't exceed the
+/// limit. The first token that would exceed it, and all following tokens, are dropped.
+///
+/// Unlike [LimitTokenCountFilter](crate::commons::LimitTokenCountFilter), it caps the
+/// size of the analyzed content regardless of the size of tokens.
+///
+/// If `consume_all_tokens` is `true`, the remaining tokens are still consumed from the
+/// previous filters once the limit is reached (they are not emitted). It is only needed
+/// if a previous filter relies on seeing all tokens.
+///
+/// ```rust
+/// use tantivy_analysis_contrib::commons::CharLimitTokenFilter;
+///
+/// let filter = CharLimitTokenFilter::new(1_000, false);
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::CharLimitTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(CharLimitTokenFilter::new(10, false))
+///    .build();
+/// let mut token_stream = tmp.token_stream("There will be 3 tokens in the end");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "There".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "will".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CharLimitTokenFilter {
+    max_chars: usize,
+    consume_all_tokens: bool,
+}
+
+impl CharLimitTokenFilter {
+    /// Create a new [CharLimitTokenFilter].
+    ///
+    /// # Parameters :
+    /// * max_chars : maximum number of characters, counted over all emitted tokens
+    /// * consume_all_tokens : consume the remaining tokens once the limit is reached
+    pub fn new(max_chars: usize, consume_all_tokens: bool) -> Self {
+        Self {
+            max_chars,
+            consume_all_tokens,
+        }
+    }
+}
+
+impl TokenFilter for CharLimitTokenFilter {
+    type Tokenizer<T: Tokenizer> = CharLimitFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        CharLimitFilterWrapper::new(token_stream, self.max_chars, self.consume_all_tokens)
+    }
+}
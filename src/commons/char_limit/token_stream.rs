@@ -0,0 +1,54 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Debug, Clone)]
+pub struct CharLimitTokenStream<T> {
+    tail: T,
+    /// Number of characters that can still be emitted
+    remaining: usize,
+    consume_all_tokens: bool,
+    /// The limit has been reached
+    done: bool,
+}
+
+impl<T> CharLimitTokenStream<T> {
+    pub(crate) fn new(tail: T, max_chars: usize, consume_all_tokens: bool) -> Self {
+        Self {
+            tail,
+            remaining: max_chars,
+            consume_all_tokens,
+            done: false,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for CharLimitTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if self.done || !self.tail.advance() {
+            return false;
+        }
+
+        let chars = self.tail.token().text.chars().count();
+        if chars <= self.remaining {
+            self.remaining -= chars;
+            return true;
+        }
+
+        self.done = true;
+        if self.consume_all_tokens {
+            while self.tail.advance() {}
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
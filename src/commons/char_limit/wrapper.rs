@@ -0,0 +1,36 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::CharLimitTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct CharLimitFilterWrapper<T> {
+    max_chars: usize,
+    consume_all_tokens: bool,
+    inner: T,
+}
+
+impl<T> CharLimitFilterWrapper<T> {
+    pub(crate) fn new(inner: T, max_chars: usize, consume_all_tokens: bool) -> Self {
+        Self {
+            max_chars,
+            consume_all_tokens,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for CharLimitFilterWrapper<T> {
+    type TokenStream<'a> = CharLimitTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        CharLimitTokenStream::new(
+            self.inner.token_stream(text),
+            self.max_chars,
+            self.consume_all_tokens,
+        )
+    }
+}
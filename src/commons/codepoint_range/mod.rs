@@ -0,0 +1,92 @@
+pub use token_filter::CodepointRangeTokenFilter;
+use token_stream::CodepointRangeTokenStream;
+use wrapper::CodepointRangeFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, min: u32, max: u32, inverse: bool) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(CodepointRangeTokenFilter::new(min, max, inverse))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_ascii_only() {
+        let result = token_stream_helper("tea café 茶 green", 0x00, 0x7F, false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "tea".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 19,
+                position: 3,
+                text: "green".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let result = token_stream_helper("tea café 茶 green", 0x00, 0x7F, true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 4,
+                offset_to: 9,
+                position: 1,
+                text: "café".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 13,
+                position: 2,
+                text: "茶".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bmp_only() {
+        let result = token_stream_helper("茶 𝒜bc", 0x0000, 0xFFFF, false);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 3,
+            position: 0,
+            text: "茶".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_all_outside() {
+        let result = token_stream_helper("茶 café", 0x00, 0x7F, false);
+        assert!(result.is_empty());
+    }
+}
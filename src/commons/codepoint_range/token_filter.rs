@@ -0,0 +1,63 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::CodepointRangeFilterWrapper;
+
+/// This [TokenFilter] filters tokens depending on whether all their characters are
+/// within a range of codepoints (inclusive).
+///
+/// By default, tokens that contain at least one character outside the range are removed. It allows,
+/// for example, to restrict a field to ASCII-only (`0x00..=0x7F`) or BMP-only (`0x0000..=0xFFFF`)
+/// content without transliteration. When `inverse` is `true`, only those tokens are kept.
+/// ```rust
+/// use tantivy_analysis_contrib::commons::CodepointRangeTokenFilter;
+///
+/// let filter = CodepointRangeTokenFilter::new(0x00, 0x7F, false);
+/// ```
+///
+/// # Example
+///
+/// In this example, only ASCII tokens are kept.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::CodepointRangeTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(CodepointRangeTokenFilter::new(0x00, 0x7F, false))
+///    .build();
+/// let mut token_stream = tmp.token_stream("café tea 茶");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "tea".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CodepointRangeTokenFilter {
+    min: u32,
+    max: u32,
+    inverse: bool,
+}
+
+impl CodepointRangeTokenFilter {
+    /// Get a new token filter.
+    /// # Parameters :
+    /// * min : first codepoint of the range (inclusive)
+    /// * max : last codepoint of the range (inclusive)
+    /// * inverse : if `true`, keep tokens that have at least one character outside the range
+    ///   instead of removing them
+    pub fn new(min: u32, max: u32, inverse: bool) -> Self {
+        CodepointRangeTokenFilter { min, max, inverse }
+    }
+}
+
+impl TokenFilter for CodepointRangeTokenFilter {
+    type Tokenizer<T: Tokenizer> = CodepointRangeFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        CodepointRangeFilterWrapper::new(token_stream, self.min, self.max, self.inverse)
+    }
+}
@@ -0,0 +1,49 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct CodepointRangeTokenStream<T> {
+    tail: T,
+    min: u32,
+    max: u32,
+    inverse: bool,
+}
+
+impl<T> CodepointRangeTokenStream<T> {
+    pub(crate) fn new(tail: T, min: u32, max: u32, inverse: bool) -> Self {
+        Self {
+            tail,
+            min,
+            max,
+            inverse,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for CodepointRangeTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            let within = self
+                .tail
+                .token()
+                .text
+                .chars()
+                .all(|c| (self.min..=self.max).contains(&(c as u32)));
+            if within != self.inverse {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,39 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::CodepointRangeTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct CodepointRangeFilterWrapper<T> {
+    min: u32,
+    max: u32,
+    inverse: bool,
+    inner: T,
+}
+
+impl<T> CodepointRangeFilterWrapper<T> {
+    pub(crate) fn new(inner: T, min: u32, max: u32, inverse: bool) -> Self {
+        Self {
+            min,
+            max,
+            inverse,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for CodepointRangeFilterWrapper<T> {
+    type TokenStream<'a> = CodepointRangeTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        CodepointRangeTokenStream::new(
+            self.inner.token_stream(text),
+            self.min,
+            self.max,
+            self.inverse,
+        )
+    }
+}
@@ -0,0 +1,288 @@
+pub use token_filter::CommonGramsTokenFilter;
+use token_stream::CommonGramsTokenStream;
+use wrapper::CommonGramsFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, query_mode: bool) -> Vec<Token> {
+        let filter = CommonGramsTokenFilter::from_iter_str(vec!["the", "in", "of"], true)
+            .unwrap()
+            .query_mode(query_mode);
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_index_mode() {
+        let result = token_stream_helper("the quick brown fox", false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "the".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "the_quick".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 9,
+                position: 1,
+                text: "quick".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 15,
+                position: 2,
+                text: "brown".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 19,
+                position: 3,
+                text: "fox".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_index_mode_consecutive_common_words() {
+        let result = token_stream_helper("The rain in the Spain falls", false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "The".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "The_rain".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 8,
+                position: 1,
+                text: "rain".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 11,
+                position: 1,
+                text: "rain_in".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 11,
+                position: 2,
+                text: "in".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 15,
+                position: 2,
+                text: "in_the".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 15,
+                position: 3,
+                text: "the".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 21,
+                position: 3,
+                text: "the_Spain".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 21,
+                position: 4,
+                text: "Spain".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 22,
+                offset_to: 27,
+                position: 5,
+                text: "falls".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_query_mode() {
+        let result = token_stream_helper("the rain in spain falls mainly", true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "the_rain".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 11,
+                position: 1,
+                text: "rain_in".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 17,
+                position: 2,
+                text: "in_spain".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 18,
+                offset_to: 23,
+                position: 4,
+                text: "falls".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 24,
+                offset_to: 30,
+                position: 5,
+                text: "mainly".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_query_mode_consecutive_common_words() {
+        let result = token_stream_helper("The rain in the Spain falls", true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "The_rain".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 11,
+                position: 1,
+                text: "rain_in".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 15,
+                position: 2,
+                text: "in_the".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 21,
+                position: 3,
+                text: "the_Spain".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 22,
+                offset_to: 27,
+                position: 5,
+                text: "falls".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_query_mode_single_common_word() {
+        let result = token_stream_helper("the", true);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 3,
+            position: 0,
+            text: "the".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_no_common_word() {
+        let result = token_stream_helper("quick brown fox", true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "quick".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 11,
+                position: 1,
+                text: "brown".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 15,
+                position: 2,
+                text: "fox".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_empty() {
+        let result = token_stream_helper("", false);
+        let expected: Vec<Token> = vec![];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use fst::Set;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::CommonGramsFilterWrapper;
+use crate::commons::{build_set, SetError};
+
+/// A token filter that emits bigrams of common words and their neighbours, while still
+/// emitting tokens. It is an equivalent of
+/// [Lucene's CommonGramsFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/commongrams/CommonGramsFilter.html).
+/// It speeds up phrase queries that contain very frequent words (e.g. stop words).
+///
+/// Tokens of a bigram are joined with `_`. A bigram is at the position of its first token
+/// and its `position_length` is 2, so `the_quick` overlaps `the` and `quick`. It is emitted
+/// right after its first token.
+///
+/// With [query_mode](CommonGramsTokenFilter::query_mode), which is an equivalent of
+/// [Lucene's CommonGramsQueryFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/commongrams/CommonGramsQueryFilter.html),
+/// tokens that are part of a bigram are not emitted. It is meant to be used at query time,
+/// when the index was built with the index mode.
+///
+/// Common words are stored in a [Set]. When they are case-insensitive, they are lowercased
+/// when the filter is built and tokens are lowercased only to check if they are common words.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::CommonGramsTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(CommonGramsTokenFilter::from_iter_str(vec!["the", "of"], true)?)
+///    .build();
+/// let mut token_stream = tmp.token_stream("The quick fox");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "The".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "The_quick".to_string());
+/// assert_eq!(token.position, 0);
+/// assert_eq!(token.position_length, 2);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "quick".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "fox".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CommonGramsTokenFilter {
+    common_words: Arc<Set<Vec<u8>>>,
+    ignore_case: bool,
+    query_mode: bool,
+}
+
+impl CommonGramsTokenFilter {
+    /// Construct a new [CommonGramsTokenFilter] from a [Set].
+    /// # Parameters :
+    /// * `common_words`: set of common words
+    /// * `ignore_case`: indicate that common words are case-insensitive. If `true`, the
+    ///   set is rebuilt with lowercased keys.
+    pub fn from_set(common_words: Set<Vec<u8>>, ignore_case: bool) -> Result<Self, SetError> {
+        let common_words = if ignore_case {
+            let keys = common_words.stream().into_strs()?;
+            build_set(keys.iter().map(|v| v.to_lowercase()))?
+        } else {
+            common_words
+        };
+        Ok(Self {
+            common_words: Arc::new(common_words),
+            ignore_case,
+            query_mode: false,
+        })
+    }
+
+    /// Construct a new [CommonGramsTokenFilter] from an iterator over [String] and a [bool].
+    /// # Parameters :
+    /// * `common_words`: list of common words, in any order
+    /// * `ignore_case`: indicate that common words are case-insensitive
+    pub fn from_iter_string(
+        common_words: impl IntoIterator<Item = String>,
+        ignore_case: bool,
+    ) -> Result<Self, SetError> {
+        let common_words = build_set(common_words.into_iter().map(|v| {
+            if ignore_case {
+                v.to_lowercase()
+            } else {
+                v
+            }
+        }))?;
+        Ok(Self {
+            common_words: Arc::new(common_words),
+            ignore_case,
+            query_mode: false,
+        })
+    }
+
+    /// Construct a new [CommonGramsTokenFilter] from an iterator over [str] and a [bool].
+    /// # Parameters :
+    /// * `common_words`: list of common words, in any order
+    /// * `ignore_case`: indicate that common words are case-insensitive
+    pub fn from_iter_str<'a>(
+        common_words: impl IntoIterator<Item = &'a str>,
+        ignore_case: bool,
+    ) -> Result<Self, SetError> {
+        Self::from_iter_string(common_words.into_iter().map(str::to_string), ignore_case)
+    }
+
+    /// Only emit tokens that are not part of a bigram. Default is `false`.
+    pub fn query_mode(mut self, query_mode: bool) -> Self {
+        self.query_mode = query_mode;
+        self
+    }
+}
+
+impl TokenFilter for CommonGramsTokenFilter {
+    type Tokenizer<T: Tokenizer> = CommonGramsFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        CommonGramsFilterWrapper::new(
+            token_stream,
+            self.common_words,
+            self.ignore_case,
+            self.query_mode,
+        )
+    }
+}
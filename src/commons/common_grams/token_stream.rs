@@ -0,0 +1,118 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use fst::Set;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+/// Separator between the two tokens of a bigram.
+const SEPARATOR: char = '_';
+
+/// Last token read, it is not emitted yet.
+#[derive(Clone, Debug)]
+struct Pending {
+    token: Token,
+    /// Is it a common word?
+    common: bool,
+    /// Is it part of the previous bigram?
+    in_gram: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct CommonGramsTokenStream<'a, T> {
+    tail: T,
+    common_words: &'a Set<Vec<u8>>,
+    ignore_case: bool,
+    query_mode: bool,
+    pending: Option<Pending>,
+    buffer: VecDeque<Token>,
+    token: Token,
+}
+
+impl<'a, T> CommonGramsTokenStream<'a, T> {
+    pub(crate) fn new(
+        tail: T,
+        common_words: &'a Set<Vec<u8>>,
+        ignore_case: bool,
+        query_mode: bool,
+    ) -> Self {
+        Self {
+            tail,
+            common_words,
+            ignore_case,
+            query_mode,
+            pending: None,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+
+    fn is_common(&self, text: &str) -> bool {
+        if self.ignore_case {
+            self.common_words.contains(text.to_lowercase())
+        } else {
+            self.common_words.contains(text)
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for CommonGramsTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.buffer.pop_front() {
+                self.token = token;
+                return true;
+            }
+
+            if !self.tail.advance() {
+                return match self.pending.take() {
+                    Some(pending) if !self.query_mode || !pending.in_gram => {
+                        self.token = pending.token;
+                        true
+                    }
+                    _ => false,
+                };
+            }
+
+            let token = self.tail.token().clone();
+            let common = self.is_common(&token.text);
+            let mut in_gram = false;
+            if let Some(previous) = self.pending.take() {
+                let gram = previous.common || common;
+                let bigram = gram.then(|| {
+                    let mut text =
+                        String::with_capacity(previous.token.text.len() + token.text.len() + 1);
+                    text.push_str(&previous.token.text);
+                    text.push(SEPARATOR);
+                    text.push_str(&token.text);
+                    Token {
+                        offset_from: previous.token.offset_from,
+                        offset_to: token.offset_to,
+                        position: previous.token.position,
+                        text,
+                        position_length: 2,
+                    }
+                });
+                if !self.query_mode || !(previous.in_gram || gram) {
+                    self.buffer.push_back(previous.token);
+                }
+                self.buffer.extend(bigram);
+                in_gram = gram;
+            }
+            self.pending = Some(Pending {
+                token,
+                common,
+                in_gram,
+            });
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -0,0 +1,47 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use fst::Set;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::CommonGramsTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct CommonGramsFilterWrapper<T> {
+    common_words: Arc<Set<Vec<u8>>>,
+    ignore_case: bool,
+    query_mode: bool,
+    inner: T,
+}
+
+impl<T> CommonGramsFilterWrapper<T> {
+    pub(crate) fn new(
+        inner: T,
+        common_words: Arc<Set<Vec<u8>>>,
+        ignore_case: bool,
+        query_mode: bool,
+    ) -> Self {
+        Self {
+            common_words,
+            ignore_case,
+            query_mode,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for CommonGramsFilterWrapper<T> {
+    type TokenStream<'a> = CommonGramsTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        CommonGramsTokenStream::new(
+            self.inner.token_stream(text),
+            &self.common_words,
+            self.ignore_case,
+            self.query_mode,
+        )
+    }
+}
@@ -0,0 +1,148 @@
+pub use token_filter::CompoundJoinTokenFilter;
+use token_stream::CompoundJoinTokenStream;
+use wrapper::CompoundJoinFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, compounds: Vec<&str>, keep_parts: bool) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(CompoundJoinTokenFilter::from_iter_str(
+                compounds, keep_parts,
+            ))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_join() {
+        let result = token_stream_helper("das fuß ball spiel", vec!["fußball"], false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "das".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 13,
+                position: 1,
+                text: "fußball".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 19,
+                position: 2,
+                text: "spiel".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_join_keep_parts() {
+        let result = token_stream_helper("fuß ball spiel", vec!["fußball"], true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "fußball".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "fuß".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 9,
+                position: 1,
+                text: "ball".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 15,
+                position: 2,
+                text: "spiel".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_several_compounds() {
+        let result =
+            token_stream_helper("hand schuh fuß ball", vec!["fußball", "handschuh"], false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 10,
+                position: 0,
+                text: "handschuh".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 11,
+                offset_to: 20,
+                position: 1,
+                text: "fußball".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_unknown_pairs() {
+        let result = token_stream_helper("ball fuß spiel", vec!["fußball"], false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "ball".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 9,
+                position: 1,
+                text: "fuß".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 15,
+                position: 2,
+                text: "spiel".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
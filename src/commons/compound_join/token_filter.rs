@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use rustc_hash::FxHashSet;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::CompoundJoinFilterWrapper;
+
+/// A token filter that joins two adjacent tokens when, together, they form a
+/// known compound word. It is the inverse of decompounding and it doesn't need
+/// a dictionary of components, only the compounds.
+///
+/// For example, if `fußball` is known, then `fuß ball` will become `fußball`.
+///
+/// Lookups are case-sensitive, you may want to lowercase tokens before this filter.
+///
+/// When parts are not kept, positions of following tokens are shifted so they stay
+/// consecutive. When parts are kept, the compound has a `position_length` of 2 and
+/// parts are emitted after it with their own positions.
+/// ```rust
+/// use tantivy_analysis_contrib::commons::CompoundJoinTokenFilter;
+///
+/// let filter = CompoundJoinTokenFilter::from_iter_str(vec!["fußball", "handschuh"], false);
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::CompoundJoinTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(CompoundJoinTokenFilter::from_iter_str(vec!["fußball"], false))
+///    .build();
+/// let mut token_stream = tmp.token_stream("fuß ball spiel");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "fußball".to_string());
+/// assert_eq!(token.position, 0);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "spiel".to_string());
+/// assert_eq!(token.position, 1);
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompoundJoinTokenFilter {
+    /// Set of known compounds
+    pub compounds: FxHashSet<String>,
+    /// Also emit the parts of joined compounds
+    pub keep_parts: bool,
+}
+
+impl CompoundJoinTokenFilter {
+    /// Construct a new [CompoundJoinTokenFilter] from an iterator over [String] and a [bool].
+    /// # Parameters :
+    /// * `compounds`: list of known compounds
+    /// * `keep_parts`: also emit the parts of joined compounds
+    pub fn from_iter_string(compounds: impl IntoIterator<Item = String>, keep_parts: bool) -> Self {
+        Self {
+            compounds: compounds.into_iter().collect(),
+            keep_parts,
+        }
+    }
+
+    /// Construct a new [CompoundJoinTokenFilter] from an iterator over [str] and a [bool].
+    /// # Parameters :
+    /// * `compounds`: list of known compounds
+    /// * `keep_parts`: also emit the parts of joined compounds
+    pub fn from_iter_str<'a>(
+        compounds: impl IntoIterator<Item = &'a str>,
+        keep_parts: bool,
+    ) -> Self {
+        Self {
+            compounds: compounds.into_iter().map(|v| v.to_string()).collect(),
+            keep_parts,
+        }
+    }
+}
+
+impl TokenFilter for CompoundJoinTokenFilter {
+    type Tokenizer<T: Tokenizer> = CompoundJoinFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        CompoundJoinFilterWrapper::new(token_stream, Arc::new(self.compounds), self.keep_parts)
+    }
+}
@@ -0,0 +1,103 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use rustc_hash::FxHashSet;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct CompoundJoinTokenStream<T> {
+    tail: T,
+    compounds: Arc<FxHashSet<String>>,
+    keep_parts: bool,
+    token: Token,
+    // Token read from tail that wasn't joined with the previous one.
+    lookahead: Option<Token>,
+    // Parts to emit after a compound.
+    parts: VecDeque<Token>,
+    // Number of positions removed by joined compounds.
+    position_shift: usize,
+}
+
+impl<T> CompoundJoinTokenStream<T> {
+    pub(crate) fn new(tail: T, compounds: Arc<FxHashSet<String>>, keep_parts: bool) -> Self {
+        Self {
+            tail,
+            compounds,
+            keep_parts,
+            token: Token::default(),
+            lookahead: None,
+            parts: VecDeque::with_capacity(2),
+            position_shift: 0,
+        }
+    }
+}
+
+impl<T: TokenStream> CompoundJoinTokenStream<T> {
+    fn next_token(&mut self) -> Option<Token> {
+        match self.lookahead.take() {
+            Some(token) => Some(token),
+            None if self.tail.advance() => {
+                let mut token = self.tail.token().clone();
+                token.position -= self.position_shift;
+                Some(token)
+            }
+            None => None,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for CompoundJoinTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if let Some(part) = self.parts.pop_front() {
+            self.token = part;
+            return true;
+        }
+
+        let first = match self.next_token() {
+            Some(token) => token,
+            None => return false,
+        };
+        let second = match self.next_token() {
+            Some(token) => token,
+            None => {
+                self.token = first;
+                return true;
+            }
+        };
+
+        let compound = format!("{}{}", first.text, second.text);
+        if second.position != first.position + 1 || !self.compounds.contains(&compound) {
+            self.token = first;
+            self.lookahead = Some(second);
+            return true;
+        }
+
+        self.token = Token {
+            offset_from: first.offset_from,
+            offset_to: second.offset_to,
+            position: first.position,
+            text: compound,
+            position_length: first.position_length,
+        };
+        if self.keep_parts {
+            self.token.position_length = 2;
+            self.parts.push_back(first);
+            self.parts.push_back(second);
+        } else {
+            self.position_shift += 1;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
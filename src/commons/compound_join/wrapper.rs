@@ -0,0 +1,39 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use rustc_hash::FxHashSet;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::CompoundJoinTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct CompoundJoinFilterWrapper<T> {
+    compounds: Arc<FxHashSet<String>>,
+    keep_parts: bool,
+    inner: T,
+}
+
+impl<T> CompoundJoinFilterWrapper<T> {
+    pub(crate) fn new(inner: T, compounds: Arc<FxHashSet<String>>, keep_parts: bool) -> Self {
+        Self {
+            compounds,
+            keep_parts,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for CompoundJoinFilterWrapper<T> {
+    type TokenStream<'a> = CompoundJoinTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        CompoundJoinTokenStream::new(
+            self.inner.token_stream(text),
+            self.compounds.clone(),
+            self.keep_parts,
+        )
+    }
+}
@@ -0,0 +1,169 @@
+use token_filter::CompoundOptions;
+pub use token_filter::{
+    DictionaryCompoundWordTokenFilter, DEFAULT_MAX_SUBWORD_SIZE, DEFAULT_MIN_SUBWORD_SIZE,
+    DEFAULT_MIN_WORD_SIZE,
+};
+use token_stream::DictionaryCompoundWordTokenStream;
+use wrapper::DictionaryCompoundWordFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{LowerCaser, TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::{build_set, SetError};
+
+    const DICTIONARY: [&str; 10] = [
+        "donau", "dampf", "schiff", "fahrt", "kraft", "fahr", "zeug", "fahrzeug", "haus", "tür",
+    ];
+
+    fn filter() -> Result<DictionaryCompoundWordTokenFilter, SetError> {
+        Ok(DictionaryCompoundWordTokenFilter::new(build_set(
+            DICTIONARY,
+        )?))
+    }
+
+    fn token_stream_helper(text: &str, filter: DictionaryCompoundWordTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(LowerCaser)
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn texts(tokens: Vec<Token>) -> Vec<String> {
+        tokens.into_iter().map(|token| token.text).collect()
+    }
+
+    #[test]
+    fn test_decompound() -> Result<(), SetError> {
+        let result = token_stream_helper("Donaudampfschifffahrt Haustür", filter()?);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 21,
+                position: 0,
+                text: "donaudampfschifffahrt".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 21,
+                position: 0,
+                text: "donau".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 21,
+                position: 0,
+                text: "dampf".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 21,
+                position: 0,
+                text: "schiff".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 21,
+                position: 0,
+                text: "fahr".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 21,
+                position: 0,
+                text: "fahrt".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 22,
+                offset_to: 30,
+                position: 1,
+                text: "haustür".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 22,
+                offset_to: 30,
+                position: 1,
+                text: "haus".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 22,
+                offset_to: 30,
+                position: 1,
+                text: "tür".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_only_longest_match() -> Result<(), SetError> {
+        let result = token_stream_helper("kraftfahrzeug", filter()?);
+        assert_eq!(
+            texts(result),
+            vec!["kraftfahrzeug", "kraft", "fahr", "fahrzeug", "zeug"]
+        );
+
+        let result = token_stream_helper("kraftfahrzeug", filter()?.with_only_longest_match(true));
+        assert_eq!(
+            texts(result),
+            vec!["kraftfahrzeug", "kraft", "fahrzeug", "zeug"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sizes() -> Result<(), SetError> {
+        // Too short to be decompounded
+        let result = token_stream_helper("haustür", filter()?.with_min_word_size(8));
+        assert_eq!(texts(result), vec!["haustür"]);
+
+        let result = token_stream_helper("kraftfahrzeug", filter()?.with_min_subword_size(5));
+        assert_eq!(texts(result), vec!["kraftfahrzeug", "kraft", "fahrzeug"]);
+
+        let result = token_stream_helper("kraftfahrzeug", filter()?.with_max_subword_size(4));
+        assert_eq!(texts(result), vec!["kraftfahrzeug", "fahr", "zeug"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ignore_case() -> Result<(), SetError> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter()?.with_ignore_case(true))
+            .build();
+        let mut token_stream = a.token_stream("HausTür");
+        let mut result = vec![];
+        while let Some(token) = token_stream.next() {
+            result.push(token.text.clone());
+        }
+        assert_eq!(result, vec!["HausTür", "Haus", "Tür"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use fst::Set;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::DictionaryCompoundWordFilterWrapper;
+
+/// Default minimum length, in characters, of a token to be decompounded.
+pub const DEFAULT_MIN_WORD_SIZE: usize = 5;
+/// Default minimum length, in characters, of subwords.
+pub const DEFAULT_MIN_SUBWORD_SIZE: usize = 2;
+/// Default maximum length, in characters, of subwords.
+pub const DEFAULT_MAX_SUBWORD_SIZE: usize = 15;
+
+/// A token filter that splits compound words, common in Germanic languages, into the
+/// words of a dictionary they contain. It is an equivalent of
+/// [Lucene's DictionaryCompoundWordTokenFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/compound/DictionaryCompoundWordTokenFilter.html).
+///
+/// The original token is emitted first, followed by each subword found in the dictionary.
+/// Subwords are found by brute force : every substring between the minimum and maximum
+/// subword size is looked up. They are at the position of the original token, keep its
+/// offsets and its `position_length`. Tokens shorter than the minimum word size are left
+/// untouched.
+///
+/// With `only_longest_match`, only the longest subword starting at a given character is
+/// emitted. Sizes are in characters.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::{build_set, DictionaryCompoundWordTokenFilter};
+///
+/// let dictionary = build_set(["kraft", "fahr", "zeug"])?;
+/// let filter = DictionaryCompoundWordTokenFilter::new(dictionary).with_ignore_case(true);
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("Kraftfahrzeug");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Kraftfahrzeug".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Kraft".to_string());
+/// assert_eq!(token.position, 0);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "fahr".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "zeug".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DictionaryCompoundWordTokenFilter {
+    dictionary: Arc<Set<Vec<u8>>>,
+    options: CompoundOptions,
+}
+
+/// Options of [DictionaryCompoundWordTokenFilter].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CompoundOptions {
+    pub(crate) min_word_size: usize,
+    pub(crate) min_subword_size: usize,
+    pub(crate) max_subword_size: usize,
+    pub(crate) only_longest_match: bool,
+    pub(crate) ignore_case: bool,
+}
+
+impl DictionaryCompoundWordTokenFilter {
+    /// Construct a new [DictionaryCompoundWordTokenFilter] with default sizes
+    /// ([DEFAULT_MIN_WORD_SIZE], [DEFAULT_MIN_SUBWORD_SIZE] and [DEFAULT_MAX_SUBWORD_SIZE]),
+    /// that emits all matching subwords.
+    pub fn new(dictionary: Set<Vec<u8>>) -> Self {
+        Self {
+            dictionary: Arc::new(dictionary),
+            options: CompoundOptions {
+                min_word_size: DEFAULT_MIN_WORD_SIZE,
+                min_subword_size: DEFAULT_MIN_SUBWORD_SIZE,
+                max_subword_size: DEFAULT_MAX_SUBWORD_SIZE,
+                only_longest_match: false,
+                ignore_case: false,
+            },
+        }
+    }
+
+    /// Set the minimum length of a token to be decompounded.
+    pub fn with_min_word_size(mut self, min_word_size: usize) -> Self {
+        self.options.min_word_size = min_word_size;
+        self
+    }
+
+    /// Set the minimum length of subwords.
+    pub fn with_min_subword_size(mut self, min_subword_size: usize) -> Self {
+        self.options.min_subword_size = min_subword_size;
+        self
+    }
+
+    /// Set the maximum length of subwords.
+    pub fn with_max_subword_size(mut self, max_subword_size: usize) -> Self {
+        self.options.max_subword_size = max_subword_size;
+        self
+    }
+
+    /// Only emit the longest subword starting at each character.
+    pub fn with_only_longest_match(mut self, only_longest_match: bool) -> Self {
+        self.options.only_longest_match = only_longest_match;
+        self
+    }
+
+    /// Lowercase subwords to look them up, the dictionary must then be lowercased.
+    /// Emitted subwords keep the case of the token.
+    pub fn with_ignore_case(mut self, ignore_case: bool) -> Self {
+        self.options.ignore_case = ignore_case;
+        self
+    }
+}
+
+impl TokenFilter for DictionaryCompoundWordTokenFilter {
+    type Tokenizer<T: Tokenizer> = DictionaryCompoundWordFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        DictionaryCompoundWordFilterWrapper::new(token_stream, self.dictionary, self.options)
+    }
+}
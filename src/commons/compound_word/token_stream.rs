@@ -0,0 +1,104 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use fst::Set;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::CompoundOptions;
+
+#[derive(Clone, Debug)]
+pub struct DictionaryCompoundWordTokenStream<'a, T> {
+    tail: T,
+    dictionary: &'a Set<Vec<u8>>,
+    options: CompoundOptions,
+    subwords: VecDeque<String>,
+    token: Token,
+}
+
+impl<'a, T> DictionaryCompoundWordTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, dictionary: &'a Set<Vec<u8>>, options: CompoundOptions) -> Self {
+        Self {
+            tail,
+            dictionary,
+            options,
+            subwords: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+
+    fn contains(&self, subword: &str) -> bool {
+        if self.options.ignore_case {
+            self.dictionary.contains(subword.to_lowercase())
+        } else {
+            self.dictionary.contains(subword)
+        }
+    }
+
+    fn decompose(&mut self) {
+        let text = &self.token.text;
+        // Byte index of each character, and the end of the text
+        let bounds: Vec<usize> = text
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(text.len()))
+            .collect();
+        let length = bounds.len() - 1;
+        if length < self.options.min_word_size {
+            return;
+        }
+
+        let mut subwords = VecDeque::new();
+        for start in 0..length {
+            let mut longest = None;
+            for size in self.options.min_subword_size..=self.options.max_subword_size {
+                if size == 0 {
+                    continue;
+                }
+                if start + size > length {
+                    break;
+                }
+                let subword = &text[bounds[start]..bounds[start + size]];
+                if self.contains(subword) {
+                    if self.options.only_longest_match {
+                        longest = Some(subword);
+                    } else {
+                        subwords.push_back(subword.to_string());
+                    }
+                }
+            }
+            if let Some(longest) = longest {
+                subwords.push_back(longest.to_string());
+            }
+        }
+        self.subwords = subwords;
+    }
+}
+
+impl<T: TokenStream> TokenStream for DictionaryCompoundWordTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if let Some(subword) = self.subwords.pop_front() {
+            // Subwords keep the position, offsets and position length of the original token
+            self.token.text = subword;
+            return true;
+        }
+
+        if !self.tail.advance() {
+            return false;
+        }
+
+        self.token.clone_from(self.tail.token());
+        self.decompose();
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
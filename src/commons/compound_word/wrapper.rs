@@ -0,0 +1,39 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use fst::Set;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{CompoundOptions, DictionaryCompoundWordTokenStream};
+
+#[derive(Clone, Debug)]
+pub struct DictionaryCompoundWordFilterWrapper<T> {
+    dictionary: Arc<Set<Vec<u8>>>,
+    options: CompoundOptions,
+    inner: T,
+}
+
+impl<T> DictionaryCompoundWordFilterWrapper<T> {
+    pub(crate) fn new(inner: T, dictionary: Arc<Set<Vec<u8>>>, options: CompoundOptions) -> Self {
+        Self {
+            dictionary,
+            options,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for DictionaryCompoundWordFilterWrapper<T> {
+    type TokenStream<'a> = DictionaryCompoundWordTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        DictionaryCompoundWordTokenStream::new(
+            self.inner.token_stream(text),
+            &self.dictionary,
+            self.options,
+        )
+    }
+}
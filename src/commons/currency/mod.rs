@@ -0,0 +1,106 @@
+pub use token_filter::{CurrencyTokenFilter, DEFAULT_SYMBOLS};
+use token_stream::CurrencyTokenStream;
+use wrapper::CurrencyFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{LowerCaser, TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, filter: CurrencyTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn token(text: &str, offset_from: usize, offset_to: usize, position: usize) -> Token {
+        Token {
+            offset_from,
+            offset_to,
+            position,
+            text: text.to_string(),
+            position_length: 1,
+        }
+    }
+
+    #[test]
+    fn test_symbols() {
+        let result = token_stream_helper("$ € £ ¥ R$ %", CurrencyTokenFilter::default());
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+        assert_eq!(texts, vec!["usd", "eur", "gbp", "jpy", "brl", "percent"]);
+    }
+
+    #[test]
+    fn test_prefixed_amount() {
+        let result = token_stream_helper("pay €100 now", CurrencyTokenFilter::default());
+        let expected = vec![
+            token("pay", 0, 3, 0),
+            token("eur", 4, 7, 1),
+            token("100", 7, 10, 2),
+            token("now", 11, 14, 3),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_suffixed_amount() {
+        let result = token_stream_helper("1,000.50£ 25% 30°C", CurrencyTokenFilter::default());
+        let expected = vec![
+            token("1,000.50", 0, 8, 0),
+            token("gbp", 8, 10, 1),
+            token("25", 11, 13, 2),
+            token("percent", 13, 14, 3),
+            token("30", 15, 17, 4),
+            token("celsius", 17, 20, 5),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_not_amount() {
+        let result = token_stream_helper("$HOME 100$$ €", CurrencyTokenFilter::default());
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+        assert_eq!(texts, vec!["$HOME", "100$$", "eur"]);
+    }
+
+    #[test]
+    fn test_no_split() {
+        let filter = CurrencyTokenFilter::default().with_split(false);
+        let result = token_stream_helper("€100 100€ next", filter);
+        let expected = vec![
+            token("eur100", 0, 6, 0),
+            token("100eur", 7, 13, 1),
+            token("next", 14, 18, 2),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_custom_symbols() {
+        let filter = CurrencyTokenFilter::new([("€", "euro")]).with_symbol("chf", "franc");
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(LowerCaser)
+            .filter(filter)
+            .build();
+        let mut token_stream = a.token_stream("€5 CHF20 $5");
+        let mut texts = vec![];
+        while let Some(token) = token_stream.next() {
+            texts.push(token.text.clone());
+        }
+        assert_eq!(texts, vec!["euro", "5", "franc", "20", "$5"]);
+    }
+}
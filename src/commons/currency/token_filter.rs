@@ -0,0 +1,154 @@
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::CurrencyFilterWrapper;
+
+/// Default symbols and their normalized form : currencies are mapped to their
+/// [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217) code, units to a word or their
+/// usual abbreviation.
+pub const DEFAULT_SYMBOLS: [(&str, &str); 28] = [
+    ("$", "usd"),
+    ("US$", "usd"),
+    ("€", "eur"),
+    ("£", "gbp"),
+    ("¥", "jpy"),
+    ("₹", "inr"),
+    ("₽", "rub"),
+    ("₩", "krw"),
+    ("₺", "try"),
+    ("₴", "uah"),
+    ("₪", "ils"),
+    ("₫", "vnd"),
+    ("₱", "php"),
+    ("฿", "thb"),
+    ("₿", "btc"),
+    ("C$", "cad"),
+    ("A$", "aud"),
+    ("R$", "brl"),
+    ("%", "percent"),
+    ("‰", "permille"),
+    ("°", "degree"),
+    ("°C", "celsius"),
+    ("℃", "celsius"),
+    ("°F", "fahrenheit"),
+    ("℉", "fahrenheit"),
+    ("㎏", "kg"),
+    ("㎞", "km"),
+    ("㎖", "ml"),
+];
+
+/// A token filter that replaces currency and unit symbols with a normalized form, so
+/// `€100` can match `eur 100`. It uses [DEFAULT_SYMBOLS] unless other symbols are given.
+///
+/// A token is changed if it's a symbol, or if it's a number with a symbol before
+/// or after it, like `€100`, `100€` or `25%`. Numbers are made of ASCII digits, `.`, `,`
+/// and `'`. Other tokens, like `$HOME`, are left untouched. When several symbols
+/// match, the longest one is used : `°C25` gives `celsius` and not `degree`.
+///
+/// By default, a symbol attached to a number is split into its own token, that keeps
+/// its place before or after the number and takes its own position. Positions of the
+/// following tokens are shifted accordingly. Offsets of each part are the ones of the
+/// symbol and the number if the token's text has the same length as in the original
+/// text, otherwise both parts keep the token's offsets. If split is disabled, the symbol
+/// is replaced inside the token : `€100` becomes `eur100`.
+///
+/// Symbols are matched on the exact token's text, so the tokenizer has to keep them,
+/// like the `WhitespaceTokenizer`, and you may want to lowercase tokens before if
+/// symbols have letters.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::CurrencyTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(CurrencyTokenFilter::default())
+///    .build();
+/// let mut token_stream = tmp.token_stream("€100 or £ 90");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "eur".to_string());
+/// assert_eq!(token.position, 0);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "100".to_string());
+/// assert_eq!(token.position, 1);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "or".to_string());
+/// assert_eq!(token.position, 2);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "gbp".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "90".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CurrencyTokenFilter {
+    /// Symbols and their normalized form, longest symbols first.
+    symbols: Arc<Vec<(String, String)>>,
+    split: bool,
+}
+
+impl Default for CurrencyTokenFilter {
+    /// Construct a [CurrencyTokenFilter] with [DEFAULT_SYMBOLS], that splits symbols
+    /// from numbers.
+    fn default() -> Self {
+        Self::new(DEFAULT_SYMBOLS)
+    }
+}
+
+impl CurrencyTokenFilter {
+    /// Construct a new [CurrencyTokenFilter] with the given pairs of symbol and
+    /// normalized form instead of the default ones. Empty symbols are ignored.
+    pub fn new<S: Into<String>, N: Into<String>>(
+        symbols: impl IntoIterator<Item = (S, N)>,
+    ) -> Self {
+        let mut filter = Self {
+            symbols: Arc::new(Vec::new()),
+            split: true,
+        };
+        for (symbol, name) in symbols {
+            filter = filter.with_symbol(symbol, name);
+        }
+        filter
+    }
+
+    /// Add a symbol, or change the normalized form of an existing one.
+    pub fn with_symbol(mut self, symbol: impl Into<String>, name: impl Into<String>) -> Self {
+        let symbol = symbol.into();
+        if symbol.is_empty() {
+            return self;
+        }
+        let symbols = Arc::make_mut(&mut self.symbols);
+        symbols.retain(|(other, _)| *other != symbol);
+        symbols.push((symbol, name.into()));
+        // Longest symbols are tried first
+        symbols.sort_by_key(|(symbol, _)| Reverse(symbol.len()));
+        self
+    }
+
+    /// Indicate if a symbol attached to a number is emitted as its own token (`true`,
+    /// the default) or replaced inside the token (`false`).
+    pub fn with_split(mut self, split: bool) -> Self {
+        self.split = split;
+        self
+    }
+}
+
+impl TokenFilter for CurrencyTokenFilter {
+    type Tokenizer<T: Tokenizer> = CurrencyFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        CurrencyFilterWrapper::new(token_stream, self.symbols, self.split)
+    }
+}
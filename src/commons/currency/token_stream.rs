@@ -0,0 +1,126 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::ops::Range;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Debug, Clone)]
+pub struct CurrencyTokenStream<'a, T> {
+    tail: T,
+    symbols: &'a [(String, String)],
+    split: bool,
+    /// Positions taken by symbols split from numbers.
+    position_shift: usize,
+    /// Number that comes after its symbol.
+    pending: Option<Token>,
+    token: Token,
+}
+
+impl<'a, T> CurrencyTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, symbols: &'a [(String, String)], split: bool) -> Self {
+        Self {
+            tail,
+            symbols,
+            split,
+            position_shift: 0,
+            pending: None,
+            token: Token::default(),
+        }
+    }
+}
+
+fn is_amount(text: &str) -> bool {
+    text.starts_with(|ch: char| ch.is_ascii_digit())
+        && text
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || matches!(ch, '.' | ',' | '\''))
+}
+
+/// Parts of a token, with their range in the token's text.
+fn parts<'s>(text: &str, symbols: &'s [(String, String)]) -> Option<Vec<(&'s str, Range<usize>)>> {
+    symbols.iter().find_map(|(symbol, name)| {
+        let name = name.as_str();
+        if text == symbol {
+            Some(vec![(name, 0..text.len())])
+        } else if text.starts_with(symbol.as_str()) && is_amount(&text[symbol.len()..]) {
+            Some(vec![
+                (name, 0..symbol.len()),
+                ("", symbol.len()..text.len()),
+            ])
+        } else if text.ends_with(symbol.as_str()) && is_amount(&text[..text.len() - symbol.len()]) {
+            let start = text.len() - symbol.len();
+            Some(vec![("", 0..start), (name, start..text.len())])
+        } else {
+            None
+        }
+    })
+}
+
+impl<T: TokenStream> TokenStream for CurrencyTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if let Some(token) = self.pending.take() {
+            self.token = token;
+            return true;
+        }
+
+        if !self.tail.advance() {
+            return false;
+        }
+
+        self.token.clone_from(self.tail.token());
+        self.token.position += self.position_shift;
+
+        let Some(parts) = parts(&self.token.text, self.symbols) else {
+            return true;
+        };
+        // An empty name is the number itself
+        let text_of = |(name, range): &(&str, Range<usize>)| {
+            if name.is_empty() {
+                self.token.text[range.clone()].to_string()
+            } else {
+                name.to_string()
+            }
+        };
+
+        if !self.split || parts.len() == 1 {
+            self.token.text = parts.iter().map(text_of).collect();
+            return true;
+        }
+
+        let same_length = self.token.offset_to - self.token.offset_from == self.token.text.len();
+        let mut tokens = parts.iter().enumerate().map(|(i, part)| {
+            let (offset_from, offset_to) = if same_length {
+                (
+                    self.token.offset_from + part.1.start,
+                    self.token.offset_from + part.1.end,
+                )
+            } else {
+                (self.token.offset_from, self.token.offset_to)
+            };
+            Token {
+                offset_from,
+                offset_to,
+                position: self.token.position + i,
+                text: text_of(part),
+                position_length: 1,
+            }
+        });
+        let first = tokens.next();
+        self.pending = tokens.next();
+        if let Some(first) = first {
+            self.token = first;
+        }
+        self.position_shift += 1;
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -0,0 +1,34 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::CurrencyTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct CurrencyFilterWrapper<T> {
+    symbols: Arc<Vec<(String, String)>>,
+    split: bool,
+    inner: T,
+}
+
+impl<T> CurrencyFilterWrapper<T> {
+    pub(crate) fn new(inner: T, symbols: Arc<Vec<(String, String)>>, split: bool) -> Self {
+        Self {
+            symbols,
+            split,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for CurrencyFilterWrapper<T> {
+    type TokenStream<'a> = CurrencyTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        CurrencyTokenStream::new(self.inner.token_stream(text), &self.symbols, self.split)
+    }
+}
@@ -0,0 +1,89 @@
+pub use token_filter::CyrillicYoTokenFilter;
+use token_stream::CyrillicYoTokenStream;
+use wrapper::CyrillicYoFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(CyrillicYoTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_lowercase() {
+        let result = token_stream_helper("всё ещё");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "все".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 13,
+                position: 1,
+                text: "еще".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_uppercase() {
+        let result = token_stream_helper("ЁЛКА Ёж");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "ЕЛКА".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 13,
+                position: 1,
+                text: "Еж".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_without_yo() {
+        let result = token_stream_helper("привет");
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 12,
+            position: 0,
+            text: "привет".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+}
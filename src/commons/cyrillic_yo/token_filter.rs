@@ -0,0 +1,42 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::CyrillicYoFilterWrapper;
+
+/// This [TokenFilter] replaces the Cyrillic letter yo `ё` by `е` (and `Ё` by `Е`).
+///
+/// Russian texts use `ё` inconsistently, most of the time it is written as `е`.
+/// Normalizing it allows `ёлка` and `елка` to match. Case is preserved, so you
+/// can use it before or after a lowercase filter.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::CyrillicYoTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(CyrillicYoTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("ёлка Ёж");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "елка".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Еж".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CyrillicYoTokenFilter;
+
+impl TokenFilter for CyrillicYoTokenFilter {
+    type Tokenizer<T: Tokenizer> = CyrillicYoFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        CyrillicYoFilterWrapper::new(token_stream)
+    }
+}
@@ -0,0 +1,48 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use crate::commons::map_chars::map_chars;
+
+#[derive(Debug, Clone)]
+pub struct CyrillicYoTokenStream<T> {
+    tail: T,
+}
+
+impl<T> CyrillicYoTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self { tail }
+    }
+}
+
+/// `е` of a `ё`.
+fn without_diaeresis(ch: char) -> Option<char> {
+    match ch {
+        'ё' => Some('е'),
+        'Ё' => Some('Е'),
+        _ => None,
+    }
+}
+
+impl<T: TokenStream> TokenStream for CyrillicYoTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        if let Some(text) = map_chars(&self.tail.token().text, without_diaeresis) {
+            self.tail.token_mut().text = text;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,82 @@
+pub use token_filter::DecimalDigitTokenFilter;
+use token_stream::DecimalDigitTokenStream;
+use wrapper::DecimalDigitFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(DecimalDigitTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_persian() {
+        let result = token_stream_helper("۱۳۷۹ ۲۵");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "1379".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 13,
+                position: 1,
+                text: "25".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bengali() {
+        let result = token_stream_helper("২০০১");
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 12,
+            position: 0,
+            text: "2001".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_fullwidth_and_mixed() {
+        let result = token_stream_helper("ＩＳＯ９００１ 𝟗𝟎 ٣a");
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+
+        assert_eq!(texts, vec!["ＩＳＯ9001", "90", "3a"]);
+    }
+
+    #[test]
+    fn test_other_numbers_untouched() {
+        let result = token_stream_helper("x² Ⅻ ½ 42");
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+
+        assert_eq!(texts, vec!["x²", "Ⅻ", "½", "42"]);
+    }
+}
@@ -0,0 +1,44 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::DecimalDigitFilterWrapper;
+
+/// This [TokenFilter] replaces any Unicode decimal digit by its ASCII equivalent. It is an
+/// equivalent of
+/// [Lucene's DecimalDigitFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/core/DecimalDigitFilter.html).
+///
+/// Only decimal digits (`Nd` general category) are replaced, so `۱۳۷۹` becomes `1379`
+/// and numeric search works across scripts. Other numeric characters, like `²` or `Ⅻ`,
+/// and letters are left untouched. Offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::DecimalDigitTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(DecimalDigitTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("۱۳۷۹ ২০০১");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "1379".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "2001".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DecimalDigitTokenFilter;
+
+impl TokenFilter for DecimalDigitTokenFilter {
+    type Tokenizer<T: Tokenizer> = DecimalDigitFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        DecimalDigitFilterWrapper::new(token_stream)
+    }
+}
@@ -0,0 +1,114 @@
+//! Helpers to compare the output of two analysis chains.
+
+use std::collections::BTreeSet;
+
+use tantivy_tokenizer_api::TokenStream;
+
+/// Terms produced by two analysis chains on the same text. See [diff_analysis].
+///
+/// Terms are sorted and deduplicated.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnalysisDiff {
+    /// Terms that are only produced by the first chain.
+    pub only_a: Vec<String>,
+    /// Terms that are only produced by the second chain.
+    pub only_b: Vec<String>,
+    /// Terms produced by both chains.
+    pub shared: Vec<String>,
+}
+
+impl AnalysisDiff {
+    /// Check that both chains produce the same terms.
+    pub fn is_same(&self) -> bool {
+        self.only_a.is_empty() && self.only_b.is_empty()
+    }
+}
+
+fn terms<T: TokenStream>(mut token_stream: T) -> BTreeSet<String> {
+    let mut terms = BTreeSet::new();
+    while token_stream.advance() {
+        terms.insert(token_stream.token().text.clone());
+    }
+    terms
+}
+
+/// Compare the terms of two token streams, usually the output of two analyzers on the
+/// same text.
+///
+/// It helps to debug mismatches between the analyzer used at index time and the one
+/// used at query time, for example when a field is indexed with
+/// [EdgeNgramTokenFilter](crate::commons::EdgeNgramTokenFilter) : query terms that are
+/// [only_b](AnalysisDiff::only_b) can't match anything.
+///
+/// Only terms are compared, not their positions nor offsets.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{LowerCaser, TextAnalyzer, WhitespaceTokenizer};
+/// use tantivy_analysis_contrib::commons::diff_analysis;
+///
+/// let mut index = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///     .filter(LowerCaser)
+///     .build();
+/// let mut query = TextAnalyzer::builder(WhitespaceTokenizer::default()).build();
+///
+/// let text = "Quick fox";
+/// let diff = diff_analysis(index.token_stream(text), query.token_stream(text));
+///
+/// assert_eq!(diff.only_a, vec!["quick".to_string()]);
+/// assert_eq!(diff.only_b, vec!["Quick".to_string()]);
+/// assert_eq!(diff.shared, vec!["fox".to_string()]);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn diff_analysis<A: TokenStream, B: TokenStream>(a: A, b: B) -> AnalysisDiff {
+    let a = terms(a);
+    let b = terms(b);
+
+    AnalysisDiff {
+        only_a: a.difference(&b).cloned().collect(),
+        only_b: b.difference(&a).cloned().collect(),
+        shared: a.intersection(&b).cloned().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use tantivy::tokenizer::{TextAnalyzer, Tokenizer, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::EdgeNgramTokenFilter;
+
+    #[test]
+    fn test_edge_ngram_index_and_query() {
+        let mut index = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(EdgeNgramTokenFilter::new(NonZeroUsize::new(2).unwrap(), None, false).unwrap())
+            .build();
+        let mut query = TextAnalyzer::builder(WhitespaceTokenizer::default()).build();
+
+        let diff = diff_analysis(index.token_stream("quick"), query.token_stream("quick"));
+        assert_eq!(
+            diff,
+            AnalysisDiff {
+                only_a: vec!["qu".to_string(), "qui".to_string(), "quic".to_string()],
+                only_b: vec![],
+                shared: vec!["quick".to_string()],
+            }
+        );
+        assert!(!diff.is_same());
+    }
+
+    #[test]
+    fn test_same() {
+        let mut a = WhitespaceTokenizer::default();
+        let mut b = WhitespaceTokenizer::default();
+
+        let diff = diff_analysis(a.token_stream("a b a"), b.token_stream("b a"));
+        assert!(diff.is_same());
+        assert_eq!(diff.shared, vec!["a".to_string(), "b".to_string()]);
+    }
+}
@@ -0,0 +1,88 @@
+use token_filter::Spans;
+pub use token_filter::{DistinctSpansTokenFilter, SpanSink};
+use token_stream::DistinctSpansTokenStream;
+use wrapper::DistinctSpansFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, sink: &SpanSink) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(DistinctSpansTokenFilter::new(sink.clone()))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_repeated_term() {
+        let sink = SpanSink::new();
+        let result = token_stream_helper("rust is rust and rust", &sink);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "rust".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 7,
+                position: 1,
+                text: "is".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 13,
+                offset_to: 16,
+                position: 3,
+                text: "and".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+
+        assert_eq!(sink.spans("rust"), vec![(0, 4), (8, 12), (17, 21)]);
+        assert_eq!(sink.spans("is"), vec![(5, 7)]);
+        assert!(sink.spans("unknown").is_empty());
+
+        let all = sink.take();
+        let expected = vec![
+            ("rust".to_string(), vec![(0, 4), (8, 12), (17, 21)]),
+            ("is".to_string(), vec![(5, 7)]),
+            ("and".to_string(), vec![(13, 16)]),
+        ];
+        assert_eq!(all, expected);
+        assert!(sink.take().is_empty());
+    }
+
+    #[test]
+    fn test_sink_cleared_between_texts() {
+        let sink = SpanSink::new();
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(DistinctSpansTokenFilter::new(sink.clone()))
+            .build();
+
+        a.token_stream("a b a").process(&mut |_| {});
+        assert_eq!(sink.spans("a"), vec![(0, 1), (4, 5)]);
+
+        a.token_stream("b a").process(&mut |_| {});
+        assert_eq!(sink.spans("a"), vec![(2, 3)]);
+        assert_eq!(sink.spans("b"), vec![(0, 1)]);
+    }
+}
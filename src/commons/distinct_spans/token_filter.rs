@@ -0,0 +1,139 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+use rustc_hash::FxHashMap;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::DistinctSpansFilterWrapper;
+
+/// Terms seen in a stream.
+#[derive(Debug, Default)]
+pub(crate) struct Spans {
+    /// Index of each term in `terms`
+    index: FxHashMap<String, usize>,
+    /// Terms, in order of first occurrence, with their spans
+    terms: Vec<(String, Vec<(usize, usize)>)>,
+}
+
+impl Spans {
+    /// Add a span to a term. Returns `true` if it's the first occurrence of the term.
+    pub(crate) fn add(&mut self, term: &str, span: (usize, usize)) -> bool {
+        match self.index.get(term) {
+            Some(i) => {
+                self.terms[*i].1.push(span);
+                false
+            }
+            None => {
+                self.index.insert(term.to_string(), self.terms.len());
+                self.terms.push((term.to_string(), vec![span]));
+                true
+            }
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.index.clear();
+        self.terms.clear();
+    }
+}
+
+/// Sink that receives the offsets of every occurrence of the terms seen by a
+/// [DistinctSpansTokenFilter].
+///
+/// It can be cloned : clones share the same spans. It only holds the spans of the
+/// last analyzed text, as they are cleared each time a new token stream is created.
+/// So a sink (and the analyzer that uses it) should not be shared between threads.
+#[derive(Clone, Debug, Default)]
+pub struct SpanSink {
+    spans: Arc<Mutex<Spans>>,
+}
+
+impl SpanSink {
+    /// Create a new, empty, [SpanSink].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Offsets (`offset_from`, `offset_to`) of every occurrence of a term, in the
+    /// order they appear in the text. It's empty if the term hasn't been seen.
+    pub fn spans(&self, term: &str) -> Vec<(usize, usize)> {
+        let spans = self.spans.lock().unwrap_or_else(PoisonError::into_inner);
+        spans
+            .index
+            .get(term)
+            .map(|i| spans.terms[*i].1.clone())
+            .unwrap_or_default()
+    }
+
+    /// Take all terms, in the order of their first occurrence, with their spans.
+    /// The sink is empty afterward.
+    pub fn take(&self) -> Vec<(String, Vec<(usize, usize)>)> {
+        let mut spans = self.spans.lock().unwrap_or_else(PoisonError::into_inner);
+        spans.index.clear();
+        std::mem::take(&mut spans.terms)
+    }
+
+    pub(crate) fn inner(&self) -> &Mutex<Spans> {
+        &self.spans
+    }
+}
+
+/// [TokenFilter] that emits each term only once, but records the offsets of all its
+/// occurrences into a [SpanSink]. It helps building a "terms with all their spans"
+/// structure, for example to highlight every occurrence of a term.
+///
+/// The emitted token is the first occurrence, with its offsets and position. Next
+/// occurrences are removed and leave a gap in positions, like stop words.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::{DistinctSpansTokenFilter, SpanSink};
+///
+/// let sink = SpanSink::new();
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(DistinctSpansTokenFilter::new(sink.clone()))
+///    .build();
+/// let mut token_stream = tmp.token_stream("to be or not to be");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "to".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "be".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "or".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "not".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+///
+/// assert_eq!(sink.spans("be"), vec![(3, 5), (16, 18)]);
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DistinctSpansTokenFilter {
+    sink: SpanSink,
+}
+
+impl DistinctSpansTokenFilter {
+    /// Create a new [DistinctSpansTokenFilter].
+    ///
+    /// # Parameters :
+    /// * sink : where spans of all occurrences are recorded
+    pub fn new(sink: SpanSink) -> Self {
+        Self { sink }
+    }
+}
+
+impl TokenFilter for DistinctSpansTokenFilter {
+    type Tokenizer<T: Tokenizer> = DistinctSpansFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        DistinctSpansFilterWrapper::new(tokenizer, self.sink)
+    }
+}
@@ -0,0 +1,46 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::sync::{Mutex, PoisonError};
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::Spans;
+
+#[derive(Debug)]
+pub struct DistinctSpansTokenStream<'a, T> {
+    tail: T,
+    spans: &'a Mutex<Spans>,
+}
+
+impl<'a, T> DistinctSpansTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, spans: &'a Mutex<Spans>) -> Self {
+        Self { tail, spans }
+    }
+}
+
+impl<T: TokenStream> TokenStream for DistinctSpansTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            let token = self.tail.token();
+            let first = self
+                .spans
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .add(&token.text, (token.offset_from, token.offset_to));
+            if first {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
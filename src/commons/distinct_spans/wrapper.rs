@@ -0,0 +1,35 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::PoisonError;
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{DistinctSpansTokenStream, SpanSink};
+
+#[derive(Clone, Debug)]
+pub struct DistinctSpansFilterWrapper<T> {
+    inner: T,
+    sink: SpanSink,
+}
+
+impl<T> DistinctSpansFilterWrapper<T> {
+    pub(crate) fn new(inner: T, sink: SpanSink) -> Self {
+        Self { inner, sink }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for DistinctSpansFilterWrapper<T> {
+    type TokenStream<'a> = DistinctSpansTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        // The sink only holds spans of the last text
+        self.sink
+            .inner()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        DistinctSpansTokenStream::new(self.inner.token_stream(text), self.sink.inner())
+    }
+}
@@ -23,6 +23,17 @@ pub enum EdgeNgramError {
     },
 }
 
+/// Side of the token from which edge-ngrams are generated.
+#[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeSide {
+    /// Prefixes : `Quick` gives `Q`, `Qu`, `Qui`, ...
+    #[default]
+    Front,
+    /// Suffixes : `Quick` gives `k`, `ck`, `ick`, ...
+    Back,
+}
+
 #[cfg(test)]
 mod tests {
     use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
@@ -49,6 +60,62 @@ mod tests {
         tokens
     }
 
+    fn token_stream_helper_back(
+        text: &str,
+        min: NonZeroUsize,
+        max: Option<NonZeroUsize>,
+        keep_original: bool,
+    ) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(
+                EdgeNgramTokenFilter::with_side(min, max, keep_original, EdgeSide::Back).unwrap(),
+            )
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[cfg(feature = "graphemes")]
+    fn token_stream_helper_graphemes(
+        text: &str,
+        min: NonZeroUsize,
+        max: Option<NonZeroUsize>,
+        side: EdgeSide,
+    ) -> Vec<String> {
+        let filter = EdgeNgramTokenFilter::with_side(min, max, false, side)
+            .unwrap()
+            .with_graphemes(true);
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.text.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn token(text: &str, offset_from: usize, offset_to: usize, position: usize) -> Token {
+        Token {
+            offset_from,
+            offset_to,
+            position,
+            text: text.to_string(),
+            position_length: 1,
+        }
+    }
+
     #[test]
     fn test_invalid_input_2() {
         let result =
@@ -435,4 +502,142 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_back_unigram() {
+        let result = token_stream_helper_back(
+            "abcde",
+            NonZeroUsize::new(1).unwrap(),
+            NonZeroUsize::new(1),
+            false,
+        );
+
+        assert_eq!(result, vec![token("e", 0, 5, 0)]);
+    }
+
+    #[test]
+    fn test_back_no_limit() {
+        let result = token_stream_helper_back("Quick", NonZeroUsize::new(1).unwrap(), None, false);
+
+        let expected = vec![
+            token("k", 0, 5, 0),
+            token("ck", 0, 5, 0),
+            token("ick", 0, 5, 0),
+            token("uick", 0, 5, 0),
+            token("Quick", 0, 5, 0),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_back_range_keep_original() {
+        let result = token_stream_helper_back(
+            "a Quick fox",
+            NonZeroUsize::new(2).unwrap(),
+            NonZeroUsize::new(3),
+            true,
+        );
+
+        let expected = vec![
+            token("a", 0, 1, 0),
+            token("ck", 2, 7, 1),
+            token("ick", 2, 7, 1),
+            token("Quick", 2, 7, 1),
+            token("ox", 8, 11, 2),
+            token("fox", 8, 11, 2),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_back_multi_bytes() {
+        let result = token_stream_helper_back(
+            "café",
+            NonZeroUsize::new(1).unwrap(),
+            NonZeroUsize::new(2),
+            false,
+        );
+
+        assert_eq!(result, vec![token("é", 0, 5, 0), token("fé", 0, 5, 0)]);
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn test_graphemes_decomposed() {
+        // "café" with a combining acute accent
+        let text = "cafe\u{0301}";
+        let result = token_stream_helper_graphemes(
+            text,
+            NonZeroUsize::new(3).unwrap(),
+            None,
+            EdgeSide::Front,
+        );
+        assert_eq!(result, vec!["caf", "cafe\u{0301}"]);
+
+        let result = token_stream_helper_graphemes(
+            text,
+            NonZeroUsize::new(1).unwrap(),
+            NonZeroUsize::new(2),
+            EdgeSide::Back,
+        );
+        assert_eq!(result, vec!["e\u{0301}", "fe\u{0301}"]);
+
+        // Counting chars splits the accent from its letter
+        let result = token_stream_helper(
+            text,
+            NonZeroUsize::new(4).unwrap(),
+            NonZeroUsize::new(4),
+            false,
+        );
+        assert_eq!(result[0].text, "cafe");
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn test_graphemes_zwj_sequence() {
+        // Family : man, ZWJ, woman, ZWJ, girl
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let text = format!("{family}ok");
+        let result = token_stream_helper_graphemes(
+            &text,
+            NonZeroUsize::new(1).unwrap(),
+            None,
+            EdgeSide::Front,
+        );
+        assert_eq!(
+            result,
+            vec![family.to_string(), format!("{family}o"), text.clone()]
+        );
+
+        let result = token_stream_helper_graphemes(
+            &text,
+            NonZeroUsize::new(3).unwrap(),
+            None,
+            EdgeSide::Back,
+        );
+        assert_eq!(result, vec![text.clone()]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let filter: EdgeNgramTokenFilter =
+            serde_json::from_str(r#"{"min": 2, "max": 3, "side": "Back"}"#).unwrap();
+        let json = serde_json::to_string(&filter).unwrap();
+        let filter: EdgeNgramTokenFilter = serde_json::from_str(&json).unwrap();
+
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+        let mut token_stream = a.token_stream("Quick");
+        let mut result = vec![];
+        token_stream.process(&mut |token: &Token| result.push(token.text.clone()));
+        assert_eq!(result, vec!["ck", "ick"]);
+
+        // Parameters are checked
+        let result = serde_json::from_str::<EdgeNgramTokenFilter>(r#"{"min": 3, "max": 2}"#);
+        assert!(result.is_err());
+        let result = serde_json::from_str::<EdgeNgramTokenFilter>(r#"{"min": 0, "max": null}"#);
+        assert!(result.is_err());
+    }
 }
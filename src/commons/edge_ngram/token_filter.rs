@@ -2,13 +2,17 @@ use std::num::NonZeroUsize;
 
 use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
 
-use super::{EdgeNgramError, EdgeNgramFilterWrapper};
+use super::{EdgeNgramError, EdgeNgramFilterWrapper, EdgeSide};
 
 /// Token filter that produce [ngram](https://docs.rs/tantivy/0.18.1/tantivy/tokenizer/struct.NgramTokenizer.html)
 /// from the start of the token.
 /// For example, `Quick` will generate
 /// `Q`, `Qu`, `Qui`, `Quic`, ...etc.
 ///
+/// With [EdgeSide::Back], ngrams are generated from the end of the token instead : `Quick`
+/// will generate `k`, `ck`, `ick`, `uick`, ...etc.
+/// Whatever the side, offsets of the ngrams are those of the whole token.
+///
 /// It is configure with two parameters:
 /// * min edge-ngram: the number of maximum characters (e.g. with min=3, `Quick`
 ///   will generate `Qui`, `Quic` and `Quick`).
@@ -45,8 +49,9 @@ use super::{EdgeNgramError, EdgeNgramFilterWrapper};
 ///
 /// This token filter is useful to do a "starts with" therefor a "search as you type".
 ///
-/// It is also easy to have an efficient "ends with" by adding the [ReverseTokenFilter](crate::commons::reverse::ReverseTokenFilter)
-/// before the edge ngram filter.
+/// For an efficient "ends with", use [EdgeSide::Back] (see [with_side](EdgeNgramTokenFilter::with_side)).
+/// Unlike adding the [ReverseTokenFilter](crate::commons::reverse::ReverseTokenFilter) before
+/// the edge ngram filter, the characters of the ngrams are not reversed.
 ///
 /// # How to use it
 ///
@@ -56,10 +61,50 @@ use super::{EdgeNgramError, EdgeNgramFilterWrapper};
 /// Please see the [example](https://github.com/Dalvany/tantivy-analysis-contrib/tree/main/examples/edge_ngram.rs)
 /// in source repository for a way to do it.
 #[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "EdgeNgramConfig")
+)]
 pub struct EdgeNgramTokenFilter {
     min: NonZeroUsize,
     max: Option<NonZeroUsize>,
     keep_original_token: bool,
+    side: EdgeSide,
+    graphemes: bool,
+}
+
+/// Deserialized parameters of an [EdgeNgramTokenFilter], that are checked
+/// as [with_side](EdgeNgramTokenFilter::with_side) does.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct EdgeNgramConfig {
+    min: NonZeroUsize,
+    max: Option<NonZeroUsize>,
+    #[serde(default)]
+    keep_original_token: bool,
+    #[serde(default)]
+    side: EdgeSide,
+    #[cfg(feature = "graphemes")]
+    #[serde(default)]
+    graphemes: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<EdgeNgramConfig> for EdgeNgramTokenFilter {
+    type Error = EdgeNgramError;
+
+    fn try_from(config: EdgeNgramConfig) -> Result<Self, Self::Error> {
+        let filter = Self::with_side(
+            config.min,
+            config.max,
+            config.keep_original_token,
+            config.side,
+        )?;
+        #[cfg(feature = "graphemes")]
+        let filter = filter.with_graphemes(config.graphemes);
+        Ok(filter)
+    }
 }
 
 impl EdgeNgramTokenFilter {
@@ -77,6 +122,48 @@ impl EdgeNgramTokenFilter {
         min: NonZeroUsize,
         max: Option<NonZeroUsize>,
         keep_original_token: bool,
+    ) -> Result<Self, EdgeNgramError> {
+        Self::with_side(min, max, keep_original_token, EdgeSide::Front)
+    }
+
+    /// Create a new `EdgeNgramTokenFilter` with the min and max ngram
+    /// provided, generated from the given side of the token.
+    ///
+    /// # Parameters
+    ///
+    /// * `min` : minimum edge-ngram.
+    /// * `max` : maximum edge-ngram. It must be greater or equals to `min`.
+    ///   Provide [None](None) for unlimited.
+    /// * `keep_original_token`: the complete token will also be output if
+    ///   the length is greater than `max`.
+    /// * `side`: generate prefixes ([EdgeSide::Front]) or suffixes ([EdgeSide::Back]).
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::num::NonZeroUsize;
+    /// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+    /// use tantivy_analysis_contrib::commons::{EdgeNgramTokenFilter, EdgeSide};
+    ///
+    /// let filter = EdgeNgramTokenFilter::with_side(NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(3), false, EdgeSide::Back)?;
+    /// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+    ///    .filter(filter)
+    ///    .build();
+    /// let mut token_stream = tmp.token_stream("Quick");
+    ///
+    /// let token = token_stream.next().expect("A token should be present.");
+    /// assert_eq!(token.text, "ck".to_string());
+    /// let token = token_stream.next().expect("A token should be present.");
+    /// assert_eq!(token.text, "ick".to_string());
+    ///
+    /// assert_eq!(None, token_stream.next());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_side(
+        min: NonZeroUsize,
+        max: Option<NonZeroUsize>,
+        keep_original_token: bool,
+        side: EdgeSide,
     ) -> Result<Self, EdgeNgramError> {
         // Check max
         if let Some(m) = max {
@@ -89,8 +176,44 @@ impl EdgeNgramTokenFilter {
             min,
             max,
             keep_original_token,
+            side,
+            graphemes: false,
         })
     }
+
+    /// Count ngrams in extended grapheme clusters instead of chars, so that a character
+    /// is never split from its combining marks (a decomposed `é` is `e` followed by
+    /// `U+0301`) and emoji sequences are kept whole. `min` and `max` are then numbers of
+    /// grapheme clusters.
+    ///
+    /// It needs the `graphemes` feature.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::num::NonZeroUsize;
+    /// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+    /// use tantivy_analysis_contrib::commons::EdgeNgramTokenFilter;
+    ///
+    /// let filter = EdgeNgramTokenFilter::new(NonZeroUsize::new(4).unwrap(), None, false)?.with_graphemes(true);
+    /// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+    ///    .filter(filter)
+    ///    .build();
+    /// let mut token_stream = tmp.token_stream("cafe\u{0301}s");
+    ///
+    /// let token = token_stream.next().expect("A token should be present.");
+    /// assert_eq!(token.text, "cafe\u{0301}".to_string());
+    /// let token = token_stream.next().expect("A token should be present.");
+    /// assert_eq!(token.text, "cafe\u{0301}s".to_string());
+    ///
+    /// assert_eq!(None, token_stream.next());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "graphemes")]
+    pub fn with_graphemes(mut self, graphemes: bool) -> Self {
+        self.graphemes = graphemes;
+        self
+    }
 }
 
 impl From<NonZeroUsize> for EdgeNgramTokenFilter {
@@ -104,6 +227,13 @@ impl TokenFilter for EdgeNgramTokenFilter {
     type Tokenizer<T: Tokenizer> = EdgeNgramFilterWrapper<T>;
 
     fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
-        EdgeNgramFilterWrapper::new(tokenizer, self.min, self.max, self.keep_original_token)
+        EdgeNgramFilterWrapper::new(
+            tokenizer,
+            self.min,
+            self.max,
+            self.keep_original_token,
+            self.side,
+            self.graphemes,
+        )
     }
 }
@@ -2,6 +2,10 @@
 //! do the real job.
 
 use tantivy_tokenizer_api::{Token, TokenStream};
+#[cfg(feature = "graphemes")]
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::EdgeSide;
 
 #[derive(Clone, Debug)]
 pub struct EdgeNgramFilterStream<T> {
@@ -16,7 +20,14 @@ pub struct EdgeNgramFilterStream<T> {
     pub(crate) count: usize,
     /// Do we have to keep the original token?
     pub(crate) keep_original_token: bool,
-    /// Avoid doing multiple time self.tail.token().chars().count()
+    /// Side from which ngrams are generated
+    pub(crate) side: EdgeSide,
+    /// Count extended grapheme clusters instead of chars
+    pub(crate) graphemes: bool,
+    /// Start, in bytes, of each char (or grapheme cluster) of the current token,
+    /// followed by the length of the token
+    pub(crate) starts: Vec<usize>,
+    /// Number of chars (or grapheme clusters) of the current token
     pub(crate) current_len: usize,
     /// Stop at
     pub(crate) stop_length: usize,
@@ -33,7 +44,19 @@ impl<T: TokenStream> TokenStream for EdgeNgramFilterStream<T> {
 
                 self.token = self.tail.token().clone();
                 // Reset everything with new token
-                self.current_len = self.tail.token().text.chars().count();
+                let text = &self.tail.token().text;
+                self.starts.clear();
+                match self.graphemes {
+                    #[cfg(feature = "graphemes")]
+                    true => self
+                        .starts
+                        .extend(text.grapheme_indices(true).map(|(index, _)| index)),
+                    _ => self
+                        .starts
+                        .extend(text.char_indices().map(|(index, _)| index)),
+                }
+                self.starts.push(text.len());
+                self.current_len = self.starts.len() - 1;
 
                 // If we have to keep the original token but its length
                 // is lower than min, then we force output it
@@ -48,9 +71,12 @@ impl<T: TokenStream> TokenStream for EdgeNgramFilterStream<T> {
             }
 
             if self.count <= self.stop_length {
-                let token_string: String =
-                    self.tail.token().text.chars().take(self.count).collect();
-                self.token.text = token_string;
+                let text = &self.tail.token().text;
+                let token_string = match self.side {
+                    EdgeSide::Front => &text[..self.starts[self.count]],
+                    EdgeSide::Back => &text[self.starts[self.current_len - self.count]..],
+                };
+                self.token.text = token_string.to_string();
 
                 // We have reached the end of token, so we reset the count to min
                 if self.count == self.stop_length {
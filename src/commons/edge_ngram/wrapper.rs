@@ -6,13 +6,15 @@ use std::num::NonZeroUsize;
 
 use tantivy_tokenizer_api::Tokenizer;
 
-use super::EdgeNgramFilterStream;
+use super::{EdgeNgramFilterStream, EdgeSide};
 
 #[derive(Clone, Debug)]
 pub struct EdgeNgramFilterWrapper<T> {
     min: NonZeroUsize,
     max: Option<NonZeroUsize>,
     keep_original_token: bool,
+    side: EdgeSide,
+    graphemes: bool,
     inner: T,
 }
 
@@ -22,11 +24,15 @@ impl<T> EdgeNgramFilterWrapper<T> {
         min: NonZeroUsize,
         max: Option<NonZeroUsize>,
         keep_original_token: bool,
+        side: EdgeSide,
+        graphemes: bool,
     ) -> Self {
         Self {
             min,
             max,
             keep_original_token,
+            side,
+            graphemes,
             inner,
         }
     }
@@ -43,6 +49,9 @@ impl<T: Tokenizer> Tokenizer for EdgeNgramFilterWrapper<T> {
             max: self.max.map(|v| v.get()),
             count: self.min.get(),
             keep_original_token: self.keep_original_token,
+            side: self.side,
+            graphemes: self.graphemes,
+            starts: Vec::new(),
             current_len: 0,
             stop_length: 0,
         }
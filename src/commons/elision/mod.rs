@@ -162,4 +162,54 @@ mod tests {
         ];
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_multi_letter_elision() {
+        let result = tokenize_all("qu'il jusqu’ici quoi", vec!["qu", "jusqu"], false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 3,
+                offset_to: 5,
+                position: 0,
+                text: "il".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 17,
+                position: 1,
+                text: "ici".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 18,
+                offset_to: 22,
+                position: 2,
+                text: "quoi".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mixed_apostrophes() {
+        let texts = |filter: ElisionTokenFilter| -> Vec<String> {
+            let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+                .filter(filter)
+                .build();
+            let mut token_stream = a.token_stream("l'un et l’autre");
+            let mut texts = vec![];
+            token_stream.process(&mut |token: &Token| texts.push(token.text.clone()));
+            texts
+        };
+
+        let filter = ElisionTokenFilter::from_iter_str(vec!["l"], false);
+        assert_eq!(texts(filter.clone()), vec!["un", "et", "autre"]);
+        assert_eq!(
+            texts(filter.typographic_apostrophe(false)),
+            vec!["un", "et", "l’autre"]
+        );
+    }
 }
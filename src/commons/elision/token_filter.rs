@@ -8,6 +8,12 @@ use super::ElisionFilterWrapper;
 /// A token filter that removes elision from a token.
 /// For example, the token `l'avion` will
 /// become `avion`.
+///
+/// Elisions are whole articles, such as `l`, `qu` or `jusqu`, that are matched against
+/// everything before the first apostrophe. Both the ASCII apostrophe `'` and the right
+/// single quotation mark `’` (U+2019), common in typeset text, are recognized. Use
+/// [typographic_apostrophe](ElisionTokenFilter::typographic_apostrophe) to only
+/// recognize the ASCII one.
 /// ```rust
 /// use tantivy_analysis_contrib::commons::ElisionTokenFilter;
 ///
@@ -64,6 +70,8 @@ pub struct ElisionTokenFilter {
     pub elisions: FxHashSet<String>,
     /// Indicates that elisions are case-insensitive
     pub ignore_case: bool,
+    /// Indicates that `’` (U+2019) is an apostrophe, as well as `'`
+    pub typographic_apostrophe: bool,
 }
 
 impl ElisionTokenFilter {
@@ -79,6 +87,7 @@ impl ElisionTokenFilter {
         Self {
             elisions,
             ignore_case,
+            typographic_apostrophe: true,
         }
     }
 
@@ -103,14 +112,27 @@ impl ElisionTokenFilter {
         Self {
             elisions,
             ignore_case,
+            typographic_apostrophe: true,
         }
     }
+
+    /// Set whether `’` (U+2019) is an apostrophe. It's `true` by default, with `false`
+    /// only `'` starts an elision.
+    pub fn typographic_apostrophe(mut self, typographic_apostrophe: bool) -> Self {
+        self.typographic_apostrophe = typographic_apostrophe;
+        self
+    }
 }
 
 impl TokenFilter for ElisionTokenFilter {
     type Tokenizer<T: Tokenizer> = ElisionFilterWrapper<T>;
 
     fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
-        ElisionFilterWrapper::new(token_stream, Arc::new(self.elisions), self.ignore_case)
+        ElisionFilterWrapper::new(
+            token_stream,
+            Arc::new(self.elisions),
+            self.ignore_case,
+            self.typographic_apostrophe,
+        )
     }
 }
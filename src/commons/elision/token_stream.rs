@@ -12,14 +12,21 @@ pub struct ElisionTokenStream<T> {
     // Use a BTreeSet as this set should be small otherwise use HashSet.
     elisions: Arc<FxHashSet<String>>,
     ignore_case: bool,
+    typographic_apostrophe: bool,
 }
 
 impl<T> ElisionTokenStream<T> {
-    pub(crate) fn new(tail: T, elisions: Arc<FxHashSet<String>>, ignore_case: bool) -> Self {
+    pub(crate) fn new(
+        tail: T,
+        elisions: Arc<FxHashSet<String>>,
+        ignore_case: bool,
+        typographic_apostrophe: bool,
+    ) -> Self {
         Self {
             tail,
             elisions,
             ignore_case,
+            typographic_apostrophe,
         }
     }
 }
@@ -30,8 +37,11 @@ impl<T: TokenStream> TokenStream for ElisionTokenStream<T> {
             return false;
         }
         let token = &self.tail.token().text;
-        let found: Option<(usize, char)> = token.char_indices().find(|(_, ch)| ch == &'\'');
-        if let Some((index, _)) = found {
+        let typographic_apostrophe = self.typographic_apostrophe;
+        let found: Option<(usize, char)> = token
+            .char_indices()
+            .find(|(_, ch)| ch == &'\'' || (typographic_apostrophe && ch == &'\u{2019}'));
+        if let Some((index, apostrophe)) = found {
             let prefix = &self.tail.token().text[0..index];
             let contains = if self.ignore_case {
                 self.elisions.contains(&prefix.to_lowercase())
@@ -39,8 +49,9 @@ impl<T: TokenStream> TokenStream for ElisionTokenStream<T> {
                 self.elisions.contains(prefix)
             };
             if contains {
-                self.tail.token_mut().text = token[index + 1..].to_string();
-                self.tail.token_mut().offset_from = self.tail.token_mut().offset_from + index + 1;
+                let end = index + apostrophe.len_utf8();
+                self.tail.token_mut().text = token[end..].to_string();
+                self.tail.token_mut().offset_from = self.tail.token_mut().offset_from + end;
             }
         }
 
@@ -13,14 +13,21 @@ use super::ElisionTokenStream;
 pub struct ElisionFilterWrapper<T> {
     elisions: Arc<FxHashSet<String>>,
     ignore_case: bool,
+    typographic_apostrophe: bool,
     inner: T,
 }
 
 impl<T> ElisionFilterWrapper<T> {
-    pub(crate) fn new(inner: T, elisions: Arc<FxHashSet<String>>, ignore_case: bool) -> Self {
+    pub(crate) fn new(
+        inner: T,
+        elisions: Arc<FxHashSet<String>>,
+        ignore_case: bool,
+        typographic_apostrophe: bool,
+    ) -> Self {
         Self {
             elisions,
             ignore_case,
+            typographic_apostrophe,
             inner,
         }
     }
@@ -34,6 +41,7 @@ impl<T: Tokenizer> Tokenizer for ElisionFilterWrapper<T> {
             self.inner.token_stream(text),
             self.elisions.clone(),
             self.ignore_case,
+            self.typographic_apostrophe,
         )
     }
 }
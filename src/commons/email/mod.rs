@@ -0,0 +1,155 @@
+pub use token_filter::{EmailNormalizeTokenFilter, EmailNormalizeTokenFilterBuilder};
+use token_stream::EmailNormalizeTokenStream;
+use wrapper::EmailNormalizeFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, filter: EmailNormalizeTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_lowercase_domain() {
+        let result =
+            token_stream_helper("John.Doe@Example.COM", EmailNormalizeTokenFilter::default());
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 20,
+            position: 0,
+            text: "John.Doe@example.com".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_lowercase_local_part() {
+        let filter = EmailNormalizeTokenFilterBuilder::default()
+            .lowercase_local_part(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("John.Doe@Example.COM", filter);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 20,
+            position: 0,
+            text: "john.doe@example.com".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_strip_plus_tag_and_dots() {
+        let filter = EmailNormalizeTokenFilterBuilder::default()
+            .strip_plus_tag(true)
+            .strip_dots(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("j.doe+news@gmail.com j.doe+@gmail.com", filter);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 20,
+                position: 0,
+                text: "jdoe@gmail.com".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 21,
+                offset_to: 37,
+                position: 1,
+                text: "jdoe@gmail.com".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_strip_dots_only_gmail() {
+        let filter = EmailNormalizeTokenFilterBuilder::default()
+            .strip_dots(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("j.doe@example.com j.doe@GoogleMail.com", filter);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 17,
+                position: 0,
+                text: "j.doe@example.com".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 18,
+                offset_to: 38,
+                position: 1,
+                text: "jdoe@googlemail.com".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_not_email() {
+        let filter = EmailNormalizeTokenFilterBuilder::default()
+            .lowercase_local_part(true)
+            .strip_plus_tag(true)
+            .strip_dots(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("Hello.World @Twitter a@B a@@B.com", filter);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 11,
+                position: 0,
+                text: "Hello.World".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 20,
+                position: 1,
+                text: "@Twitter".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 21,
+                offset_to: 24,
+                position: 2,
+                text: "a@B".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 25,
+                offset_to: 33,
+                position: 3,
+                text: "a@@B.com".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,70 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::EmailNormalizeFilterWrapper;
+
+/// A token filter that normalizes tokens that are email addresses. Tokens
+/// that are not valid email addresses are left untouched.
+///
+/// The domain is always lowercased. Optionally :
+/// * the local part (before the `@`) can be lowercased,
+/// * the plus-tag can be removed from the local part (`john+news@example.com` becomes `john@example.com`),
+/// * dots can be removed from the local part of Gmail addresses (`j.doe@gmail.com` becomes
+///   `jdoe@gmail.com`).
+///
+/// Dots are only removed for `gmail.com` and `googlemail.com`, where they are not
+/// significant. For other providers `j.doe` and `jdoe` may be different mailboxes.
+///
+/// To construct a new [EmailNormalizeTokenFilter] you can use the [EmailNormalizeTokenFilterBuilder]
+/// or the [Default] implementation that only lowercases the domain.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::EmailNormalizeTokenFilterBuilder;
+///
+/// let filter = EmailNormalizeTokenFilterBuilder::default()
+///     .lowercase_local_part(true)
+///     .strip_plus_tag(true)
+///     .strip_dots(true)
+///     .build()?;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("John.Doe+News@GMail.com Hello");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "johndoe@gmail.com".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Hello".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Builder)]
+#[builder(setter(into), default)]
+pub struct EmailNormalizeTokenFilter {
+    /// Lowercase the local part (before the `@`).
+    pub lowercase_local_part: bool,
+    /// Remove everything from the first `+` in the local part.
+    pub strip_plus_tag: bool,
+    /// Remove dots from the local part of `gmail.com` and `googlemail.com` addresses.
+    pub strip_dots: bool,
+}
+
+impl TokenFilter for EmailNormalizeTokenFilter {
+    type Tokenizer<T: Tokenizer> = EmailNormalizeFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        EmailNormalizeFilterWrapper::new(
+            token_stream,
+            self.lowercase_local_part,
+            self.strip_plus_tag,
+            self.strip_dots,
+        )
+    }
+}
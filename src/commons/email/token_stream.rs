@@ -0,0 +1,106 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+/// Domains where dots of the local part are not significant.
+const DOTLESS_DOMAINS: [&str; 2] = ["gmail.com", "googlemail.com"];
+
+#[derive(Clone, Debug)]
+pub struct EmailNormalizeTokenStream<T> {
+    tail: T,
+    lowercase_local_part: bool,
+    strip_plus_tag: bool,
+    strip_dots: bool,
+}
+
+impl<T> EmailNormalizeTokenStream<T> {
+    pub(crate) fn new(
+        tail: T,
+        lowercase_local_part: bool,
+        strip_plus_tag: bool,
+        strip_dots: bool,
+    ) -> Self {
+        Self {
+            tail,
+            lowercase_local_part,
+            strip_plus_tag,
+            strip_dots,
+        }
+    }
+
+    /// Returns the normalized email or [None] if the text is not
+    /// an email address.
+    fn normalize(&self, text: &str) -> Option<String> {
+        let (local, domain) = text.split_once('@')?;
+        if !is_valid_local_part(local) || !is_valid_domain(domain) {
+            return None;
+        }
+
+        let mut local = local;
+        if self.strip_plus_tag {
+            if let Some((untagged, _)) = local.split_once('+') {
+                if !untagged.is_empty() {
+                    local = untagged;
+                }
+            }
+        }
+
+        let domain = domain.to_lowercase();
+        let strip_dots = self.strip_dots && DOTLESS_DOMAINS.contains(&domain.as_str());
+        let mut result = String::with_capacity(text.len());
+        for c in local.chars().filter(|c| !strip_dots || *c != '.') {
+            if self.lowercase_local_part {
+                result.extend(c.to_lowercase());
+            } else {
+                result.push(c);
+            }
+        }
+        result.push('@');
+        result.push_str(&domain);
+
+        Some(result)
+    }
+}
+
+fn is_valid_local_part(local: &str) -> bool {
+    !local.is_empty()
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && !local.contains("..")
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c))
+}
+
+fn is_valid_domain(domain: &str) -> bool {
+    domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+impl<T: TokenStream> TokenStream for EmailNormalizeTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        if let Some(normalized) = self.normalize(&self.tail.token().text) {
+            self.tail.token_mut().text = normalized;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,44 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::EmailNormalizeTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct EmailNormalizeFilterWrapper<T> {
+    lowercase_local_part: bool,
+    strip_plus_tag: bool,
+    strip_dots: bool,
+    inner: T,
+}
+
+impl<T> EmailNormalizeFilterWrapper<T> {
+    pub(crate) fn new(
+        inner: T,
+        lowercase_local_part: bool,
+        strip_plus_tag: bool,
+        strip_dots: bool,
+    ) -> Self {
+        Self {
+            lowercase_local_part,
+            strip_plus_tag,
+            strip_dots,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for EmailNormalizeFilterWrapper<T> {
+    type TokenStream<'a> = EmailNormalizeTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        EmailNormalizeTokenStream::new(
+            self.inner.token_stream(text),
+            self.lowercase_local_part,
+            self.strip_plus_tag,
+            self.strip_dots,
+        )
+    }
+}
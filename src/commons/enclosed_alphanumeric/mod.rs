@@ -0,0 +1,77 @@
+pub use token_filter::EnclosedAlphanumericTokenFilter;
+pub(crate) use token_stream::enclosed_number;
+use token_stream::EnclosedAlphanumericTokenStream;
+use wrapper::EnclosedAlphanumericFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(EnclosedAlphanumericTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn texts(text: &str) -> Vec<String> {
+        token_stream_helper(text)
+            .into_iter()
+            .map(|token| token.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_circled() {
+        let result = texts("Ⓗⓔⓛⓛⓞ ①②③ ⑳ ㊿ ⓪ 🅝🅔🅦");
+        assert_eq!(result, vec!["Hello", "123", "20", "50", "0", "NEW"]);
+    }
+
+    #[test]
+    fn test_parenthesized() {
+        let result = texts("⒜⒝⒞ 🄐 ⑴ ⒇ ⒈");
+        assert_eq!(result, vec!["abc", "A", "1", "20", "1"]);
+    }
+
+    #[test]
+    fn test_squared_and_fullwidth() {
+        let result = texts("🄲🄾🄾🄻 🅲🅾🅾🅻 ｆｕｌｌ ＷＩＤＴＨ ２０２４");
+        assert_eq!(result, vec!["COOL", "COOL", "full", "WIDTH", "2024"]);
+    }
+
+    #[test]
+    fn test_offsets() {
+        let result = token_stream_helper("ⓐⓑ plain");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "ab".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 12,
+                position: 1,
+                text: "plain".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,50 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::EnclosedAlphanumericFilterWrapper;
+
+/// This [TokenFilter] replaces enclosed and fullwidth letters and numbers, found in
+/// product listings and social media, by their plain form :
+/// * circled, parenthesized, squared and negative letters (`Ⓐ`, `ⓐ`, `⒜`, `🄰`, `🅐`, `🅰`...),
+/// * circled, parenthesized and full stop numbers (`①`, `⑴`, `⒈`, `❶`, `㉑`...),
+/// * fullwidth letters and digits (`Ａ`, `ａ`, `０`...).
+///
+/// It follows the compatibility decomposition (NFKC) when there is one, except that
+/// the enclosing parentheses and full stops are dropped : `⒜` becomes `a` and `⑫`
+/// becomes `12`, so the token matches the plain letter or number. Negative letters,
+/// that have no decomposition, are replaced too. Offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::EnclosedAlphanumericTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(EnclosedAlphanumericTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("ⓢⓐⓛⓔ ⑴ ＵＳＢ３");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "sale".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "1".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "USB3".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EnclosedAlphanumericTokenFilter;
+
+impl TokenFilter for EnclosedAlphanumericTokenFilter {
+    type Tokenizer<T: Tokenizer> = EnclosedAlphanumericFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        EnclosedAlphanumericFilterWrapper::new(token_stream)
+    }
+}
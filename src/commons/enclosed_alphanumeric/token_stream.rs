@@ -0,0 +1,131 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use crate::commons::map_chars::{map_chars, Replacement};
+
+/// ASCII digits of the numbers from 0 to 50, the values of enclosed numbers.
+const NUMBERS: [&str; 51] = [
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16",
+    "17", "18", "19", "20", "21", "22", "23", "24", "25", "26", "27", "28", "29", "30", "31", "32",
+    "33", "34", "35", "36", "37", "38", "39", "40", "41", "42", "43", "44", "45", "46", "47", "48",
+    "49", "50",
+];
+
+/// Offset of a letter in a range starting with `A` (or `a`).
+fn letter(base: char, ch: char, start: u32) -> Option<char> {
+    char::from_u32(base as u32 + ch as u32 - start)
+}
+
+/// Plain letter of an enclosed or fullwidth letter.
+fn enclosed_letter(ch: char) -> Option<char> {
+    match ch {
+        // ⒜ to ⒵
+        '\u{249C}'..='\u{24B5}' => letter('a', ch, 0x249C),
+        // Ⓐ to Ⓩ
+        '\u{24B6}'..='\u{24CF}' => letter('A', ch, 0x24B6),
+        // ⓐ to ⓩ
+        '\u{24D0}'..='\u{24E9}' => letter('a', ch, 0x24D0),
+        // 🄐 to 🄩
+        '\u{1F110}'..='\u{1F129}' => letter('A', ch, 0x1F110),
+        // 🄰 to 🅉
+        '\u{1F130}'..='\u{1F149}' => letter('A', ch, 0x1F130),
+        // 🅐 to 🅩
+        '\u{1F150}'..='\u{1F169}' => letter('A', ch, 0x1F150),
+        // 🅰 to 🆉
+        '\u{1F170}'..='\u{1F189}' => letter('A', ch, 0x1F170),
+        // Ａ to Ｚ
+        '\u{FF21}'..='\u{FF3A}' => letter('A', ch, 0xFF21),
+        // ａ to ｚ
+        '\u{FF41}'..='\u{FF5A}' => letter('a', ch, 0xFF41),
+        _ => None,
+    }
+}
+
+/// Value of a circled, parenthesized or full stop number.
+fn enclosed_value(ch: char) -> Option<u32> {
+    let value = ch as u32;
+    match ch {
+        // ⓪, ⓿
+        '\u{24EA}' | '\u{24FF}' => Some(0),
+        // ① to ⑳
+        '\u{2460}'..='\u{2473}' => Some(value - 0x2460 + 1),
+        // ⑴ to ⒇
+        '\u{2474}'..='\u{2487}' => Some(value - 0x2474 + 1),
+        // ⒈ to ⒛
+        '\u{2488}'..='\u{249B}' => Some(value - 0x2488 + 1),
+        // ⓫ to ⓴
+        '\u{24EB}'..='\u{24F4}' => Some(value - 0x24EB + 11),
+        // ⓵ to ⓾
+        '\u{24F5}'..='\u{24FE}' => Some(value - 0x24F5 + 1),
+        // ❶ to ❿
+        '\u{2776}'..='\u{277F}' => Some(value - 0x2776 + 1),
+        // ➀ to ➉
+        '\u{2780}'..='\u{2789}' => Some(value - 0x2780 + 1),
+        // ➊ to ➓
+        '\u{278A}'..='\u{2793}' => Some(value - 0x278A + 1),
+        // ㉑ to ㉟
+        '\u{3251}'..='\u{325F}' => Some(value - 0x3251 + 21),
+        // ㊱ to ㊿
+        '\u{32B1}'..='\u{32BF}' => Some(value - 0x32B1 + 36),
+        _ => None,
+    }
+}
+
+/// ASCII digits of a circled, parenthesized or full stop number. It is also used by
+/// the [SuperscriptDigitTokenFilter](crate::commons::SuperscriptDigitTokenFilter).
+pub(crate) fn enclosed_number(ch: char) -> Option<&'static str> {
+    enclosed_value(ch).and_then(|value| NUMBERS.get(value as usize).copied())
+}
+
+/// ASCII digits of an enclosed or fullwidth number.
+fn number(ch: char) -> Option<&'static str> {
+    let value = match ch {
+        // ０ to ９
+        '\u{FF10}'..='\u{FF19}' => ch as u32 - 0xFF10,
+        // 🄀, then 🄁 to 🄊
+        '\u{1F100}' => 0,
+        '\u{1F101}'..='\u{1F10A}' => ch as u32 - 0x1F101,
+        _ => return enclosed_number(ch),
+    };
+    NUMBERS.get(value as usize).copied()
+}
+
+#[derive(Debug, Clone)]
+pub struct EnclosedAlphanumericTokenStream<T> {
+    tail: T,
+}
+
+impl<T> EnclosedAlphanumericTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self { tail }
+    }
+}
+
+impl<T: TokenStream> TokenStream for EnclosedAlphanumericTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let text = map_chars(&self.tail.token().text, |ch| {
+            enclosed_letter(ch)
+                .map(Replacement::Char)
+                .or_else(|| number(ch).map(Replacement::Str))
+        });
+        if let Some(text) = text {
+            self.tail.token_mut().text = text;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
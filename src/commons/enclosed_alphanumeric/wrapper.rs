@@ -0,0 +1,26 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::EnclosedAlphanumericTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct EnclosedAlphanumericFilterWrapper<T> {
+    inner: T,
+}
+
+impl<T> EnclosedAlphanumericFilterWrapper<T> {
+    pub(crate) fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for EnclosedAlphanumericFilterWrapper<T> {
+    type TokenStream<'a> = EnclosedAlphanumericTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        EnclosedAlphanumericTokenStream::new(self.inner.token_stream(text))
+    }
+}
@@ -0,0 +1,78 @@
+//! Helpers to measure how much an analysis chain expands a text.
+
+use tantivy_tokenizer_api::{TokenStream, Tokenizer};
+
+/// Get the ratio between the number of tokens emitted by a [Tokenizer] and the number
+/// of whitespace separated words of the text.
+///
+/// It helps to detect analysis chains that over-expand text (aggressive n-gramming,
+/// synonyms blowup, ...) before indexing at scale. A plain tokenizer has a ratio close
+/// to `1.0`. If the text has no words, the ratio is `0.0`.
+///
+/// Analysis chains can be built by applying [TokenFilter](tantivy_tokenizer_api::TokenFilter)
+/// on a tokenizer with [transform](tantivy_tokenizer_api::TokenFilter::transform).
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::num::NonZeroUsize;
+/// use tantivy::tokenizer::{TokenFilter, WhitespaceTokenizer};
+/// use tantivy_analysis_contrib::commons::{expansion_ratio, EdgeNgramTokenFilter};
+///
+/// let mut tokenizer = EdgeNgramTokenFilter::new(NonZeroUsize::new(1).unwrap(), None, false)?
+///     .transform(WhitespaceTokenizer::default());
+///
+/// assert_eq!(expansion_ratio(&mut tokenizer, "ab abcd"), 3.0);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn expansion_ratio<T: Tokenizer>(tokenizer: &mut T, text: &str) -> f32 {
+    let words = text.split_whitespace().count();
+    if words == 0 {
+        return 0.0;
+    }
+
+    let mut token_stream = tokenizer.token_stream(text);
+    let mut tokens = 0usize;
+    while token_stream.advance() {
+        tokens += 1;
+    }
+
+    tokens as f32 / words as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use tantivy::tokenizer::{TokenFilter, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::EdgeNgramTokenFilter;
+
+    #[test]
+    fn test_plain() {
+        let mut tokenizer = WhitespaceTokenizer::default();
+        let ratio = expansion_ratio(&mut tokenizer, "the quick brown fox");
+        assert_eq!(ratio, 1.0);
+    }
+
+    #[test]
+    fn test_edge_ngram() {
+        let mut tokenizer =
+            EdgeNgramTokenFilter::new(NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(4), false)
+                .unwrap()
+                .transform(WhitespaceTokenizer::default());
+        // "quick" -> qu, qui, quic; "brown" -> br, bro, brow; "fox" -> fo, fox
+        let ratio = expansion_ratio(&mut tokenizer, "quick brown fox");
+        assert_eq!(ratio, 8.0 / 3.0);
+    }
+
+    #[test]
+    fn test_no_words() {
+        let mut tokenizer = WhitespaceTokenizer::default();
+        let ratio = expansion_ratio(&mut tokenizer, " \t ");
+        assert_eq!(ratio, 0.0);
+    }
+}
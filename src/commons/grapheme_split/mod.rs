@@ -0,0 +1,73 @@
+pub use token_filter::GraphemeSplitTokenFilter;
+use token_stream::GraphemeSplitTokenStream;
+use wrapper::GraphemeSplitFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(GraphemeSplitTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_emoji_zwj_sequence() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let result = token_stream_helper(&format!("{family}!"));
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 18,
+                position: 0,
+                text: family.to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 18,
+                offset_to: 19,
+                position: 1,
+                text: "!".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_devanagari_cluster() {
+        // "नमस्ते" : न, म and the conjunct स्ते (स, virama, त and the vowel sign े)
+        let result = token_stream_helper("नमस्ते ok");
+        let texts: Vec<&str> = result.iter().map(|token| token.text.as_str()).collect();
+        assert_eq!(texts, vec!["न", "म", "स्ते", "o", "k"]);
+
+        let positions: Vec<usize> = result.iter().map(|token| token.position).collect();
+        assert_eq!(positions, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_combining_mark() {
+        let result = token_stream_helper("e\u{0301}a");
+        let texts: Vec<(&str, usize, usize)> = result
+            .iter()
+            .map(|token| (token.text.as_str(), token.offset_from, token.offset_to))
+            .collect();
+        assert_eq!(texts, vec![("e\u{0301}", 0, 3), ("a", 3, 4)]);
+    }
+}
@@ -0,0 +1,51 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::GraphemeSplitFilterWrapper;
+
+/// [TokenFilter] that splits tokens into one token per extended grapheme cluster,
+/// what a reader sees as a single character.
+///
+/// Unlike splitting on characters, emoji sequences (ZWJ sequences like `👨‍👩‍👧`, flags,
+/// skin tones), letters followed by combining marks and Indic clusters (`क्षि`) are kept
+/// as single tokens. It allows searching emoji or scripts written without spaces one
+/// cluster at a time.
+///
+/// Each cluster takes its own position. Offsets of a cluster are its offsets in the
+/// original text when the token text has the length of its offsets, otherwise clusters
+/// keep the offsets of the token.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::GraphemeSplitTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(GraphemeSplitTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("a👨‍👩‍👧é");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "a".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "👨‍👩‍👧".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "é".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GraphemeSplitTokenFilter;
+
+impl TokenFilter for GraphemeSplitTokenFilter {
+    type Tokenizer<T: Tokenizer> = GraphemeSplitFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        GraphemeSplitFilterWrapper::new(tokenizer)
+    }
+}
@@ -0,0 +1,74 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug, Clone)]
+pub struct GraphemeSplitTokenStream<T> {
+    tail: T,
+    /// Positions taken by clusters of previous tokens.
+    position_shift: usize,
+    buffer: VecDeque<Token>,
+    token: Token,
+}
+
+impl<T> GraphemeSplitTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self {
+            tail,
+            position_shift: 0,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for GraphemeSplitTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.buffer.pop_front() {
+                self.token = token;
+                return true;
+            }
+
+            if !self.tail.advance() {
+                return false;
+            }
+
+            let token = self.tail.token();
+            // Offsets can only be adjusted if they match the text
+            let adjust_offsets = token.offset_to - token.offset_from == token.text.len();
+            let position = token.position + self.position_shift;
+            let mut count = 0;
+            for (i, (index, grapheme)) in token.text.grapheme_indices(true).enumerate() {
+                let (offset_from, offset_to) = if adjust_offsets {
+                    let offset_from = token.offset_from + index;
+                    (offset_from, offset_from + grapheme.len())
+                } else {
+                    (token.offset_from, token.offset_to)
+                };
+                self.buffer.push_back(Token {
+                    offset_from,
+                    offset_to,
+                    position: position + i,
+                    text: grapheme.to_string(),
+                    position_length: token.position_length,
+                });
+                count += 1;
+            }
+            // Empty tokens are removed
+            self.position_shift += count.max(1) - 1;
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
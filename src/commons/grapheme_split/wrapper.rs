@@ -0,0 +1,26 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::GraphemeSplitTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct GraphemeSplitFilterWrapper<T> {
+    inner: T,
+}
+
+impl<T> GraphemeSplitFilterWrapper<T> {
+    pub(crate) fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for GraphemeSplitFilterWrapper<T> {
+    type TokenStream<'a> = GraphemeSplitTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        GraphemeSplitTokenStream::new(self.inner.token_stream(text))
+    }
+}
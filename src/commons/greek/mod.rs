@@ -0,0 +1,120 @@
+pub use token_filter::GreekNormalizationTokenFilter;
+use token_stream::GreekNormalizationTokenStream;
+use wrapper::GreekNormalizationFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(GreekNormalizationTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_tonos() {
+        let result = token_stream_helper("Γράφεται ΆΈΉΊΌΎΏ");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 16,
+                position: 0,
+                text: "γραφεται".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 17,
+                offset_to: 31,
+                position: 1,
+                text: "αεηιουω".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_dialytika() {
+        let result = token_stream_helper("Μάϊος προϋπόθεση ΐ ΰ");
+        let expected: Vec<&str> = vec!["μαιοσ", "προυποθεση", "ι", "υ"];
+
+        let result: Vec<String> = result.into_iter().map(|t| t.text).collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_decomposed() {
+        // "ά" as alpha + combining acute accent and "ϊ" as iota + combining diaeresis.
+        let result = token_stream_helper("Μα\u{0301}ι\u{0308}ος");
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 14,
+            position: 0,
+            text: "μαιοσ".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_final_sigma() {
+        let result = token_stream_helper("ΟΔΟΣ οδος οδοϲ");
+        let result: Vec<String> = result.into_iter().map(|t| t.text).collect();
+        let expected: Vec<&str> = vec!["οδοσ", "οδοσ", "οδοσ"];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_non_greek() {
+        let result = token_stream_helper("Café");
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 5,
+            position: 0,
+            text: "café".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_non_greek_combining_marks() {
+        // "é" as e + combining acute accent and "ë" as e + combining diaeresis.
+        let result = token_stream_helper("Cafe\u{0301} Noe\u{0308}l");
+        let result: Vec<String> = result.into_iter().map(|t| t.text).collect();
+        let expected: Vec<&str> = vec!["cafe\u{0301}", "noe\u{0308}l"];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mixed_script_combining_marks() {
+        // Marks are removed after a Greek character, even after another mark, and kept
+        // after a Latin one in the same token.
+        let result = token_stream_helper("ε\u{0313}\u{0301}e\u{0301}ι\u{0308}");
+        let result: Vec<String> = result.into_iter().map(|t| t.text).collect();
+        let expected: Vec<&str> = vec!["ε\u{0313}e\u{0301}ι"];
+
+        assert_eq!(result, expected);
+    }
+}
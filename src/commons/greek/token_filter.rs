@@ -0,0 +1,47 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::GreekNormalizationFilterWrapper;
+
+/// This [TokenFilter] normalizes Greek tokens for accent-insensitive search. It is an
+/// equivalent of Lucene's
+/// [GreekLowerCaseFilter](https://lucene.apache.org/core/9_1_0/analysis/common/org/apache/lucene/analysis/el/GreekLowerCaseFilter.html) :
+/// * it lowercases text,
+/// * it removes tonos and dialytika (`ά` becomes `α`, `ϊ` becomes `ι`),
+/// * it replaces final sigma `ς` (and lunate sigma `ϲ`) with `σ`.
+///
+/// Other characters are only lowercased (`É` becomes `é`). The combining acute (tonos),
+/// diaeresis (dialytika) and dialytika tonos marks are only removed when they follow a
+/// Greek character, so the decomposed `ε\u{301}` becomes `ε` but `e\u{301}` is kept.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::GreekNormalizationTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(GreekNormalizationTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("Γράφεται Μάιος");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "γραφεται".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "μαιοσ".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct GreekNormalizationTokenFilter;
+
+impl TokenFilter for GreekNormalizationTokenFilter {
+    type Tokenizer<T: Tokenizer> = GreekNormalizationFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        GreekNormalizationFilterWrapper::new(token_stream)
+    }
+}
@@ -0,0 +1,93 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::mem;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+/// Combining acute accent (tonos when decomposed).
+const COMBINING_ACUTE: char = '\u{0301}';
+/// Combining diaeresis (dialytika when decomposed).
+const COMBINING_DIAERESIS: char = '\u{0308}';
+/// Combining greek dialytika tonos.
+const COMBINING_DIALYTIKA_TONOS: char = '\u{0344}';
+
+#[derive(Debug, Clone)]
+pub struct GreekNormalizationTokenStream<T> {
+    tail: T,
+    buffer: String,
+}
+
+impl<T> GreekNormalizationTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self {
+            tail,
+            buffer: String::with_capacity(100),
+        }
+    }
+}
+
+/// Whether `ch` is in the Greek and Coptic or the Greek Extended blocks.
+fn is_greek(ch: char) -> bool {
+    matches!(ch, '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}')
+}
+
+/// Whether `ch` is in the Combining Diacritical Marks block.
+fn is_combining(ch: char) -> bool {
+    matches!(ch, '\u{0300}'..='\u{036F}')
+}
+
+/// Normalize a token. Decomposed tonos and dialytika are only removed when they follow
+/// a Greek character, possibly after other marks.
+fn normalize_text(text: &str, buffer: &mut String) {
+    let mut after_greek = false;
+    for ch in text.chars() {
+        match ch {
+            COMBINING_ACUTE | COMBINING_DIAERESIS | COMBINING_DIALYTIKA_TONOS if after_greek => {}
+            _ if is_combining(ch) => buffer.push(ch),
+            _ => {
+                after_greek = is_greek(ch);
+                normalize(ch, buffer);
+            }
+        }
+    }
+}
+
+/// Map a Greek character to its normalized form. This is
+/// the same mapping as Lucene's.
+fn normalize(ch: char, buffer: &mut String) {
+    match ch {
+        // Final sigma and lunate sigma
+        'ς' | 'ϲ' | 'Ϲ' => buffer.push('σ'),
+        'Ά' | 'ά' => buffer.push('α'),
+        'Έ' | 'έ' => buffer.push('ε'),
+        'Ή' | 'ή' => buffer.push('η'),
+        'Ί' | 'ί' | 'Ϊ' | 'ϊ' | 'ΐ' => buffer.push('ι'),
+        'Ύ' | 'ύ' | 'Ϋ' | 'ϋ' | 'ΰ' => buffer.push('υ'),
+        'Ό' | 'ό' => buffer.push('ο'),
+        'Ώ' | 'ώ' => buffer.push('ω'),
+        _ => buffer.extend(ch.to_lowercase()),
+    }
+}
+
+impl<T: TokenStream> TokenStream for GreekNormalizationTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        self.buffer.clear();
+        normalize_text(&self.tail.token().text, &mut self.buffer);
+        mem::swap(&mut self.tail.token_mut().text, &mut self.buffer);
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,337 @@
+//! Removal of HTML markup before tokenization.
+
+use crate::commons::OffsetMap;
+
+/// Elements whose content is not text. They are removed with their content.
+const RAW_TEXT: [&str; 2] = ["script", "style"];
+
+/// Elements that separate text, they are replaced by a new line so that words
+/// on both sides are not glued together.
+const BLOCKS: [&str; 37] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "br",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+];
+
+/// Removes HTML markup of a text before it is tokenized, like
+/// [Lucene's HTMLStripCharFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/charfilter/HTMLStripCharFilter.html).
+/// As with [MappingRewriter](crate::commons::MappingRewriter), the returned [OffsetMap]
+/// brings token offsets back to the HTML source, so that highlighting works on it.
+///
+/// * tags, comments, doctype and processing instructions are removed. Block level tags
+///   (`<p>`, `<br>`, `<li>`...) are replaced by a new line, inline ones (`<b>`, `<a>`...)
+///   by nothing,
+/// * `<script>` and `<style>` elements are removed with their content, up to the end of
+///   the text if they are not closed,
+/// * common named entities (`&amp;`, `&lt;`, `&gt;`, `&quot;`, `&apos;`, `&nbsp;`,
+///   `&copy;`...) and numeric ones (`&#169;`, `&#xA9;`) are decoded. Unknown or invalid
+///   entities are kept as is,
+/// * a `<` that doesn't start a tag, or whose tag is not closed by a `>` before the next
+///   `<` or the end of the text, is kept as text.
+///
+/// # Example
+///
+/// ```rust
+/// use tantivy::tokenizer::{SimpleTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::HtmlStripRewriter;
+///
+/// let text = "<p>Fish &amp; <b>chips</b></p>";
+/// let (rewritten, offsets) = HtmlStripRewriter.rewrite(text);
+/// assert_eq!(rewritten, "\nFish & chips\n");
+///
+/// let mut analyzer = TextAnalyzer::builder(SimpleTokenizer::default()).build();
+/// let mut token_stream = analyzer.token_stream(&rewritten);
+/// let mut tokens = vec![];
+/// token_stream.process(&mut |token: &Token| {
+///     let mut token = token.clone();
+///     offsets.correct_token(&mut token);
+///     tokens.push(token);
+/// });
+///
+/// assert_eq!(tokens[1].text, "chips");
+/// assert_eq!(&text[tokens[1].offset_from..tokens[1].offset_to], "chips");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HtmlStripRewriter;
+
+impl HtmlStripRewriter {
+    /// Rewrite `text`. It returns the text without markup and the [OffsetMap] that
+    /// maps its offsets back to `text`.
+    pub fn rewrite(&self, text: &str) -> (String, OffsetMap) {
+        let mut output = String::with_capacity(text.len());
+        let mut offsets = OffsetMap::default();
+
+        let mut position = 0;
+        while let Some(ch) = text[position..].chars().next() {
+            let rest = &text[position..];
+            if let Some((len, to)) = markup(rest) {
+                offsets.replaced(output.len(), position, len);
+                output.push_str(to);
+                position += len;
+            } else if let Some((len, to)) = entity(rest) {
+                offsets.replaced(output.len(), position, len);
+                output.push(to);
+                position += len;
+            } else {
+                offsets.copied(output.len(), position);
+                output.push(ch);
+                position += ch.len_utf8();
+            }
+        }
+        offsets.copied(output.len(), position);
+
+        (output, offsets)
+    }
+}
+
+/// Length of the markup at the start of `text` and its replacement, if `text`
+/// starts with markup.
+fn markup(text: &str) -> Option<(usize, &'static str)> {
+    if let Some(comment) = text.strip_prefix("<!--") {
+        let len = comment.find("-->").map_or(text.len(), |end| end + 7);
+        return Some((len, ""));
+    }
+
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&b'<') {
+        return None;
+    }
+    let closing = bytes.get(1) == Some(&b'/');
+    let name_start = match bytes.get(1)? {
+        b'/' if bytes.get(2).is_some_and(u8::is_ascii_alphabetic) => 2,
+        b'!' | b'?' => 2,
+        b if b.is_ascii_alphabetic() => 1,
+        _ => return None,
+    };
+    let name_len = bytes[name_start..]
+        .iter()
+        .take_while(|b| b.is_ascii_alphanumeric())
+        .count();
+    let name = &text[name_start..name_start + name_len];
+    let end = tag_end(text, name_start + name_len)?;
+
+    let is = |names: &[&str]| names.iter().any(|n| n.eq_ignore_ascii_case(name));
+    if !closing && is(&RAW_TEXT) {
+        return Some((raw_text_end(text, end, name), "\n"));
+    }
+    Some((end, if is(&BLOCKS) { "\n" } else { "" }))
+}
+
+/// Offset right after the `>` that closes the tag of `text`, looking from `from`.
+/// Quoted attribute values can contain `>`. It's [None] if the tag isn't closed.
+fn tag_end(text: &str, from: usize) -> Option<usize> {
+    let mut quote = None;
+    let mut previous = b'<';
+    for (i, b) in text.bytes().enumerate().skip(from) {
+        match quote {
+            Some(q) if b == q => quote = None,
+            Some(_) => {}
+            None => match b {
+                b'"' | b'\'' if previous == b'=' => quote = Some(b),
+                b'>' => return Some(i + 1),
+                b'<' => return None,
+                _ => {}
+            },
+        }
+        if !b.is_ascii_whitespace() {
+            previous = b;
+        }
+    }
+    None
+}
+
+/// Offset right after the tag that closes the `name` element whose content starts
+/// at `from`, or the length of `text` if it is not closed.
+fn raw_text_end(text: &str, from: usize, name: &str) -> usize {
+    text[from..]
+        .match_indices("</")
+        .map(|(i, _)| from + i)
+        .find(|&i| {
+            let after = i + 2 + name.len();
+            text.get(i + 2..after)
+                .is_some_and(|n| n.eq_ignore_ascii_case(name))
+                && !text
+                    .as_bytes()
+                    .get(after)
+                    .is_some_and(u8::is_ascii_alphanumeric)
+        })
+        .map_or(text.len(), |i| {
+            tag_end(text, i + 2 + name.len()).unwrap_or(text.len())
+        })
+}
+
+/// Length of the entity at the start of `text` and the character it stands for,
+/// if `text` starts with a known entity.
+fn entity(text: &str) -> Option<(usize, char)> {
+    let name = text.strip_prefix('&')?;
+    let end = name.find(|c: char| !c.is_ascii_alphanumeric() && c != '#')?;
+    if !name[end..].starts_with(';') {
+        return None;
+    }
+    let name = &name[..end];
+
+    let ch = match name.strip_prefix('#') {
+        Some(number) => {
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code).filter(|&ch| ch != '\0')?
+        }
+        None => match name {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => '\u{A0}',
+            "copy" => '©',
+            "reg" => '®',
+            "trade" => '™',
+            "hellip" => '…',
+            "ndash" => '–',
+            "mdash" => '—',
+            "laquo" => '«',
+            "raquo" => '»',
+            "euro" => '€',
+            _ => return None,
+        },
+    };
+    // `&`, the name and `;`
+    Some((name.len() + 2, ch))
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::Token;
+
+    use super::*;
+
+    /// Original text of a substring of the rewritten text, as a token.
+    fn original<'a>(text: &'a str, substring: &str) -> &'a str {
+        let (rewritten, offsets) = HtmlStripRewriter.rewrite(text);
+        let start = rewritten
+            .find(substring)
+            .expect("Substring should be in the rewritten text");
+        let mut token = Token {
+            offset_from: start,
+            offset_to: start + substring.len(),
+            position: 0,
+            text: substring.to_string(),
+            position_length: 1,
+        };
+        offsets.correct_token(&mut token);
+        &text[token.offset_from..token.offset_to]
+    }
+
+    #[test]
+    fn test_nested_tags() {
+        let text = "<div class=\"a>b\"><p>Hello <b><i>big</i></b> world</p></div>";
+        let (rewritten, _) = HtmlStripRewriter.rewrite(text);
+        assert_eq!(rewritten, "\n\nHello big world\n\n");
+        assert_eq!(original(text, "Hello"), "Hello");
+        assert_eq!(original(text, "big"), "big");
+        assert_eq!(original(text, "big world"), "big</i></b> world");
+        assert_eq!(original(text, "world"), "world");
+    }
+
+    #[test]
+    fn test_entities() {
+        let text = "Tom &amp; Jerry &lt;3 &#169; &#xA9;2024 &unknown; & x";
+        let (rewritten, _) = HtmlStripRewriter.rewrite(text);
+        assert_eq!(rewritten, "Tom & Jerry <3 © ©2024 &unknown; & x");
+        assert_eq!(original(text, "&"), "&amp;");
+        assert_eq!(original(text, "Jerry"), "Jerry");
+        assert_eq!(original(text, "<3"), "&lt;3");
+        assert_eq!(original(text, "©2024"), "&#xA9;2024");
+        assert_eq!(original(text, "&unknown;"), "&unknown;");
+    }
+
+    #[test]
+    fn test_script() {
+        let text =
+            "before<SCRIPT type=\"text/javascript\">if (a < b) { x = '</p>'; }</script>after";
+        let (rewritten, _) = HtmlStripRewriter.rewrite(text);
+        assert_eq!(rewritten, "before\nafter");
+        assert_eq!(original(text, "after"), "after");
+
+        let text = "<style>p { color: red; }</style>styled";
+        let (rewritten, _) = HtmlStripRewriter.rewrite(text);
+        assert_eq!(rewritten, "\nstyled");
+        assert_eq!(original(text, "styled"), "styled");
+
+        // Not closed, the rest of the text is removed
+        let (rewritten, offsets) = HtmlStripRewriter.rewrite("text<script>var a;");
+        assert_eq!(rewritten, "text\n");
+        assert_eq!(offsets.correct(5), 18);
+    }
+
+    #[test]
+    fn test_unclosed() {
+        // The element is never closed
+        let text = "<b>bold and <i>italic";
+        let (rewritten, _) = HtmlStripRewriter.rewrite(text);
+        assert_eq!(rewritten, "bold and italic");
+        assert_eq!(original(text, "italic"), "italic");
+
+        // The tag is never closed, it's text
+        let text = "a <b and <i>c</i> 1 < 2 <a href=\"x";
+        let (rewritten, _) = HtmlStripRewriter.rewrite(text);
+        assert_eq!(rewritten, "a <b and c 1 < 2 <a href=\"x");
+        assert_eq!(original(text, "c"), "c");
+    }
+
+    #[test]
+    fn test_comments() {
+        let text = "<!DOCTYPE html>a<!-- <b>hidden</b> -->b<?xml version=\"1.0\"?>c<!-- open";
+        let (rewritten, offsets) = HtmlStripRewriter.rewrite(text);
+        assert_eq!(rewritten, "abc");
+        assert_eq!(original(text, "b"), "b");
+        assert_eq!(offsets.correct(3), text.len());
+    }
+
+    #[test]
+    fn test_no_markup() {
+        let (rewritten, offsets) = HtmlStripRewriter.rewrite("héllo");
+        assert_eq!(rewritten, "héllo");
+        assert_eq!(offsets.correct(6), 6);
+
+        let (rewritten, offsets) = HtmlStripRewriter.rewrite("");
+        assert_eq!(rewritten, "");
+        assert_eq!(offsets.correct(0), 0);
+    }
+}
@@ -0,0 +1,201 @@
+pub use token_filter::HybridTokenFilter;
+use token_stream::HybridTokenStream;
+use wrapper::HybridFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::num::NonZeroUsize;
+
+    use tantivy::collector::TopDocs;
+    use tantivy::query::QueryParser;
+    use tantivy::schema::{
+        IndexRecordOption, SchemaBuilder, TextFieldIndexing, TextOptions, Value,
+    };
+    use tantivy::tokenizer::{
+        LowerCaser, Stemmer, TextAnalyzer, Token, TokenizerManager, WhitespaceTokenizer,
+    };
+    use tantivy::{doc, Index, ReloadPolicy, TantivyDocument};
+
+    use super::*;
+    use crate::commons::EdgeNgramTokenFilter;
+
+    const ANALYSIS_NAME: &str = "hybrid";
+
+    fn index_analyzer() -> TextAnalyzer {
+        let edge_ngram =
+            EdgeNgramTokenFilter::new(NonZeroUsize::new(3).unwrap(), None, false).unwrap();
+        TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(LowerCaser)
+            .filter(HybridTokenFilter::new(Stemmer::default(), edge_ngram))
+            .build()
+    }
+
+    fn search_analyzer() -> TextAnalyzer {
+        TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(LowerCaser)
+            .filter(Stemmer::default())
+            .build()
+    }
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = index_analyzer();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_hybrid() {
+        let result = token_stream_helper("Running dogs");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 7,
+                position: 0,
+                text: "run".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 7,
+                position: 0,
+                text: "runn".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 7,
+                position: 0,
+                text: "runni".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 7,
+                position: 0,
+                text: "runnin".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 7,
+                position: 0,
+                text: "running".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 12,
+                position: 1,
+                text: "dog".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 12,
+                position: 1,
+                text: "dogs".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_short_token() {
+        let result = token_stream_helper("to be");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 2,
+                position: 0,
+                text: "to".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 3,
+                offset_to: 5,
+                position: 1,
+                text: "be".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_index_and_search() -> Result<(), Box<dyn std::error::Error>> {
+        let options = TextOptions::default()
+            .set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(ANALYSIS_NAME)
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+            )
+            .set_stored();
+        let mut schema = SchemaBuilder::new();
+        let field = schema.add_text_field("field", options);
+        let schema = schema.build();
+
+        let index = Index::create_in_ram(schema.clone());
+        index.tokenizers().register(ANALYSIS_NAME, index_analyzer());
+
+        let mut index_writer = index.writer(15_000_000)?;
+        index_writer.add_document(doc!(field => "The dog is running"))?;
+        index_writer.add_document(doc!(field => "Runners love marathons"))?;
+        index_writer.add_document(doc!(field => "Cats sleep"))?;
+        index_writer.commit()?;
+
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()?;
+        let searcher = reader.searcher();
+
+        let search_tokenizer_manager = TokenizerManager::new();
+        search_tokenizer_manager.register(ANALYSIS_NAME, search_analyzer());
+        let parser = QueryParser::new(schema, vec![field], search_tokenizer_manager);
+
+        let search = |query: &str| -> Result<BTreeSet<String>, Box<dyn std::error::Error>> {
+            let query = parser.parse_query(query)?;
+            let top_docs = searcher.search(&query, &TopDocs::with_limit(10))?;
+            let mut result = BTreeSet::new();
+            for (_, doc_address) in top_docs {
+                let doc = searcher.doc::<TantivyDocument>(doc_address)?;
+                if let Some(value) = doc.get_first(field).and_then(|v| v.as_str()) {
+                    result.insert(value.to_string());
+                }
+            }
+            Ok(result)
+        };
+
+        // Stemmed term
+        let expected = BTreeSet::from(["The dog is running".to_string()]);
+        assert_eq!(search("dogs")?, expected);
+
+        // Prefix
+        let expected = BTreeSet::from([
+            "The dog is running".to_string(),
+            "Runners love marathons".to_string(),
+        ]);
+        assert_eq!(search("runn")?, expected);
+
+        // Phrase still works
+        let expected = BTreeSet::from(["The dog is running".to_string()]);
+        assert_eq!(search("\"dog is runs\"")?, expected);
+
+        assert_eq!(search("marathonian")?, BTreeSet::new());
+
+        Ok(())
+    }
+}
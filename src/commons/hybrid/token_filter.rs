@@ -0,0 +1,101 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::HybridFilterWrapper;
+use crate::commons::single::SingleTokenTokenizer;
+use crate::commons::EdgeNgramTokenFilter;
+
+/// A token filter that emits, for each token, both the output of a relevance filter
+/// (typically a stemmer) and the [edge-ngrams](EdgeNgramTokenFilter) of the original
+/// token. It allows one field to serve both relevance and prefix matching
+/// (autocomplete).
+///
+/// Relevance terms are emitted first, then edge-ngrams. They are all at the position
+/// of the token they come from and keep its offsets, so phrase queries still work.
+/// Edge-ngrams that are equal to a relevance term of the same token are not emitted
+/// twice.
+///
+/// The relevance filter is applied on each token on its own, so it must handle tokens
+/// independently (stemmers, phonetic filters, ...).
+///
+/// # Query time
+///
+/// Edge-ngrams must not be generated at query time : it is not efficient and would
+/// match irrelevant documents. At query time, use the same analysis chain with only
+/// the relevance filter (e.g. `WhitespaceTokenizer`, `LowerCaser` and `Stemmer`),
+/// registered in a separate `TokenizerManager` given to the `QueryParser`. A full
+/// query term then matches the stemmed terms, and a partial one matches the
+/// edge-ngrams.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::num::NonZeroUsize;
+/// use tantivy::tokenizer::{LowerCaser, Stemmer, TextAnalyzer, Token, WhitespaceTokenizer};
+/// use tantivy_analysis_contrib::commons::{EdgeNgramTokenFilter, HybridTokenFilter};
+///
+/// let edge_ngram = EdgeNgramTokenFilter::new(NonZeroUsize::new(3).unwrap(), None, false)?;
+/// // Used to index
+/// let mut index_analyzer = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(LowerCaser)
+///    .filter(HybridTokenFilter::new(Stemmer::default(), edge_ngram))
+///    .build();
+/// // Used to search
+/// let mut search_analyzer = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(LowerCaser)
+///    .filter(Stemmer::default())
+///    .build();
+///
+/// let mut token_stream = index_analyzer.token_stream("Running");
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "run".to_string());
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "runn".to_string());
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "runni".to_string());
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "runnin".to_string());
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "running".to_string());
+/// assert_eq!(None, token_stream.next());
+///
+/// let mut token_stream = search_analyzer.token_stream("Runs");
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "run".to_string());
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct HybridTokenFilter<F> {
+    relevance: F,
+    prefix: EdgeNgramTokenFilter,
+}
+
+impl<F: TokenFilter> HybridTokenFilter<F> {
+    /// Construct a new [HybridTokenFilter].
+    ///
+    /// # Parameters :
+    ///
+    /// * `relevance` : filter that produces terms used for relevance (e.g. a stemmer).
+    /// * `prefix` : edge-ngram filter that produces terms used for prefix matching.
+    pub fn new(relevance: F, prefix: EdgeNgramTokenFilter) -> Self {
+        Self { relevance, prefix }
+    }
+}
+
+impl<F: TokenFilter> TokenFilter for HybridTokenFilter<F> {
+    type Tokenizer<T: Tokenizer> = HybridFilterWrapper<
+        T,
+        F::Tokenizer<SingleTokenTokenizer>,
+        <EdgeNgramTokenFilter as TokenFilter>::Tokenizer<SingleTokenTokenizer>,
+    >;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        HybridFilterWrapper::new(
+            tokenizer,
+            self.relevance.transform(SingleTokenTokenizer),
+            self.prefix.transform(SingleTokenTokenizer),
+        )
+    }
+}
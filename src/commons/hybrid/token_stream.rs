@@ -0,0 +1,69 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use tantivy_tokenizer_api::{Token, TokenStream, Tokenizer};
+
+use crate::commons::single::filter_token;
+
+#[derive(Debug)]
+pub struct HybridTokenStream<'a, T, R, P> {
+    tail: T,
+    /// Relevance filter, applied on a single token.
+    relevance: &'a mut R,
+    /// Edge-ngram filter, applied on a single token.
+    prefix: &'a mut P,
+    /// Tokens produced for the current token that are not emitted yet.
+    buffer: VecDeque<Token>,
+    token: Token,
+}
+
+impl<'a, T, R, P> HybridTokenStream<'a, T, R, P> {
+    pub(crate) fn new(tail: T, relevance: &'a mut R, prefix: &'a mut P) -> Self {
+        Self {
+            tail,
+            relevance,
+            prefix,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+}
+
+/// Apply the filter to the token and append the result to the buffer. Terms that
+/// are already in the buffer are skipped.
+fn apply<F: Tokenizer>(filter: &mut F, token: &Token, buffer: &mut VecDeque<Token>) {
+    filter_token(filter, token, |filtered| {
+        if !buffer.iter().any(|t| t.text == filtered.text) {
+            buffer.push_back(filtered);
+        }
+    });
+}
+
+impl<T: TokenStream, R: Tokenizer, P: Tokenizer> TokenStream for HybridTokenStream<'_, T, R, P> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.buffer.pop_front() {
+                self.token = token;
+                return true;
+            }
+
+            if !self.tail.advance() {
+                return false;
+            }
+
+            let token = self.tail.token();
+            apply(self.relevance, token, &mut self.buffer);
+            apply(self.prefix, token, &mut self.buffer);
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -0,0 +1,36 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::HybridTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct HybridFilterWrapper<T, R, P> {
+    relevance: R,
+    prefix: P,
+    inner: T,
+}
+
+impl<T, R, P> HybridFilterWrapper<T, R, P> {
+    pub(crate) fn new(inner: T, relevance: R, prefix: P) -> Self {
+        Self {
+            relevance,
+            prefix,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer, R: Tokenizer, P: Tokenizer> Tokenizer for HybridFilterWrapper<T, R, P> {
+    type TokenStream<'a> = HybridTokenStream<'a, T::TokenStream<'a>, R, P>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        HybridTokenStream::new(
+            self.inner.token_stream(text),
+            &mut self.relevance,
+            &mut self.prefix,
+        )
+    }
+}
@@ -0,0 +1,105 @@
+use token_stream::KeywordTokenStream;
+pub use tokenizer::KeywordTokenizer;
+
+mod token_stream;
+mod tokenizer;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+    use super::*;
+
+    fn tokenize_all(text: &str, mut tokenizer: KeywordTokenizer) -> Vec<Token> {
+        let mut result: Vec<Token> = Vec::new();
+
+        let mut tokenizer = tokenizer.token_stream(text);
+        while tokenizer.advance() {
+            result.push(tokenizer.token().clone());
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_whole_text() {
+        let result = tokenize_all("The Lord of the Rings", KeywordTokenizer::default());
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 21,
+            position: 0,
+            text: "The Lord of the Rings".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_truncated() {
+        let result = tokenize_all("abcdef", KeywordTokenizer::with_max_length(4));
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 4,
+            position: 0,
+            text: "abcd".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_truncated_on_char_boundary() {
+        // "é" takes bytes 0 and 1, the limit falls inside it.
+        let result = tokenize_all("été", KeywordTokenizer::with_max_length(1));
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 0,
+            position: 0,
+            text: "".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+
+        let result = tokenize_all("été", KeywordTokenizer::with_max_length(4));
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 3,
+            position: 0,
+            text: "ét".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shorter_than_max_length() {
+        let result = tokenize_all("abc", KeywordTokenizer::with_max_length(10));
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 3,
+            position: 0,
+            text: "abc".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_empty() {
+        let result = tokenize_all("", KeywordTokenizer::default());
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 0,
+            position: 0,
+            text: "".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,41 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct KeywordTokenStream {
+    token: Token,
+    done: bool,
+}
+
+impl KeywordTokenStream {
+    pub(crate) fn new(text: &str) -> Self {
+        Self {
+            token: Token {
+                offset_from: 0,
+                offset_to: text.len(),
+                position: 0,
+                text: text.to_string(),
+                position_length: 1,
+            },
+            done: false,
+        }
+    }
+}
+
+impl TokenStream for KeywordTokenStream {
+    fn advance(&mut self) -> bool {
+        let result = !self.done;
+        self.done = true;
+        result
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
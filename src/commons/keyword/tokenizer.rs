@@ -0,0 +1,69 @@
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::KeywordTokenStream;
+
+/// A [Tokenizer] that emits the whole text as a single token. It is an equivalent of
+/// [Lucene's KeywordTokenizer](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/core/KeywordTokenizer.html).
+///
+/// It's useful for fields that are matched as a whole, like identifiers, or
+/// before filters such as [ReverseTokenFilter](crate::commons::ReverseTokenFilter).
+/// An empty text gives an empty token.
+///
+/// The text can be truncated to a maximum length in bytes. Truncation never splits
+/// a character : if the limit falls inside a character, the token stops before it.
+/// ```rust
+/// use tantivy_analysis_contrib::commons::KeywordTokenizer;
+///
+/// let tokenizer = KeywordTokenizer::default();
+/// let tokenizer = KeywordTokenizer::with_max_length(256);
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use tantivy::tokenizer::{TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::KeywordTokenizer;
+///
+/// let mut tmp = TextAnalyzer::builder(KeywordTokenizer::with_max_length(6)).build();
+/// let mut token_stream = tmp.token_stream("Crème brûlée");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Crème".to_string());
+/// assert_eq!(token.offset_from, 0);
+/// assert_eq!(token.offset_to, 6);
+///
+/// assert_eq!(None, token_stream.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KeywordTokenizer {
+    max_length: Option<usize>,
+}
+
+impl KeywordTokenizer {
+    /// Construct a [KeywordTokenizer] that truncates the text.
+    /// # Parameters :
+    /// * `max_length`: maximum length of the token, in bytes
+    pub fn with_max_length(max_length: usize) -> Self {
+        Self {
+            max_length: Some(max_length),
+        }
+    }
+}
+
+impl Tokenizer for KeywordTokenizer {
+    type TokenStream<'a> = KeywordTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let text = match self.max_length {
+            Some(max_length) if max_length < text.len() => {
+                let mut end = max_length;
+                while !text.is_char_boundary(end) {
+                    end -= 1;
+                }
+                &text[..end]
+            }
+            _ => text,
+        };
+        KeywordTokenStream::new(text)
+    }
+}
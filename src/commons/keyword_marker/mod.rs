@@ -0,0 +1,175 @@
+pub use token_filter::KeywordMarkerTokenFilter;
+use token_stream::KeywordMarkerTokenStream;
+use wrapper::KeywordMarkerFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use tantivy::tokenizer::{TextAnalyzer, Token, TokenFilter, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::{build_set, ASCIIFoldingTokenFilter, NGramTokenFilter, StopTokenFilter};
+
+    fn token_stream_helper<F: TokenFilter>(
+        text: &str,
+        filter: KeywordMarkerTokenFilter<F>,
+    ) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_protected_from_folding() {
+        let keywords = build_set(vec!["café"]).unwrap();
+        let filter =
+            KeywordMarkerTokenFilter::protect(keywords).around(ASCIIFoldingTokenFilter::default());
+        let result = token_stream_helper("café résumé café", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "café".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 14,
+                position: 1,
+                text: "resume".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 15,
+                offset_to: 20,
+                position: 2,
+                text: "café".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_protected_from_removal() {
+        let keywords = build_set(vec!["The"]).unwrap();
+        let stop = StopTokenFilter::from_iter_str(vec!["the", "of"], true).unwrap();
+        let filter = KeywordMarkerTokenFilter::protect(keywords).around(stop);
+        let result = token_stream_helper("The Lord of the Rings", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "The".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 8,
+                position: 1,
+                text: "Lord".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 21,
+                position: 4,
+                text: "Rings".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_several_tokens_from_filter() {
+        let keywords = build_set(vec!["abc"]).unwrap();
+        let ngram = NGramTokenFilter::new(NonZeroUsize::new(2).unwrap(), None, false).unwrap();
+        let filter = KeywordMarkerTokenFilter::protect(keywords).around(ngram);
+        let result = token_stream_helper("abc xyz", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "abc".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "xy".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "yz".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "xyz".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "phonetic")]
+    #[test]
+    fn test_protected_from_phonetic() {
+        use crate::phonetic::{Mapping, PhoneticAlgorithm, PhoneticTokenFilter, SpecialHW};
+
+        let keywords = build_set(vec!["Rupert"]).unwrap();
+        let algorithm = PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None));
+        // Codes replace tokens
+        let phonetic = PhoneticTokenFilter::try_from((algorithm, false)).unwrap();
+        let filter = KeywordMarkerTokenFilter::protect(keywords).around(phonetic);
+        let result = token_stream_helper("Robert Rupert Rubin", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "R163".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 13,
+                position: 1,
+                text: "Rupert".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 19,
+                position: 2,
+                text: "R150".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use fst::Set;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::KeywordMarkerFilterWrapper;
+use crate::commons::single::SingleTokenTokenizer;
+
+/// A token filter that protects keywords from another token filter. It is close to
+/// [Lucene's KeywordMarkerFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/miscellaneous/KeywordMarkerFilter.html).
+///
+/// Tantivy's tokens have no keyword attribute, so instead of marking tokens, this filter
+/// wraps the filter to bypass : tokens that are in the keyword [Set] are emitted unchanged,
+/// the others go through the wrapped filter.
+///
+/// The wrapped filter is applied on each token on its own, it can't see the previous or
+/// next tokens. Thus, it only works with filters that handle tokens independently, such as
+/// the filters provided by this crate (phonetic, stemming, folding, ...). Tokens produced
+/// by the wrapped filter keep the offsets of the token they come from.
+///
+/// Keywords are stored in a [Set], use [build_set](crate::commons::build_set) if they
+/// are not sorted.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::{build_set, ASCIIFoldingTokenFilter, KeywordMarkerTokenFilter};
+///
+/// let keywords = build_set(vec!["café"])?;
+/// let filter = KeywordMarkerTokenFilter::protect(keywords).around(ASCIIFoldingTokenFilter::default());
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("café résumé");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "café".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "resume".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeywordMarkerTokenFilter<F = ()> {
+    keywords: Arc<Set<Vec<u8>>>,
+    filter: F,
+}
+
+impl KeywordMarkerTokenFilter {
+    /// Protect the keywords of the [Set]. Use [around](KeywordMarkerTokenFilter::around)
+    /// to provide the filter they are protected from.
+    pub fn protect(keywords: Set<Vec<u8>>) -> Self {
+        Self {
+            keywords: Arc::new(keywords),
+            filter: (),
+        }
+    }
+}
+
+impl<F> KeywordMarkerTokenFilter<F> {
+    /// Set the token filter that keywords will bypass.
+    pub fn around<G: TokenFilter>(self, filter: G) -> KeywordMarkerTokenFilter<G> {
+        KeywordMarkerTokenFilter {
+            keywords: self.keywords,
+            filter,
+        }
+    }
+}
+
+impl<F: TokenFilter> TokenFilter for KeywordMarkerTokenFilter<F> {
+    type Tokenizer<T: Tokenizer> =
+        KeywordMarkerFilterWrapper<T, F::Tokenizer<SingleTokenTokenizer>>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        KeywordMarkerFilterWrapper::new(
+            tokenizer,
+            self.keywords,
+            self.filter.transform(SingleTokenTokenizer),
+        )
+    }
+}
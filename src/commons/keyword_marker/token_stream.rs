@@ -0,0 +1,65 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use fst::Set;
+use tantivy_tokenizer_api::{Token, TokenStream, Tokenizer};
+
+use crate::commons::single::filter_token;
+
+#[derive(Debug)]
+pub struct KeywordMarkerTokenStream<'a, T, F> {
+    tail: T,
+    keywords: &'a Set<Vec<u8>>,
+    /// Protected filter, applied on a single token.
+    filter: &'a mut F,
+    /// Tokens produced by the protected filter that are not emitted yet.
+    buffer: VecDeque<Token>,
+    token: Token,
+}
+
+impl<'a, T, F> KeywordMarkerTokenStream<'a, T, F> {
+    pub(crate) fn new(tail: T, keywords: &'a Set<Vec<u8>>, filter: &'a mut F) -> Self {
+        Self {
+            tail,
+            keywords,
+            filter,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+}
+
+impl<T: TokenStream, F: Tokenizer> TokenStream for KeywordMarkerTokenStream<'_, T, F> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.buffer.pop_front() {
+                self.token = token;
+                return true;
+            }
+
+            if !self.tail.advance() {
+                return false;
+            }
+
+            let token = self.tail.token();
+            if self.keywords.contains(&token.text) {
+                self.token.clone_from(token);
+                return true;
+            }
+
+            filter_token(self.filter, token, |filtered| {
+                self.buffer.push_back(filtered)
+            });
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
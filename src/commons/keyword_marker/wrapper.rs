@@ -0,0 +1,39 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use fst::Set;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::KeywordMarkerTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct KeywordMarkerFilterWrapper<T, F> {
+    keywords: Arc<Set<Vec<u8>>>,
+    filter: F,
+    inner: T,
+}
+
+impl<T, F> KeywordMarkerFilterWrapper<T, F> {
+    pub(crate) fn new(inner: T, keywords: Arc<Set<Vec<u8>>>, filter: F) -> Self {
+        Self {
+            keywords,
+            filter,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer, F: Tokenizer> Tokenizer for KeywordMarkerFilterWrapper<T, F> {
+    type TokenStream<'a> = KeywordMarkerTokenStream<'a, T::TokenStream<'a>, F>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        KeywordMarkerTokenStream::new(
+            self.inner.token_stream(text),
+            &self.keywords,
+            &mut self.filter,
+        )
+    }
+}
@@ -0,0 +1,100 @@
+pub use token_filter::KeywordNormalizeTokenFilter;
+use token_stream::KeywordNormalizeTokenStream;
+use wrapper::KeywordNormalizeFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(RawTokenizer::default())
+            .filter(KeywordNormalizeTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    /// Original text of a substring of the normalized text.
+    fn original<'a>(text: &'a str, substring: &str) -> &'a str {
+        let (normalized, offsets) = KeywordNormalizeTokenFilter::normalize(text);
+        let start = normalized
+            .find(substring)
+            .expect("Substring should be in the normalized text");
+        let end = start + substring.len();
+        &text[offsets.correct(start)..offsets.correct(end)]
+    }
+
+    #[test]
+    fn test_normalize() {
+        let result = token_stream_helper(" \t Foo   BAR\n\nbaz  ");
+        let expected = vec![Token {
+            offset_from: 3,
+            offset_to: 17,
+            position: 0,
+            text: "foo bar baz".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_unchanged() {
+        let result = token_stream_helper("foo bar");
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 7,
+            position: 0,
+            text: "foo bar".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_offsets() {
+        let text = " \t Foo   BAR\n\nbaz  ";
+        assert_eq!(original(text, "foo"), "Foo");
+        assert_eq!(original(text, "bar"), "BAR");
+        assert_eq!(original(text, "baz"), "baz");
+        assert_eq!(original(text, "foo bar"), "Foo   BAR");
+        assert_eq!(original(text, "bar baz"), "BAR\n\nbaz");
+        assert_eq!(original(text, "foo bar baz"), "Foo   BAR\n\nbaz");
+    }
+
+    #[test]
+    fn test_offsets_length_change() {
+        // `İ` (2 bytes) is lowercased to `i̇` (3 bytes), `\u{3000}` (3 bytes) becomes a space
+        let text = "İstanbul\u{3000}Kadıköy";
+        let (normalized, _) = KeywordNormalizeTokenFilter::normalize(text);
+        assert_eq!(normalized, "i\u{307}stanbul kadıköy");
+        assert_eq!(original(text, "stanbul"), "stanbul");
+        assert_eq!(original(text, "i\u{307}stanbul"), "İstanbul");
+        assert_eq!(original(text, "kadıköy"), "Kadıköy");
+        assert_eq!(original(text, " "), "\u{3000}");
+    }
+
+    #[test]
+    fn test_all_whitespace() {
+        let result = token_stream_helper(" \t\n ");
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_empty() {
+        let result = token_stream_helper("");
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::KeywordNormalizeFilterWrapper;
+use crate::commons::OffsetMap;
+
+/// This is a [TokenFilter] that normalizes multi-word values of keyword fields in a
+/// single pass : leading and trailing whitespaces are removed, runs of internal
+/// whitespaces are replaced by a single space and the text is lowercased. It's meant
+/// to be used with a tokenizer that emits the whole text, like
+/// [KeywordTokenizer](crate::commons::KeywordTokenizer).
+///
+/// Token offsets are adjusted to the trimmed text, unless the token's text doesn't
+/// match its offsets anymore (because a previous filter changed it). For highlighting
+/// part of a value, [normalize](KeywordNormalizeTokenFilter::normalize) gives the
+/// [OffsetMap] that maps offsets of the normalized text back to the original text.
+/// Tokens that are empty once normalized are removed.
+///
+/// # Example
+///
+/// ```rust
+/// use tantivy::tokenizer::{RawTokenizer, TextAnalyzer};
+/// use tantivy_analysis_contrib::commons::KeywordNormalizeTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+///    .filter(KeywordNormalizeTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("  New \t York  City ");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "new york city".to_string());
+/// assert_eq!(token.offset_from, 2);
+/// assert_eq!(token.offset_to, 18);
+///
+/// assert_eq!(None, token_stream.next());
+///
+/// // "york" in the normalized text, back in the original text
+/// let text = "  New \t York  City ";
+/// let (normalized, offsets) = KeywordNormalizeTokenFilter::normalize(text);
+/// let start = normalized.find("york").unwrap();
+/// let end = start + "york".len();
+/// assert_eq!(&text[offsets.correct(start)..offsets.correct(end)], "York");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct KeywordNormalizeTokenFilter;
+
+impl KeywordNormalizeTokenFilter {
+    /// Normalize `text` the way the filter does. It returns the normalized text
+    /// and the [OffsetMap] that maps its offsets back to `text`.
+    pub fn normalize(text: &str) -> (String, OffsetMap) {
+        let mut output = String::with_capacity(text.len());
+        let mut offsets = OffsetMap::default();
+
+        let trimmed = text.trim_start();
+        let mut position = text.len() - trimmed.len();
+        let end = position + trimmed.trim_end().len();
+        while let Some(ch) = text[position..end].chars().next() {
+            if ch.is_whitespace() {
+                let run = text[position..end]
+                    .find(|c: char| !c.is_whitespace())
+                    .unwrap_or(end - position);
+                if run > 1 || ch != ' ' {
+                    offsets.replaced(output.len(), position, run);
+                } else {
+                    offsets.copied(output.len(), position);
+                }
+                output.push(' ');
+                position += run;
+            } else {
+                let len = output.len();
+                output.extend(ch.to_lowercase());
+                if output.len() - len == ch.len_utf8() {
+                    offsets.copied(len, position);
+                } else {
+                    offsets.replaced(len, position, ch.len_utf8());
+                }
+                position += ch.len_utf8();
+            }
+        }
+        offsets.copied(output.len(), position);
+
+        (output, offsets)
+    }
+}
+
+impl TokenFilter for KeywordNormalizeTokenFilter {
+    type Tokenizer<T: Tokenizer> = KeywordNormalizeFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        KeywordNormalizeFilterWrapper::new(token_stream)
+    }
+}
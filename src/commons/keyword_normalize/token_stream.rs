@@ -0,0 +1,48 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::KeywordNormalizeTokenFilter;
+
+#[derive(Debug, Clone)]
+pub struct KeywordNormalizeTokenStream<T> {
+    tail: T,
+}
+
+impl<T> KeywordNormalizeTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self { tail }
+    }
+}
+
+impl<T: TokenStream> TokenStream for KeywordNormalizeTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            let token = self.tail.token_mut();
+            let (normalized, offsets) = KeywordNormalizeTokenFilter::normalize(&token.text);
+            if normalized.is_empty() {
+                continue;
+            }
+
+            // Offsets can only be adjusted if they still match the text
+            if token.offset_to.checked_sub(token.offset_from) == Some(token.text.len()) {
+                token.offset_to = token.offset_from + offsets.correct(normalized.len());
+                token.offset_from += offsets.correct(0);
+            }
+            token.text = normalized;
+
+            return true;
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
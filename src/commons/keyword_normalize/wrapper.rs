@@ -0,0 +1,26 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::KeywordNormalizeTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct KeywordNormalizeFilterWrapper<T> {
+    inner: T,
+}
+
+impl<T> KeywordNormalizeFilterWrapper<T> {
+    pub(crate) fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for KeywordNormalizeFilterWrapper<T> {
+    type TokenStream<'a> = KeywordNormalizeTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        KeywordNormalizeTokenStream::new(self.inner.token_stream(text))
+    }
+}
@@ -0,0 +1,74 @@
+pub use token_filter::DictionaryLemmatizeTokenFilter;
+use token_stream::DictionaryLemmatizeTokenStream;
+use wrapper::DictionaryLemmatizeFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use fst::Map;
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::SetError;
+
+    fn token_stream_helper(text: &str, filter: DictionaryLemmatizeTokenFilter) -> Vec<String> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.text.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_inflections() -> Result<(), SetError> {
+        let filter = DictionaryLemmatizeTokenFilter::from_pairs([
+            ("went", "go"),
+            ("geese", "goose"),
+            ("goes", "go"),
+            ("gone", "go"),
+        ])?;
+        let result = token_stream_helper("geese went gone goes", filter);
+        assert_eq!(result, vec!["goose", "go", "go", "go"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_words() -> Result<(), SetError> {
+        let filter = DictionaryLemmatizeTokenFilter::from_pairs([("mice", "mouse")])?;
+        let result = token_stream_helper("three Mice mice run", filter);
+        assert_eq!(result, vec!["three", "Mice", "mouse", "run"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicated_form() -> Result<(), SetError> {
+        let filter = DictionaryLemmatizeTokenFilter::from_pairs([("saw", "see"), ("saw", "saw")])?;
+        let result = token_stream_helper("saw", filter);
+        assert_eq!(result, vec!["see"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_map() -> Result<(), SetError> {
+        let forms = Map::from_iter([("best", 0), ("better", 0), ("broken", 7)])?;
+        let filter = DictionaryLemmatizeTokenFilter::from_map(forms, vec!["good".to_string()]);
+        let result = token_stream_helper("best broken better", filter);
+        // Out of range index is ignored
+        assert_eq!(result, vec!["good", "broken", "good"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use fst::Map;
+use rustc_hash::FxHashMap;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::DictionaryLemmatizeFilterWrapper;
+use crate::commons::SetError;
+
+/// A token filter that replaces inflected forms with their lemma, using a dictionary.
+/// For example, with `mice` mapped to `mouse`, the token `mice` becomes `mouse`. Tokens
+/// that aren't in the dictionary are left untouched.
+///
+/// Inflected forms are stored in a [Map] whose values are indexes in the list of lemmas,
+/// so large dictionaries stay compact in memory. Forms are matched on the exact token's
+/// text, so you may want to lowercase tokens before.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::DictionaryLemmatizeTokenFilter;
+///
+/// let filter = DictionaryLemmatizeTokenFilter::from_pairs([("mice", "mouse"), ("ran", "run")])?;
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("mice ran away");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "mouse".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "run".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "away".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DictionaryLemmatizeTokenFilter {
+    forms: Arc<Map<Vec<u8>>>,
+    lemmas: Arc<Vec<String>>,
+}
+
+impl DictionaryLemmatizeTokenFilter {
+    /// Construct a new [DictionaryLemmatizeTokenFilter] from a [Map].
+    /// # Parameters :
+    /// * `forms`: map of inflected forms to the index of their lemma in `lemmas`. Forms
+    ///   whose index is out of `lemmas` are left untouched.
+    /// * `lemmas`: list of lemmas
+    pub fn from_map(forms: Map<Vec<u8>>, lemmas: Vec<String>) -> Self {
+        Self {
+            forms: Arc::new(forms),
+            lemmas: Arc::new(lemmas),
+        }
+    }
+
+    /// Construct a new [DictionaryLemmatizeTokenFilter] from pairs of inflected form and
+    /// lemma, in any order. If a form is given several times, the first lemma is kept.
+    pub fn from_pairs<F: AsRef<[u8]>, L: Into<String>>(
+        pairs: impl IntoIterator<Item = (F, L)>,
+    ) -> Result<Self, SetError> {
+        let mut lemmas: Vec<String> = Vec::new();
+        let mut indexes: FxHashMap<String, u64> = FxHashMap::default();
+        let mut forms: Vec<(Vec<u8>, u64)> = Vec::new();
+        for (form, lemma) in pairs {
+            let lemma = lemma.into();
+            let index = *indexes.entry(lemma.clone()).or_insert_with(|| {
+                lemmas.push(lemma);
+                lemmas.len() as u64 - 1
+            });
+            forms.push((form.as_ref().to_vec(), index));
+        }
+        // Stable sort keeps the first lemma of a duplicated form
+        forms.sort_by(|a, b| a.0.cmp(&b.0));
+        forms.dedup_by(|a, b| a.0 == b.0);
+
+        Ok(Self::from_map(Map::from_iter(forms)?, lemmas))
+    }
+}
+
+impl TokenFilter for DictionaryLemmatizeTokenFilter {
+    type Tokenizer<T: Tokenizer> = DictionaryLemmatizeFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        DictionaryLemmatizeFilterWrapper::new(token_stream, self.forms, self.lemmas)
+    }
+}
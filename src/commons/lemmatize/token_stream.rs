@@ -0,0 +1,48 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use fst::Map;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct DictionaryLemmatizeTokenStream<'a, T> {
+    tail: T,
+    forms: &'a Map<Vec<u8>>,
+    lemmas: &'a [String],
+}
+
+impl<'a, T> DictionaryLemmatizeTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, forms: &'a Map<Vec<u8>>, lemmas: &'a [String]) -> Self {
+        Self {
+            tail,
+            forms,
+            lemmas,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for DictionaryLemmatizeTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let lemma = self
+            .forms
+            .get(&self.tail.token().text)
+            .and_then(|index| self.lemmas.get(index as usize));
+        if let Some(lemma) = lemma {
+            self.tail.token_mut().text.clone_from(lemma);
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,39 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use fst::Map;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::DictionaryLemmatizeTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct DictionaryLemmatizeFilterWrapper<T> {
+    forms: Arc<Map<Vec<u8>>>,
+    lemmas: Arc<Vec<String>>,
+    inner: T,
+}
+
+impl<T> DictionaryLemmatizeFilterWrapper<T> {
+    pub(crate) fn new(inner: T, forms: Arc<Map<Vec<u8>>>, lemmas: Arc<Vec<String>>) -> Self {
+        Self {
+            forms,
+            lemmas,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for DictionaryLemmatizeFilterWrapper<T> {
+    type TokenStream<'a> = DictionaryLemmatizeTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        DictionaryLemmatizeTokenStream::new(
+            self.inner.token_stream(text),
+            &self.forms,
+            &self.lemmas,
+        )
+    }
+}
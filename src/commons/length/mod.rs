@@ -1,11 +1,43 @@
 pub use token_filter::LengthTokenFilter;
 use token_stream::LengthTokenStream;
+#[cfg(feature = "graphemes")]
+use unicode_segmentation::UnicodeSegmentation;
 use wrapper::LengthFilterWrapper;
 
 mod token_filter;
 mod token_stream;
 mod wrapper;
 
+/// How the length of a token is measured by the [LengthTokenFilter].
+///
+/// `Graphemes` needs the `graphemes` feature, so matching on it needs a wildcard arm.
+#[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum LengthCountMode {
+    /// Number of bytes of the UTF-8 text : `中国` has a length of 6.
+    Bytes,
+    /// Number of characters (Unicode scalar values) : `中国` has a length of 2.
+    #[default]
+    Chars,
+    /// Number of extended grapheme clusters, what a reader sees as a character :
+    /// `e` followed by a combining acute accent, or the family emoji `👨‍👩‍👧`,
+    /// have a length of 1.
+    #[cfg(feature = "graphemes")]
+    Graphemes,
+}
+
+impl LengthCountMode {
+    fn length(self, text: &str) -> usize {
+        match self {
+            LengthCountMode::Bytes => text.len(),
+            LengthCountMode::Chars => text.chars().count(),
+            #[cfg(feature = "graphemes")]
+            LengthCountMode::Graphemes => text.graphemes(true).count(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
@@ -160,4 +192,95 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_length_in_chars() {
+        let result = token_stream_helper("中国人 中国人民", Some(2), Some(3));
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 9,
+            position: 0,
+            text: "中国人".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    fn texts_with_mode(text: &str, min: usize, max: usize, mode: LengthCountMode) -> Vec<String> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(LengthTokenFilter::new(Some(min), Some(max)).with_count_mode(mode))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut texts = vec![];
+        token_stream.process(&mut |token: &Token| texts.push(token.text.clone()));
+        texts
+    }
+
+    #[test]
+    fn test_count_mode() {
+        // A combining mark : 2 chars and 3 bytes
+        let decomposed = "e\u{0301}";
+        // A ZWJ sequence : 5 chars and 18 bytes
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let text = format!("{decomposed} {family}");
+
+        assert_eq!(
+            texts_with_mode(&text, 1, 1, LengthCountMode::Chars),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            texts_with_mode(&text, 2, 5, LengthCountMode::Chars),
+            vec![decomposed, family]
+        );
+        assert_eq!(
+            texts_with_mode(&text, 3, 3, LengthCountMode::Bytes),
+            vec![decomposed]
+        );
+        assert_eq!(
+            texts_with_mode(&text, 18, 18, LengthCountMode::Bytes),
+            vec![family]
+        );
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn test_count_mode_graphemes() {
+        // A combining mark and a ZWJ sequence are a single grapheme
+        let decomposed = "e\u{0301}";
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let text = format!("{decomposed} {family}");
+
+        assert_eq!(
+            texts_with_mode(&text, 1, 1, LengthCountMode::Graphemes),
+            vec![decomposed, family]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let filter: LengthTokenFilter =
+            serde_json::from_str(r#"{"min": 2, "max": 4, "mode": "Bytes"}"#).unwrap();
+        let json = serde_json::to_string(&filter).unwrap();
+        let filter: LengthTokenFilter = serde_json::from_str(&json).unwrap();
+
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+        let mut token_stream = a.token_stream("a ab abcd abcde 中国");
+        let mut result = vec![];
+        token_stream.process(&mut |token: &Token| result.push(token.text.clone()));
+        // `中国` is 6 bytes long
+        assert_eq!(result, vec!["ab", "abcd"]);
+
+        // The count mode is optional
+        let filter: LengthTokenFilter = serde_json::from_str(r#"{"min": 2, "max": null}"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&filter).unwrap(),
+            r#"{"min":2,"max":null,"mode":"Chars"}"#
+        );
+    }
 }
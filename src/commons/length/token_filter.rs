@@ -1,8 +1,11 @@
 use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
 
-use super::LengthFilterWrapper;
+use super::{LengthCountMode, LengthFilterWrapper};
 
 /// This [TokenFilter] filters tokens that don't match a min or a max length (inclusive).
+/// Lengths are counted in characters (Unicode scalar values), like Lucene's `LengthFilter`,
+/// so `中国人` has a length of 3. Use [with_count_mode](LengthTokenFilter::with_count_mode)
+/// to count bytes or grapheme clusters instead.
 /// ```rust
 /// use tantivy_analysis_contrib::commons::LengthTokenFilter;
 ///
@@ -32,18 +35,33 @@ use super::LengthFilterWrapper;
 /// # }
 /// ```
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LengthTokenFilter {
     min: Option<usize>,
     max: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    mode: LengthCountMode,
 }
 
 impl LengthTokenFilter {
     /// Get a new token filter.
     /// # Parameters :
-    /// * min : minimum length a token should have (inclusive)
-    /// * max : maximum length a token should have (inclusive)
+    /// * min : minimum length, in characters, a token should have (inclusive)
+    /// * max : maximum length, in characters, a token should have (inclusive)
     pub fn new(min: Option<usize>, max: Option<usize>) -> Self {
-        LengthTokenFilter { min, max }
+        LengthTokenFilter {
+            min,
+            max,
+            mode: LengthCountMode::default(),
+        }
+    }
+
+    /// Set how the length of tokens is measured.
+    ///
+    /// Default is [LengthCountMode::Chars].
+    pub fn with_count_mode(mut self, mode: LengthCountMode) -> Self {
+        self.mode = mode;
+        self
     }
 }
 
@@ -51,6 +69,6 @@ impl TokenFilter for LengthTokenFilter {
     type Tokenizer<T: Tokenizer> = LengthFilterWrapper<T>;
 
     fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
-        LengthFilterWrapper::new(token_stream, self.min, self.max)
+        LengthFilterWrapper::new(token_stream, self.min, self.max, self.mode)
     }
 }
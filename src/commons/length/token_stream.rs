@@ -3,16 +3,29 @@
 
 use tantivy_tokenizer_api::{Token, TokenStream};
 
+use super::LengthCountMode;
+
 #[derive(Clone, Debug)]
 pub struct LengthTokenStream<T> {
     tail: T,
     min: Option<usize>,
     max: Option<usize>,
+    mode: LengthCountMode,
 }
 
 impl<T> LengthTokenStream<T> {
-    pub(crate) fn new(tail: T, min: Option<usize>, max: Option<usize>) -> Self {
-        Self { tail, min, max }
+    pub(crate) fn new(
+        tail: T,
+        min: Option<usize>,
+        max: Option<usize>,
+        mode: LengthCountMode,
+    ) -> Self {
+        Self {
+            tail,
+            min,
+            max,
+            mode,
+        }
     }
 }
 
@@ -23,7 +36,7 @@ impl<T: TokenStream> TokenStream for LengthTokenStream<T> {
         while result && !length_ok {
             result = self.tail.advance();
             if result {
-                let size = self.tail.token().text.len();
+                let size = self.mode.length(&self.tail.token().text);
                 length_ok =
                     self.min.map_or(true, |v| v <= size) && self.max.map_or(true, |v| size <= v);
             }
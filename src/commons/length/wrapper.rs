@@ -4,18 +4,29 @@
 
 use tantivy_tokenizer_api::Tokenizer;
 
-use super::LengthTokenStream;
+use super::{LengthCountMode, LengthTokenStream};
 
 #[derive(Clone, Debug)]
 pub struct LengthFilterWrapper<T> {
     min: Option<usize>,
     max: Option<usize>,
+    mode: LengthCountMode,
     inner: T,
 }
 
 impl<T> LengthFilterWrapper<T> {
-    pub(crate) fn new(inner: T, min: Option<usize>, max: Option<usize>) -> Self {
-        Self { min, max, inner }
+    pub(crate) fn new(
+        inner: T,
+        min: Option<usize>,
+        max: Option<usize>,
+        mode: LengthCountMode,
+    ) -> Self {
+        Self {
+            min,
+            max,
+            mode,
+            inner,
+        }
     }
 }
 
@@ -23,6 +34,6 @@ impl<T: Tokenizer> Tokenizer for LengthFilterWrapper<T> {
     type TokenStream<'a> = LengthTokenStream<T::TokenStream<'a>>;
 
     fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
-        LengthTokenStream::new(self.inner.token_stream(text), self.min, self.max)
+        LengthTokenStream::new(self.inner.token_stream(text), self.min, self.max, self.mode)
     }
 }
@@ -8,7 +8,12 @@ mod wrapper;
 
 #[cfg(test)]
 mod tests {
-    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use tantivy::tokenizer::{
+        TextAnalyzer, Token, TokenFilter, TokenStream, Tokenizer, WhitespaceTokenizer,
+    };
 
     use super::*;
 
@@ -130,4 +135,77 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    /// A [TokenFilter] that counts the number of times its stream is advanced.
+    #[derive(Clone, Debug, Default)]
+    struct AdvanceCounter(Arc<AtomicUsize>);
+
+    #[derive(Clone, Debug)]
+    struct AdvanceCounterWrapper<T>(T, Arc<AtomicUsize>);
+
+    struct AdvanceCounterStream<T>(T, Arc<AtomicUsize>);
+
+    impl TokenFilter for AdvanceCounter {
+        type Tokenizer<T: Tokenizer> = AdvanceCounterWrapper<T>;
+
+        fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+            AdvanceCounterWrapper(tokenizer, self.0)
+        }
+    }
+
+    impl<T: Tokenizer> Tokenizer for AdvanceCounterWrapper<T> {
+        type TokenStream<'a> = AdvanceCounterStream<T::TokenStream<'a>>;
+
+        fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+            AdvanceCounterStream(self.0.token_stream(text), self.1.clone())
+        }
+    }
+
+    impl<T: TokenStream> TokenStream for AdvanceCounterStream<T> {
+        fn advance(&mut self) -> bool {
+            self.1.fetch_add(1, Ordering::SeqCst);
+            self.0.advance()
+        }
+
+        fn token(&self) -> &Token {
+            self.0.token()
+        }
+
+        fn token_mut(&mut self) -> &mut Token {
+            self.0.token_mut()
+        }
+    }
+
+    fn count_advances(text: &str, filter: LimitTokenCountFilter) -> (Vec<String>, usize) {
+        let counter = AdvanceCounter::default();
+        let advances = counter.0.clone();
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(counter)
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+        let mut texts = vec![];
+        token_stream.process(&mut |token: &Token| texts.push(token.text.clone()));
+
+        (texts, advances.load(Ordering::SeqCst))
+    }
+
+    #[test]
+    fn test_consume_all() {
+        // 5 tokens, the 6th advance tells the end of the stream
+        let text = "one two three four five";
+
+        let (texts, advances) = count_advances(text, LimitTokenCountFilter::new(2));
+        assert_eq!(texts, vec!["one", "two"]);
+        assert_eq!(advances, 2);
+
+        let (texts, advances) = count_advances(text, LimitTokenCountFilter::new_with(2, true));
+        assert_eq!(texts, vec!["one", "two"]);
+        assert_eq!(advances, 6);
+
+        let (texts, advances) = count_advances(text, LimitTokenCountFilter::new_with(10, true));
+        assert_eq!(texts.len(), 5);
+        assert_eq!(advances, 6);
+    }
 }
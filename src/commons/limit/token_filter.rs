@@ -38,6 +38,7 @@ use super::LimitTokenCountFilterWrapper;
 #[derive(Clone, Copy, Debug)]
 pub struct LimitTokenCountFilter {
     max_tokens: usize,
+    consume_all: bool,
 }
 
 impl LimitTokenCountFilter {
@@ -46,13 +47,28 @@ impl LimitTokenCountFilter {
     /// # Parameters :
     /// * max_tokens : maximum number of tokens that will be indexed
     pub fn new(max_tokens: usize) -> Self {
-        Self { max_tokens }
+        Self::new_with(max_tokens, false)
+    }
+
+    /// Create a new [LimitTokenCountFilter], like Lucene's one with its
+    /// `consumeAllTokens` flag.
+    ///
+    /// # Parameters :
+    /// * max_tokens : maximum number of tokens that will be indexed
+    /// * consume_all : once the limit is reached, keep advancing the underlying
+    ///   stream until its end, without emitting its tokens. Components below this
+    ///   filter then see the whole text, at the cost of reading it entirely.
+    pub fn new_with(max_tokens: usize, consume_all: bool) -> Self {
+        Self {
+            max_tokens,
+            consume_all,
+        }
     }
 }
 
 impl From<usize> for LimitTokenCountFilter {
     fn from(max_tokens: usize) -> Self {
-        Self { max_tokens }
+        Self::new(max_tokens)
     }
 }
 
@@ -60,6 +76,6 @@ impl TokenFilter for LimitTokenCountFilter {
     type Tokenizer<T: Tokenizer> = LimitTokenCountFilterWrapper<T>;
 
     fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
-        LimitTokenCountFilterWrapper::new(token_stream, self.max_tokens)
+        LimitTokenCountFilterWrapper::new(token_stream, self.max_tokens, self.consume_all)
     }
 }
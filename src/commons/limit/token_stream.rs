@@ -7,17 +7,28 @@ use tantivy_tokenizer_api::{Token, TokenStream};
 pub struct LimitTokenCountStream<T> {
     tail: T,
     count: usize,
+    /// Drain the tail once the limit is reached
+    consume_all: bool,
 }
 
 impl<T> LimitTokenCountStream<T> {
-    pub(crate) fn new(tail: T, count: usize) -> Self {
-        Self { tail, count }
+    pub(crate) fn new(tail: T, count: usize, consume_all: bool) -> Self {
+        Self {
+            tail,
+            count,
+            consume_all,
+        }
     }
 }
 
 impl<T: TokenStream> TokenStream for LimitTokenCountStream<T> {
     fn advance(&mut self) -> bool {
         if self.count == 0 {
+            if self.consume_all {
+                while self.tail.advance() {}
+                // The tail is exhausted, no need to do it again
+                self.consume_all = false;
+            }
             return false;
         }
 
@@ -9,12 +9,17 @@ use super::LimitTokenCountStream;
 #[derive(Clone, Debug)]
 pub struct LimitTokenCountFilterWrapper<T> {
     count: usize,
+    consume_all: bool,
     inner: T,
 }
 
 impl<T> LimitTokenCountFilterWrapper<T> {
-    pub(crate) fn new(inner: T, count: usize) -> Self {
-        Self { count, inner }
+    pub(crate) fn new(inner: T, count: usize, consume_all: bool) -> Self {
+        Self {
+            count,
+            consume_all,
+            inner,
+        }
     }
 }
 
@@ -22,6 +27,6 @@ impl<T: Tokenizer> Tokenizer for LimitTokenCountFilterWrapper<T> {
     type TokenStream<'a> = LimitTokenCountStream<T::TokenStream<'a>>;
 
     fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
-        LimitTokenCountStream::new(self.inner.token_stream(text), self.count)
+        LimitTokenCountStream::new(self.inner.token_stream(text), self.count, self.consume_all)
     }
 }
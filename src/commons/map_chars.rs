@@ -0,0 +1,73 @@
+//! Module that contains [map_chars], shared by the filters that replace some
+//! characters of a token one by one.
+
+/// What a character is replaced with by [map_chars].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Replacement<'a> {
+    Char(char),
+    Str(&'a str),
+}
+
+impl From<char> for Replacement<'_> {
+    fn from(ch: char) -> Self {
+        Replacement::Char(ch)
+    }
+}
+
+impl<'a> From<&'a str> for Replacement<'a> {
+    fn from(text: &'a str) -> Self {
+        Replacement::Str(text)
+    }
+}
+
+impl Replacement<'_> {
+    fn push_to(self, text: &mut String) {
+        match self {
+            Replacement::Char(ch) => text.push(ch),
+            Replacement::Str(s) => text.push_str(s),
+        }
+    }
+}
+
+/// Replace each character of `text` for which `map` returns a replacement. It
+/// returns [None], without allocating, if no character is replaced.
+pub(crate) fn map_chars<'a, R: Into<Replacement<'a>>>(
+    text: &str,
+    map: impl Fn(char) -> Option<R>,
+) -> Option<String> {
+    let mut chars = text.char_indices();
+    let (index, first) = chars.find_map(|(index, ch)| map(ch).map(|r| (index, r)))?;
+
+    let mut result = String::with_capacity(text.len());
+    result.push_str(&text[..index]);
+    first.into().push_to(&mut result);
+    for (_, ch) in chars {
+        match map(ch) {
+            Some(replacement) => replacement.into().push_to(&mut result),
+            None => result.push(ch),
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nothing_replaced() {
+        assert_eq!(map_chars("abc", |_| None::<char>), None);
+    }
+
+    #[test]
+    fn test_replace() {
+        let result = map_chars("aébè", |ch| match ch {
+            'é' => Some("e"),
+            'b' => Some(""),
+            'è' => Some("ee"),
+            _ => None,
+        });
+        assert_eq!(result, Some("aeee".to_string()));
+    }
+}
@@ -0,0 +1,189 @@
+//! Rewriting of character sequences before tokenization.
+
+use rustc_hash::FxHashMap;
+
+use crate::commons::OffsetMap;
+
+/// Rewrites sequences of characters of a text before it is tokenized, like
+/// [Lucene's MappingCharFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/charfilter/MappingCharFilter.html).
+/// Tantivy has no char filters, so the text is rewritten before being given to the
+/// analyzer, and the returned [OffsetMap] brings token offsets back to the original text.
+///
+/// Sequences are replaced from left to right, the longest sequence that matches wins and
+/// replacements are not rewritten again. A sequence can be replaced by an empty string to
+/// remove it. Empty sequences are ignored, and if a sequence is given several times the
+/// last replacement is used.
+///
+/// # Example
+///
+/// ```rust
+/// use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+/// use tantivy_analysis_contrib::commons::MappingRewriter;
+///
+/// let rewriter = MappingRewriter::new([("&", " and "), ("\u{200D}", "")]);
+/// let text = "salt&pepper";
+/// let (rewritten, offsets) = rewriter.rewrite(text);
+/// assert_eq!(rewritten, "salt and pepper");
+///
+/// let mut analyzer = TextAnalyzer::builder(WhitespaceTokenizer::default()).build();
+/// let mut token_stream = analyzer.token_stream(&rewritten);
+/// let mut tokens = vec![];
+/// token_stream.process(&mut |token: &Token| {
+///     let mut token = token.clone();
+///     offsets.correct_token(&mut token);
+///     tokens.push(token);
+/// });
+///
+/// assert_eq!(tokens[1].text, "and");
+/// assert_eq!(&text[tokens[1].offset_from..tokens[1].offset_to], "&");
+/// assert_eq!(tokens[2].text, "pepper");
+/// assert_eq!(&text[tokens[2].offset_from..tokens[2].offset_to], "pepper");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MappingRewriter {
+    /// Sequences and their replacement by first character, longest sequence first.
+    mappings: FxHashMap<char, Vec<(String, String)>>,
+}
+
+impl MappingRewriter {
+    /// Construct a new [MappingRewriter].
+    /// # Parameters :
+    /// * `mappings`: pairs of sequence to replace and its replacement.
+    pub fn new<F: Into<String>, T: Into<String>>(
+        mappings: impl IntoIterator<Item = (F, T)>,
+    ) -> Self {
+        let mut by_sequence: FxHashMap<String, String> = FxHashMap::default();
+        for (from, to) in mappings {
+            let from = from.into();
+            if !from.is_empty() {
+                by_sequence.insert(from, to.into());
+            }
+        }
+
+        let mut mappings: FxHashMap<char, Vec<(String, String)>> = FxHashMap::default();
+        for (from, to) in by_sequence {
+            if let Some(first) = from.chars().next() {
+                mappings.entry(first).or_default().push((from, to));
+            }
+        }
+        for candidates in mappings.values_mut() {
+            candidates.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        }
+
+        Self { mappings }
+    }
+
+    /// Rewrite `text`. It returns the rewritten text and the [OffsetMap] that maps
+    /// its offsets back to `text`.
+    pub fn rewrite(&self, text: &str) -> (String, OffsetMap) {
+        let mut output = String::with_capacity(text.len());
+        let mut offsets = OffsetMap::default();
+
+        let mut position = 0;
+        while let Some(ch) = text[position..].chars().next() {
+            let replacement = self.mappings.get(&ch).and_then(|candidates| {
+                candidates
+                    .iter()
+                    .find(|(from, _)| text[position..].starts_with(from.as_str()))
+            });
+            match replacement {
+                Some((from, to)) => {
+                    offsets.replaced(output.len(), position, from.len());
+                    output.push_str(to);
+                    position += from.len();
+                }
+                None => {
+                    offsets.copied(output.len(), position);
+                    output.push(ch);
+                    position += ch.len_utf8();
+                }
+            }
+        }
+        offsets.copied(output.len(), position);
+
+        (output, offsets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::Token;
+
+    use super::*;
+
+    #[test]
+    fn test_expansion() {
+        let rewriter = MappingRewriter::new([("&", " and ")]);
+        let text = "a&b c";
+        let (rewritten, offsets) = rewriter.rewrite(text);
+        assert_eq!(rewritten, "a and b c");
+
+        // Copied text before the expansion
+        assert_eq!(offsets.correct(0), 0);
+        assert_eq!(offsets.correct(1), 1);
+        // Inside the expansion, offsets stay in the replaced `&`
+        assert_eq!(offsets.correct(2), 2);
+        assert_eq!(offsets.correct(4), 2);
+        // Copied text after the expansion is shifted back
+        assert_eq!(offsets.correct(6), 2);
+        assert_eq!(offsets.correct(8), 4);
+        assert_eq!(offsets.correct(9), 5);
+        assert_eq!(&text[offsets.correct(8)..offsets.correct(9)], "c");
+
+        // A token made of the expansion covers the replaced text
+        let mut token = Token {
+            offset_from: 2,
+            offset_to: 5,
+            position: 1,
+            text: "and".to_string(),
+            position_length: 1,
+        };
+        offsets.correct_token(&mut token);
+        assert_eq!(&text[token.offset_from..token.offset_to], "&");
+    }
+
+    #[test]
+    fn test_contraction() {
+        let rewriter = MappingRewriter::new([("&amp;", "&"), ("\u{200D}", "")]);
+        let text = "r&amp;d te\u{200D}st";
+        let (rewritten, offsets) = rewriter.rewrite(text);
+        assert_eq!(rewritten, "r&d test");
+
+        // `r&d`
+        assert_eq!(offsets.correct(0), 0);
+        assert_eq!(offsets.correct(1), 1);
+        assert_eq!(offsets.correct(2), 6);
+        assert_eq!(offsets.correct(3), 7);
+        assert_eq!(&text[offsets.correct(0)..offsets.correct(3)], "r&amp;d");
+        // `test`, the zero width joiner is removed but still in the original text
+        assert_eq!(offsets.correct(4), 8);
+        assert_eq!(offsets.correct(8), 15);
+        assert_eq!(
+            &text[offsets.correct(4)..offsets.correct(8)],
+            "te\u{200D}st"
+        );
+    }
+
+    #[test]
+    fn test_longest_match() {
+        let rewriter = MappingRewriter::new([("a", "1"), ("ab", "2"), ("abc", "3")]);
+        let (rewritten, offsets) = rewriter.rewrite("abcaba");
+        assert_eq!(rewritten, "321");
+        assert_eq!(offsets.correct(1), 3);
+        assert_eq!(offsets.correct(2), 5);
+        assert_eq!(offsets.correct(3), 6);
+    }
+
+    #[test]
+    fn test_no_mapping() {
+        let rewriter = MappingRewriter::new(Vec::<(String, String)>::new());
+        let (rewritten, offsets) = rewriter.rewrite("héllo");
+        assert_eq!(rewritten, "héllo");
+        assert_eq!(offsets.correct(3), 3);
+        assert_eq!(offsets.correct(6), 6);
+
+        let (rewritten, offsets) = rewriter.rewrite("");
+        assert_eq!(rewritten, "");
+        assert_eq!(offsets.correct(0), 0);
+    }
+}
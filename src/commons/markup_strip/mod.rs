@@ -0,0 +1,173 @@
+pub use token_filter::MarkupStripTokenFilter;
+use token_stream::MarkupStripTokenStream;
+use wrapper::MarkupStripFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(MarkupStripTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_bold() {
+        let result = token_stream_helper("**bold** __bold__ '''bold'''");
+        let expected = vec![
+            Token {
+                offset_from: 2,
+                offset_to: 6,
+                position: 0,
+                text: "bold".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 11,
+                offset_to: 15,
+                position: 1,
+                text: "bold".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 21,
+                offset_to: 25,
+                position: 2,
+                text: "bold".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_italic() {
+        let result = token_stream_helper("*italic* _italic_ ''italic''");
+        let expected = vec![
+            Token {
+                offset_from: 1,
+                offset_to: 7,
+                position: 0,
+                text: "italic".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 16,
+                position: 1,
+                text: "italic".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 20,
+                offset_to: 26,
+                position: 2,
+                text: "italic".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_code() {
+        let result = token_stream_helper("`code` ```rust");
+        let expected = vec![
+            Token {
+                offset_from: 1,
+                offset_to: 5,
+                position: 0,
+                text: "code".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 14,
+                position: 1,
+                text: "rust".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_link() {
+        let result = token_stream_helper("[link](https://example.com) ![alt](image.png) [[Page]]");
+        let expected = vec![
+            Token {
+                offset_from: 1,
+                offset_to: 5,
+                position: 0,
+                text: "link".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 30,
+                offset_to: 33,
+                position: 1,
+                text: "alt".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 48,
+                offset_to: 52,
+                position: 2,
+                text: "Page".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_markup_only() {
+        let result = token_stream_helper("## Title ** ==");
+        let expected = vec![Token {
+            offset_from: 3,
+            offset_to: 8,
+            position: 1,
+            text: "Title".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_inner_markup() {
+        let result = token_stream_helper("snake_case **don't**");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 10,
+                position: 0,
+                text: "snake_case".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 13,
+                offset_to: 18,
+                position: 1,
+                text: "don't".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
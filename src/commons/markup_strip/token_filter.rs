@@ -0,0 +1,49 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::MarkupStripFilterWrapper;
+
+/// This is a [TokenFilter] that removes markdown and wiki markup from tokens, so that
+/// `**bold**` becomes `bold`.
+///
+/// It removes leading and trailing markup characters (`*`, `_`, `#`, `` ` ``, `~`, `=`,
+/// `'`, `[` and `]`) and reduces links such as `[text](https://example.com)` or
+/// `![alt](image.png)` to their text. Markup inside a word (e.g. `snake_case`) is kept.
+///
+/// Offsets are adjusted to match the remaining text, unless the token's text doesn't
+/// match its offsets anymore (because a previous filter changed it). Tokens that are
+/// only markup (e.g. `##` or `**`) are removed.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::MarkupStripTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(MarkupStripTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("## **bold** [link](https://example.com)");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "bold".to_string());
+/// assert_eq!(token.offset_from, 5);
+/// assert_eq!(token.offset_to, 9);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "link".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MarkupStripTokenFilter;
+
+impl TokenFilter for MarkupStripTokenFilter {
+    type Tokenizer<T: Tokenizer> = MarkupStripFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        MarkupStripFilterWrapper::new(token_stream)
+    }
+}
@@ -0,0 +1,71 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+/// Characters that are removed at the start and the end of tokens.
+const MARKUP: [char; 9] = ['*', '_', '#', '`', '~', '=', '\'', '[', ']'];
+
+/// Byte range of the text once markup is removed.
+fn strip(text: &str) -> (usize, usize) {
+    // Links : keep the text between brackets
+    let (start, end) = match text.find("](") {
+        Some(close) => match text[..close].rfind('[') {
+            Some(open) => (open + 1, close),
+            None => (0, text.len()),
+        },
+        None => (0, text.len()),
+    };
+
+    let inner = &text[start..end];
+    let leading = inner.len() - inner.trim_start_matches(MARKUP).len();
+    let trimmed = inner.trim_matches(MARKUP);
+
+    (start + leading, start + leading + trimmed.len())
+}
+
+#[derive(Debug, Clone)]
+pub struct MarkupStripTokenStream<T> {
+    tail: T,
+}
+
+impl<T> MarkupStripTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self { tail }
+    }
+}
+
+impl<T: TokenStream> TokenStream for MarkupStripTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            let token = self.tail.token_mut();
+            let len = token.text.len();
+            let (start, end) = strip(&token.text);
+            if start == end {
+                continue;
+            }
+
+            if start != 0 || end != len {
+                // Offsets can only be adjusted if they still match the text
+                if token.offset_to.checked_sub(token.offset_from) == Some(len) {
+                    token.offset_to = token.offset_from + end;
+                    token.offset_from += start;
+                }
+                token.text.truncate(end);
+                token.text.drain(..start);
+            }
+
+            return true;
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,80 @@
+pub use token_filter::MathAlphanumericTokenFilter;
+use token_stream::MathAlphanumericTokenStream;
+use wrapper::MathAlphanumericFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(MathAlphanumericTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn texts(text: &str) -> Vec<String> {
+        token_stream_helper(text)
+            .into_iter()
+            .map(|token| token.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_latin_styles() {
+        let result = texts("𝐇𝐞𝐥𝐥𝐨 𝐻𝑒𝑙𝑙𝑜 𝓗𝓮𝓵𝓵𝓸 𝔥𝔢𝔩𝔩𝔬 𝕙𝕖𝕝𝕝𝕠 𝗵𝗲𝗹𝗹𝗼 𝘩𝘦𝘭𝘭𝘰 𝚑𝚎𝚕𝚕𝚘");
+        assert_eq!(
+            result,
+            vec!["Hello", "Hello", "Hello", "hello", "hello", "hello", "hello", "hello"]
+        );
+    }
+
+    #[test]
+    fn test_letterlike_holes() {
+        // Script H, fraktur H and double-struck H are letterlike symbols
+        let result = texts("ℋ𝒶𝓃𝓈 ℌ𝔞𝔫𝔰 ℍ𝕒𝕟𝕤 𝑝ℎ𝑜𝑛𝑒");
+        assert_eq!(result, vec!["Hans", "Hans", "Hans", "phone"]);
+    }
+
+    #[test]
+    fn test_digits_and_greek() {
+        let result = texts("𝟏𝟐𝟑 𝟙𝟚𝟛 𝟶𝟿 𝛂𝛃𝛄 𝜴");
+        assert_eq!(result, vec!["123", "123", "09", "αβγ", "Ω"]);
+    }
+
+    #[test]
+    fn test_offsets() {
+        let result = token_stream_helper("plain 𝐀𝐁");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "plain".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 14,
+                position: 1,
+                text: "AB".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,45 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::MathAlphanumericFilterWrapper;
+
+/// This [TokenFilter] replaces styled letters and digits of the Mathematical
+/// Alphanumeric Symbols block (`𝐀`, `𝒜`, `𝔸`, `𝟙`...), often pasted from social media,
+/// by their base letters and digits. `𝓱𝓮𝓵𝓵𝓸` becomes `hello`.
+///
+/// It follows the compatibility decomposition (NFKC) of those symbols : Latin letters
+/// and digits become ASCII, Greek letters become regular Greek letters (`𝛂` becomes `α`).
+/// The letterlike symbols that complete some styles (`ℌ`, `ℝ`, `ℯ`...) are replaced
+/// too. Offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::MathAlphanumericTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(MathAlphanumericTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("𝓱𝓮𝓵𝓵𝓸 𝕎𝕠𝕣𝕝𝕕");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "hello".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "World".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MathAlphanumericTokenFilter;
+
+impl TokenFilter for MathAlphanumericTokenFilter {
+    type Tokenizer<T: Tokenizer> = MathAlphanumericFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        MathAlphanumericFilterWrapper::new(token_stream)
+    }
+}
@@ -0,0 +1,105 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use crate::commons::map_chars::map_chars;
+
+/// Greek letters, in the order of each Greek style of the Mathematical Alphanumeric
+/// Symbols block. Symbol variants (`ϴ`, `ϵ`, `ϑ`...) are replaced by their letter, as
+/// NFKC does.
+const GREEK: [char; 58] = [
+    'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ', 'Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Ο', 'Π', 'Ρ', 'Θ', 'Σ',
+    'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', '∇', 'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ',
+    'ν', 'ξ', 'ο', 'π', 'ρ', 'ς', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω', '∂', 'ε', 'θ', 'κ', 'φ', 'ρ',
+    'π',
+];
+
+/// Base letter or digit of a mathematical alphanumeric symbol.
+fn math_alphanumeric(ch: char) -> Option<char> {
+    let value = ch as u32;
+    match value {
+        // 13 Latin styles of 52 letters, from 𝐀 (bold) to 𝚣 (monospace)
+        0x1D400..=0x1D6A3 => {
+            let index = (value - 0x1D400) % 52;
+            if index < 26 {
+                char::from_u32('A' as u32 + index)
+            } else {
+                char::from_u32('a' as u32 + index - 26)
+            }
+        }
+        // 𝚤, 𝚥
+        0x1D6A4 => Some('ı'),
+        0x1D6A5 => Some('ȷ'),
+        // 5 Greek styles, from 𝚨 (bold) to 𝟉 (sans-serif bold italic)
+        0x1D6A8..=0x1D7C9 => Some(GREEK[((value - 0x1D6A8) % 58) as usize]),
+        // 𝟊, 𝟋
+        0x1D7CA => Some('Ϝ'),
+        0x1D7CB => Some('ϝ'),
+        // 5 digit styles, from 𝟎 (bold) to 𝟿 (monospace)
+        0x1D7CE..=0x1D7FF => char::from_u32('0' as u32 + (value - 0x1D7CE) % 10),
+        _ => letterlike(ch),
+    }
+}
+
+/// Letterlike symbols that fill the holes of the Mathematical Alphanumeric Symbols
+/// block, as they were encoded before it (`ℌ` is the fraktur `H`).
+fn letterlike(ch: char) -> Option<char> {
+    match ch {
+        'ℎ' => Some('h'),
+        'ℬ' => Some('B'),
+        'ℰ' => Some('E'),
+        'ℱ' => Some('F'),
+        'ℋ' | 'ℌ' | 'ℍ' => Some('H'),
+        'ℐ' | 'ℑ' => Some('I'),
+        'ℒ' => Some('L'),
+        'ℳ' => Some('M'),
+        'ℛ' | 'ℜ' | 'ℝ' => Some('R'),
+        'ℯ' | 'ⅇ' => Some('e'),
+        'ℊ' => Some('g'),
+        'ℴ' => Some('o'),
+        'ℭ' | 'ℂ' => Some('C'),
+        'ℨ' | 'ℤ' => Some('Z'),
+        'ℕ' => Some('N'),
+        'ℙ' => Some('P'),
+        'ℚ' => Some('Q'),
+        'ⅅ' => Some('D'),
+        'ⅆ' => Some('d'),
+        'ⅈ' => Some('i'),
+        'ⅉ' => Some('j'),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MathAlphanumericTokenStream<T> {
+    tail: T,
+}
+
+impl<T> MathAlphanumericTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self { tail }
+    }
+}
+
+impl<T: TokenStream> TokenStream for MathAlphanumericTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        if let Some(text) = map_chars(&self.tail.token().text, math_alphanumeric) {
+            self.tail.token_mut().text = text;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -7,18 +7,343 @@
 //! * [ReverseTokenFilter]: a filter that reverse the string.
 //! * [ElisionTokenFilter]: a filter that remove elisions.
 //! * [EdgeNgramTokenFilter]: a token filter that produces 'edge-ngram'.
+//! * [GreekNormalizationTokenFilter]: a filter that lowercases Greek and removes accents.
+//! * [CyrillicYoTokenFilter]: a filter that replaces `ё` with `е`.
+//! * [PositionRangeTokenFilter]: a filter that applies another filter on a range of positions.
+//! * [EmailNormalizeTokenFilter]: a filter that normalizes email addresses.
+//! * [CodepointRangeTokenFilter]: a filter that keeps tokens depending on the codepoints of their characters.
+//! * [CompoundJoinTokenFilter]: a filter that joins adjacent tokens forming a known compound.
+//! * [PatternStopTokenFilter]: a filter that removes tokens matching regular expressions.
+//! * [TrimTokenFilter]: a filter that removes leading and trailing whitespaces.
+//! * [StopTokenFilter]: a filter that removes stop words.
+//! * [VietnameseToneNormalizeTokenFilter]: a filter that normalizes the placement of Vietnamese tone marks.
+//! * [ASCIIFoldingTokenFilter]: a filter that folds characters to their ASCII equivalent.
+//! * [ScriptReverseTokenFilter]: a filter that reverses tokens of a script only.
+//! * [NGramTokenFilter]: a token filter that produces ngrams of tokens.
+//! * [KeywordMarkerTokenFilter]: a filter that protects keywords from another filter.
+//! * [MarkupStripTokenFilter]: a filter that removes markdown and wiki markup from tokens.
+//! * [HybridTokenFilter]: a filter that emits both relevance terms (e.g. stems) and edge-ngrams of tokens.
+//! * [WordDelimiterTokenFilter]: a filter that splits tokens into sub-words (case changes, letter/digit transitions and delimiters).
+//! * [CharLimitTokenFilter]: a filter that limits the number of characters emitted.
+//! * [ShingleTokenFilter]: a filter that produces shingles (n-grams of tokens).
+//! * [CommonGramsTokenFilter]: a filter that emits bigrams of common words and their neighbours.
+//! * [SuperscriptDigitTokenFilter]: a filter that replaces superscript, subscript and circled digits by ASCII digits.
+//! * [PatternReplaceTokenFilter]: a filter that rewrites tokens with a regular expression.
+//! * [PatternTokenizer]: a tokenizer that splits text, or captures tokens, with a regular expression.
+//! * [BoundedDistinctTokenFilter]: a filter that removes duplicated tokens within a window of distinct terms.
+//! * [SocialTokenFilter]: a filter that normalizes hashtags and mentions, and splits hashtags into words.
+//! * [KeywordTokenizer]: a tokenizer that emits the whole text as a single token.
+//! * [CharGroupTokenizer]: a tokenizer that splits text on any character of a group.
+//! * [QuoteBracketTokenFilter]: a filter that canonicalizes quotation marks and brackets.
+//! * [UrlEmailTokenizer]: a tokenizer that keeps URLs and email addresses as single tokens.
+//! * [DecimalDigitTokenFilter]: a filter that replaces Unicode decimal digits by ASCII digits.
+//! * [SearchFoldTokenFilter]: a filter that folds width, accents and case in a single pass.
+//! * [LowerCaseTokenFilter] and [UpperCaseTokenFilter]: filters that lowercase or uppercase tokens using Unicode rules.
+//! * [ThaiNormalizationTokenFilter]: a filter that decomposes sara am and puts Thai combining marks in canonical order.
+//! * [CapitalizationTokenFilter]: a filter that capitalizes words, with keep words and length rules.
+//! * [BomStripTokenFilter]: a filter that removes a byte order mark at the start of the first token.
+//! * [AbbreviationExpandTokenFilter]: a filter that replaces abbreviations with their full form, possibly of several words.
+//! * [ArchaicLatinTokenFilter]: a filter that maps archaic Latin forms (long s, ligatures...) to their modern equivalent.
+//! * [DistinctSpansTokenFilter]: a filter that emits each term once and records the offsets of all its occurrences into a [SpanSink].
+//! * [PunctuationRunTokenFilter]: a filter that normalizes runs of the same punctuation character (`!!!` becomes `!`).
+//! * [MathAlphanumericTokenFilter]: a filter that replaces mathematical alphanumeric symbols (`𝓱𝓮𝓵𝓵𝓸`) by their base letters and digits.
+//! * [EnclosedAlphanumericTokenFilter]: a filter that replaces enclosed and fullwidth letters and numbers (`Ⓐ`, `⒜`, `①`, `Ａ`) by their plain form.
+//! * [BudgetTokenFilter]: a filter that stops the token stream once a budget of tokens or bytes is exhausted, optionally reporting each truncated text.
+//! * [DictionaryLemmatizeTokenFilter]: a filter that replaces inflected forms with their lemma using a dictionary.
+//! * [CurrencyTokenFilter]: a filter that normalizes currency and unit symbols, like `€` into `eur`.
+//! * [DictionaryCompoundWordTokenFilter]: a filter that splits compound words into the dictionary words they contain.
+//! * [SentinelTokenFilter]: a filter that switches another filter on and off when it meets a sentinel token.
+//! * [KeywordNormalizeTokenFilter]: a filter that trims, collapses whitespaces and lowercases keyword values while keeping track of offsets.
+//!
+//! It also provides [build_set] to build a [Set] from unsorted keys, [expansion_ratio]
+//! to measure how much an analysis chain expands a text and [diff_analysis] to compare
+//! the terms of two analysis chains. [MappingRewriter] rewrites a text before tokenization,
+//! like Lucene's char filters, with an [OffsetMap] to bring offsets back to the original text.
+//! [HtmlStripRewriter] removes HTML markup the same way. [CharFilterTokenizer] applies
+//! them, as [CharFilter]s, to the text before another tokenizer.
+//!
+//! With the `register` feature, the `register` module registers default-configured
+//! components into a tantivy `TokenizerManager` by name, and the `analyzer` module builds
+//! a tantivy `TextAnalyzer` from a validated configuration of char filters, tokenizer
+//! and filters.
+//!
+//! Components built from regular expressions fail with [RegexError] on invalid patterns.
+//!
+//! Token filters never reset `position_length`: the value from the source token is kept
+//! on every token they emit, so they can be used after a tokenizer that produces a graph.
 pub use fst::Set;
+pub use regex::Error as RegexError;
 
-pub use crate::commons::edge_ngram::{EdgeNgramError, EdgeNgramTokenFilter};
+pub use crate::commons::abbreviation::AbbreviationExpandTokenFilter;
+pub use crate::commons::archaic_latin::{ArchaicLatinTokenFilter, ArchaicLatinTokenFilterBuilder};
+pub use crate::commons::ascii_folding::ASCIIFoldingTokenFilter;
+pub use crate::commons::bom_strip::BomStripTokenFilter;
+pub use crate::commons::bounded_distinct::BoundedDistinctTokenFilter;
+pub use crate::commons::budget::BudgetTokenFilter;
+pub use crate::commons::capitalization::{
+    CapitalizationTokenFilter, CapitalizationTokenFilterBuilder,
+};
+pub use crate::commons::case::{LowerCaseTokenFilter, UpperCaseTokenFilter};
+pub use crate::commons::char_filter::{CharFilter, CharFilterTokenizer};
+pub use crate::commons::char_group::{CharGroupTokenizer, CharGroupTokenizerBuilder};
+pub use crate::commons::char_limit::CharLimitTokenFilter;
+pub use crate::commons::codepoint_range::CodepointRangeTokenFilter;
+pub use crate::commons::common_grams::CommonGramsTokenFilter;
+pub use crate::commons::compound_join::CompoundJoinTokenFilter;
+pub use crate::commons::compound_word::{
+    DictionaryCompoundWordTokenFilter, DEFAULT_MAX_SUBWORD_SIZE, DEFAULT_MIN_SUBWORD_SIZE,
+    DEFAULT_MIN_WORD_SIZE,
+};
+pub use crate::commons::currency::{CurrencyTokenFilter, DEFAULT_SYMBOLS};
+pub use crate::commons::cyrillic_yo::CyrillicYoTokenFilter;
+pub use crate::commons::decimal_digit::DecimalDigitTokenFilter;
+pub use crate::commons::diff::{diff_analysis, AnalysisDiff};
+pub use crate::commons::distinct_spans::{DistinctSpansTokenFilter, SpanSink};
+pub use crate::commons::edge_ngram::{EdgeNgramError, EdgeNgramTokenFilter, EdgeSide};
 pub use crate::commons::elision::ElisionTokenFilter;
-pub use crate::commons::length::LengthTokenFilter;
+pub use crate::commons::email::{EmailNormalizeTokenFilter, EmailNormalizeTokenFilterBuilder};
+pub use crate::commons::enclosed_alphanumeric::EnclosedAlphanumericTokenFilter;
+pub use crate::commons::expansion::expansion_ratio;
+#[cfg(feature = "graphemes")]
+pub use crate::commons::grapheme_split::GraphemeSplitTokenFilter;
+pub use crate::commons::greek::GreekNormalizationTokenFilter;
+pub use crate::commons::html_strip::HtmlStripRewriter;
+pub use crate::commons::hybrid::HybridTokenFilter;
+pub use crate::commons::keyword::KeywordTokenizer;
+pub use crate::commons::keyword_marker::KeywordMarkerTokenFilter;
+pub use crate::commons::keyword_normalize::KeywordNormalizeTokenFilter;
+pub use crate::commons::lemmatize::DictionaryLemmatizeTokenFilter;
+pub use crate::commons::length::{LengthCountMode, LengthTokenFilter};
 pub use crate::commons::limit::LimitTokenCountFilter;
+pub use crate::commons::mapping::MappingRewriter;
+pub use crate::commons::markup_strip::MarkupStripTokenFilter;
+pub use crate::commons::math_alphanumeric::MathAlphanumericTokenFilter;
+pub use crate::commons::ngram::NGramTokenFilter;
+pub use crate::commons::offset_map::OffsetMap;
 pub use crate::commons::path::{PathTokenizer, PathTokenizerBuilder};
+pub use crate::commons::pattern_replace::PatternReplaceTokenFilter;
+pub use crate::commons::pattern_stop::PatternStopTokenFilter;
+pub use crate::commons::pattern_tokenizer::{PatternTokenizer, PatternTokenizerError};
+pub use crate::commons::position_range::PositionRangeTokenFilter;
+pub use crate::commons::punctuation_run::{
+    PunctuationClass, PunctuationRunTokenFilter, PunctuationRunTokenFilterBuilder,
+};
+pub use crate::commons::quote_bracket::QuoteBracketTokenFilter;
 pub use crate::commons::reverse::ReverseTokenFilter;
+pub use crate::commons::script_reverse::ScriptReverseTokenFilter;
+pub use crate::commons::search_fold::SearchFoldTokenFilter;
+pub use crate::commons::sentinel::SentinelTokenFilter;
+pub use crate::commons::set::{build_set, SetError};
+pub use crate::commons::shingle::{ShingleError, ShingleTokenFilter};
+pub use crate::commons::social::SocialTokenFilter;
+pub use crate::commons::stop::StopTokenFilter;
+pub use crate::commons::superscript_digit::SuperscriptDigitTokenFilter;
+pub use crate::commons::thai::ThaiNormalizationTokenFilter;
+pub use crate::commons::trim::TrimTokenFilter;
+pub use crate::commons::url_email::UrlEmailTokenizer;
+pub use crate::commons::vietnamese::VietnameseToneNormalizeTokenFilter;
+pub use crate::commons::word_delimiter::{
+    WordDelimiterTokenFilter, WordDelimiterTokenFilterBuilder,
+};
 
+mod abbreviation;
+#[cfg(feature = "register")]
+pub mod analyzer;
+mod archaic_latin;
+mod ascii_folding;
+mod bom_strip;
+mod bounded_distinct;
+mod budget;
+mod capitalization;
+mod case;
+mod char_filter;
+mod char_group;
+mod char_limit;
+mod codepoint_range;
+mod common_grams;
+mod compound_join;
+mod compound_word;
+mod currency;
+mod cyrillic_yo;
+mod decimal_digit;
+mod diff;
+mod distinct_spans;
 mod edge_ngram;
 mod elision;
+mod email;
+mod enclosed_alphanumeric;
+mod expansion;
+#[cfg(feature = "graphemes")]
+mod grapheme_split;
+mod greek;
+mod html_strip;
+mod hybrid;
+mod keyword;
+mod keyword_marker;
+mod keyword_normalize;
+mod lemmatize;
 mod length;
 mod limit;
+mod map_chars;
+mod mapping;
+mod markup_strip;
+mod math_alphanumeric;
+mod ngram;
+mod offset_map;
 mod path;
+mod pattern_replace;
+mod pattern_stop;
+mod pattern_tokenizer;
+mod position_range;
+mod punctuation_run;
+mod quote_bracket;
+#[cfg(feature = "register")]
+pub mod register;
 mod reverse;
+mod script_reverse;
+mod search_fold;
+mod sentinel;
+mod set;
+mod shingle;
+mod single;
+mod social;
+mod stop;
+mod superscript_digit;
+mod thai;
+mod trim;
+mod url_email;
+mod vietnamese;
+mod word_delimiter;
+
+// Tests shared by every commons component.
+// It contains helpers that can be used from the components' tests.
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::num::NonZeroUsize;
+
+    use tantivy::tokenizer::{
+        TextAnalyzer, Token, TokenFilter, TokenStream, Tokenizer, WhitespaceTokenizer,
+    };
+
+    use super::*;
+
+    /// A [Tokenizer] that split on whitespaces like [WhitespaceTokenizer]
+    /// but that emits tokens with a custom `position_length`. It mimics a
+    /// graph token stream.
+    #[derive(Clone, Debug)]
+    pub struct PositionLengthTokenizer(pub usize);
+
+    #[derive(Debug)]
+    pub struct VecTokenStream {
+        tokens: Vec<Token>,
+        index: usize,
+    }
+
+    impl TokenStream for VecTokenStream {
+        fn advance(&mut self) -> bool {
+            self.index += 1;
+            self.index <= self.tokens.len()
+        }
+
+        fn token(&self) -> &Token {
+            &self.tokens[self.index - 1]
+        }
+
+        fn token_mut(&mut self) -> &mut Token {
+            &mut self.tokens[self.index - 1]
+        }
+    }
+
+    impl Tokenizer for PositionLengthTokenizer {
+        type TokenStream<'a> = VecTokenStream;
+
+        fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+            let mut tokenizer = WhitespaceTokenizer::default();
+            let mut token_stream = tokenizer.token_stream(text);
+            let mut tokens = vec![];
+            while let Some(token) = token_stream.next() {
+                let mut token = token.clone();
+                token.position_length = self.0;
+                tokens.push(token);
+            }
+
+            VecTokenStream { tokens, index: 0 }
+        }
+    }
+
+    fn position_lengths<F: TokenFilter>(filter: F, text: &str) -> Vec<usize> {
+        let mut a = TextAnalyzer::builder(PositionLengthTokenizer(2))
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut result = vec![];
+        let mut add_position_length = |token: &Token| {
+            result.push(token.position_length);
+        };
+        token_stream.process(&mut add_position_length);
+        result
+    }
+
+    #[test]
+    fn test_length_preserve_position_length() {
+        let result = position_lengths(LengthTokenFilter::new(Some(2), None), "a bc def");
+        assert_eq!(result, vec![2, 2]);
+    }
+
+    #[test]
+    fn test_limit_preserve_position_length() {
+        let result = position_lengths(LimitTokenCountFilter::new(2), "a bc def");
+        assert_eq!(result, vec![2, 2]);
+    }
+
+    #[test]
+    fn test_reverse_preserve_position_length() {
+        let result = position_lengths(ReverseTokenFilter::default(), "a bc def");
+        assert_eq!(result, vec![2, 2, 2]);
+    }
+
+    #[test]
+    fn test_elision_preserve_position_length() {
+        let result = position_lengths(
+            ElisionTokenFilter::from_iter_str(vec!["l"], false),
+            "l'avion bc",
+        );
+        assert_eq!(result, vec![2, 2]);
+    }
+
+    #[test]
+    fn test_keyword_marker_preserve_position_length() {
+        let filter = KeywordMarkerTokenFilter::protect(build_set(vec!["bc"]).unwrap())
+            .around(LowerCaseTokenFilter);
+        let result = position_lengths(filter, "A bc DEF");
+        assert_eq!(result, vec![2, 2, 2]);
+    }
+
+    #[test]
+    fn test_hybrid_preserve_position_length() {
+        let prefix =
+            EdgeNgramTokenFilter::new(NonZeroUsize::new(1).unwrap(), NonZeroUsize::new(2), false)
+                .unwrap();
+        let filter = HybridTokenFilter::new(LowerCaseTokenFilter, prefix);
+        let result = position_lengths(filter, "Ab c");
+        assert_eq!(result, vec![2, 2, 2, 2]);
+    }
+
+    #[test]
+    fn test_sentinel_preserve_position_length() {
+        let filter = SentinelTokenFilter::new("|").around(LowerCaseTokenFilter);
+        let result = position_lengths(filter, "A | B | C");
+        assert_eq!(result, vec![2, 2, 2]);
+    }
+
+    #[test]
+    fn test_edge_ngram_preserve_position_length() {
+        let filter =
+            EdgeNgramTokenFilter::new(NonZeroUsize::new(1).unwrap(), NonZeroUsize::new(2), true)
+                .unwrap();
+        let result = position_lengths(filter, "a bcd");
+        assert_eq!(result, vec![2, 2, 2, 2]);
+    }
+}
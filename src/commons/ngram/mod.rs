@@ -0,0 +1,311 @@
+pub use token_filter::NGramTokenFilter;
+use token_stream::NgramFilterStream;
+use wrapper::NgramFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::EdgeNgramError;
+
+    fn token_stream_helper(
+        text: &str,
+        min: NonZeroUsize,
+        max: Option<NonZeroUsize>,
+        keep_original: bool,
+    ) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(NGramTokenFilter::new(min, max, keep_original).unwrap())
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let result =
+            NGramTokenFilter::new(NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(1), false);
+
+        let expected = EdgeNgramError::MaximumLowerThanMinimum {
+            min: NonZeroUsize::new(2).unwrap(),
+            max: NonZeroUsize::new(1).unwrap(),
+        };
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_unigram_bigram() {
+        let result = token_stream_helper(
+            "abc",
+            NonZeroUsize::new(1).unwrap(),
+            NonZeroUsize::new(2),
+            false,
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "b".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "c".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "ab".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "bc".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_no_max() {
+        let result = token_stream_helper("abc", NonZeroUsize::new(2).unwrap(), None, false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "ab".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "bc".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "abc".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_short_token() {
+        let result = token_stream_helper(
+            "a abc",
+            NonZeroUsize::new(2).unwrap(),
+            NonZeroUsize::new(2),
+            false,
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 2,
+                offset_to: 5,
+                position: 1,
+                text: "ab".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 5,
+                position: 1,
+                text: "bc".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_keep_original() {
+        let result = token_stream_helper(
+            "a abcd ab",
+            NonZeroUsize::new(2).unwrap(),
+            NonZeroUsize::new(3),
+            true,
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 1,
+                position: 0,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 6,
+                position: 1,
+                text: "ab".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 6,
+                position: 1,
+                text: "bc".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 6,
+                position: 1,
+                text: "cd".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 6,
+                position: 1,
+                text: "abc".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 6,
+                position: 1,
+                text: "bcd".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 6,
+                position: 1,
+                text: "abcd".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 9,
+                position: 2,
+                text: "ab".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_cjk() {
+        let result = token_stream_helper(
+            "東京都 é",
+            NonZeroUsize::new(2).unwrap(),
+            NonZeroUsize::new(2),
+            false,
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "東京".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "京都".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multibyte() {
+        let result = token_stream_helper(
+            "café",
+            NonZeroUsize::new(1).unwrap(),
+            NonZeroUsize::new(2),
+            false,
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "c".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "f".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "é".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "ca".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "af".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "fé".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,95 @@
+use std::num::NonZeroUsize;
+
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::NgramFilterWrapper;
+use crate::commons::EdgeNgramError;
+
+/// Token filter that produces [ngram](https://docs.rs/tantivy/0.18.1/tantivy/tokenizer/struct.NgramTokenizer.html)
+/// of tokens : all substrings whose length is between min and max, at each position.
+/// For example, with min=1 and max=2, `abc` will generate `a`, `b`, `c`, `ab` and `bc`.
+/// It is an equivalent of [Lucene's NGramTokenFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/ngram/NGramTokenFilter.html).
+///
+/// Ngrams are emitted by increasing length, then by position. Lengths are counted in characters,
+/// and every ngram keeps the offsets of the whole token, like
+/// [EdgeNgramTokenFilter](crate::commons::EdgeNgramTokenFilter) does.
+///
+/// It is configured with three parameters:
+/// * min ngram: the minimum number of characters. It must be greater than 0.
+/// * max ngram: the maximum number of characters. It is optional, if there is no maximum,
+///   ngrams up to the whole token are generated.
+/// * keep original: also emit the original token if it is shorter than min or longer than max.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::num::NonZeroUsize;
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::NGramTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(NGramTokenFilter::new(NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(3), false)?)
+///    .build();
+/// let mut token_stream = tmp.token_stream("abcd");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ab".to_string());
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "bc".to_string());
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "cd".to_string());
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "abc".to_string());
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "bcd".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct NGramTokenFilter {
+    min: NonZeroUsize,
+    max: Option<NonZeroUsize>,
+    keep_original: bool,
+}
+
+impl NGramTokenFilter {
+    /// Create a new `NGramTokenFilter` with the min and max ngram
+    /// provided.
+    ///
+    /// # Parameters
+    ///
+    /// * `min` : minimum ngram.
+    /// * `max` : maximum ngram. It must be greater or equals to `min`.
+    ///   Provide [None](None) for unlimited.
+    /// * `keep_original`: the complete token will also be output if
+    ///   its length is lower than `min` or greater than `max`.
+    pub fn new(
+        min: NonZeroUsize,
+        max: Option<NonZeroUsize>,
+        keep_original: bool,
+    ) -> Result<Self, EdgeNgramError> {
+        // Check max
+        if let Some(m) = max {
+            if m < min {
+                return Err(EdgeNgramError::MaximumLowerThanMinimum { min, max: m });
+            }
+        }
+
+        Ok(NGramTokenFilter {
+            min,
+            max,
+            keep_original,
+        })
+    }
+}
+
+impl TokenFilter for NGramTokenFilter {
+    type Tokenizer<T: Tokenizer> = NgramFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        NgramFilterWrapper::new(tokenizer, self.min, self.max, self.keep_original)
+    }
+}
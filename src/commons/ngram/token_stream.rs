@@ -0,0 +1,94 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct NgramFilterStream<T> {
+    tail: T,
+    /// Current token to emit
+    token: Token,
+    /// Minimum ngram must be greater than 0
+    min: usize,
+    /// Maximum ngram, None means no limit
+    max: Option<usize>,
+    /// Do we have to keep the original token?
+    keep_original: bool,
+    /// Characters of the current token
+    chars: Vec<char>,
+    /// Length of the next ngram
+    size: usize,
+    /// Start of the next ngram
+    start: usize,
+    /// Is the original token still to be emitted?
+    original_pending: bool,
+}
+
+impl<T> NgramFilterStream<T> {
+    pub(crate) fn new(tail: T, min: usize, max: Option<usize>, keep_original: bool) -> Self {
+        Self {
+            tail,
+            token: Token::default(),
+            min,
+            max,
+            keep_original,
+            chars: Vec::new(),
+            size: 0,
+            start: 0,
+            original_pending: false,
+        }
+    }
+
+    /// Largest ngram for the current token.
+    fn stop_length(&self) -> usize {
+        let len = self.chars.len();
+        self.max.map_or(len, |max| max.min(len))
+    }
+}
+
+impl<T: TokenStream> TokenStream for NgramFilterStream<T> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if self.size >= self.min && self.size <= self.stop_length() {
+                if self.start + self.size <= self.chars.len() {
+                    self.token.text.clear();
+                    self.token
+                        .text
+                        .extend(&self.chars[self.start..self.start + self.size]);
+                    self.start += 1;
+                    return true;
+                }
+                self.size += 1;
+                self.start = 0;
+                continue;
+            }
+
+            if self.original_pending {
+                self.original_pending = false;
+                self.token.text.clone_from(&self.tail.token().text);
+                return true;
+            }
+
+            if !self.tail.advance() {
+                return false;
+            }
+
+            self.token = self.tail.token().clone();
+            self.chars.clear();
+            self.chars.extend(self.tail.token().text.chars());
+            self.size = self.min;
+            self.start = 0;
+            let len = self.chars.len();
+            self.original_pending =
+                self.keep_original && (len < self.min || self.max.is_some_and(|max| len > max));
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -0,0 +1,46 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::num::NonZeroUsize;
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::NgramFilterStream;
+
+#[derive(Clone, Debug)]
+pub struct NgramFilterWrapper<T> {
+    min: NonZeroUsize,
+    max: Option<NonZeroUsize>,
+    keep_original: bool,
+    inner: T,
+}
+
+impl<T> NgramFilterWrapper<T> {
+    pub(crate) fn new(
+        inner: T,
+        min: NonZeroUsize,
+        max: Option<NonZeroUsize>,
+        keep_original: bool,
+    ) -> Self {
+        Self {
+            min,
+            max,
+            keep_original,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for NgramFilterWrapper<T> {
+    type TokenStream<'a> = NgramFilterStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        NgramFilterStream::new(
+            self.inner.token_stream(text),
+            self.min.get(),
+            self.max.map(|v| v.get()),
+            self.keep_original,
+        )
+    }
+}
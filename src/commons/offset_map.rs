@@ -0,0 +1,106 @@
+//! Correction of offsets of a rewritten text.
+
+use tantivy_tokenizer_api::Token;
+
+/// Start of a segment of the rewritten text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Anchor {
+    /// Offset of the segment in the rewritten text.
+    output: usize,
+    /// Offset of the segment in the original text.
+    original: usize,
+    /// Length of the segment in the original text, offsets inside the segment
+    /// never go past it. It's [usize::MAX] for copied text.
+    original_len: usize,
+}
+
+/// Maps offsets (in bytes) of a rewritten text back to offsets of the original text,
+/// like the offset correction of Lucene's char filters. Tokenizers run on the rewritten
+/// text, then token offsets are corrected so that highlighting uses the original text.
+///
+/// Text that is copied keeps its offsets, shifted by the length changes before it.
+/// Offsets inside a replacement are mapped inside the replaced text, at most to its
+/// end : the offset right after an expansion maps to the end of the text it replaces,
+/// and the end of a contraction maps to the end of the longer original text.
+///
+/// An [OffsetMap] is built by a rewriter like [MappingRewriter](crate::commons::MappingRewriter).
+/// The [Default] one doesn't change offsets.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OffsetMap {
+    /// Segments, sorted by offset in the rewritten text.
+    anchors: Vec<Anchor>,
+}
+
+impl OffsetMap {
+    /// Offset in the original text of an `offset` of the rewritten text.
+    pub fn correct(&self, offset: usize) -> usize {
+        match self.anchor(offset) {
+            Some(anchor) => anchor.original + (offset - anchor.output).min(anchor.original_len),
+            None => offset,
+        }
+    }
+
+    /// Correct the offsets of a token produced from the rewritten text. Unlike
+    /// [correct](OffsetMap::correct), a token that starts or ends inside a replacement
+    /// covers the whole replaced text, so that a token made of an expansion (`and`
+    /// for `&`) still highlights something, and a token followed by removed text
+    /// (like a closing tag) ends before it.
+    pub fn correct_token(&self, token: &mut Token) {
+        let from = token.offset_from;
+        token.offset_from = match self.anchor(from) {
+            Some(anchor) if anchor.original_len != usize::MAX && from > anchor.output => {
+                anchor.original
+            }
+            _ => self.correct(from),
+        };
+
+        // The end of a token belongs to the segment of its last byte
+        let to = token.offset_to;
+        let index = self.anchors.partition_point(|anchor| anchor.output < to);
+        token.offset_to = match index.checked_sub(1).map(|index| self.anchors[index]) {
+            Some(anchor) if anchor.original_len != usize::MAX => {
+                anchor.original + anchor.original_len
+            }
+            Some(anchor) => anchor.original + (to - anchor.output),
+            None => self.correct(to),
+        };
+    }
+
+    /// Segment that contains `offset` of the rewritten text.
+    fn anchor(&self, offset: usize) -> Option<Anchor> {
+        // Several segments can start at the same offset when text is removed,
+        // the last one is the text that is really there.
+        let index = self
+            .anchors
+            .partition_point(|anchor| anchor.output <= offset);
+        index.checked_sub(1).map(|index| self.anchors[index])
+    }
+
+    /// Text of the original text from `output` in the rewritten text is copied.
+    pub(crate) fn copied(&mut self, output: usize, original: usize) {
+        if let Some(last) = self.anchors.last() {
+            // Still the same copied segment
+            if last.original_len == usize::MAX
+                && output >= last.output
+                && output - last.output == original.wrapping_sub(last.original)
+            {
+                return;
+            }
+        }
+        self.anchors.push(Anchor {
+            output,
+            original,
+            original_len: usize::MAX,
+        });
+    }
+
+    /// `original_len` bytes of the original text are replaced at `output` in the
+    /// rewritten text.
+    pub(crate) fn replaced(&mut self, output: usize, original: usize, original_len: usize) {
+        self.anchors.push(Anchor {
+            output,
+            original,
+            original_len,
+        });
+    }
+}
@@ -5,6 +5,7 @@ mod token_stream;
 mod tokenizer;
 
 const DEFAULT_SEPARATOR: char = '/';
+const DEFAULT_SEPARATOR_STR: &str = "/";
 
 #[cfg(test)]
 mod tests {
@@ -166,6 +167,23 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_empty() {
+        for tokenizer in [
+            PathTokenizer::default(),
+            PathTokenizerBuilder::default()
+                .reverse(true)
+                .build()
+                .unwrap(),
+            PathTokenizerBuilder::default()
+                .bidirectional(true)
+                .build()
+                .unwrap(),
+        ] {
+            assert_eq!(tokenize_all("", tokenizer), vec![]);
+        }
+    }
+
     #[test]
     fn test_only_delimiter() {
         let tokenizer = PathTokenizer::default();
@@ -324,11 +342,7 @@ mod tests {
 
     #[test]
     fn test_basic_skip() {
-        #[allow(trivial_numeric_casts)]
-        let tokenizer = PathTokenizerBuilder::default()
-            .skip(1_usize)
-            .build()
-            .unwrap();
+        let tokenizer = PathTokenizer::with_skip(1);
 
         let result = tokenize_all("/a/b/c", tokenizer);
         let expected: Vec<Token> = vec![
@@ -829,4 +843,242 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_positions() {
+        let tokenizer = PathTokenizerBuilder::default()
+            .positions(true)
+            .build()
+            .unwrap();
+
+        let result = tokenize_all("/a/b/c", tokenizer);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 2,
+                position: 0,
+                text: "/a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 1,
+                text: "/a/b".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 2,
+                text: "/a/b/c".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_positions_reverse_skip() {
+        let tokenizer = PathTokenizerBuilder::default()
+            .delimiter('.')
+            .reverse(true)
+            .skip(1_usize)
+            .positions(true)
+            .build()
+            .unwrap();
+
+        let result = tokenize_all("www.mail.google.com", tokenizer);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 9,
+                offset_to: 16,
+                position: 0,
+                text: "google.".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 16,
+                position: 1,
+                text: "mail.google.".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 16,
+                position: 2,
+                text: "www.mail.google.".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    fn texts_and_offsets(tokens: Vec<Token>) -> Vec<(String, usize, usize)> {
+        tokens
+            .into_iter()
+            .map(|token| (token.text, token.offset_from, token.offset_to))
+            .collect()
+    }
+
+    #[test]
+    fn test_multi_char_delimiter() {
+        let tokenizer = PathTokenizer::with_delimiter("::");
+
+        let result = texts_and_offsets(tokenize_all("com::foo::bar", tokenizer));
+        let expected = vec![
+            ("com".to_string(), 0, 3),
+            ("com::foo".to_string(), 0, 8),
+            ("com::foo::bar".to_string(), 0, 13),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multi_char_delimiter_reverse() {
+        let tokenizer = PathTokenizerBuilder::default()
+            .delimiter("::")
+            .reverse(true)
+            .build()
+            .unwrap();
+
+        let result = texts_and_offsets(tokenize_all("com::foo::bar", tokenizer));
+        let expected = vec![
+            ("bar".to_string(), 10, 13),
+            ("foo::bar".to_string(), 5, 13),
+            ("com::foo::bar".to_string(), 0, 13),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_multi_char_delimiter_replacement() {
+        let tokenizer = PathTokenizerBuilder::default()
+            .delimiter("::")
+            .replacement('/')
+            .skip(1_usize)
+            .build()
+            .unwrap();
+
+        // Offsets are those of the original text
+        let result = texts_and_offsets(tokenize_all("::com::foo::bar", tokenizer));
+        let expected = vec![("/foo".to_string(), 5, 10), ("/foo/bar".to_string(), 5, 15)];
+
+        assert_eq!(result, expected);
+
+        let tokenizer = PathTokenizerBuilder::default()
+            .delimiter("::")
+            .replacement('/')
+            .reverse(true)
+            .build()
+            .unwrap();
+
+        let result = texts_and_offsets(tokenize_all("com::foo::bar", tokenizer));
+        let expected = vec![
+            ("bar".to_string(), 10, 13),
+            ("foo/bar".to_string(), 5, 13),
+            ("com/foo/bar".to_string(), 0, 13),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_char_delimiter() {
+        let tokenizer = PathTokenizer::with_delimiter('.');
+
+        let result = texts_and_offsets(tokenize_all("a.b", tokenizer));
+        let expected = vec![("a".to_string(), 0, 1), ("a.b".to_string(), 0, 3)];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bidirectional() {
+        let tokenizer = PathTokenizerBuilder::default()
+            .bidirectional(true)
+            .build()
+            .unwrap();
+
+        let result = texts_and_offsets(tokenize_all("/a/b/c", tokenizer));
+        let expected = vec![
+            ("/a".to_string(), 0, 2),
+            ("/a/b".to_string(), 0, 4),
+            ("/a/b/c".to_string(), 0, 6),
+            ("c".to_string(), 5, 6),
+            ("b/c".to_string(), 3, 6),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bidirectional_relative_skip_positions() {
+        let tokenizer = PathTokenizerBuilder::default()
+            .delimiter('.')
+            .bidirectional(true)
+            .positions(true)
+            .build()
+            .unwrap();
+
+        let result: Vec<(String, usize)> = tokenize_all("mail.google.com", tokenizer)
+            .into_iter()
+            .map(|token| (token.text, token.position))
+            .collect();
+        let expected = vec![
+            ("mail".to_string(), 0),
+            ("mail.google".to_string(), 1),
+            ("mail.google.com".to_string(), 2),
+            ("com".to_string(), 3),
+            ("google.com".to_string(), 4),
+        ];
+
+        assert_eq!(result, expected);
+
+        let tokenizer = PathTokenizerBuilder::default()
+            .bidirectional(true)
+            .skip(1_usize)
+            .build()
+            .unwrap();
+
+        let result: Vec<String> = tokenize_all("/a/b/c", tokenizer)
+            .into_iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(result, vec!["/b", "/b/c", "b/"]);
+    }
+
+    #[test]
+    fn test_bidirectional_single_part() {
+        let tokenizer = PathTokenizerBuilder::default()
+            .bidirectional(true)
+            .build()
+            .unwrap();
+
+        let result: Vec<String> = tokenize_all("/a", tokenizer)
+            .into_iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(result, vec!["/a"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let tokenizer: PathTokenizer =
+            serde_json::from_str(r#"{"delimiter": "::", "replacement": "/", "skip": 1}"#).unwrap();
+        let json = serde_json::to_string(&tokenizer).unwrap();
+        let tokenizer: PathTokenizer = serde_json::from_str(&json).unwrap();
+
+        let result: Vec<String> = tokenize_all("com::foo::bar", tokenizer)
+            .into_iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(result, vec!["/foo", "/foo/bar"]);
+    }
 }
@@ -1,30 +1,81 @@
-use std::iter::{Rev, Skip};
-use std::str::Split;
+use std::iter::Skip;
+use std::str::{RSplit, Split};
 
 use either::Either;
 use tantivy_tokenizer_api::{Token, TokenStream};
 
 #[derive(Debug, Clone)]
 pub struct PathTokenStream<'a> {
-    pub(crate) text: Skip<Either<Split<'a, char>, Rev<Split<'a, char>>>>,
+    pub(crate) text: Skip<Either<Split<'a, &'a str>, RSplit<'a, &'a str>>>,
     pub(crate) buffer: String,
     pub(crate) token: Token,
-    pub(crate) separator: char,
+    pub(crate) separator: &'a str,
+    /// Length of the delimiter in the original text
+    pub(crate) delimiter_len: usize,
     pub(crate) offset: usize,
+    /// Length of the current token in the original text, that differs from
+    /// the length of the buffer when there is a replacement
+    pub(crate) length: usize,
     pub(crate) starts_with: bool,
     pub(crate) reverse: bool,
+    /// Give each level its own position
+    pub(crate) positions: bool,
+    /// Position of the next level
+    pub(crate) position: usize,
+    /// Number of tokens left to emit, [None] if there is no limit
+    pub(crate) remaining: Option<usize>,
+    /// Stream that continues this one, for bidirectional hierarchies
+    pub(crate) then: Option<Box<PathTokenStream<'a>>>,
 }
 
 impl TokenStream for PathTokenStream<'_> {
     fn advance(&mut self) -> bool {
+        if self.advance_hierarchy() {
+            return true;
+        }
+
+        match self.then.take() {
+            Some(then) => {
+                let position = self.position;
+                *self = *then;
+                // Positions go on after the previous hierarchy
+                self.position = position;
+                self.advance()
+            }
+            None => false,
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+impl PathTokenStream<'_> {
+    fn advance_hierarchy(&mut self) -> bool {
+        if self.remaining == Some(0) {
+            return false;
+        }
+
         if let Some(part) = self.text.next() {
+            if let Some(remaining) = self.remaining.as_mut() {
+                *remaining -= 1;
+            }
+
             if !self.starts_with {
                 // Do not add the separator (or replacement) if it doesn't start (or end) with the separator
                 self.starts_with = true;
-            } else if self.reverse {
-                self.buffer.insert(0, self.separator);
             } else {
-                self.buffer.push(self.separator);
+                if self.reverse {
+                    self.buffer.insert_str(0, self.separator);
+                } else {
+                    self.buffer.push_str(self.separator);
+                }
+                self.length += self.delimiter_len;
             }
 
             if self.reverse {
@@ -32,9 +83,10 @@ impl TokenStream for PathTokenStream<'_> {
             } else {
                 self.buffer.push_str(part);
             }
+            self.length += part.len();
 
             let offset_from = if self.reverse {
-                self.offset - self.buffer.len()
+                self.offset - self.length
             } else {
                 self.offset
             };
@@ -42,13 +94,20 @@ impl TokenStream for PathTokenStream<'_> {
             let offset_to = if self.reverse {
                 self.offset
             } else {
-                self.offset + self.buffer.len()
+                self.offset + self.length
+            };
+
+            let position = if self.positions {
+                self.position += 1;
+                self.position - 1
+            } else {
+                0
             };
 
             self.token = Token {
                 offset_from,
                 offset_to,
-                position: 0,
+                position,
                 text: self.buffer.clone(),
                 position_length: 1,
             };
@@ -57,12 +116,4 @@ impl TokenStream for PathTokenStream<'_> {
             false
         }
     }
-
-    fn token(&self) -> &Token {
-        &self.token
-    }
-
-    fn token_mut(&mut self) -> &mut Token {
-        &mut self.token
-    }
 }
@@ -1,10 +1,9 @@
-use std::iter::Rev;
-use std::str::Split;
+use std::str::{RSplit, Split};
 
 use either::Either;
 use tantivy_tokenizer_api::Tokenizer;
 
-use super::{PathTokenStream, DEFAULT_SEPARATOR};
+use super::{PathTokenStream, DEFAULT_SEPARATOR, DEFAULT_SEPARATOR_STR};
 
 /// Path tokenizer. It will tokenize this :
 /// ```norust
@@ -20,9 +19,15 @@ use super::{PathTokenStream, DEFAULT_SEPARATOR};
 /// Enabling `reverse` will make this tokenizer to behave like Lucene's except that tokens will not be ordered the same way. See
 /// [ReversePathHierarchyTokenizer](https://lucene.apache.org/core/9_1_0/analysis/common/org/apache/lucene/analysis/path/ReversePathHierarchyTokenizer.html)
 ///
-/// # Warning
-/// To construct a new [PathTokenizer] you should use the [PathTokenizerBuilder] or the [Default] implementation as
-/// [From] trait will probably be removed.
+/// The delimiter can be made of several characters, for example `::` to tokenize
+/// `com::foo::bar` into `com`, `com::foo` and `com::foo::bar`.
+///
+/// An empty text gives no token.
+///
+/// # Construction
+/// A [PathTokenizer] is constructed with the [PathTokenizerBuilder], the [Default] implementation or
+/// one of the typed constructors [with_delimiter](PathTokenizer::with_delimiter) and
+/// [with_skip](PathTokenizer::with_skip).
 ///
 /// # Examples
 ///
@@ -55,6 +60,41 @@ use super::{PathTokenStream, DEFAULT_SEPARATOR};
 /// # }
 /// ```
 ///
+/// With `bidirectional`, both hierarchies are emitted in one stream, so a path can be
+/// searched by its folders and by its file.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::PathTokenizerBuilder;
+///
+/// let path_tokenizer = PathTokenizerBuilder::default()
+///    .bidirectional(true)
+///    .build()?;
+///
+/// let mut tmp = TextAnalyzer::builder(path_tokenizer).build();
+/// let mut token_stream = tmp.token_stream("/a/b/c");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "/a".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "/a/b".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "/a/b/c".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "c".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "b/c".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+///
 /// This second example shows what tokens are produced if `reverse` is set to `true` and what does `replacement` parameter.
 ///
 /// ```rust
@@ -87,8 +127,13 @@ use super::{PathTokenStream, DEFAULT_SEPARATOR};
 /// #     Ok(())
 /// # }
 /// ```
-#[derive(Clone, Copy, Debug, Builder)]
+#[derive(Clone, Debug, Builder)]
 #[builder(setter(into), default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct PathTokenizer {
     /// Do the tokenization backward.
     /// ```norust
@@ -110,10 +155,12 @@ pub struct PathTokenizer {
     /// ```norust
     /// /part1/part2/part3
     /// ```
-    #[builder(default = "DEFAULT_SEPARATOR")]
-    pub delimiter: char,
-    /// Character that replaces delimiter for generated parts.
-    /// If [None] then the same char as delimiter will be used.
+    /// It can be a single character or a string. An empty delimiter is replaced
+    /// by the default one (`/`).
+    #[builder(default = "DEFAULT_SEPARATOR.to_string()")]
+    pub delimiter: String,
+    /// String that replaces delimiter for generated parts.
+    /// If [None] then the delimiter will be used.
     /// For example, if delimiter is `/` and replacement is `|`
     /// ```norust
     /// /part1/part2/part3
@@ -124,7 +171,19 @@ pub struct PathTokenizer {
     /// |part1|part2
     /// |part1|part2|part3
     /// ```
-    pub replacement: Option<char>,
+    #[builder(setter(into, strip_option))]
+    pub replacement: Option<String>,
+    /// Give each level its own position : `/part1` is at position 0, `/part1/part2` at
+    /// position 1, and so on (in reverse mode, the last part is at position 0). If `false`,
+    /// all tokens are at position 0.
+    #[builder(default = "false")]
+    pub positions: bool,
+    /// Emit both the forward and the reverse hierarchies, forward first. `reverse`
+    /// is then ignored. Reverse tokens are emitted until the full path, that is
+    /// already a forward token : `/a/b/c` gives `/a`, `/a/b`, `/a/b/c`, `c` and `b/c`.
+    /// `skip` applies to both hierarchies.
+    #[builder(default = "false")]
+    pub bidirectional: bool,
 }
 
 impl Default for PathTokenizer {
@@ -134,27 +193,57 @@ impl Default for PathTokenizer {
         PathTokenizer {
             reverse: false,
             skip: 0,
-            delimiter: DEFAULT_SEPARATOR,
+            delimiter: DEFAULT_SEPARATOR.to_string(),
             replacement: None,
+            positions: false,
+            bidirectional: false,
         }
     }
 }
 
-impl Tokenizer for PathTokenizer {
-    type TokenStream<'a> = PathTokenStream<'a>;
+impl PathTokenizer {
+    /// Construct a [PathTokenizer] with the given delimiter, which can be a [char]
+    /// or a string. Other parameters are the default ones.
+    pub fn with_delimiter(delimiter: impl Into<String>) -> Self {
+        PathTokenizer {
+            delimiter: delimiter.into(),
+            ..Default::default()
+        }
+    }
 
-    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+    /// Construct a [PathTokenizer] that skips the given number of parts. Other
+    /// parameters are the default ones.
+    pub fn with_skip(skip: usize) -> Self {
+        PathTokenizer {
+            skip,
+            ..Default::default()
+        }
+    }
+}
+
+impl PathTokenizer {
+    fn delimiter(&self) -> &str {
+        if self.delimiter.is_empty() {
+            DEFAULT_SEPARATOR_STR
+        } else {
+            self.delimiter.as_str()
+        }
+    }
+
+    /// Stream of the hierarchy in one direction.
+    fn hierarchy<'a>(&'a self, text: &'a str, reverse: bool) -> PathTokenStream<'a> {
+        let delimiter = self.delimiter();
         let mut offset = 0;
-        let mut starts_with = if self.reverse {
-            text.ends_with(self.delimiter)
+        let mut starts_with = if reverse {
+            text.ends_with(delimiter)
         } else {
-            text.starts_with(self.delimiter)
+            text.starts_with(delimiter)
         };
-        let split = text.split(self.delimiter);
-        let split: Either<Split<char>, Rev<Split<char>>> = if self.reverse {
-            Either::Right(split.rev())
+        // A string delimiter can't be searched from both ends, so reverse uses `rsplit`
+        let split: Either<Split<&str>, RSplit<&str>> = if reverse {
+            Either::Right(text.rsplit(delimiter))
         } else {
-            Either::Left(split)
+            Either::Left(text.split(delimiter))
         };
 
         let skip = if starts_with { 1 } else { 0 };
@@ -164,7 +253,7 @@ impl Tokenizer for PathTokenizer {
         while i > 0 {
             if let Some(token) = split.next() {
                 if starts_with {
-                    offset += 1;
+                    offset += delimiter.len();
                 } else {
                     starts_with = true;
                 }
@@ -173,7 +262,7 @@ impl Tokenizer for PathTokenizer {
             i -= 1;
         }
 
-        if self.reverse {
+        if reverse {
             offset = text.len() - offset;
         }
 
@@ -181,10 +270,46 @@ impl Tokenizer for PathTokenizer {
             text: split,
             buffer: String::with_capacity(text.len()),
             token: Default::default(),
-            separator: self.replacement.unwrap_or(self.delimiter),
+            separator: self.replacement.as_deref().unwrap_or(delimiter),
+            delimiter_len: delimiter.len(),
             offset,
+            length: 0,
             starts_with,
-            reverse: self.reverse,
+            reverse,
+            positions: self.positions,
+            position: 0,
+            remaining: None,
+            then: None,
+        }
+    }
+}
+
+impl Tokenizer for PathTokenizer {
+    type TokenStream<'a> = PathTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        // An empty text has no part, so no token
+        if text.is_empty() {
+            let mut empty = self.hierarchy(text, false);
+            empty.remaining = Some(0);
+            return empty;
         }
+
+        if !self.bidirectional {
+            return self.hierarchy(text, self.reverse);
+        }
+
+        // Reverse tokens that contain the first part are the full path, already
+        // emitted by the forward hierarchy.
+        let delimiter = self.delimiter();
+        let parts = text.split(delimiter).count()
+            - usize::from(text.starts_with(delimiter))
+            - usize::from(text.ends_with(delimiter));
+        let mut reverse = self.hierarchy(text, true);
+        reverse.remaining = Some(parts.saturating_sub(1).saturating_sub(self.skip));
+
+        let mut forward = self.hierarchy(text, false);
+        forward.then = Some(Box::new(reverse));
+        forward
     }
 }
@@ -0,0 +1,93 @@
+pub use token_filter::PatternReplaceTokenFilter;
+use token_stream::PatternReplaceTokenStream;
+use wrapper::PatternReplaceFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(
+        text: &str,
+        pattern: &str,
+        replacement: &str,
+        replace_all: bool,
+    ) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(PatternReplaceTokenFilter::new(pattern, replacement, replace_all).unwrap())
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_replace_first() {
+        let result = token_stream_helper("a-b-c", "-", "_", false);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 5,
+            position: 0,
+            text: "a_b-c".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_replace_all() {
+        let result = token_stream_helper("a-b-c d", "-", "", true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "abc".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 7,
+                position: 1,
+                text: "d".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_capture_groups() {
+        let result = token_stream_helper(
+            "2024-01-31",
+            r"(?P<y>\d{4})-(\d{2})-(\d{2})",
+            "$3/$2/${y}",
+            false,
+        );
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 10,
+            position: 0,
+            text: "31/01/2024".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let result = PatternReplaceTokenFilter::new(r"^\d{4", "", true);
+        assert!(result.is_err());
+    }
+}
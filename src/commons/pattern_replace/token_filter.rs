@@ -0,0 +1,81 @@
+use regex::{Error, Regex};
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::PatternReplaceFilterWrapper;
+
+/// This [TokenFilter] rewrites tokens with a
+/// [regular expression](https://docs.rs/regex/latest/regex/#syntax). It is an equivalent of
+/// [Lucene's PatternReplaceFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/pattern/PatternReplaceFilter.html).
+///
+/// The replacement can reference capture groups, like `$1` or `${name}` (see
+/// [Regex::replace]). Either the first match or all matches are replaced.
+///
+/// Offsets are not modified : once a token is rewritten, its offsets are still those
+/// of the original text and don't map to the replaced text anymore.
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy_analysis_contrib::commons::PatternReplaceTokenFilter;
+///
+/// let filter = PatternReplaceTokenFilter::new(r"(\d+)-(\d+)", "$2-$1", false)?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// # Example
+///
+/// In this example, dashes and dots are removed from tokens.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::PatternReplaceTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(PatternReplaceTokenFilter::new(r"[-.]", "", true)?)
+///    .build();
+/// let mut token_stream = tmp.token_stream("e-mail U.S.A.");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "email".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "USA".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PatternReplaceTokenFilter {
+    pattern: Regex,
+    replacement: String,
+    replace_all: bool,
+}
+
+impl PatternReplaceTokenFilter {
+    /// Get a new token filter. It fails if the pattern is invalid.
+    /// # Parameters :
+    /// * pattern : the regular expression to replace
+    /// * replacement : the replacement, it can reference capture groups (`$1`, `${name}`)
+    /// * replace_all : replace all matches if `true`, only the first one otherwise
+    pub fn new(pattern: &str, replacement: &str, replace_all: bool) -> Result<Self, Error> {
+        Ok(PatternReplaceTokenFilter {
+            pattern: Regex::new(pattern)?,
+            replacement: replacement.to_string(),
+            replace_all,
+        })
+    }
+}
+
+impl TokenFilter for PatternReplaceTokenFilter {
+    type Tokenizer<T: Tokenizer> = PatternReplaceFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        PatternReplaceFilterWrapper::new(
+            tokenizer,
+            self.pattern,
+            self.replacement,
+            self.replace_all,
+        )
+    }
+}
@@ -0,0 +1,60 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::borrow::Cow;
+
+use regex::Regex;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct PatternReplaceTokenStream<'a, T> {
+    tail: T,
+    pattern: &'a Regex,
+    replacement: &'a str,
+    replace_all: bool,
+}
+
+impl<'a, T> PatternReplaceTokenStream<'a, T> {
+    pub(crate) fn new(
+        tail: T,
+        pattern: &'a Regex,
+        replacement: &'a str,
+        replace_all: bool,
+    ) -> Self {
+        Self {
+            tail,
+            pattern,
+            replacement,
+            replace_all,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for PatternReplaceTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let text = &self.tail.token().text;
+        let replaced = if self.replace_all {
+            self.pattern.replace_all(text, self.replacement)
+        } else {
+            self.pattern.replace(text, self.replacement)
+        };
+        // Nothing is allocated when the pattern doesn't match.
+        if let Cow::Owned(replaced) = replaced {
+            self.tail.token_mut().text = replaced;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
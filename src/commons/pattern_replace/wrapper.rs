@@ -0,0 +1,40 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use regex::Regex;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::PatternReplaceTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct PatternReplaceFilterWrapper<T> {
+    pattern: Regex,
+    replacement: String,
+    replace_all: bool,
+    inner: T,
+}
+
+impl<T> PatternReplaceFilterWrapper<T> {
+    pub(crate) fn new(inner: T, pattern: Regex, replacement: String, replace_all: bool) -> Self {
+        Self {
+            pattern,
+            replacement,
+            replace_all,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for PatternReplaceFilterWrapper<T> {
+    type TokenStream<'a> = PatternReplaceTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        PatternReplaceTokenStream::new(
+            self.inner.token_stream(text),
+            &self.pattern,
+            &self.replacement,
+            self.replace_all,
+        )
+    }
+}
@@ -0,0 +1,99 @@
+pub use token_filter::PatternStopTokenFilter;
+use token_stream::PatternStopTokenStream;
+use wrapper::PatternStopFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, patterns: &[&str]) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(PatternStopTokenFilter::new(patterns).unwrap())
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_drop_dates() {
+        let result = token_stream_helper(
+            "released 2024-01-31 or 2024-1-31",
+            &[r"^\d{4}-\d{2}-\d{2}$"],
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "released".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 20,
+                offset_to: 22,
+                position: 2,
+                text: "or".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 23,
+                offset_to: 32,
+                position: 3,
+                text: "2024-1-31".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_drop_hashtags_and_dates() {
+        let result = token_stream_helper(
+            "#rust is great 2024-01-31 c#",
+            &[r"^\d{4}-\d{2}-\d{2}$", r"^#\w+$"],
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 6,
+                offset_to: 8,
+                position: 1,
+                text: "is".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 14,
+                position: 2,
+                text: "great".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 26,
+                offset_to: 28,
+                position: 4,
+                text: "c#".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let result = PatternStopTokenFilter::new([r"^\d{4"]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use regex::{Error, RegexSet};
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::PatternStopFilterWrapper;
+
+/// This [TokenFilter] removes tokens that match any of a list of
+/// [regular expressions](https://docs.rs/regex/latest/regex/#syntax). It's a
+/// stop filter driven by patterns instead of words.
+///
+/// Patterns are compiled once, when the filter is created. As with
+/// [Regex::is_match](regex::Regex::is_match), a pattern matches if it matches
+/// anywhere in the token, use `^` and `$` to match whole tokens.
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy_analysis_contrib::commons::PatternStopTokenFilter;
+///
+/// let filter = PatternStopTokenFilter::new([r"^\d{4}-\d{2}-\d{2}$", r"^#\w+$"])?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// # Example
+///
+/// In this example, dates and hashtags are removed.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::PatternStopTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(PatternStopTokenFilter::new([r"^\d{4}-\d{2}-\d{2}$", r"^#\w+$"])?)
+///    .build();
+/// let mut token_stream = tmp.token_stream("posted 2024-01-31 #rust");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "posted".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PatternStopTokenFilter {
+    patterns: RegexSet,
+}
+
+impl PatternStopTokenFilter {
+    /// Get a new token filter. It fails if a pattern is invalid.
+    /// # Parameters :
+    /// * patterns : tokens matching any of these patterns are removed
+    pub fn new<I, S>(patterns: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Ok(PatternStopTokenFilter {
+            patterns: RegexSet::new(patterns)?,
+        })
+    }
+}
+
+impl TokenFilter for PatternStopTokenFilter {
+    type Tokenizer<T: Tokenizer> = PatternStopFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        PatternStopFilterWrapper::new(tokenizer, self.patterns)
+    }
+}
@@ -0,0 +1,37 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use regex::RegexSet;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct PatternStopTokenStream<'a, T> {
+    tail: T,
+    patterns: &'a RegexSet,
+}
+
+impl<'a, T> PatternStopTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, patterns: &'a RegexSet) -> Self {
+        Self { tail, patterns }
+    }
+}
+
+impl<T: TokenStream> TokenStream for PatternStopTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            if !self.patterns.is_match(&self.tail.token().text) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
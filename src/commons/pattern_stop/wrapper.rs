@@ -0,0 +1,28 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use regex::RegexSet;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::PatternStopTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct PatternStopFilterWrapper<T> {
+    patterns: RegexSet,
+    inner: T,
+}
+
+impl<T> PatternStopFilterWrapper<T> {
+    pub(crate) fn new(inner: T, patterns: RegexSet) -> Self {
+        Self { patterns, inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for PatternStopFilterWrapper<T> {
+    type TokenStream<'a> = PatternStopTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        PatternStopTokenStream::new(self.inner.token_stream(text), &self.patterns)
+    }
+}
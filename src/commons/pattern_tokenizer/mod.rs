@@ -0,0 +1,157 @@
+use thiserror::Error;
+use token_stream::{Matches, PatternTokenStream};
+pub use tokenizer::PatternTokenizer;
+
+mod token_stream;
+mod tokenizer;
+
+/// Errors that can occur when building a [PatternTokenizer].
+#[derive(Debug, Error)]
+pub enum PatternTokenizerError {
+    /// The pattern is not a valid regular expression.
+    #[error("Invalid pattern: {0}")]
+    Regex(#[from] regex::Error),
+    /// The group is neither `-1` nor a capture group of the pattern.
+    #[error("Group {group} doesn't exist in the pattern")]
+    InvalidGroup {
+        /// The invalid group.
+        group: i32,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+    use super::*;
+
+    fn tokenize_all(text: &str, mut tokenizer: PatternTokenizer) -> Vec<Token> {
+        let mut result: Vec<Token> = Vec::new();
+
+        let mut tokenizer = tokenizer.token_stream(text);
+        while tokenizer.advance() {
+            result.push(tokenizer.token().clone());
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_split() {
+        let tokenizer = PatternTokenizer::new(r"\s+", -1).unwrap();
+
+        let result = tokenize_all("  été  à\u{3000}Noël ", tokenizer);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 2,
+                offset_to: 7,
+                position: 0,
+                text: "été".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 11,
+                position: 1,
+                text: "à".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 19,
+                position: 2,
+                text: "Noël".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_capture_whole_match() {
+        let tokenizer = PatternTokenizer::new(r"\w+", 0).unwrap();
+
+        let result = tokenize_all("l'été, 東京!", tokenizer);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 1,
+                position: 0,
+                text: "l".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 7,
+                position: 1,
+                text: "été".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 15,
+                position: 2,
+                text: "東京".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_capture_group() {
+        let tokenizer = PatternTokenizer::new(r"'([^']*)'", 1).unwrap();
+
+        let result = tokenize_all("'aaa' '' 'bbb'", tokenizer);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 1,
+                offset_to: 4,
+                position: 0,
+                text: "aaa".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 13,
+                position: 1,
+                text: "bbb".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_empty() {
+        let tokenizer = PatternTokenizer::new(r"\s+", -1).unwrap();
+
+        let result = tokenize_all("", tokenizer);
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let result = PatternTokenizer::new(r"^\d{4", -1);
+        assert!(matches!(result, Err(PatternTokenizerError::Regex(_))));
+    }
+
+    #[test]
+    fn test_invalid_group() {
+        let result = PatternTokenizer::new(r"(\w+)", 2);
+        assert!(matches!(
+            result,
+            Err(PatternTokenizerError::InvalidGroup { group: 2 })
+        ));
+
+        let result = PatternTokenizer::new(r"(\w+)", -2);
+        assert!(matches!(
+            result,
+            Err(PatternTokenizerError::InvalidGroup { group: -2 })
+        ));
+    }
+}
@@ -0,0 +1,68 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use regex::{CaptureMatches, Split};
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Debug)]
+pub(crate) enum Matches<'a> {
+    /// Text between matches.
+    Split(Split<'a, 'a>),
+    /// A capture group of each match.
+    Group(CaptureMatches<'a, 'a>, usize),
+}
+
+#[derive(Debug)]
+pub struct PatternTokenStream<'a> {
+    text: &'a str,
+    matches: Matches<'a>,
+    token: Token,
+}
+
+impl<'a> PatternTokenStream<'a> {
+    pub(crate) fn new(text: &'a str, matches: Matches<'a>) -> Self {
+        Self {
+            text,
+            matches,
+            token: Token::default(),
+        }
+    }
+
+    fn next_part(&mut self) -> Option<(usize, &'a str)> {
+        match &mut self.matches {
+            Matches::Split(split) => {
+                let part = split.find(|part| !part.is_empty())?;
+                // Parts are slices of the text, their offset is the distance between pointers.
+                let offset = part.as_ptr() as usize - self.text.as_ptr() as usize;
+                Some((offset, part))
+            }
+            Matches::Group(captures, group) => captures
+                .filter_map(|captures| captures.get(*group))
+                .find(|m| !m.is_empty())
+                .map(|m| (m.start(), m.as_str())),
+        }
+    }
+}
+
+impl TokenStream for PatternTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        let Some((offset, part)) = self.next_part() else {
+            return false;
+        };
+
+        self.token.text.clear();
+        self.token.text.push_str(part);
+        self.token.offset_from = offset;
+        self.token.offset_to = offset + part.len();
+        self.token.position = self.token.position.wrapping_add(1);
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
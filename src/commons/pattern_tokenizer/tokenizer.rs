@@ -0,0 +1,89 @@
+use regex::Regex;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{Matches, PatternTokenStream, PatternTokenizerError};
+
+/// A [Tokenizer] that uses a [regular expression](https://docs.rs/regex/latest/regex/#syntax)
+/// to produce tokens. It is an equivalent of
+/// [Lucene's PatternTokenizer](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/pattern/PatternTokenizer.html).
+///
+/// Depending on `group`, it either :
+/// * splits the text on matches, if `group` is `-1`. Text between matches are the tokens.
+/// * emits the given capture group of each match as tokens, if `group` is `0` or more.
+///   `0` is the whole match.
+///
+/// Empty tokens are never emitted. Offsets are in bytes.
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy_analysis_contrib::commons::PatternTokenizer;
+///
+/// // Split on commas
+/// let tokenizer = PatternTokenizer::new(r"\s*,\s*", -1)?;
+/// // Keep quoted values without their quotes
+/// let tokenizer = PatternTokenizer::new(r#""([^"]*)""#, 1)?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::PatternTokenizer;
+///
+/// let mut tmp = TextAnalyzer::builder(PatternTokenizer::new(r"\s*,\s*", -1)?).build();
+/// let mut token_stream = tmp.token_stream("red, green ,blue");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "red".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "green".to_string());
+/// assert_eq!(token.offset_from, 5);
+/// assert_eq!(token.offset_to, 10);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "blue".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PatternTokenizer {
+    pattern: Regex,
+    group: Option<usize>,
+}
+
+impl PatternTokenizer {
+    /// Get a new tokenizer. It fails if the pattern is invalid or if the group doesn't
+    /// exist in the pattern.
+    /// # Parameters :
+    /// * pattern : the regular expression
+    /// * group : `-1` to split on matches, otherwise the capture group to emit
+    pub fn new(pattern: &str, group: i32) -> Result<Self, PatternTokenizerError> {
+        let pattern = Regex::new(pattern)?;
+        let group = match group {
+            -1 => None,
+            _ => match usize::try_from(group) {
+                Ok(index) if index < pattern.captures_len() => Some(index),
+                _ => return Err(PatternTokenizerError::InvalidGroup { group }),
+            },
+        };
+
+        Ok(PatternTokenizer { pattern, group })
+    }
+}
+
+impl Tokenizer for PatternTokenizer {
+    type TokenStream<'a> = PatternTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let matches = match self.group {
+            None => Matches::Split(self.pattern.split(text)),
+            Some(group) => Matches::Group(self.pattern.captures_iter(text), group),
+        };
+        PatternTokenStream::new(text, matches)
+    }
+}
@@ -0,0 +1,123 @@
+pub use token_filter::PositionRangeTokenFilter;
+use token_stream::{PositionRangeTokenStream, ReplayTokenizer};
+use wrapper::PositionRangeFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{LowerCaser, TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::LengthTokenFilter;
+
+    fn token_stream_helper(text: &str, start: usize, end: usize) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(PositionRangeTokenFilter::new(start, end, LowerCaser))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_range_at_start() {
+        let result = token_stream_helper("ONE TWO THREE FOUR", 0, 2);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "one".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "two".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 13,
+                position: 2,
+                text: "three".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 18,
+                position: 3,
+                text: "FOUR".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_range_in_the_middle() {
+        let result = token_stream_helper("ONE TWO THREE FOUR", 1, 2);
+        let result: Vec<String> = result.into_iter().map(|t| t.text).collect();
+
+        assert_eq!(result, vec!["ONE", "two", "three", "FOUR"]);
+    }
+
+    #[test]
+    fn test_range_outside() {
+        let result = token_stream_helper("ONE TWO", 5, 10);
+        let result: Vec<String> = result.into_iter().map(|t| t.text).collect();
+
+        assert_eq!(result, vec!["ONE", "TWO"]);
+    }
+
+    #[test]
+    fn test_inner_filter_removes_tokens() {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(PositionRangeTokenFilter::new(
+                0,
+                1,
+                LengthTokenFilter::new(Some(3), None),
+            ))
+            .build();
+
+        let mut token_stream = a.token_stream("a bcd e fg");
+        let mut result = vec![];
+        while let Some(token) = token_stream.next() {
+            result.push((token.text.clone(), token.position));
+        }
+
+        let expected = vec![
+            ("bcd".to_string(), 1),
+            ("e".to_string(), 2),
+            ("fg".to_string(), 3),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_reuse_analyzer() {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(PositionRangeTokenFilter::new(0, 0, LowerCaser))
+            .build();
+
+        for _ in 0..2 {
+            let mut token_stream = a.token_stream("ONE TWO");
+            let mut result = vec![];
+            while let Some(token) = token_stream.next() {
+                result.push(token.text.clone());
+            }
+            assert_eq!(result, vec!["one", "TWO"]);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::PositionRangeFilterWrapper;
+
+/// This [TokenFilter] applies another token filter only on tokens whose position
+/// is within a range (inclusive). Tokens outside the range are left untouched.
+///
+/// For example, it allows processing the first tokens of a field (e.g. a title) differently
+/// from the rest of the field.
+///
+/// The token stream of the source is entirely consumed to find tokens that are within
+/// the range. These tokens are then fed to the inner filter.
+///
+/// # Example
+///
+/// Here only the first two tokens (position 0 and 1) are lowercased.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{LowerCaser, WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::PositionRangeTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(PositionRangeTokenFilter::new(0, 1, LowerCaser))
+///    .build();
+/// let mut token_stream = tmp.token_stream("The TITLE and THE BODY");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "the".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "title".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "and".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "THE".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "BODY".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PositionRangeTokenFilter<F> {
+    start: usize,
+    end: usize,
+    filter: F,
+}
+
+impl<F> PositionRangeTokenFilter<F> {
+    /// Create a new [PositionRangeTokenFilter].
+    ///
+    /// # Parameters :
+    /// * `start` : first position (inclusive) on which the filter is applied
+    /// * `end` : last position (inclusive) on which the filter is applied
+    /// * `filter` : the token filter to apply
+    pub fn new(start: usize, end: usize, filter: F) -> Self {
+        Self { start, end, filter }
+    }
+}
+
+impl<F: TokenFilter + Clone> TokenFilter for PositionRangeTokenFilter<F> {
+    type Tokenizer<T: Tokenizer> = PositionRangeFilterWrapper<T, F>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        PositionRangeFilterWrapper::new(token_stream, self.start, self.end, self.filter)
+    }
+}
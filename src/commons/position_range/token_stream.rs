@@ -0,0 +1,133 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::vec::IntoIter;
+
+use tantivy_tokenizer_api::{Token, TokenStream, Tokenizer};
+
+/// A [Tokenizer] that ignores the text and replays tokens
+/// that have been collected. It is the source of the inner filter.
+#[derive(Clone, Debug, Default)]
+pub struct ReplayTokenizer {
+    tokens: Vec<Token>,
+}
+
+impl ReplayTokenizer {
+    pub(crate) fn new(tokens: Vec<Token>) -> Self {
+        Self { tokens }
+    }
+}
+
+impl Tokenizer for ReplayTokenizer {
+    type TokenStream<'a> = ReplayTokenStream;
+
+    fn token_stream<'a>(&'a mut self, _text: &'a str) -> Self::TokenStream<'a> {
+        ReplayTokenStream {
+            tokens: std::mem::take(&mut self.tokens).into_iter(),
+            token: Token::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ReplayTokenStream {
+    tokens: IntoIter<Token>,
+    token: Token,
+}
+
+impl TokenStream for ReplayTokenStream {
+    fn advance(&mut self) -> bool {
+        match self.tokens.next() {
+            Some(token) => {
+                self.token = token;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+/// Where we are in the stream.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Stage {
+    /// Tokens before the range.
+    Before,
+    /// Tokens within the range, they come from the inner filter.
+    Range,
+    /// Tokens after the range.
+    After,
+}
+
+#[derive(Debug)]
+pub struct PositionRangeTokenStream<S> {
+    before: IntoIter<Token>,
+    range: S,
+    after: IntoIter<Token>,
+    token: Token,
+    stage: Stage,
+}
+
+impl<S> PositionRangeTokenStream<S> {
+    pub(crate) fn new(before: Vec<Token>, range: S, after: Vec<Token>) -> Self {
+        Self {
+            before: before.into_iter(),
+            range,
+            after: after.into_iter(),
+            token: Token::default(),
+            stage: Stage::Before,
+        }
+    }
+}
+
+impl<S: TokenStream> TokenStream for PositionRangeTokenStream<S> {
+    fn advance(&mut self) -> bool {
+        loop {
+            match self.stage {
+                Stage::Before => match self.before.next() {
+                    Some(token) => {
+                        self.token = token;
+                        return true;
+                    }
+                    None => self.stage = Stage::Range,
+                },
+                Stage::Range => {
+                    if self.range.advance() {
+                        return true;
+                    }
+                    self.stage = Stage::After;
+                }
+                Stage::After => {
+                    return match self.after.next() {
+                        Some(token) => {
+                            self.token = token;
+                            true
+                        }
+                        None => false,
+                    };
+                }
+            }
+        }
+    }
+
+    fn token(&self) -> &Token {
+        match self.stage {
+            Stage::Range => self.range.token(),
+            _ => &self.token,
+        }
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        match self.stage {
+            Stage::Range => self.range.token_mut(),
+            _ => &mut self.token,
+        }
+    }
+}
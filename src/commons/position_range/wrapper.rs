@@ -0,0 +1,73 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::fmt::{Debug, Formatter};
+
+use tantivy_tokenizer_api::{TokenFilter, TokenStream, Tokenizer};
+
+use super::{PositionRangeTokenStream, ReplayTokenizer};
+
+#[derive(Clone)]
+pub struct PositionRangeFilterWrapper<T, F: TokenFilter> {
+    start: usize,
+    end: usize,
+    filter: F,
+    inner: T,
+    // The inner filter applied on the tokens of the range. It is
+    // rebuilt for each token stream as the tokens are given to the
+    // replay tokenizer.
+    range: Option<F::Tokenizer<ReplayTokenizer>>,
+}
+
+impl<T: Debug, F: TokenFilter + Debug> Debug for PositionRangeFilterWrapper<T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PositionRangeFilterWrapper")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("filter", &self.filter)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T, F: TokenFilter> PositionRangeFilterWrapper<T, F> {
+    pub(crate) fn new(inner: T, start: usize, end: usize, filter: F) -> Self {
+        Self {
+            start,
+            end,
+            filter,
+            inner,
+            range: None,
+        }
+    }
+}
+
+impl<T: Tokenizer, F: TokenFilter + Clone> Tokenizer for PositionRangeFilterWrapper<T, F> {
+    type TokenStream<'a> =
+        PositionRangeTokenStream<<F::Tokenizer<ReplayTokenizer> as Tokenizer>::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let mut before = Vec::new();
+        let mut range = Vec::new();
+        let mut after = Vec::new();
+
+        let mut tail = self.inner.token_stream(text);
+        while tail.advance() {
+            let token = tail.token().clone();
+            if token.position < self.start {
+                before.push(token);
+            } else if token.position <= self.end {
+                range.push(token);
+            } else {
+                after.push(token);
+            }
+        }
+
+        let range_tokenizer = self
+            .range
+            .insert(self.filter.clone().transform(ReplayTokenizer::new(range)));
+
+        PositionRangeTokenStream::new(before, range_tokenizer.token_stream(text), after)
+    }
+}
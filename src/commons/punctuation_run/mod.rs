@@ -0,0 +1,87 @@
+pub use token_filter::{
+    PunctuationClass, PunctuationRunTokenFilter, PunctuationRunTokenFilterBuilder,
+};
+use token_stream::PunctuationRunTokenStream;
+use wrapper::PunctuationRunFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn texts(text: &str, filter: PunctuationRunTokenFilter) -> Vec<String> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.text.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_exclamation_runs() {
+        let result = texts("wow!!! great!", PunctuationRunTokenFilter::default());
+        assert_eq!(result, vec!["wow!", "great!"]);
+
+        let filter = PunctuationRunTokenFilterBuilder::default()
+            .max_run(2_usize)
+            .build()
+            .unwrap();
+        let result = texts("wow!!!! ¡¡¡olé!!", filter);
+        assert_eq!(result, vec!["wow!!", "¡¡olé!!"]);
+    }
+
+    #[test]
+    fn test_ellipsis() {
+        let result = texts(
+            "wait... what.. e.g. ……",
+            PunctuationRunTokenFilter::default(),
+        );
+        assert_eq!(result, vec!["wait.", "what.", "e.g.", "…"]);
+    }
+
+    #[test]
+    fn test_drop() {
+        let filter = PunctuationRunTokenFilterBuilder::default()
+            .drop(true)
+            .build()
+            .unwrap();
+        let result = texts("wow!!! ??? great! ok", filter);
+        // `???` becomes empty and is removed
+        assert_eq!(result, vec!["wow", "great!", "ok"]);
+    }
+
+    #[test]
+    fn test_mixed_punctuation_left_alone() {
+        let result = texts("what?! ?!?! -.-", PunctuationRunTokenFilter::default());
+        assert_eq!(result, vec!["what?!", "?!?!", "-.-"]);
+    }
+
+    #[test]
+    fn test_classes() {
+        let filter = PunctuationRunTokenFilterBuilder::default()
+            .classes(vec![PunctuationClass::Exclamation])
+            .build()
+            .unwrap();
+        let result = texts("wow!!! what??? so... --", filter);
+        assert_eq!(result, vec!["wow!", "what???", "so...", "--"]);
+
+        let filter = PunctuationRunTokenFilterBuilder::default()
+            .classes(vec![PunctuationClass::Dash, PunctuationClass::Other])
+            .build()
+            .unwrap();
+        let result = texts("a——b ## wow!!!", filter);
+        assert_eq!(result, vec!["a—b", "#", "wow!!!"]);
+    }
+}
@@ -0,0 +1,110 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::PunctuationRunFilterWrapper;
+
+/// Classes of punctuation whose runs are normalized by a [PunctuationRunTokenFilter].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PunctuationClass {
+    /// `!` and `¡`.
+    Exclamation,
+    /// `?` and `¿`.
+    Question,
+    /// `.` and `…`.
+    Period,
+    /// `-`, `‐`, `‒`, `–`, `—` and `―`.
+    Dash,
+    /// Any other ASCII punctuation, such as `#`, `*` or `~`.
+    Other,
+}
+
+impl PunctuationClass {
+    /// All the classes.
+    pub const ALL: [PunctuationClass; 5] = [
+        PunctuationClass::Exclamation,
+        PunctuationClass::Question,
+        PunctuationClass::Period,
+        PunctuationClass::Dash,
+        PunctuationClass::Other,
+    ];
+
+    pub(crate) fn contains(self, ch: char) -> bool {
+        match self {
+            PunctuationClass::Exclamation => matches!(ch, '!' | '¡'),
+            PunctuationClass::Question => matches!(ch, '?' | '¿'),
+            PunctuationClass::Period => matches!(ch, '.' | '…'),
+            PunctuationClass::Dash => matches!(ch, '-' | '‐' | '‒' | '–' | '—' | '―'),
+            PunctuationClass::Other => {
+                ch.is_ascii_punctuation() && !matches!(ch, '!' | '?' | '.' | '-')
+            }
+        }
+    }
+}
+
+/// A token filter that normalizes runs of the same punctuation character, such as
+/// expressive punctuation in titles or keywords. A run longer than `max_run` is
+/// shortened to `max_run` characters (`!!!` becomes `!`, `...` becomes `.`) or, if
+/// `drop` is set, removed entirely (`wow!!!` becomes `wow`). Tokens that are empty
+/// once runs are dropped are removed from the stream.
+///
+/// Only runs of a single character are normalized, mixed punctuation such as `?!` is
+/// left alone. Runs are normalized only for the enabled `classes`, all of them by
+/// default. Offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::PunctuationRunTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(PunctuationRunTokenFilter::default())
+///    .build();
+/// let mut token_stream = tmp.token_stream("Wow!!! What?! Well...");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Wow!".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "What?!".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Well.".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Builder)]
+#[builder(setter(into), default)]
+pub struct PunctuationRunTokenFilter {
+    /// Maximum length of a run, longer runs are shortened to this length.
+    #[builder(default = "1")]
+    pub max_run: usize,
+    /// Remove runs longer than `max_run` instead of shortening them.
+    #[builder(default = "false")]
+    pub drop: bool,
+    /// Classes of punctuation to normalize.
+    #[builder(default = "PunctuationClass::ALL.to_vec()")]
+    pub classes: Vec<PunctuationClass>,
+}
+
+impl Default for PunctuationRunTokenFilter {
+    /// Construct a [PunctuationRunTokenFilter] that shortens every punctuation run to
+    /// one character.
+    fn default() -> Self {
+        PunctuationRunTokenFilter {
+            max_run: 1,
+            drop: false,
+            classes: PunctuationClass::ALL.to_vec(),
+        }
+    }
+}
+
+impl TokenFilter for PunctuationRunTokenFilter {
+    type Tokenizer<T: Tokenizer> = PunctuationRunFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        PunctuationRunFilterWrapper::new(tokenizer, self)
+    }
+}
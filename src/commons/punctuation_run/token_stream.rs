@@ -0,0 +1,76 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::PunctuationRunTokenFilter;
+
+#[derive(Debug, Clone)]
+pub struct PunctuationRunTokenStream<'a, T> {
+    tail: T,
+    config: &'a PunctuationRunTokenFilter,
+}
+
+impl<'a, T> PunctuationRunTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, config: &'a PunctuationRunTokenFilter) -> Self {
+        Self { tail, config }
+    }
+
+    /// Whether runs of this character are normalized.
+    fn is_normalized(&self, ch: char) -> bool {
+        self.config.classes.iter().any(|class| class.contains(ch))
+    }
+
+    /// Normalized text, [None] if there is no run to normalize.
+    fn normalize(&self, text: &str) -> Option<String> {
+        let mut result: Option<String> = None;
+        let mut chars = text.char_indices().peekable();
+        while let Some((index, ch)) = chars.next() {
+            let mut run = 1;
+            while chars.next_if(|(_, next)| *next == ch).is_some() {
+                run += 1;
+            }
+
+            if run > self.config.max_run && self.is_normalized(ch) {
+                // Copy what was before the first run, then go on with the result
+                let result = result.get_or_insert_with(|| text[..index].to_string());
+                if !self.config.drop {
+                    for _ in 0..self.config.max_run {
+                        result.push(ch);
+                    }
+                }
+            } else if let Some(result) = result.as_mut() {
+                for _ in 0..run {
+                    result.push(ch);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: TokenStream> TokenStream for PunctuationRunTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            if let Some(text) = self.normalize(&self.tail.token().text) {
+                if text.is_empty() {
+                    // The token was only made of dropped runs
+                    continue;
+                }
+                self.tail.token_mut().text = text;
+            }
+            return true;
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
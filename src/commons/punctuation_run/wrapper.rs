@@ -0,0 +1,27 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{PunctuationRunTokenFilter, PunctuationRunTokenStream};
+
+#[derive(Clone, Debug)]
+pub struct PunctuationRunFilterWrapper<T> {
+    config: PunctuationRunTokenFilter,
+    inner: T,
+}
+
+impl<T> PunctuationRunFilterWrapper<T> {
+    pub(crate) fn new(inner: T, config: PunctuationRunTokenFilter) -> Self {
+        Self { config, inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for PunctuationRunFilterWrapper<T> {
+    type TokenStream<'a> = PunctuationRunTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        PunctuationRunTokenStream::new(self.inner.token_stream(text), &self.config)
+    }
+}
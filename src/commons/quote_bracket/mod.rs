@@ -0,0 +1,105 @@
+pub use token_filter::QuoteBracketTokenFilter;
+use token_stream::QuoteBracketTokenStream;
+use wrapper::QuoteBracketFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, drop: bool) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(QuoteBracketTokenFilter::new(drop))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn texts(tokens: Vec<Token>) -> Vec<String> {
+        tokens.into_iter().map(|token| token.text).collect()
+    }
+
+    #[test]
+    fn test_cjk() {
+        let result = token_stream_helper("「東京」 『大阪』 【新刊】 〔注〕 《書名》", false);
+        assert_eq!(
+            texts(result),
+            vec!["\"東京\"", "\"大阪\"", "[新刊]", "[注]", "<書名>"]
+        );
+    }
+
+    #[test]
+    fn test_typographic() {
+        let result = token_stream_helper("“double” ‘single’ „low“ «guillemets» l’été", false);
+        assert_eq!(
+            texts(result),
+            vec![
+                "\"double\"",
+                "'single'",
+                "\"low\"",
+                "\"guillemets\"",
+                "l'été"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fullwidth() {
+        let result = token_stream_helper("（１）［a］｛b｝＂c＂＇d＇", false);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 37,
+            position: 0,
+            text: "(１)[a]{b}\"c\"'d'".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_drop() {
+        let result = token_stream_helper("「東京」 “quoted” 【】 (x)", true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 12,
+                position: 0,
+                text: "東京".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 13,
+                offset_to: 25,
+                position: 1,
+                text: "quoted".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 33,
+                offset_to: 36,
+                position: 3,
+                text: "x".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_unchanged() {
+        let result = token_stream_helper("plain \"ascii\" (text)", false);
+        assert_eq!(texts(result), vec!["plain", "\"ascii\"", "(text)"]);
+    }
+}
@@ -0,0 +1,63 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::QuoteBracketFilterWrapper;
+
+/// This [TokenFilter] canonicalizes quotation marks and brackets.
+///
+/// Typographic quotes (`“”„‘’`), guillemets (`«»‹›`), CJK corner brackets (`「」『』`)
+/// and fullwidth forms are replaced by the ASCII `"` or `'`. Fullwidth, lenticular,
+/// tortoise shell and angle brackets (`（）［］【】〔〕《》`...) are replaced by their
+/// ASCII counterpart. Differently typed punctuation then matches, which is useful for
+/// keyword fields.
+///
+/// Instead of being replaced, quotation marks and brackets can be dropped. Tokens that
+/// are empty afterwards are removed.
+///
+/// Offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::QuoteBracketTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(QuoteBracketTokenFilter::new(false))
+///    .build();
+/// let mut token_stream = tmp.token_stream("「東京」 “quoted” （1）");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "\"東京\"".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "\"quoted\"".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "(1)".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct QuoteBracketTokenFilter {
+    drop: bool,
+}
+
+impl QuoteBracketTokenFilter {
+    /// Construct a new [QuoteBracketTokenFilter].
+    /// # Parameters :
+    /// * `drop`: remove quotation marks and brackets instead of replacing them
+    pub fn new(drop: bool) -> Self {
+        Self { drop }
+    }
+}
+
+impl TokenFilter for QuoteBracketTokenFilter {
+    type Tokenizer<T: Tokenizer> = QuoteBracketFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        QuoteBracketFilterWrapper::new(token_stream, self.drop)
+    }
+}
@@ -0,0 +1,82 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use crate::commons::map_chars::map_chars;
+
+/// Canonical ASCII form of quotation marks and brackets.
+fn canonical(ch: char) -> Option<char> {
+    match ch {
+        // Double quotes, guillemets and CJK corner brackets
+        '"' | '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{00AB}' | '\u{00BB}'
+        | '\u{301D}' | '\u{301E}' | '\u{301F}' | '\u{FF02}' | '\u{300C}' | '\u{300D}'
+        | '\u{300E}' | '\u{300F}' | '\u{FE41}' | '\u{FE42}' | '\u{FE43}' | '\u{FE44}'
+        | '\u{FF62}' | '\u{FF63}' => Some('"'),
+        // Single quotes and single guillemets
+        '\'' | '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2039}' | '\u{203A}'
+        | '\u{FF07}' => Some('\''),
+        // Parentheses
+        '(' | '\u{FF08}' | '\u{FF5F}' | '\u{2985}' => Some('('),
+        ')' | '\u{FF09}' | '\u{FF60}' | '\u{2986}' => Some(')'),
+        // Square and lenticular brackets
+        '[' | '\u{FF3B}' | '\u{3010}' | '\u{3014}' | '\u{3016}' | '\u{3018}' | '\u{301A}' => {
+            Some('[')
+        }
+        ']' | '\u{FF3D}' | '\u{3011}' | '\u{3015}' | '\u{3017}' | '\u{3019}' | '\u{301B}' => {
+            Some(']')
+        }
+        // Curly brackets
+        '{' | '\u{FF5B}' => Some('{'),
+        '}' | '\u{FF5D}' => Some('}'),
+        // Angle brackets
+        '<' | '\u{3008}' | '\u{300A}' | '\u{27E8}' | '\u{2329}' => Some('<'),
+        '>' | '\u{3009}' | '\u{300B}' | '\u{27E9}' | '\u{232A}' => Some('>'),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuoteBracketTokenStream<T> {
+    tail: T,
+    drop: bool,
+}
+
+impl<T> QuoteBracketTokenStream<T> {
+    pub(crate) fn new(tail: T, drop: bool) -> Self {
+        Self { tail, drop }
+    }
+}
+
+impl<T: TokenStream> TokenStream for QuoteBracketTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            let token = self.tail.token_mut();
+            let text = if self.drop {
+                map_chars(&token.text, |ch| canonical(ch).map(|_| ""))
+            } else {
+                map_chars(&token.text, |ch| {
+                    canonical(ch).filter(|&canonical| canonical != ch)
+                })
+            };
+            let Some(text) = text else {
+                return true;
+            };
+
+            token.text = text;
+            if !token.text.is_empty() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,27 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::QuoteBracketTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct QuoteBracketFilterWrapper<T> {
+    inner: T,
+    drop: bool,
+}
+
+impl<T> QuoteBracketFilterWrapper<T> {
+    pub(crate) fn new(inner: T, drop: bool) -> Self {
+        Self { inner, drop }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for QuoteBracketFilterWrapper<T> {
+    type TokenStream<'a> = QuoteBracketTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        QuoteBracketTokenStream::new(self.inner.token_stream(text), self.drop)
+    }
+}
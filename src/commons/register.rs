@@ -0,0 +1,144 @@
+//! Registration of default-configured components into a tantivy
+//! [TokenizerManager], so that analyzers can be chosen by name, for example from
+//! a configuration file.
+//!
+//! Tokenizers are registered as is, filters are registered on top of a
+//! [WhitespaceTokenizer]. The names are :
+//!
+//! | Name            | Analyzer                                                 |
+//! |-----------------|----------------------------------------------------------|
+//! | `path`          | [PathTokenizer] with default parameters                  |
+//! | `reverse_path`  | [PathTokenizer] with `reverse` set to `true`             |
+//! | `keyword`       | [KeywordTokenizer] without maximum length                |
+//! | `url_email`     | [UrlEmailTokenizer]                                      |
+//! | `reverse`       | [ReverseTokenFilter] without marker                      |
+//! | `edge_ngram`    | [EdgeNgramTokenFilter] of 1 to 2 characters              |
+//! | `ngram`         | [NGramTokenFilter] of 1 to 2 characters                  |
+//! | `ascii_folding` | [ASCIIFoldingTokenFilter] without the original token     |
+//! | `search_fold`   | [SearchFoldTokenFilter]                                  |
+//!
+//! ```rust
+//! use tantivy::tokenizer::TokenizerManager;
+//! use tantivy_analysis_contrib::commons::register;
+//!
+//! let manager = TokenizerManager::default();
+//! register::register_all(&manager);
+//!
+//! let mut analyzer = manager.get("path").expect("path should be registered");
+//! let mut token_stream = analyzer.token_stream("/a/b");
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "/a".to_string());
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "/a/b".to_string());
+//!
+//! assert_eq!(None, token_stream.next());
+//! ```
+//!
+//! [PathTokenizer]: crate::commons::PathTokenizer
+//! [KeywordTokenizer]: crate::commons::KeywordTokenizer
+//! [UrlEmailTokenizer]: crate::commons::UrlEmailTokenizer
+//! [ReverseTokenFilter]: crate::commons::ReverseTokenFilter
+//! [EdgeNgramTokenFilter]: crate::commons::EdgeNgramTokenFilter
+//! [NGramTokenFilter]: crate::commons::NGramTokenFilter
+//! [ASCIIFoldingTokenFilter]: crate::commons::ASCIIFoldingTokenFilter
+//! [SearchFoldTokenFilter]: crate::commons::SearchFoldTokenFilter
+
+use std::num::NonZeroUsize;
+
+use tantivy::tokenizer::{TextAnalyzer, TokenizerManager, WhitespaceTokenizer};
+
+use crate::commons::{
+    ASCIIFoldingTokenFilter, EdgeNgramTokenFilter, KeywordTokenizer, NGramTokenFilter,
+    PathTokenizer, ReverseTokenFilter, SearchFoldTokenFilter, UrlEmailTokenizer,
+};
+
+/// Names of the registered analyzers.
+pub const NAMES: [&str; 9] = [
+    "path",
+    "reverse_path",
+    "keyword",
+    "url_email",
+    "reverse",
+    "edge_ngram",
+    "ngram",
+    "ascii_folding",
+    "search_fold",
+];
+
+/// Register all [NAMES] into `manager`. An analyzer already registered with the
+/// same name is replaced.
+pub fn register_all(manager: &TokenizerManager) {
+    // Both are valid as 1 <= 2
+    let min = NonZeroUsize::MIN;
+    let max = NonZeroUsize::new(2);
+    let edge_ngram =
+        EdgeNgramTokenFilter::new(min, max, false).expect("Default edge ngram should be valid");
+    let ngram = NGramTokenFilter::new(min, max, false).expect("Default ngram should be valid");
+
+    manager.register("path", PathTokenizer::default());
+    manager.register(
+        "reverse_path",
+        PathTokenizer {
+            reverse: true,
+            ..Default::default()
+        },
+    );
+    manager.register("keyword", KeywordTokenizer::default());
+    manager.register("url_email", UrlEmailTokenizer);
+    manager.register("reverse", with_whitespace(ReverseTokenFilter::default()));
+    manager.register("edge_ngram", with_whitespace(edge_ngram));
+    manager.register("ngram", with_whitespace(ngram));
+    manager.register(
+        "ascii_folding",
+        with_whitespace(ASCIIFoldingTokenFilter::new(false)),
+    );
+    manager.register("search_fold", with_whitespace(SearchFoldTokenFilter));
+}
+
+fn with_whitespace<F: tantivy::tokenizer::TokenFilter>(filter: F) -> TextAnalyzer {
+    TextAnalyzer::builder(WhitespaceTokenizer::default())
+        .filter(filter)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::Token;
+
+    use super::*;
+
+    fn texts(manager: &TokenizerManager, name: &str, text: &str) -> Vec<String> {
+        let mut analyzer = manager.get(name).expect("Analyzer should be registered");
+        let mut token_stream = analyzer.token_stream(text);
+
+        let mut tokens = vec![];
+        token_stream.process(&mut |token: &Token| tokens.push(token.text.clone()));
+        tokens
+    }
+
+    #[test]
+    fn test_register_all() {
+        let manager = TokenizerManager::default();
+        register_all(&manager);
+
+        for name in NAMES {
+            assert!(manager.get(name).is_some(), "{name}");
+        }
+        // tantivy's analyzers are kept
+        assert!(manager.get("default").is_some());
+
+        assert_eq!(
+            texts(&manager, "reverse_path", "a/b/c"),
+            vec!["c", "b/c", "a/b/c"]
+        );
+        assert_eq!(
+            texts(&manager, "reverse", "day night"),
+            vec!["yad", "thgin"]
+        );
+        assert_eq!(texts(&manager, "edge_ngram", "abc"), vec!["a", "ab"]);
+        assert_eq!(texts(&manager, "ascii_folding", "Crème"), vec!["Creme"]);
+        assert_eq!(texts(&manager, "keyword", "a b"), vec!["a b"]);
+    }
+}
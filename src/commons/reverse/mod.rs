@@ -13,7 +13,7 @@ mod tests {
     use super::*;
 
     fn token_stream_helper_whitespace(text: &str) -> Vec<Token> {
-        let filter = ReverseTokenFilter;
+        let filter = ReverseTokenFilter::default();
         let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
             .filter(filter)
             .build();
@@ -29,8 +29,12 @@ mod tests {
     }
 
     fn token_stream_helper_raw(text: &str) -> Vec<Token> {
+        token_stream_helper_raw_with(text, ReverseTokenFilter::default())
+    }
+
+    fn token_stream_helper_raw_with(text: &str, filter: ReverseTokenFilter) -> Vec<Token> {
         let mut a = TextAnalyzer::builder(RawTokenizer::default())
-            .filter(ReverseTokenFilter)
+            .filter(filter)
             .build();
 
         let mut token_stream = a.token_stream(text);
@@ -165,4 +169,32 @@ mod tests {
         }];
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_marker() {
+        let filter = ReverseTokenFilter::with_marker('\u{0001}');
+        let result = token_stream_helper_raw_with("day", filter);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 3,
+            position: 0,
+            text: "\u{0001}yad".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+
+        let result = token_stream_helper_raw_with("𩬅abc", filter);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 7,
+            position: 0,
+            text: "\u{0001}cba𩬅".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+
+        // Without marker
+        let result = token_stream_helper_raw("𩬅abc");
+        assert_eq!(result[0].text, "cba𩬅".to_string());
+    }
 }
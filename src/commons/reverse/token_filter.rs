@@ -4,6 +4,11 @@ use super::ReverseFilterWrapper;
 
 /// This is a [TokenFilter] that reverse a string.
 ///
+/// Reversed tokens allow efficient "ends-with" searches. To avoid collisions between
+/// reversed tokens and terms that look reversed, a marker can be prepended to each
+/// reversed token with [with_marker](ReverseTokenFilter::with_marker), as Lucene does.
+/// Reversing is done on characters, so supplementary characters are kept whole.
+///
 /// # Example
 ///
 /// ```rust
@@ -12,7 +17,7 @@ use super::ReverseFilterWrapper;
 /// use tantivy_analysis_contrib::commons::ReverseTokenFilter;
 ///
 /// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
-///    .filter(ReverseTokenFilter)
+///    .filter(ReverseTokenFilter::default())
 ///    .build();
 /// let mut token_stream = tmp.token_stream("ReverseTokenFilter");
 ///
@@ -23,13 +28,44 @@ use super::ReverseFilterWrapper;
 /// #     Ok(())
 /// # }
 /// ```
-#[derive(Clone, Copy, Debug)]
-pub struct ReverseTokenFilter;
+///
+/// With a marker :
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::ReverseTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+///    .filter(ReverseTokenFilter::with_marker('\u{0001}'))
+///    .build();
+/// let mut token_stream = tmp.token_stream("day");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "\u{0001}yad".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReverseTokenFilter {
+    marker: Option<char>,
+}
+
+impl ReverseTokenFilter {
+    /// Construct a [ReverseTokenFilter] that prepends `marker` to each reversed token.
+    pub fn with_marker(marker: char) -> Self {
+        Self {
+            marker: Some(marker),
+        }
+    }
+}
 
 impl TokenFilter for ReverseTokenFilter {
     type Tokenizer<T: Tokenizer> = ReverseFilterWrapper<T>;
 
     fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
-        ReverseFilterWrapper::new(token_stream)
+        ReverseFilterWrapper::new(token_stream, self.marker)
     }
 }
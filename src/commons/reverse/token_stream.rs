@@ -1,8 +1,6 @@
 //! Module that contains the [TokenStream] implementation. It's this that
 //! do the real job.
 
-// TODO Allow marker ?
-
 use std::mem;
 
 use tantivy_tokenizer_api::{Token, TokenStream};
@@ -10,11 +8,12 @@ use tantivy_tokenizer_api::{Token, TokenStream};
 #[derive(Debug, Clone)]
 pub struct ReverseTokenStream<T> {
     tail: T,
+    marker: Option<char>,
 }
 
 impl<T> ReverseTokenStream<T> {
-    pub(crate) fn new(tail: T) -> Self {
-        Self { tail }
+    pub(crate) fn new(tail: T, marker: Option<char>) -> Self {
+        Self { tail, marker }
     }
 }
 
@@ -23,7 +22,8 @@ impl<T: TokenStream> TokenStream for ReverseTokenStream<T> {
         if !self.tail.advance() {
             return false;
         }
-        let mut buffer = self.tail.token().text.clone().chars().rev().collect();
+        let mut buffer: String = self.marker.into_iter().collect();
+        buffer.extend(self.tail.token().text.chars().rev());
         mem::swap(&mut self.tail.token_mut().text, &mut buffer);
 
         true
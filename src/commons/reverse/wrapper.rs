@@ -8,12 +8,13 @@ use super::ReverseTokenStream;
 
 #[derive(Clone, Debug)]
 pub struct ReverseFilterWrapper<T> {
+    marker: Option<char>,
     inner: T,
 }
 
 impl<T> ReverseFilterWrapper<T> {
-    pub(crate) fn new(inner: T) -> Self {
-        Self { inner }
+    pub(crate) fn new(inner: T, marker: Option<char>) -> Self {
+        Self { marker, inner }
     }
 }
 
@@ -21,6 +22,6 @@ impl<T: Tokenizer> Tokenizer for ReverseFilterWrapper<T> {
     type TokenStream<'a> = ReverseTokenStream<T::TokenStream<'a>>;
 
     fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
-        ReverseTokenStream::new(self.inner.token_stream(text))
+        ReverseTokenStream::new(self.inner.token_stream(text), self.marker)
     }
 }
@@ -0,0 +1,126 @@
+pub use token_filter::ScriptReverseTokenFilter;
+use token_stream::ScriptReverseTokenStream;
+use wrapper::ScriptReverseFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, filter: ScriptReverseTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_cjk() {
+        let result = token_stream_helper(
+            "中华人民 hello ひらがな 한국어 𩬅艱 tantivy",
+            ScriptReverseTokenFilter::cjk(),
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 12,
+                position: 0,
+                text: "民人华中".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 13,
+                offset_to: 18,
+                position: 1,
+                text: "hello".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 19,
+                offset_to: 31,
+                position: 2,
+                text: "ながらひ".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 32,
+                offset_to: 41,
+                position: 3,
+                text: "어국한".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 42,
+                offset_to: 49,
+                position: 4,
+                text: "艱𩬅".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 50,
+                offset_to: 57,
+                position: 5,
+                text: "tantivy".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mixed_token() {
+        let result = token_stream_helper("中国abc 123", ScriptReverseTokenFilter::cjk());
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "中国abc".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 13,
+                position: 1,
+                text: "123".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_custom_ranges() {
+        let result = token_stream_helper("abc ABC", ScriptReverseTokenFilter::new(vec!['a'..='z']));
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "cba".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "ABC".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
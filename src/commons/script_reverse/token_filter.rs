@@ -0,0 +1,92 @@
+use std::ops::RangeInclusive;
+
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::ScriptReverseFilterWrapper;
+
+/// Ranges of CJK characters : Han (with radicals and extensions), Bopomofo, Hiragana,
+/// Katakana (with halfwidth forms) and Hangul.
+const CJK: [RangeInclusive<char>; 18] = [
+    '\u{1100}'..='\u{11FF}',
+    '\u{2E80}'..='\u{2FDF}',
+    '\u{3005}'..='\u{3007}',
+    '\u{3021}'..='\u{3029}',
+    '\u{3038}'..='\u{303B}',
+    '\u{3040}'..='\u{309F}',
+    '\u{30A0}'..='\u{30FF}',
+    '\u{3100}'..='\u{312F}',
+    '\u{3130}'..='\u{318F}',
+    '\u{31F0}'..='\u{31FF}',
+    '\u{3400}'..='\u{4DBF}',
+    '\u{4E00}'..='\u{9FFF}',
+    '\u{A960}'..='\u{A97F}',
+    '\u{AC00}'..='\u{D7AF}',
+    '\u{F900}'..='\u{FAFF}',
+    '\u{FF66}'..='\u{FF9F}',
+    '\u{20000}'..='\u{2FA1F}',
+    '\u{30000}'..='\u{3134F}',
+];
+
+/// This is a [TokenFilter] that reverses tokens whose characters all belong to a script,
+/// other tokens are left unchanged. It's a targeted version of
+/// [ReverseTokenFilter](crate::commons::ReverseTokenFilter).
+///
+/// A script is given as ranges of characters. [cjk](ScriptReverseTokenFilter::cjk) provides
+/// the ranges of Chinese, Japanese and Korean characters.
+/// ```rust
+/// use tantivy_analysis_contrib::commons::ScriptReverseTokenFilter;
+///
+/// let filter = ScriptReverseTokenFilter::new(vec!['\u{0590}'..='\u{05FF}']);
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::ScriptReverseTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(ScriptReverseTokenFilter::cjk())
+///    .build();
+/// let mut token_stream = tmp.token_stream("中国人 Tantivy");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "人国中".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Tantivy".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScriptReverseTokenFilter {
+    ranges: Vec<RangeInclusive<char>>,
+}
+
+impl ScriptReverseTokenFilter {
+    /// Get a new token filter.
+    /// # Parameters :
+    /// * ranges : ranges of characters (inclusive) of the script. A token is reversed
+    ///   if all its characters are within one of these ranges.
+    pub fn new(ranges: impl IntoIterator<Item = RangeInclusive<char>>) -> Self {
+        ScriptReverseTokenFilter {
+            ranges: ranges.into_iter().collect(),
+        }
+    }
+
+    /// Get a new token filter that reverses CJK tokens.
+    pub fn cjk() -> Self {
+        Self::new(CJK)
+    }
+}
+
+impl TokenFilter for ScriptReverseTokenFilter {
+    type Tokenizer<T: Tokenizer> = ScriptReverseFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        ScriptReverseFilterWrapper::new(token_stream, self.ranges)
+    }
+}
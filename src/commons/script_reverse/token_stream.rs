@@ -0,0 +1,45 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::ops::RangeInclusive;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Debug, Clone)]
+pub struct ScriptReverseTokenStream<'a, T> {
+    tail: T,
+    ranges: &'a [RangeInclusive<char>],
+}
+
+impl<'a, T> ScriptReverseTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, ranges: &'a [RangeInclusive<char>]) -> Self {
+        Self { tail, ranges }
+    }
+}
+
+impl<T: TokenStream> TokenStream for ScriptReverseTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let text = &self.tail.token().text;
+        if text
+            .chars()
+            .all(|c| self.ranges.iter().any(|range| range.contains(&c)))
+        {
+            let reversed = text.chars().rev().collect();
+            self.tail.token_mut().text = reversed;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
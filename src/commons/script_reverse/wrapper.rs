@@ -0,0 +1,29 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::ops::RangeInclusive;
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::ScriptReverseTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct ScriptReverseFilterWrapper<T> {
+    ranges: Vec<RangeInclusive<char>>,
+    inner: T,
+}
+
+impl<T> ScriptReverseFilterWrapper<T> {
+    pub(crate) fn new(inner: T, ranges: Vec<RangeInclusive<char>>) -> Self {
+        Self { ranges, inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for ScriptReverseFilterWrapper<T> {
+    type TokenStream<'a> = ScriptReverseTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        ScriptReverseTokenStream::new(self.inner.token_stream(text), &self.ranges)
+    }
+}
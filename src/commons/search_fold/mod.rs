@@ -0,0 +1,103 @@
+pub use token_filter::SearchFoldTokenFilter;
+use token_stream::SearchFoldTokenStream;
+use wrapper::SearchFoldFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{LowerCaser, TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::ASCIIFoldingTokenFilter;
+
+    fn collect(a: &mut TextAnalyzer, text: &str) -> Vec<Token> {
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(SearchFoldTokenFilter)
+            .build();
+        collect(&mut a, text)
+    }
+
+    #[test]
+    fn test_fold() {
+        let result = token_stream_helper("Ｒéｓｕｍé Æsir");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 16,
+                position: 0,
+                text: "resume".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 17,
+                offset_to: 22,
+                position: 1,
+                text: "aesir".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_same_as_chain() {
+        let mut chain = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(ASCIIFoldingTokenFilter::new(false))
+            .filter(LowerCaser)
+            .build();
+        let mut single = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(SearchFoldTokenFilter)
+            .build();
+
+        for text in [
+            "Crème Brûlée",
+            "ＡＢＣ １２３ ｘｙｚ",
+            "ǅemal Œuvre ﬁnal ẞ",
+            "ΑΘΗΝΑ ΣΟΦΟΣ Москва",
+            "İstanbul ǈ Ⓐ ⑴",
+            "already lowercase ascii",
+            "東京 한국어 ١٢٣",
+            "",
+        ] {
+            assert_eq!(
+                collect(&mut single, text),
+                collect(&mut chain, text),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fold_marks() {
+        let result = token_stream_helper("Cafe\u{301} Ἀθῆναι Ёлка ﾃｽﾄ 한국");
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+        assert_eq!(texts, vec!["cafe", "αθηναι", "елка", "テスト", "한국"]);
+    }
+
+    #[test]
+    fn test_not_folded() {
+        let result = token_stream_helper("abc");
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 3,
+            position: 0,
+            text: "abc".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,54 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::SearchFoldFilterWrapper;
+
+/// This [TokenFilter] folds width, accents and case of tokens in a single pass, the
+/// common "make it searchable" normalization.
+///
+/// Characters known by the [ASCIIFoldingTokenFilter](crate::commons::ASCIIFoldingTokenFilter)
+/// are replaced by their ASCII equivalent (ligatures are expanded). Other characters go
+/// through a compatibility decomposition (NFKD) which folds width and compatibility
+/// forms, their non-spacing marks (`\p{Mn}`) are removed, then everything is lowercased
+/// and recomposed (NFC). That way decomposed accents (`e\u{301}`), Greek or Cyrillic
+/// accents and half-width forms are folded too. On text the
+/// [ASCIIFoldingTokenFilter](crate::commons::ASCIIFoldingTokenFilter) fully handles, the
+/// output is the same as that filter followed by tantivy's `LowerCaser`, but each token
+/// is read only once.
+///
+/// Offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::SearchFoldTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(SearchFoldTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("Ｃａｆé ÉTÉ Straße");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "cafe".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ete".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "strasse".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SearchFoldTokenFilter;
+
+impl TokenFilter for SearchFoldTokenFilter {
+    type Tokenizer<T: Tokenizer> = SearchFoldFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        SearchFoldFilterWrapper::new(token_stream)
+    }
+}
@@ -0,0 +1,90 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::char::decompose_compatible;
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+use crate::commons::ascii_folding::fold;
+
+/// Fold one non ASCII character into `result`, lowercased. Characters known by the
+/// ASCII folding are replaced by their ASCII equivalent, others are decomposed
+/// (compatibility decomposition) and lose their non-spacing marks.
+fn fold_char(c: char, result: &mut String) {
+    if let Some(folded) = fold(c) {
+        // Folded characters are ASCII
+        result.extend(folded.chars().map(|f| f.to_ascii_lowercase()));
+        return;
+    }
+
+    decompose_compatible(c, |d| {
+        if d.is_ascii() {
+            result.push(d.to_ascii_lowercase());
+        } else if let Some(folded) = fold(d) {
+            result.extend(folded.chars().map(|f| f.to_ascii_lowercase()));
+        } else if get_general_category(d) != GeneralCategory::NonspacingMark {
+            result.extend(d.to_lowercase());
+        }
+    });
+}
+
+/// Fold width, accents and case of a text in one pass. It returns [None] if there is
+/// nothing to fold.
+fn search_fold(text: &str) -> Option<String> {
+    if !text
+        .bytes()
+        .any(|b| !b.is_ascii() || b.is_ascii_uppercase())
+    {
+        return None;
+    }
+
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() {
+            result.push(c.to_ascii_lowercase());
+        } else {
+            fold_char(c, &mut result);
+        }
+    }
+
+    // Recompose what the decomposition split and kept (Hangul syllables, spacing marks)
+    if !result.is_ascii() && is_nfc_quick(result.chars()) != IsNormalized::Yes {
+        result = result.nfc().collect();
+    }
+
+    (result != text).then_some(result)
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchFoldTokenStream<T> {
+    tail: T,
+}
+
+impl<T> SearchFoldTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self { tail }
+    }
+}
+
+impl<T: TokenStream> TokenStream for SearchFoldTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        if let Some(folded) = search_fold(&self.tail.token().text) {
+            self.tail.token_mut().text = folded;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
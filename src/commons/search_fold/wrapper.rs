@@ -0,0 +1,26 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::SearchFoldTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct SearchFoldFilterWrapper<T> {
+    inner: T,
+}
+
+impl<T> SearchFoldFilterWrapper<T> {
+    pub(crate) fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for SearchFoldFilterWrapper<T> {
+    type TokenStream<'a> = SearchFoldTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        SearchFoldTokenStream::new(self.inner.token_stream(text))
+    }
+}
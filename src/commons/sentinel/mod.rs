@@ -0,0 +1,111 @@
+pub use token_filter::SentinelTokenFilter;
+use token_stream::SentinelTokenStream;
+use wrapper::SentinelFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use tantivy::tokenizer::{TextAnalyzer, Token, TokenFilter, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::{NGramTokenFilter, ReverseTokenFilter};
+
+    fn token_stream_helper<F: TokenFilter>(
+        text: &str,
+        filter: SentinelTokenFilter<F>,
+    ) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn texts(tokens: &[Token]) -> Vec<&str> {
+        tokens.iter().map(|token| token.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sentinel_disables_ngram() {
+        let ngram = NGramTokenFilter::new(NonZeroUsize::new(2).unwrap(), None, false).unwrap();
+        let filter = SentinelTokenFilter::new("__no_ngram__").around(ngram);
+        let result = token_stream_helper("abc __no_ngram__ xyz", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "ab".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "bc".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "abc".to_string(),
+                position_length: 1,
+            },
+            // The sentinel is removed and leaves a gap
+            Token {
+                offset_from: 17,
+                offset_to: 20,
+                position: 2,
+                text: "xyz".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sentinel_toggles() {
+        let filter = SentinelTokenFilter::new("|").around(ReverseTokenFilter::default());
+        let result = token_stream_helper("ab | cd ef | gh", filter);
+        assert_eq!(texts(&result), vec!["ba", "cd", "ef", "hg"]);
+    }
+
+    #[test]
+    fn test_bypass() {
+        let filter = SentinelTokenFilter::new("|")
+            .around(ReverseTokenFilter::default())
+            .with_bypass(true);
+        let result = token_stream_helper("ab | cd", filter);
+        assert_eq!(texts(&result), vec!["ab", "dc"]);
+    }
+
+    #[test]
+    fn test_state_is_reset() {
+        let filter = SentinelTokenFilter::new("|").around(ReverseTokenFilter::default());
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream("| ab");
+        let token = token_stream.next().expect("A token should be present.");
+        assert_eq!(token.text, "ab".to_string());
+        drop(token_stream);
+
+        let mut token_stream = a.token_stream("ab");
+        let token = token_stream.next().expect("A token should be present.");
+        assert_eq!(token.text, "ba".to_string());
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::SentinelFilterWrapper;
+use crate::commons::single::SingleTokenTokenizer;
+
+/// A token filter that switches another token filter on and off when it meets a sentinel
+/// token. It allows to control the analysis of a document from its text, for example to
+/// disable n-gramming of the body once a marker injected upstream (`__no_ngram__`) is seen.
+///
+/// # Sentinel contract
+///
+/// * The sentinel is compared with the whole token text, byte for byte, so filters that
+///   run before this one (lowercasing, folding, ...) must leave it unchanged, and the
+///   tokenizer must emit it as a single token.
+/// * The sentinel token itself is removed. Like a stop word, it leaves a gap in positions.
+/// * The wrapped filter is applied until the first sentinel. Each sentinel toggles it :
+///   tokens after the first sentinel are emitted unchanged, tokens after the second one go
+///   through the wrapped filter again, and so on. Use
+///   [with_bypass](SentinelTokenFilter::with_bypass) to start with the wrapped filter
+///   switched off.
+/// * The state only lasts for one token stream, each document starts again in the
+///   initial state.
+///
+/// Like [KeywordMarkerTokenFilter](crate::commons::KeywordMarkerTokenFilter), the wrapped
+/// filter is applied on each token on its own, it can't see the previous or next tokens.
+/// Tokens produced by the wrapped filter keep the offsets of the token they come from.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::{ReverseTokenFilter, SentinelTokenFilter};
+///
+/// let filter = SentinelTokenFilter::new("__raw__").around(ReverseTokenFilter::default());
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("abc __raw__ def");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "cba".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "def".to_string());
+/// assert_eq!(token.position, 2);
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SentinelTokenFilter<F = ()> {
+    sentinel: Arc<str>,
+    bypass: bool,
+    filter: F,
+}
+
+impl SentinelTokenFilter {
+    /// Toggle a token filter on each `sentinel` token. Use
+    /// [around](SentinelTokenFilter::around) to provide the filter to toggle.
+    pub fn new(sentinel: impl Into<String>) -> Self {
+        Self {
+            sentinel: Arc::from(sentinel.into()),
+            bypass: false,
+            filter: (),
+        }
+    }
+}
+
+impl<F> SentinelTokenFilter<F> {
+    /// Set the token filter that sentinels switch on and off.
+    pub fn around<G: TokenFilter>(self, filter: G) -> SentinelTokenFilter<G> {
+        SentinelTokenFilter {
+            sentinel: self.sentinel,
+            bypass: self.bypass,
+            filter,
+        }
+    }
+
+    /// If `true`, tokens are emitted unchanged until the first sentinel, which
+    /// switches the wrapped filter on.
+    ///
+    /// Default is `false`.
+    pub fn with_bypass(mut self, bypass: bool) -> Self {
+        self.bypass = bypass;
+        self
+    }
+}
+
+impl<F: TokenFilter> TokenFilter for SentinelTokenFilter<F> {
+    type Tokenizer<T: Tokenizer> = SentinelFilterWrapper<T, F::Tokenizer<SingleTokenTokenizer>>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        SentinelFilterWrapper::new(
+            tokenizer,
+            self.sentinel,
+            self.bypass,
+            self.filter.transform(SingleTokenTokenizer),
+        )
+    }
+}
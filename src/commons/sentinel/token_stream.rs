@@ -0,0 +1,72 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use tantivy_tokenizer_api::{Token, TokenStream, Tokenizer};
+
+use crate::commons::single::filter_token;
+
+#[derive(Debug)]
+pub struct SentinelTokenStream<'a, T, F> {
+    tail: T,
+    sentinel: &'a str,
+    /// Whether tokens currently bypass the filter.
+    bypass: bool,
+    /// Toggled filter, applied on a single token.
+    filter: &'a mut F,
+    /// Tokens produced by the toggled filter that are not emitted yet.
+    buffer: VecDeque<Token>,
+    token: Token,
+}
+
+impl<'a, T, F> SentinelTokenStream<'a, T, F> {
+    pub(crate) fn new(tail: T, sentinel: &'a str, bypass: bool, filter: &'a mut F) -> Self {
+        Self {
+            tail,
+            sentinel,
+            bypass,
+            filter,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+}
+
+impl<T: TokenStream, F: Tokenizer> TokenStream for SentinelTokenStream<'_, T, F> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.buffer.pop_front() {
+                self.token = token;
+                return true;
+            }
+
+            if !self.tail.advance() {
+                return false;
+            }
+
+            let token = self.tail.token();
+            if token.text == self.sentinel {
+                self.bypass = !self.bypass;
+                continue;
+            }
+
+            if self.bypass {
+                self.token.clone_from(token);
+                return true;
+            }
+
+            filter_token(self.filter, token, |filtered| {
+                self.buffer.push_back(filtered)
+            });
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
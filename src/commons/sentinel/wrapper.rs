@@ -0,0 +1,41 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::SentinelTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct SentinelFilterWrapper<T, F> {
+    sentinel: Arc<str>,
+    bypass: bool,
+    filter: F,
+    inner: T,
+}
+
+impl<T, F> SentinelFilterWrapper<T, F> {
+    pub(crate) fn new(inner: T, sentinel: Arc<str>, bypass: bool, filter: F) -> Self {
+        Self {
+            sentinel,
+            bypass,
+            filter,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer, F: Tokenizer> Tokenizer for SentinelFilterWrapper<T, F> {
+    type TokenStream<'a> = SentinelTokenStream<'a, T::TokenStream<'a>, F>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        SentinelTokenStream::new(
+            self.inner.token_stream(text),
+            &self.sentinel,
+            self.bypass,
+            &mut self.filter,
+        )
+    }
+}
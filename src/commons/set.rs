@@ -0,0 +1,86 @@
+//! Helpers around [fst::Set].
+
+use fst::Set;
+use thiserror::Error;
+
+/// Errors that can occur when building a [Set].
+#[derive(Debug, Error)]
+pub enum SetError {
+    /// The underlying FST can't be built.
+    #[error("Can't build FST set: {0}")]
+    Build(#[from] fst::Error),
+}
+
+/// Build a [Set] from keys in any order.
+///
+/// Building a [Set] with [Set::from_iter] requires keys to be lexicographically
+/// sorted and without duplicates, otherwise it fails. This function sorts and
+/// deduplicates the keys before building the set.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy_analysis_contrib::commons::build_set;
+///
+/// let set = build_set(vec!["the", "a", "of", "the"])?;
+///
+/// assert_eq!(set.len(), 3);
+/// assert!(set.contains("of"));
+/// #     Ok(())
+/// # }
+/// ```
+pub fn build_set<I, K>(keys: I) -> Result<Set<Vec<u8>>, SetError>
+where
+    I: IntoIterator<Item = K>,
+    K: AsRef<[u8]>,
+{
+    let mut keys: Vec<Vec<u8>> = keys.into_iter().map(|k| k.as_ref().to_vec()).collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    Ok(Set::from_iter(keys)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsorted() -> Result<(), SetError> {
+        let set = build_set(vec!["c", "a", "b"])?;
+
+        let keys = set.stream().into_strs()?;
+        assert_eq!(keys, vec!["a", "b", "c"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicates() -> Result<(), SetError> {
+        let set = build_set(vec!["the", "a", "the", "of", "a"])?;
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains("the"));
+        assert!(set.contains("a"));
+        assert!(set.contains("of"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty() -> Result<(), SetError> {
+        let set = build_set(Vec::<String>::new())?;
+
+        assert!(set.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsorted_without_helper() {
+        let result = Set::from_iter(vec!["c", "a", "b"]);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,309 @@
+use thiserror::Error;
+pub use token_filter::ShingleTokenFilter;
+use token_stream::ShingleTokenStream;
+use wrapper::ShingleFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+/// Shingle errors
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Error)]
+pub enum ShingleError {
+    /// Error raised when the minimum shingle size is lower than 2.
+    #[error("Minimum '{min}' must be greater or equals to 2")]
+    MinimumTooLow {
+        /// Minimum shingle size.
+        min: usize,
+    },
+    /// Error raised when the maximum shingle size is strictly lower than the minimum.
+    #[error("Maximum '{max}' must be greater or equals to minimum '{min}'")]
+    MaximumLowerThanMinimum {
+        /// Minimum shingle size.
+        min: usize,
+        /// Maximum shingle size.
+        max: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{StopWordFilter, TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, filter: ShingleTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(StopWordFilter::remove(vec!["the".to_string()]))
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(
+            ShingleTokenFilter::new(1, 2, true),
+            Err(ShingleError::MinimumTooLow { min: 1 })
+        );
+        assert_eq!(
+            ShingleTokenFilter::new(3, 2, true),
+            Err(ShingleError::MaximumLowerThanMinimum { min: 3, max: 2 })
+        );
+    }
+
+    #[test]
+    fn test_bigrams_with_unigrams() {
+        let result = token_stream_helper(
+            "please divide this sentence",
+            ShingleTokenFilter::new(2, 2, true).unwrap(),
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "please".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 13,
+                position: 0,
+                text: "please divide".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 13,
+                position: 1,
+                text: "divide".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 18,
+                position: 1,
+                text: "divide this".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 18,
+                position: 2,
+                text: "this".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 27,
+                position: 2,
+                text: "this sentence".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 19,
+                offset_to: 27,
+                position: 3,
+                text: "sentence".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bigrams_trigrams() {
+        let result = token_stream_helper(
+            "please divide this sentence",
+            ShingleTokenFilter::new(2, 3, false).unwrap(),
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 13,
+                position: 0,
+                text: "please divide".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 18,
+                position: 0,
+                text: "please divide this".to_string(),
+                position_length: 3,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 18,
+                position: 1,
+                text: "divide this".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 27,
+                position: 1,
+                text: "divide this sentence".to_string(),
+                position_length: 3,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 27,
+                position: 2,
+                text: "this sentence".to_string(),
+                position_length: 2,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_trigrams() {
+        let result = token_stream_helper(
+            "please divide this sentence",
+            ShingleTokenFilter::new(3, 3, false).unwrap(),
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 18,
+                position: 0,
+                text: "please divide this".to_string(),
+                position_length: 3,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 27,
+                position: 1,
+                text: "divide this sentence".to_string(),
+                position_length: 3,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shorter_than_min() {
+        let result = token_stream_helper(
+            "please divide",
+            ShingleTokenFilter::new(3, 4, false).unwrap(),
+        );
+        let expected: Vec<Token> = vec![];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_shorter_than_min_with_unigrams() {
+        let result = token_stream_helper(
+            "please divide",
+            ShingleTokenFilter::new(3, 4, true).unwrap(),
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "please".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 13,
+                position: 1,
+                text: "divide".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_empty() {
+        let result = token_stream_helper("", ShingleTokenFilter::new(2, 2, true).unwrap());
+        let expected: Vec<Token> = vec![];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_filler() {
+        let result = token_stream_helper(
+            "please divide the sentence",
+            ShingleTokenFilter::new(2, 2, false).unwrap(),
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 13,
+                position: 0,
+                text: "please divide".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 18,
+                position: 1,
+                text: "divide _".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 13,
+                offset_to: 26,
+                position: 2,
+                text: "_ sentence".to_string(),
+                position_length: 2,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_only_fillers() {
+        let result = token_stream_helper(
+            "please the the sentence",
+            ShingleTokenFilter::new(2, 2, true)
+                .unwrap()
+                .filler_token("*")
+                .token_separator("+"),
+        );
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "please".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 15,
+                position: 0,
+                text: "please+*".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 23,
+                position: 2,
+                text: "*+sentence".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 15,
+                offset_to: 23,
+                position: 3,
+                text: "sentence".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
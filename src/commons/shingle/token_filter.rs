@@ -0,0 +1,124 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::{ShingleError, ShingleFilterWrapper};
+
+/// Default separator between tokens of a shingle.
+const DEFAULT_TOKEN_SEPARATOR: &str = " ";
+/// Default token used for missing positions.
+const DEFAULT_FILLER_TOKEN: &str = "_";
+
+/// Token filter that produces shingles, that is n-grams of tokens. It is an equivalent of
+/// [Lucene's ShingleFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/shingle/ShingleFilter.html).
+/// For example, with a minimum and a maximum of 2, `please divide this` will generate `please`,
+/// `please divide`, `divide`, `divide this` and `this`.
+///
+/// A shingle is at the position of its first token, its `position_length` is the number of
+/// positions it covers and its offsets go from the start of its first token to the end of its
+/// last token. Tokens are emitted in position order, the unigram first, then shingles from the
+/// shortest to the longest.
+///
+/// If positions are missing (e.g. a previous filter removed stop words), the
+/// [filler token](ShingleTokenFilter::filler_token) is used for each missing position. Shingles
+/// made only of filler tokens are not emitted.
+///
+/// It is configured with :
+/// * min shingle size: the minimum number of tokens of a shingle. It must be at least 2.
+/// * max shingle size: the maximum number of tokens of a shingle. It must be greater or equals to
+///   min shingle size.
+/// * output unigrams: emit input tokens as well.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::ShingleTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(ShingleTokenFilter::new(2, 2, false)?.token_separator("_"))
+///    .build();
+/// let mut token_stream = tmp.token_stream("please divide this");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "please_divide".to_string());
+/// assert_eq!(token.position_length, 2);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "divide_this".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ShingleTokenFilter {
+    min_shingle_size: usize,
+    max_shingle_size: usize,
+    output_unigrams: bool,
+    token_separator: String,
+    filler_token: String,
+}
+
+impl ShingleTokenFilter {
+    /// Create a new `ShingleTokenFilter`. Token separator is a space and filler token
+    /// is `_`.
+    ///
+    /// # Parameters
+    ///
+    /// * `min_shingle_size` : minimum number of tokens of a shingle, it must be at least 2.
+    /// * `max_shingle_size` : maximum number of tokens of a shingle. It must be greater or
+    ///   equals to `min_shingle_size`.
+    /// * `output_unigrams`: also emit input tokens.
+    pub fn new(
+        min_shingle_size: usize,
+        max_shingle_size: usize,
+        output_unigrams: bool,
+    ) -> Result<Self, ShingleError> {
+        if min_shingle_size < 2 {
+            return Err(ShingleError::MinimumTooLow {
+                min: min_shingle_size,
+            });
+        }
+        if max_shingle_size < min_shingle_size {
+            return Err(ShingleError::MaximumLowerThanMinimum {
+                min: min_shingle_size,
+                max: max_shingle_size,
+            });
+        }
+
+        Ok(ShingleTokenFilter {
+            min_shingle_size,
+            max_shingle_size,
+            output_unigrams,
+            token_separator: DEFAULT_TOKEN_SEPARATOR.to_string(),
+            filler_token: DEFAULT_FILLER_TOKEN.to_string(),
+        })
+    }
+
+    /// Set the string used to join tokens of a shingle. Default is a space.
+    pub fn token_separator(mut self, token_separator: impl Into<String>) -> Self {
+        self.token_separator = token_separator.into();
+        self
+    }
+
+    /// Set the token used for missing positions. Default is `_`.
+    pub fn filler_token(mut self, filler_token: impl Into<String>) -> Self {
+        self.filler_token = filler_token.into();
+        self
+    }
+}
+
+impl TokenFilter for ShingleTokenFilter {
+    type Tokenizer<T: Tokenizer> = ShingleFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        ShingleFilterWrapper::new(
+            tokenizer,
+            self.min_shingle_size,
+            self.max_shingle_size,
+            self.output_unigrams,
+            self.token_separator,
+            self.filler_token,
+        )
+    }
+}
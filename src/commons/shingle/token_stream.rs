@@ -0,0 +1,135 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+/// A position of the window.
+#[derive(Clone, Debug)]
+struct Entry {
+    token: Token,
+    filler: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct ShingleTokenStream<'a, T> {
+    tail: T,
+    min_shingle_size: usize,
+    max_shingle_size: usize,
+    output_unigrams: bool,
+    token_separator: &'a str,
+    filler_token: &'a str,
+    /// Consecutive positions, starting at the position of the next shingles.
+    window: VecDeque<Entry>,
+    /// Is the tail exhausted?
+    exhausted: bool,
+    /// Tokens to emit
+    buffer: VecDeque<Token>,
+    token: Token,
+}
+
+impl<'a, T> ShingleTokenStream<'a, T> {
+    pub(crate) fn new(
+        tail: T,
+        min_shingle_size: usize,
+        max_shingle_size: usize,
+        output_unigrams: bool,
+        token_separator: &'a str,
+        filler_token: &'a str,
+    ) -> Self {
+        Self {
+            tail,
+            min_shingle_size,
+            max_shingle_size,
+            output_unigrams,
+            token_separator,
+            filler_token,
+            window: VecDeque::with_capacity(max_shingle_size),
+            exhausted: false,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+
+    /// Emit the unigram and the shingles that start at the first position of the window.
+    fn shingles(&mut self) {
+        let Some(first) = self.window.front() else {
+            return;
+        };
+        if self.output_unigrams && !first.filler {
+            self.buffer.push_back(first.token.clone());
+        }
+
+        for size in self.min_shingle_size..=self.max_shingle_size.min(self.window.len()) {
+            let entries = self.window.range(..size);
+            if entries.clone().all(|entry| entry.filler) {
+                continue;
+            }
+            let last = &self.window[size - 1].token;
+            let text = entries
+                .map(|entry| entry.token.text.as_str())
+                .collect::<Vec<&str>>()
+                .join(self.token_separator);
+            self.buffer.push_back(Token {
+                offset_from: first.token.offset_from,
+                offset_to: last.offset_to,
+                position: first.token.position,
+                text,
+                position_length: size,
+            });
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for ShingleTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.buffer.pop_front() {
+                self.token = token;
+                return true;
+            }
+
+            while !self.exhausted && self.window.len() < self.max_shingle_size {
+                if !self.tail.advance() {
+                    self.exhausted = true;
+                    break;
+                }
+                let token = self.tail.token();
+                // Fill missing positions
+                if let Some(previous) = self.window.back().map(|entry| entry.token.clone()) {
+                    for position in previous.position + 1..token.position {
+                        self.window.push_back(Entry {
+                            token: Token {
+                                offset_from: previous.offset_to,
+                                offset_to: token.offset_from,
+                                position,
+                                text: self.filler_token.to_string(),
+                                position_length: 1,
+                            },
+                            filler: true,
+                        });
+                    }
+                }
+                self.window.push_back(Entry {
+                    token: token.clone(),
+                    filler: false,
+                });
+            }
+
+            if self.window.is_empty() {
+                return false;
+            }
+            self.shingles();
+            self.window.pop_front();
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -0,0 +1,52 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::ShingleTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct ShingleFilterWrapper<T> {
+    min_shingle_size: usize,
+    max_shingle_size: usize,
+    output_unigrams: bool,
+    token_separator: String,
+    filler_token: String,
+    inner: T,
+}
+
+impl<T> ShingleFilterWrapper<T> {
+    pub(crate) fn new(
+        inner: T,
+        min_shingle_size: usize,
+        max_shingle_size: usize,
+        output_unigrams: bool,
+        token_separator: String,
+        filler_token: String,
+    ) -> Self {
+        Self {
+            min_shingle_size,
+            max_shingle_size,
+            output_unigrams,
+            token_separator,
+            filler_token,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for ShingleFilterWrapper<T> {
+    type TokenStream<'a> = ShingleTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        ShingleTokenStream::new(
+            self.inner.token_stream(text),
+            self.min_shingle_size,
+            self.max_shingle_size,
+            self.output_unigrams,
+            &self.token_separator,
+            &self.filler_token,
+        )
+    }
+}
@@ -0,0 +1,67 @@
+//! Module that contains a [Tokenizer] that outputs its whole text as a
+//! single token. It is used to apply a wrapped filter one token at a time.
+
+use tantivy_tokenizer_api::{Token, TokenStream, Tokenizer};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SingleTokenTokenizer;
+
+impl Tokenizer for SingleTokenTokenizer {
+    type TokenStream<'a> = SingleTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        SingleTokenStream {
+            token: Token {
+                offset_from: 0,
+                offset_to: text.len(),
+                position: 0,
+                text: text.to_string(),
+                position_length: 1,
+            },
+            done: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SingleTokenStream {
+    token: Token,
+    done: bool,
+}
+
+impl TokenStream for SingleTokenStream {
+    fn advance(&mut self) -> bool {
+        let result = !self.done;
+        self.done = true;
+        result
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+/// Apply `filter`, built on a [SingleTokenTokenizer], to `token` and give each produced
+/// token to `emit`. Produced tokens keep the offsets and the `position_length` of
+/// `token`, their position is relative to the position of `token`.
+pub(crate) fn filter_token<F: Tokenizer>(
+    filter: &mut F,
+    token: &Token,
+    mut emit: impl FnMut(Token),
+) {
+    let mut stream = filter.token_stream(&token.text);
+    while stream.advance() {
+        let filtered = stream.token();
+        emit(Token {
+            offset_from: token.offset_from,
+            offset_to: token.offset_to,
+            position: token.position + filtered.position,
+            text: filtered.text.clone(),
+            position_length: token.position_length,
+        });
+    }
+}
@@ -0,0 +1,145 @@
+pub use token_filter::SocialTokenFilter;
+use token_stream::SocialTokenStream;
+use wrapper::SocialFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, split_hashtags: bool) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(SocialTokenFilter::new(split_hashtags))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_camel_case_hashtag() {
+        let result = token_stream_helper("#BlackLivesMatter now", true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 1,
+                offset_to: 17,
+                position: 0,
+                text: "BlackLivesMatter".to_string(),
+                position_length: 3,
+            },
+            Token {
+                offset_from: 1,
+                offset_to: 6,
+                position: 0,
+                text: "Black".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 11,
+                position: 1,
+                text: "Lives".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 11,
+                offset_to: 17,
+                position: 2,
+                text: "Matter".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 18,
+                offset_to: 21,
+                position: 3,
+                text: "now".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_hashtag_not_split() {
+        let result = token_stream_helper("#BlackLivesMatter", false);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 1,
+            offset_to: 17,
+            position: 0,
+            text: "BlackLivesMatter".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_acronym_digits_and_underscore() {
+        let result = token_stream_helper("#HTMLParser2024 #rust_lang", true);
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "HTMLParser2024",
+                "HTML",
+                "Parser",
+                "2024",
+                "rust_lang",
+                "rust",
+                "lang"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mention() {
+        let result = token_stream_helper("@JaneDoe", true);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 1,
+            offset_to: 8,
+            position: 0,
+            text: "JaneDoe".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_plain_word() {
+        let result = token_stream_helper("CamelCase # @", true);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "CamelCase".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 11,
+                position: 1,
+                text: "#".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 13,
+                position: 2,
+                text: "@".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,69 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::SocialFilterWrapper;
+
+/// This [TokenFilter] normalizes hashtags and mentions of social media texts.
+///
+/// The `#` or `@` at the beginning of tokens is removed, so `#rust` matches `rust`.
+/// Hashtags can also be split into words on case changes and between letters and
+/// digits : `#BlackLivesMatter` gives `BlackLivesMatter`, then `Black`, `Lives`
+/// and `Matter`. The whole tag is kept, at the position of its first word and spanning
+/// all of them, so phrase queries on the words still match. Mentions are never split.
+///
+/// Case is preserved, use a lowercase filter after this one to lowercase words.
+/// Offsets are adjusted, unless the token's text doesn't match its offsets anymore
+/// (because a previous filter changed it).
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{LowerCaser, WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::SocialTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(SocialTokenFilter::new(true))
+///    .filter(LowerCaser)
+///    .build();
+/// let mut token_stream = tmp.token_stream("@jane #BlackLivesMatter");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "jane".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "blacklivesmatter".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "black".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "lives".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "matter".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SocialTokenFilter {
+    split_hashtags: bool,
+}
+
+impl SocialTokenFilter {
+    /// Construct a new [SocialTokenFilter].
+    /// # Parameters :
+    /// * `split_hashtags`: also emit the words of hashtags
+    pub fn new(split_hashtags: bool) -> Self {
+        Self { split_hashtags }
+    }
+}
+
+impl TokenFilter for SocialTokenFilter {
+    type Tokenizer<T: Tokenizer> = SocialFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        SocialFilterWrapper::new(token_stream, self.split_hashtags)
+    }
+}
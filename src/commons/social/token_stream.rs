@@ -0,0 +1,143 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+const HASHTAG: char = '#';
+const MENTION: char = '@';
+
+/// Split a hashtag into words, as byte ranges. A word ends on a lower case letter
+/// followed by an upper case one, between letters and digits, before the last upper
+/// case letter of an acronym followed by a lower case one (`HTMLParser` gives `HTML`
+/// and `Parser`) and on any other character, which is dropped.
+fn split_words(text: &str) -> Vec<(usize, usize)> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start: Option<usize> = None;
+    for (i, &(index, c)) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if let Some(from) = start.take() {
+                words.push((from, index));
+            }
+            continue;
+        }
+        if let Some(from) = start {
+            let (_, previous) = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|(_, n)| n.is_lowercase());
+            let is_break = (previous.is_lowercase() && c.is_uppercase())
+                || (previous.is_alphabetic() && c.is_numeric())
+                || (previous.is_numeric() && c.is_alphabetic())
+                || (previous.is_uppercase() && c.is_uppercase() && next_is_lower);
+            if is_break {
+                words.push((from, index));
+                start = Some(index);
+            }
+        } else {
+            start = Some(index);
+        }
+    }
+    if let Some(from) = start {
+        words.push((from, text.len()));
+    }
+
+    words
+}
+
+#[derive(Debug, Clone)]
+pub struct SocialTokenStream<T> {
+    tail: T,
+    split_hashtags: bool,
+    /// Positions taken by words of previous hashtags.
+    position_shift: usize,
+    buffer: VecDeque<Token>,
+    token: Token,
+}
+
+impl<T> SocialTokenStream<T> {
+    pub(crate) fn new(tail: T, split_hashtags: bool) -> Self {
+        Self {
+            tail,
+            split_hashtags,
+            position_shift: 0,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for SocialTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if let Some(token) = self.buffer.pop_front() {
+            self.token = token;
+            return true;
+        }
+
+        if !self.tail.advance() {
+            return false;
+        }
+
+        self.token.clone_from(self.tail.token());
+        self.token.position += self.position_shift;
+
+        let Some(marker) = self
+            .token
+            .text
+            .chars()
+            .next()
+            .filter(|c| *c == HASHTAG || *c == MENTION)
+        else {
+            return true;
+        };
+        let marker_len = marker.len_utf8();
+        if self.token.text.len() == marker_len {
+            return true;
+        }
+
+        // Offsets can only be adjusted if they still match the text
+        let adjust =
+            self.token.offset_to.checked_sub(self.token.offset_from) == Some(self.token.text.len());
+        self.token.text.drain(..marker_len);
+        if adjust {
+            self.token.offset_from += marker_len;
+        }
+
+        if marker != HASHTAG || !self.split_hashtags {
+            return true;
+        }
+
+        let words = split_words(&self.token.text);
+        if words.len() < 2 {
+            return true;
+        }
+
+        // The whole tag spans the positions of its words
+        self.token.position_length = words.len();
+        for (i, (from, to)) in words.iter().copied().enumerate() {
+            let (offset_from, offset_to) = if adjust {
+                (self.token.offset_from + from, self.token.offset_from + to)
+            } else {
+                (self.token.offset_from, self.token.offset_to)
+            };
+            self.buffer.push_back(Token {
+                offset_from,
+                offset_to,
+                position: self.token.position + i,
+                text: self.token.text[from..to].to_string(),
+                position_length: 1,
+            });
+        }
+        self.position_shift += words.len() - 1;
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
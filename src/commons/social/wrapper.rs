@@ -0,0 +1,30 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::SocialTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct SocialFilterWrapper<T> {
+    inner: T,
+    split_hashtags: bool,
+}
+
+impl<T> SocialFilterWrapper<T> {
+    pub(crate) fn new(inner: T, split_hashtags: bool) -> Self {
+        Self {
+            inner,
+            split_hashtags,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for SocialFilterWrapper<T> {
+    type TokenStream<'a> = SocialTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        SocialTokenStream::new(self.inner.token_stream(text), self.split_hashtags)
+    }
+}
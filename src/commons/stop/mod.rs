@@ -0,0 +1,202 @@
+pub use token_filter::StopTokenFilter;
+use token_stream::StopTokenStream;
+use wrapper::StopFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::build_set;
+
+    const ENGLISH_STOP_WORDS: [&str; 8] = ["a", "an", "and", "are", "as", "of", "the", "to"];
+
+    fn token_stream_helper(text: &str, filter: StopTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_case_sensitive() {
+        let filter = StopTokenFilter::from_iter_str(ENGLISH_STOP_WORDS, false).unwrap();
+        let result = token_stream_helper("The cat AND the hat of A king", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "The".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "cat".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 11,
+                position: 2,
+                text: "AND".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 19,
+                position: 4,
+                text: "hat".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 23,
+                offset_to: 24,
+                position: 6,
+                text: "A".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 25,
+                offset_to: 29,
+                position: 7,
+                text: "king".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ignore_case() {
+        let filter = StopTokenFilter::from_iter_str(ENGLISH_STOP_WORDS, true).unwrap();
+        let result = token_stream_helper("The cat AND the hat of A king", filter);
+        let expected = vec![
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "cat".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 19,
+                position: 4,
+                text: "hat".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 25,
+                offset_to: 29,
+                position: 7,
+                text: "king".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ignore_case_uppercase_stop_words() {
+        let filter = StopTokenFilter::from_iter_str(vec!["THE", "And"], true).unwrap();
+        let result = token_stream_helper("The cat AND the hat of A king", filter);
+        let expected = vec![
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "cat".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 19,
+                position: 4,
+                text: "hat".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 20,
+                offset_to: 22,
+                position: 5,
+                text: "of".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 23,
+                offset_to: 24,
+                position: 6,
+                text: "A".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 25,
+                offset_to: 29,
+                position: 7,
+                text: "king".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_set() {
+        let set = build_set(vec!["The", "of"]).unwrap();
+        let filter = StopTokenFilter::from_set(set, true).unwrap();
+        let result = token_stream_helper("The cat AND the hat of A king", filter);
+        let expected = vec![
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "cat".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 8,
+                offset_to: 11,
+                position: 2,
+                text: "AND".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 19,
+                position: 4,
+                text: "hat".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 23,
+                offset_to: 24,
+                position: 6,
+                text: "A".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 25,
+                offset_to: 29,
+                position: 7,
+                text: "king".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
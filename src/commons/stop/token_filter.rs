@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use fst::Set;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::StopFilterWrapper;
+use crate::commons::{build_set, SetError};
+
+/// A token filter that removes stop words. It is an equivalent of
+/// [Lucene's StopFilter](https://lucene.apache.org/core/9_0_0/core/org/apache/lucene/analysis/StopFilter.html).
+///
+/// Stop words are stored in a [Set], so large lists stay compact in memory.
+///
+/// When stop words are case-insensitive, they are lowercased when the filter is built and
+/// tokens are lowercased only to check if they are stop words : emitted tokens keep their
+/// text.
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy_analysis_contrib::commons::StopTokenFilter;
+///
+/// let filter = StopTokenFilter::from_iter_str(vec!["a", "an", "and", "of", "the"], true)?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::StopTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(StopTokenFilter::from_iter_str(vec!["a", "of", "the"], true)?)
+///    .build();
+/// let mut token_stream = tmp.token_stream("The Lord of the Rings");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Lord".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Rings".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct StopTokenFilter {
+    stop_words: Arc<Set<Vec<u8>>>,
+    ignore_case: bool,
+}
+
+impl StopTokenFilter {
+    /// Construct a new [StopTokenFilter] from a [Set].
+    /// # Parameters :
+    /// * `stop_words`: set of words to remove
+    /// * `ignore_case`: indicate that stop words are case-insensitive. If `true`, the
+    ///   set is rebuilt with lowercased keys.
+    pub fn from_set(stop_words: Set<Vec<u8>>, ignore_case: bool) -> Result<Self, SetError> {
+        let stop_words = if ignore_case {
+            let keys = stop_words.stream().into_strs()?;
+            build_set(keys.iter().map(|v| v.to_lowercase()))?
+        } else {
+            stop_words
+        };
+        Ok(Self {
+            stop_words: Arc::new(stop_words),
+            ignore_case,
+        })
+    }
+
+    /// Construct a new [StopTokenFilter] from an iterator over [String] and a [bool].
+    /// # Parameters :
+    /// * `stop_words`: list of words to remove, in any order
+    /// * `ignore_case`: indicate that stop words are case-insensitive
+    pub fn from_iter_string(
+        stop_words: impl IntoIterator<Item = String>,
+        ignore_case: bool,
+    ) -> Result<Self, SetError> {
+        let stop_words =
+            build_set(
+                stop_words
+                    .into_iter()
+                    .map(|v| if ignore_case { v.to_lowercase() } else { v }),
+            )?;
+        Ok(Self {
+            stop_words: Arc::new(stop_words),
+            ignore_case,
+        })
+    }
+
+    /// Construct a new [StopTokenFilter] from an iterator over [str] and a [bool].
+    /// # Parameters :
+    /// * `stop_words`: list of words to remove, in any order
+    /// * `ignore_case`: indicate that stop words are case-insensitive
+    pub fn from_iter_str<'a>(
+        stop_words: impl IntoIterator<Item = &'a str>,
+        ignore_case: bool,
+    ) -> Result<Self, SetError> {
+        Self::from_iter_string(stop_words.into_iter().map(str::to_string), ignore_case)
+    }
+}
+
+impl TokenFilter for StopTokenFilter {
+    type Tokenizer<T: Tokenizer> = StopFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        StopFilterWrapper::new(token_stream, self.stop_words, self.ignore_case)
+    }
+}
@@ -0,0 +1,48 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use fst::Set;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct StopTokenStream<'a, T> {
+    tail: T,
+    stop_words: &'a Set<Vec<u8>>,
+    ignore_case: bool,
+}
+
+impl<'a, T> StopTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, stop_words: &'a Set<Vec<u8>>, ignore_case: bool) -> Self {
+        Self {
+            tail,
+            stop_words,
+            ignore_case,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for StopTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            let text = &self.tail.token().text;
+            let is_stop_word = if self.ignore_case {
+                self.stop_words.contains(text.to_lowercase())
+            } else {
+                self.stop_words.contains(text)
+            };
+            if !is_stop_word {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,39 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use fst::Set;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::StopTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct StopFilterWrapper<T> {
+    stop_words: Arc<Set<Vec<u8>>>,
+    ignore_case: bool,
+    inner: T,
+}
+
+impl<T> StopFilterWrapper<T> {
+    pub(crate) fn new(inner: T, stop_words: Arc<Set<Vec<u8>>>, ignore_case: bool) -> Self {
+        Self {
+            stop_words,
+            ignore_case,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for StopFilterWrapper<T> {
+    type TokenStream<'a> = StopTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        StopTokenStream::new(
+            self.inner.token_stream(text),
+            &self.stop_words,
+            self.ignore_case,
+        )
+    }
+}
@@ -0,0 +1,112 @@
+pub use token_filter::SuperscriptDigitTokenFilter;
+use token_stream::SuperscriptDigitTokenStream;
+use wrapper::SuperscriptDigitFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, enclosed: bool) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(SuperscriptDigitTokenFilter::new(enclosed))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_subscript() {
+        let result = token_stream_helper("H₂O C₆H₁₂O₆", false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "H2O".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 21,
+                position: 1,
+                text: "C6H12O6".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_superscript() {
+        let result = token_stream_helper("x² 10⁻³ ⁰¹²³⁴⁵⁶⁷⁸⁹", false);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "x2".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 11,
+                position: 1,
+                text: "10⁻3".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 39,
+                position: 2,
+                text: "0123456789".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_circled() {
+        let result = token_stream_helper("① ⑫ ⑴ ⒈ ❿ ㉑ ⓪", true);
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+
+        assert_eq!(texts, vec!["1", "12", "1", "1", "10", "21", "0"]);
+    }
+
+    #[test]
+    fn test_circled_not_enabled() {
+        let result = token_stream_helper("①²", false);
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 5,
+            position: 0,
+            text: "①2".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_unchanged() {
+        let result = token_stream_helper("abc 123", true);
+        let texts: Vec<String> = result.into_iter().map(|token| token.text).collect();
+
+        assert_eq!(texts, vec!["abc", "123"]);
+    }
+}
@@ -0,0 +1,59 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::SuperscriptDigitFilterWrapper;
+
+/// This [TokenFilter] replaces superscript and subscript digits by ASCII digits, so
+/// `H₂O` becomes `H2O` and `x²` becomes `x2`.
+///
+/// It can also replace circled, parenthesized and full stop numbers (`①`, `⑴`,
+/// `⒈`, `❶`, `㉑`...) by their value in ASCII digits. As some of them are greater
+/// than 9, `⑫` becomes `12`.
+///
+/// Offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::SuperscriptDigitTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(SuperscriptDigitTokenFilter::new(true))
+///    .build();
+/// let mut token_stream = tmp.token_stream("H₂O x² ⑫");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "H2O".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "x2".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "12".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SuperscriptDigitTokenFilter {
+    enclosed: bool,
+}
+
+impl SuperscriptDigitTokenFilter {
+    /// Construct a new [SuperscriptDigitTokenFilter].
+    /// # Parameters :
+    /// * `enclosed`: also replace circled, parenthesized and full stop numbers
+    pub fn new(enclosed: bool) -> Self {
+        Self { enclosed }
+    }
+}
+
+impl TokenFilter for SuperscriptDigitTokenFilter {
+    type Tokenizer<T: Tokenizer> = SuperscriptDigitFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        SuperscriptDigitFilterWrapper::new(token_stream, self.enclosed)
+    }
+}
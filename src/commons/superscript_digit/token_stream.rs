@@ -0,0 +1,62 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use crate::commons::enclosed_alphanumeric::enclosed_number;
+use crate::commons::map_chars::map_chars;
+
+#[derive(Debug, Clone)]
+pub struct SuperscriptDigitTokenStream<T> {
+    tail: T,
+    enclosed: bool,
+}
+
+impl<T> SuperscriptDigitTokenStream<T> {
+    pub(crate) fn new(tail: T, enclosed: bool) -> Self {
+        Self { tail, enclosed }
+    }
+}
+
+/// ASCII digit of a superscript or subscript digit.
+fn script_digit(ch: char) -> Option<&'static str> {
+    match ch {
+        '\u{2070}' | '\u{2080}' => Some("0"),
+        '\u{00B9}' | '\u{2081}' => Some("1"),
+        '\u{00B2}' | '\u{2082}' => Some("2"),
+        '\u{00B3}' | '\u{2083}' => Some("3"),
+        '\u{2074}' | '\u{2084}' => Some("4"),
+        '\u{2075}' | '\u{2085}' => Some("5"),
+        '\u{2076}' | '\u{2086}' => Some("6"),
+        '\u{2077}' | '\u{2087}' => Some("7"),
+        '\u{2078}' | '\u{2088}' => Some("8"),
+        '\u{2079}' | '\u{2089}' => Some("9"),
+        _ => None,
+    }
+}
+
+impl<T: TokenStream> TokenStream for SuperscriptDigitTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let enclosed = self.enclosed;
+        let text = map_chars(&self.tail.token().text, |ch| {
+            script_digit(ch).or_else(|| if enclosed { enclosed_number(ch) } else { None })
+        });
+        if let Some(text) = text {
+            self.tail.token_mut().text = text;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
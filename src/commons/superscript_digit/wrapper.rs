@@ -0,0 +1,27 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::SuperscriptDigitTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct SuperscriptDigitFilterWrapper<T> {
+    inner: T,
+    enclosed: bool,
+}
+
+impl<T> SuperscriptDigitFilterWrapper<T> {
+    pub(crate) fn new(inner: T, enclosed: bool) -> Self {
+        Self { inner, enclosed }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for SuperscriptDigitFilterWrapper<T> {
+    type TokenStream<'a> = SuperscriptDigitTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        SuperscriptDigitTokenStream::new(self.inner.token_stream(text), self.enclosed)
+    }
+}
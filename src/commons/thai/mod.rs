@@ -0,0 +1,75 @@
+pub use token_filter::ThaiNormalizationTokenFilter;
+use token_stream::ThaiNormalizationTokenStream;
+use wrapper::ThaiNormalizationFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(ThaiNormalizationTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn texts(tokens: Vec<Token>) -> Vec<String> {
+        tokens.into_iter().map(|token| token.text).collect()
+    }
+
+    #[test]
+    fn test_sara_am() {
+        // ทำ : tho thahan + sara am
+        let result = token_stream_helper("\u{0E17}\u{0E33}");
+        let expected: Vec<Token> = vec![Token {
+            offset_from: 0,
+            offset_to: 6,
+            position: 0,
+            text: "\u{0E17}\u{0E4D}\u{0E32}".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sara_am_with_tone_mark() {
+        // น้ำ typed as : tone then sara am, sara am then tone, decomposed in both orders
+        let result = token_stream_helper(
+            "\u{0E19}\u{0E49}\u{0E33} \u{0E19}\u{0E33}\u{0E49} \u{0E19}\u{0E4D}\u{0E49}\u{0E32} \u{0E19}\u{0E49}\u{0E4D}\u{0E32}",
+        );
+        assert_eq!(texts(result), vec!["\u{0E19}\u{0E4D}\u{0E49}\u{0E32}"; 4]);
+    }
+
+    #[test]
+    fn test_vowel_tone_reordering() {
+        // กิ่ : vowel above then tone mark is the canonical order
+        let result = token_stream_helper("\u{0E01}\u{0E48}\u{0E34} \u{0E01}\u{0E34}\u{0E48}");
+        assert_eq!(texts(result), vec!["\u{0E01}\u{0E34}\u{0E48}"; 2]);
+    }
+
+    #[test]
+    fn test_repeated_marks() {
+        let result = token_stream_helper("\u{0E01}\u{0E48}\u{0E48}\u{0E32}");
+        assert_eq!(texts(result), vec!["\u{0E01}\u{0E48}\u{0E32}"]);
+    }
+
+    #[test]
+    fn test_unchanged() {
+        let result = token_stream_helper("ภาษาไทย thai");
+        assert_eq!(texts(result), vec!["ภาษาไทย", "thai"]);
+    }
+}
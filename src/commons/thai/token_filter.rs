@@ -0,0 +1,47 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::ThaiNormalizationFilterWrapper;
+
+/// This [TokenFilter] normalizes Thai tokens, so that the different ways of typing the
+/// same word match. It complements the Thai word breaking of the `ICUTokenizer`.
+/// * sara am `ำ` is decomposed into nikhahit `ํ` and sara aa `า`, as its Unicode
+///   compatibility decomposition,
+/// * combining marks above or below a consonant are put in canonical order : vowels
+///   first (including the nikhahit of sara am), then tone marks, then thanthakhat,
+/// * repeated combining marks are removed.
+///
+/// For example, `น้ำ` (tone mark typed before sara am), `นํ้า` and `น้ํา` all become
+/// `นํ้า`. Characters that are not Thai are left untouched and offsets are not modified.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::ThaiNormalizationTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(ThaiNormalizationTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("น้ำ น้ํา");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "น\u{0E4D}\u{0E49}\u{0E32}".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "น\u{0E4D}\u{0E49}\u{0E32}".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ThaiNormalizationTokenFilter;
+
+impl TokenFilter for ThaiNormalizationTokenFilter {
+    type Tokenizer<T: Tokenizer> = ThaiNormalizationFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        ThaiNormalizationFilterWrapper::new(token_stream)
+    }
+}
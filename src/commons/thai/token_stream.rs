@@ -0,0 +1,97 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+const SARA_AM: char = '\u{0E33}';
+const NIKHAHIT: char = '\u{0E4D}';
+const SARA_AA: char = '\u{0E32}';
+
+/// Rank of a Thai combining mark in the canonical order : vowels above and below the
+/// consonant first, then tone marks, then other signs. [None] if it's not a combining mark.
+fn mark_rank(c: char) -> Option<u8> {
+    match c {
+        // Mai han-akat, vowels above and below, mai taikhu and nikhahit
+        '\u{0E31}' | '\u{0E34}'..='\u{0E3A}' | '\u{0E47}' | NIKHAHIT => Some(0),
+        // Tone marks
+        '\u{0E48}'..='\u{0E4B}' => Some(1),
+        // Thanthakhat and yamakkan
+        '\u{0E4C}' | '\u{0E4E}' => Some(2),
+        _ => None,
+    }
+}
+
+fn is_thai(c: char) -> bool {
+    ('\u{0E00}'..='\u{0E7F}').contains(&c)
+}
+
+/// Write the combining marks of a cluster in canonical order, without repeated marks,
+/// then sara aa if the cluster had a sara am.
+fn flush(result: &mut String, marks: &mut Vec<char>, sara_aa: &mut bool) {
+    // Stable sort, marks with the same rank keep their order
+    marks.sort_by_key(|c| mark_rank(*c));
+    marks.dedup();
+    result.extend(marks.drain(..));
+    if *sara_aa {
+        result.push(SARA_AA);
+        *sara_aa = false;
+    }
+}
+
+/// Normalize a Thai text. Sara am is decomposed into nikhahit and sara aa, combining
+/// marks of each cluster are sorted in canonical order and repeated marks are removed.
+fn normalize(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 3);
+    let mut marks: Vec<char> = Vec::new();
+    let mut sara_aa = false;
+
+    for c in text.chars() {
+        if c == SARA_AM {
+            marks.push(NIKHAHIT);
+            sara_aa = true;
+        } else if mark_rank(c).is_some() {
+            marks.push(c);
+        } else {
+            flush(&mut result, &mut marks, &mut sara_aa);
+            result.push(c);
+        }
+    }
+    flush(&mut result, &mut marks, &mut sara_aa);
+
+    result
+}
+
+#[derive(Debug, Clone)]
+pub struct ThaiNormalizationTokenStream<T> {
+    tail: T,
+}
+
+impl<T> ThaiNormalizationTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self { tail }
+    }
+}
+
+impl<T: TokenStream> TokenStream for ThaiNormalizationTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        // Avoid allocating when there's nothing to normalize, which is the most common case.
+        if self.tail.token().text.contains(is_thai) {
+            let text = normalize(&self.tail.token().text);
+            self.tail.token_mut().text = text;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
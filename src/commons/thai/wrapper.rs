@@ -0,0 +1,26 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::ThaiNormalizationTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct ThaiNormalizationFilterWrapper<T> {
+    inner: T,
+}
+
+impl<T> ThaiNormalizationFilterWrapper<T> {
+    pub(crate) fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for ThaiNormalizationFilterWrapper<T> {
+    type TokenStream<'a> = ThaiNormalizationTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        ThaiNormalizationTokenStream::new(self.inner.token_stream(text))
+    }
+}
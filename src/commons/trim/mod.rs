@@ -0,0 +1,93 @@
+pub use token_filter::TrimTokenFilter;
+use token_stream::TrimTokenStream;
+use wrapper::TrimFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(RawTokenizer::default())
+            .filter(TrimTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_leading() {
+        let result = token_stream_helper("  abc");
+        let expected = vec![Token {
+            offset_from: 2,
+            offset_to: 5,
+            position: 0,
+            text: "abc".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_trailing() {
+        let result = token_stream_helper("abc \t");
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 3,
+            position: 0,
+            text: "abc".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_both() {
+        let result = token_stream_helper("\u{3000} a b \n");
+        let expected = vec![Token {
+            offset_from: 4,
+            offset_to: 7,
+            position: 0,
+            text: "a b".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_unchanged() {
+        let result = token_stream_helper("abc");
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 3,
+            position: 0,
+            text: "abc".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_all_whitespace() {
+        let result = token_stream_helper(" \t\n ");
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_empty() {
+        let result = token_stream_helper("");
+        assert!(result.is_empty());
+    }
+}
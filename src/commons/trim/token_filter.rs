@@ -0,0 +1,43 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::TrimFilterWrapper;
+
+/// This is a [TokenFilter] that removes leading and trailing whitespaces of tokens.
+/// It is an equivalent of
+/// [Lucene's TrimFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/miscellaneous/TrimFilter.html).
+///
+/// Offsets are adjusted to match the trimmed text, unless the token's text doesn't
+/// match its offsets anymore (because a previous filter changed it). Tokens that are
+/// empty once trimmed are removed.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::TrimTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+///    .filter(TrimTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("  trim me ");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "trim me".to_string());
+/// assert_eq!(token.offset_from, 2);
+/// assert_eq!(token.offset_to, 9);
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TrimTokenFilter;
+
+impl TokenFilter for TrimTokenFilter {
+    type Tokenizer<T: Tokenizer> = TrimFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        TrimFilterWrapper::new(token_stream)
+    }
+}
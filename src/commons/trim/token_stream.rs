@@ -0,0 +1,52 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Debug, Clone)]
+pub struct TrimTokenStream<T> {
+    tail: T,
+}
+
+impl<T> TrimTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self { tail }
+    }
+}
+
+impl<T: TokenStream> TokenStream for TrimTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        while self.tail.advance() {
+            let token = self.tail.token_mut();
+            let trimmed = token.text.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            let len = token.text.len();
+            if len != trimmed.len() {
+                let leading = len - token.text.trim_start().len();
+                let trailing = len - token.text.trim_end().len();
+                // Offsets can only be adjusted if they still match the text
+                if token.offset_to.checked_sub(token.offset_from) == Some(len) {
+                    token.offset_from += leading;
+                    token.offset_to -= trailing;
+                }
+                token.text.truncate(len - trailing);
+                token.text.drain(..leading);
+            }
+
+            return true;
+        }
+
+        false
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,98 @@
+use token_stream::UrlEmailTokenStream;
+pub use tokenizer::UrlEmailTokenizer;
+
+mod token_stream;
+mod tokenizer;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+
+    use super::*;
+
+    fn tokenize_all(text: &str) -> Vec<Token> {
+        let mut result: Vec<Token> = Vec::new();
+
+        let mut tokenizer = UrlEmailTokenizer;
+        let mut tokenizer = tokenizer.token_stream(text);
+        while tokenizer.advance() {
+            result.push(tokenizer.token().clone());
+        }
+
+        result
+    }
+
+    fn token(text: &str, offset_from: usize, position: usize) -> Token {
+        Token {
+            offset_from,
+            offset_to: offset_from + text.len(),
+            position,
+            text: text.to_string(),
+            position_length: 1,
+        }
+    }
+
+    #[test]
+    fn test_email() {
+        let result = tokenize_all("contact me at a@b.com.");
+        let expected: Vec<Token> = vec![
+            token("contact", 0, 0),
+            token("me", 8, 1),
+            token("at", 11, 2),
+            token("a@b.com", 14, 3),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_url() {
+        let result = tokenize_all("see http://x.y/z, thanks");
+        let expected: Vec<Token> = vec![
+            token("see", 0, 0),
+            token("http://x.y/z", 4, 1),
+            token("thanks", 18, 2),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_url_with_parenthesis() {
+        let result = tokenize_all("(https://en.wikipedia.org/wiki/Rust_(language))");
+        let expected: Vec<Token> =
+            vec![token("https://en.wikipedia.org/wiki/Rust_(language)", 1, 0)];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_www_and_multibyte() {
+        let result = tokenize_all("Voilà www.exemple.fr/été! Café jean.dupont@exemple.fr");
+        let expected: Vec<Token> = vec![
+            token("Voilà", 0, 0),
+            token("www.exemple.fr/été", 7, 1),
+            token("Café", 29, 2),
+            token("jean.dupont@exemple.fr", 35, 3),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_only_words() {
+        let result = tokenize_all("no links, here!");
+        let expected: Vec<Token> = vec![
+            token("no", 0, 0),
+            token("links", 3, 1),
+            token("here", 10, 2),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(tokenize_all("").is_empty());
+    }
+}
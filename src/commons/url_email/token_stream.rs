@@ -0,0 +1,96 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use regex::{Match, Matches};
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+/// Characters that end a sentence rather than a URL.
+const TRAILING: [char; 11] = ['.', ',', ';', ':', '!', '?', '\'', '"', ')', ']', '}'];
+
+/// Remove trailing punctuation from a URL. A closing parenthesis is kept if it
+/// closes one of the URL (e.g. `https://en.wikipedia.org/wiki/Rust_(language)`).
+fn trim_url(url: &str) -> &str {
+    let mut url = url;
+    while let Some(last) = url.chars().last() {
+        if !TRAILING.contains(&last) {
+            break;
+        }
+        if last == ')' && url.matches('(').count() >= url.matches(')').count() {
+            break;
+        }
+        url = &url[..url.len() - last.len_utf8()];
+    }
+    url
+}
+
+#[derive(Debug)]
+pub struct UrlEmailTokenStream<'a> {
+    text: &'a str,
+    matches: Matches<'static, 'a>,
+    /// Next URL or email.
+    next_match: Option<Match<'a>>,
+    /// Byte offset where the next word is searched.
+    cursor: usize,
+    token: Token,
+}
+
+impl<'a> UrlEmailTokenStream<'a> {
+    pub(crate) fn new(text: &'a str, mut matches: Matches<'static, 'a>) -> Self {
+        let next_match = matches.next();
+        Self {
+            text,
+            matches,
+            next_match,
+            cursor: 0,
+            token: Token::default(),
+        }
+    }
+
+    fn set_token(&mut self, from: usize, to: usize) {
+        self.token.text.clear();
+        self.token.text.push_str(&self.text[from..to]);
+        self.token.offset_from = from;
+        self.token.offset_to = to;
+        self.token.position = self.token.position.wrapping_add(1);
+    }
+}
+
+impl TokenStream for UrlEmailTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        let end = self.next_match.map_or(self.text.len(), |m| m.start());
+
+        // Words before the next URL or email
+        let region = &self.text[self.cursor..end];
+        if let Some(start) = region.find(char::is_alphanumeric) {
+            let len = region[start..]
+                .find(|c: char| !c.is_alphanumeric())
+                .unwrap_or(region.len() - start);
+            let from = self.cursor + start;
+            self.set_token(from, from + len);
+            self.cursor = from + len;
+            return true;
+        }
+
+        let Some(m) = self.next_match.take() else {
+            self.cursor = self.text.len();
+            return false;
+        };
+        self.next_match = self.matches.next();
+        let matched = if m.as_str().contains('@') && !m.as_str().contains("://") {
+            m.as_str()
+        } else {
+            trim_url(m.as_str())
+        };
+        self.set_token(m.start(), m.start() + matched.len());
+        self.cursor = m.start() + matched.len();
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
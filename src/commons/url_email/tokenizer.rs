@@ -0,0 +1,62 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::UrlEmailTokenStream;
+
+/// URLs with a scheme or starting with `www.`, and email addresses.
+const URL_EMAIL_PATTERN: &str = r#"(?i)(?:(?:https?|ftp|file)://|www\.)[^\s<>"]+|[a-z0-9._%+-]+@[a-z0-9-]+(?:\.[a-z0-9-]+)*\.[a-z]{2,}"#;
+
+fn pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(URL_EMAIL_PATTERN).expect("URL and email pattern is valid"))
+}
+
+/// A [Tokenizer] that keeps URLs and email addresses as single tokens. It is close to
+/// [Lucene's UAX29URLEmailTokenizer](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/email/UAX29URLEmailTokenizer.html).
+///
+/// URLs are recognized by their scheme (`http://`, `https://`, `ftp://`, `file://`) or
+/// when they start with `www.`. Punctuation at the end of a URL, like the period ending a
+/// sentence, is not part of the token. A closing parenthesis is kept when it closes one
+/// of the URL.
+///
+/// Elsewhere, text is split into words made of alphanumeric characters, like tantivy's
+/// `SimpleTokenizer`. Offsets are in bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use tantivy::tokenizer::{TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::UrlEmailTokenizer;
+///
+/// let mut tmp = TextAnalyzer::builder(UrlEmailTokenizer).build();
+/// let mut token_stream = tmp.token_stream("Mail a@b.com or see https://x.y/z.");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Mail".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "a@b.com".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "or".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "see".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "https://x.y/z".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UrlEmailTokenizer;
+
+impl Tokenizer for UrlEmailTokenizer {
+    type TokenStream<'a> = UrlEmailTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        UrlEmailTokenStream::new(text, pattern().find_iter(text))
+    }
+}
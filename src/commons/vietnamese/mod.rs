@@ -0,0 +1,269 @@
+pub use token_filter::VietnameseToneNormalizeTokenFilter;
+use token_stream::VietnameseToneTokenStream;
+use wrapper::VietnameseToneFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(VietnameseToneNormalizeTokenFilter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_old_style() {
+        let result = token_stream_helper("hòa hòe thủy");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "hoà".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 9,
+                position: 1,
+                text: "hoè".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 16,
+                position: 2,
+                text: "thuỷ".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_new_style() {
+        let result = token_stream_helper("hoà hoè thuỷ");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "hoà".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 9,
+                position: 1,
+                text: "hoè".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 16,
+                position: 2,
+                text: "thuỷ".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_final_consonant() {
+        let result = token_stream_helper("hoàng thuận");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "hoàng".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 14,
+                position: 1,
+                text: "thuận".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_diacritics() {
+        let result = token_stream_helper("người tiếng muốn cứu");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "người".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 16,
+                position: 1,
+                text: "tiếng".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 17,
+                offset_to: 23,
+                position: 2,
+                text: "muốn".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 24,
+                offset_to: 29,
+                position: 3,
+                text: "cứu".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_clusters() {
+        let result = token_stream_helper("mùa muà ngoài ngòai quý gìn");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "mùa".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 9,
+                position: 1,
+                text: "mùa".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 16,
+                position: 2,
+                text: "ngoài".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 17,
+                offset_to: 23,
+                position: 3,
+                text: "ngoài".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 24,
+                offset_to: 28,
+                position: 4,
+                text: "quý".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 29,
+                offset_to: 33,
+                position: 5,
+                text: "gìn".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    /// Combining tone marks are composed with their vowel
+    #[test]
+    fn test_combining() {
+        let result = token_stream_helper("hoa\u{0300} ho\u{0300}a thuy\u{0309}");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "hoà".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 11,
+                position: 1,
+                text: "hoà".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 12,
+                offset_to: 18,
+                position: 2,
+                text: "thuỷ".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_uppercase() {
+        let result = token_stream_helper("HÒA Thủy");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "HOÀ".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 11,
+                position: 1,
+                text: "Thuỷ".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_not_vietnamese() {
+        let result = token_stream_helper("hello cafés");
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "hello".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 12,
+                position: 1,
+                text: "cafés".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
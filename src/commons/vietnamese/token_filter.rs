@@ -0,0 +1,46 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::VietnameseToneFilterWrapper;
+
+/// This [TokenFilter] normalizes the placement of Vietnamese tone marks.
+///
+/// Vietnamese has two conventions to place tone marks on `oa`, `oe` and `uy` : the old one
+/// puts the mark on the first vowel (`hòa`, `thủy`) while the new one puts it on the
+/// second (`hoà`, `thuỷ`). This filter moves the tone mark of each token to its canonical
+/// place, which is the new convention, so that differently typed words match.
+///
+/// Tone marks written as combining characters are first composed with their vowel, like NFC
+/// does. Tokens without exactly one tone mark are left untouched, as is the case.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::VietnameseToneNormalizeTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(VietnameseToneNormalizeTokenFilter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("hòa hoà");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "hoà".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "hoà".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct VietnameseToneNormalizeTokenFilter;
+
+impl TokenFilter for VietnameseToneNormalizeTokenFilter {
+    type Tokenizer<T: Tokenizer> = VietnameseToneFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        VietnameseToneFilterWrapper::new(token_stream)
+    }
+}
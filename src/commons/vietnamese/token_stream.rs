@@ -0,0 +1,160 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+/// Vietnamese vowels. The first column is the vowel without tone mark, then
+/// the vowel with grave, acute, hook above, tilde and dot below.
+const VOWELS: [[char; 6]; 24] = [
+    ['a', 'à', 'á', 'ả', 'ã', 'ạ'],
+    ['A', 'À', 'Á', 'Ả', 'Ã', 'Ạ'],
+    ['ă', 'ằ', 'ắ', 'ẳ', 'ẵ', 'ặ'],
+    ['Ă', 'Ằ', 'Ắ', 'Ẳ', 'Ẵ', 'Ặ'],
+    ['â', 'ầ', 'ấ', 'ẩ', 'ẫ', 'ậ'],
+    ['Â', 'Ầ', 'Ấ', 'Ẩ', 'Ẫ', 'Ậ'],
+    ['e', 'è', 'é', 'ẻ', 'ẽ', 'ẹ'],
+    ['E', 'È', 'É', 'Ẻ', 'Ẽ', 'Ẹ'],
+    ['ê', 'ề', 'ế', 'ể', 'ễ', 'ệ'],
+    ['Ê', 'Ề', 'Ế', 'Ể', 'Ễ', 'Ệ'],
+    ['i', 'ì', 'í', 'ỉ', 'ĩ', 'ị'],
+    ['I', 'Ì', 'Í', 'Ỉ', 'Ĩ', 'Ị'],
+    ['o', 'ò', 'ó', 'ỏ', 'õ', 'ọ'],
+    ['O', 'Ò', 'Ó', 'Ỏ', 'Õ', 'Ọ'],
+    ['ô', 'ồ', 'ố', 'ổ', 'ỗ', 'ộ'],
+    ['Ô', 'Ồ', 'Ố', 'Ổ', 'Ỗ', 'Ộ'],
+    ['ơ', 'ờ', 'ớ', 'ở', 'ỡ', 'ợ'],
+    ['Ơ', 'Ờ', 'Ớ', 'Ở', 'Ỡ', 'Ợ'],
+    ['u', 'ù', 'ú', 'ủ', 'ũ', 'ụ'],
+    ['U', 'Ù', 'Ú', 'Ủ', 'Ũ', 'Ụ'],
+    ['ư', 'ừ', 'ứ', 'ử', 'ữ', 'ự'],
+    ['Ư', 'Ừ', 'Ứ', 'Ử', 'Ữ', 'Ự'],
+    ['y', 'ỳ', 'ý', 'ỷ', 'ỹ', 'ỵ'],
+    ['Y', 'Ỳ', 'Ý', 'Ỷ', 'Ỹ', 'Ỵ'],
+];
+
+/// Combining tone marks, in the same order as the columns of [VOWELS].
+const TONE_MARKS: [char; 5] = ['\u{0300}', '\u{0301}', '\u{0309}', '\u{0303}', '\u{0323}'];
+
+/// Get the row and the column of a vowel in [VOWELS].
+fn find_vowel(c: char) -> Option<(usize, usize)> {
+    VOWELS
+        .iter()
+        .enumerate()
+        .find_map(|(row, vowels)| vowels.iter().position(|v| *v == c).map(|tone| (row, tone)))
+}
+
+/// Vowels that carry a diacritic (`ă`, `â`, `ê`, `ô`, `ơ`, `ư`). They always get the tone mark.
+fn has_diacritic(row: usize) -> bool {
+    matches!(row, 2..=5 | 8 | 9 | 14..=17 | 20 | 21)
+}
+
+/// Normalize the tone mark placement of a syllable. It returns [None] if
+/// nothing changed.
+fn normalize(text: &str) -> Option<String> {
+    // Compose combining tone marks with their vowel
+    let mut chars: Vec<char> = Vec::with_capacity(text.len());
+    let mut composed = false;
+    for c in text.chars() {
+        let mark = TONE_MARKS.iter().position(|m| *m == c);
+        let previous = chars.last().and_then(|p| find_vowel(*p));
+        match (mark, previous) {
+            (Some(mark), Some((row, 0))) => {
+                chars.pop();
+                chars.push(VOWELS[row][mark + 1]);
+                composed = true;
+            }
+            _ => chars.push(c),
+        }
+    }
+
+    let vowels: Vec<Option<(usize, usize)>> = chars.iter().map(|c| find_vowel(*c)).collect();
+    let mut toned = vowels
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| matches!(v, Some((_, tone)) if *tone > 0));
+    let (current, tone) = match (toned.next(), toned.next()) {
+        (Some((index, Some((_, tone)))), None) => (index, *tone),
+        _ => return composed.then(|| chars.into_iter().collect()),
+    };
+
+    // Vowels cluster that contains the tone mark
+    let mut start = current;
+    while start > 0 && vowels[start - 1].is_some() {
+        start -= 1;
+    }
+    let mut end = current + 1;
+    while end < vowels.len() && vowels[end].is_some() {
+        end += 1;
+    }
+    // `u` of `qu` and `i` of `gi` are part of the consonant
+    if start > 0 && end - start > 1 {
+        let consonant = chars[start - 1].to_ascii_lowercase();
+        let vowel = chars[start].to_ascii_lowercase();
+        if (consonant == 'q' && vowel == 'u') || (consonant == 'g' && vowel == 'i') {
+            start += 1;
+        }
+    }
+    let has_final_consonant = end < chars.len() && chars[end].is_alphabetic();
+
+    let cluster: String = vowels[start..end]
+        .iter()
+        .flatten()
+        .map(|(row, _)| VOWELS[*row & !1][0])
+        .collect();
+    let target = match vowels[start..end]
+        .iter()
+        .rposition(|v| matches!(v, Some((row, _)) if has_diacritic(*row)))
+    {
+        Some(index) => start + index,
+        None => match end - start {
+            1 => start,
+            2 if has_final_consonant || matches!(cluster.as_str(), "oa" | "oe" | "uy") => start + 1,
+            2 => start,
+            _ => start + 1,
+        },
+    };
+
+    if target == current {
+        return composed.then(|| chars.into_iter().collect());
+    }
+
+    if let (Some((from, _)), Some((to, _))) = (vowels[current], vowels[target]) {
+        chars[current] = VOWELS[from][0];
+        chars[target] = VOWELS[to][tone];
+    }
+
+    Some(chars.into_iter().collect())
+}
+
+#[derive(Debug, Clone)]
+pub struct VietnameseToneTokenStream<T> {
+    tail: T,
+}
+
+impl<T> VietnameseToneTokenStream<T> {
+    pub(crate) fn new(tail: T) -> Self {
+        Self { tail }
+    }
+}
+
+impl<T: TokenStream> TokenStream for VietnameseToneTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        if let Some(text) = normalize(&self.tail.token().text) {
+            self.tail.token_mut().text = text;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
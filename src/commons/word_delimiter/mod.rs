@@ -0,0 +1,598 @@
+pub use token_filter::{WordDelimiterTokenFilter, WordDelimiterTokenFilterBuilder};
+use token_stream::WordDelimiterTokenStream;
+use wrapper::WordDelimiterFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::commons::ASCIIFoldingTokenFilter;
+
+    fn token_stream_helper(text: &str, filter: WordDelimiterTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn token_stream_helper_folding(text: &str, filter: WordDelimiterTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(ASCIIFoldingTokenFilter::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_case_change() {
+        let filter = WordDelimiterTokenFilterBuilder::default().build().unwrap();
+        let result = token_stream_helper("PowerShot", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "Power".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 9,
+                position: 1,
+                text: "Shot".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_catenate_words() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .catenate_words(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("PowerShot", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "PowerShot".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "Power".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 9,
+                position: 1,
+                text: "Shot".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_no_split_on_case_change() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .split_on_case_change(false)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("PowerShot Wi-Fi", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "PowerShot".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 12,
+                position: 1,
+                text: "Wi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 13,
+                offset_to: 15,
+                position: 2,
+                text: "Fi".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_upper_then_lower() {
+        let filter = WordDelimiterTokenFilterBuilder::default().build().unwrap();
+        let result = token_stream_helper("XMLParser iPod", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "XMLParser".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 11,
+                position: 1,
+                text: "i".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 11,
+                offset_to: 14,
+                position: 2,
+                text: "Pod".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_delimiters() {
+        let filter = WordDelimiterTokenFilterBuilder::default().build().unwrap();
+        let result = token_stream_helper("Wi-Fi network", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 2,
+                position: 0,
+                text: "Wi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 3,
+                offset_to: 5,
+                position: 1,
+                text: "Fi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 13,
+                position: 2,
+                text: "network".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_numerics() {
+        let filter = WordDelimiterTokenFilterBuilder::default().build().unwrap();
+        let result = token_stream_helper("PowerShot2000 SD500", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "Power".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 9,
+                position: 1,
+                text: "Shot".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 13,
+                position: 2,
+                text: "2000".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 16,
+                position: 3,
+                text: "SD".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 19,
+                position: 4,
+                text: "500".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_no_split_on_numerics() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .split_on_numerics(false)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("PowerShot2000", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "Power".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 13,
+                position: 1,
+                text: "Shot2000".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_catenate_all() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .catenate_all(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("PowerShot2000", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 13,
+                position: 0,
+                text: "PowerShot2000".to_string(),
+                position_length: 3,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "Power".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 9,
+                position: 1,
+                text: "Shot".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 13,
+                position: 2,
+                text: "2000".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_catenate_numbers() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .catenate_numbers(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("500-42 x", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "50042".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "500".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 6,
+                position: 1,
+                text: "42".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 8,
+                position: 2,
+                text: "x".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_catenate_words_and_numbers() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .catenate_words(true)
+            .catenate_numbers(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("wi-fi-4000", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "wifi".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 2,
+                position: 0,
+                text: "wi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 3,
+                offset_to: 5,
+                position: 1,
+                text: "fi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 10,
+                position: 2,
+                text: "4000".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_catenate_all_same_as_words() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .catenate_words(true)
+            .catenate_all(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("Wi-Fi", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "WiFi".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 2,
+                position: 0,
+                text: "Wi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 3,
+                offset_to: 5,
+                position: 1,
+                text: "Fi".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_only_catenate() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .generate_word_parts(false)
+            .generate_number_parts(false)
+            .catenate_words(true)
+            .catenate_numbers(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("wi-fi-4000 router", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "wifi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 10,
+                position: 1,
+                text: "4000".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 11,
+                offset_to: 17,
+                position: 2,
+                text: "router".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_preserve_original() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .preserve_original(true)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("Wi-Fi router", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "Wi-Fi".to_string(),
+                position_length: 2,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 2,
+                position: 0,
+                text: "Wi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 3,
+                offset_to: 5,
+                position: 1,
+                text: "Fi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 12,
+                position: 2,
+                text: "router".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_possessive() {
+        let filter = WordDelimiterTokenFilterBuilder::default().build().unwrap();
+        let result = token_stream_helper("O'Neil's", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 1,
+                position: 0,
+                text: "O".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 6,
+                position: 1,
+                text: "Neil".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_no_possessive() {
+        let filter = WordDelimiterTokenFilterBuilder::default()
+            .stem_english_possessive(false)
+            .build()
+            .unwrap();
+        let result = token_stream_helper("O'Neil's", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 1,
+                position: 0,
+                text: "O".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 6,
+                position: 1,
+                text: "Neil".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 8,
+                position: 2,
+                text: "s".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_leading_trailing_delimiters() {
+        let filter = WordDelimiterTokenFilterBuilder::default().build().unwrap();
+        let result = token_stream_helper("-foo- --- bar", filter);
+        let expected = vec![
+            Token {
+                offset_from: 1,
+                offset_to: 4,
+                position: 0,
+                text: "foo".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 13,
+                position: 2,
+                text: "bar".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_offsets_not_adjusted() {
+        let filter = WordDelimiterTokenFilterBuilder::default().build().unwrap();
+        let result = token_stream_helper_folding("Café-Bar", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "Cafe".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 1,
+                text: "Bar".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+}
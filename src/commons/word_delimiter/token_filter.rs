@@ -0,0 +1,124 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::WordDelimiterFilterWrapper;
+
+/// A token filter that splits tokens into sub-words and can catenate them. It is modeled on
+/// [Lucene's WordDelimiterGraphFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/miscellaneous/WordDelimiterGraphFilter.html).
+///
+/// Tokens are split :
+/// * on intra-word delimiters, that is every character that is neither a letter nor a digit
+///   (`Wi-Fi` gives `Wi` and `Fi`). Leading and trailing delimiters are removed.
+/// * on case changes, from lower case to upper case (`PowerShot` gives `Power` and `Shot`).
+///   An upper case letter followed by a lower case one doesn't split, `XMLParser` stays
+///   as is.
+/// * on letter/digit transitions (`SD500` gives `SD` and `500`).
+/// * trailing english possessive `'s` are removed (`O'Neil's` gives `O` and `Neil`).
+///
+/// Tokens that don't need to be split are left untouched.
+///
+/// Each sub-word gets its own position. Catenated tokens (and the original token, if preserved)
+/// are at the position of their first sub-word and their `position_length` is the number of
+/// positions they span, so phrase queries still work. Positions of following tokens are
+/// shifted accordingly. Tokens at the same position are ordered from the longest to the shortest.
+///
+/// Offsets of generated tokens are those of the sub-words, unless the token's text doesn't
+/// match its offsets anymore (because a previous filter changed it), in that case all generated
+/// tokens keep the offsets of the original token.
+///
+/// Use [WordDelimiterTokenFilterBuilder] to configure it, the [Default] implementation
+/// generates word and number parts and splits on case changes and letter/digit transitions.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::commons::WordDelimiterTokenFilterBuilder;
+///
+/// let filter = WordDelimiterTokenFilterBuilder::default()
+///     .catenate_words(true)
+///     .build()?;
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("PowerShot camera");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "PowerShot".to_string());
+/// assert_eq!(token.position, 0);
+/// assert_eq!(token.position_length, 2);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Power".to_string());
+/// assert_eq!(token.position, 0);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Shot".to_string());
+/// assert_eq!(token.position, 1);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "camera".to_string());
+/// assert_eq!(token.position, 2);
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Builder)]
+#[builder(setter(into), default)]
+pub struct WordDelimiterTokenFilter {
+    /// Emit word parts : `PowerShot` gives `Power` and `Shot`.
+    #[builder(default = "true")]
+    pub generate_word_parts: bool,
+    /// Emit number parts : `500-42` gives `500` and `42`.
+    #[builder(default = "true")]
+    pub generate_number_parts: bool,
+    /// Emit consecutive word parts catenated : `wi-fi-4000` gives `wifi`.
+    #[builder(default = "false")]
+    pub catenate_words: bool,
+    /// Emit consecutive number parts catenated : `500-42` gives `50042`.
+    #[builder(default = "false")]
+    pub catenate_numbers: bool,
+    /// Emit all parts catenated : `wi-fi-4000` gives `wifi4000`.
+    #[builder(default = "false")]
+    pub catenate_all: bool,
+    /// Also emit the original token when it is split.
+    #[builder(default = "false")]
+    pub preserve_original: bool,
+    /// Split on case changes : `PowerShot` gives `Power` and `Shot`.
+    #[builder(default = "true")]
+    pub split_on_case_change: bool,
+    /// Split on letter/digit transitions : `SD500` gives `SD` and `500`.
+    #[builder(default = "true")]
+    pub split_on_numerics: bool,
+    /// Remove trailing `'s` : `O'Neil's` gives `O` and `Neil`.
+    #[builder(default = "true")]
+    pub stem_english_possessive: bool,
+}
+
+impl Default for WordDelimiterTokenFilter {
+    /// Construct a [WordDelimiterTokenFilter] that generates word and number parts,
+    /// splits on case changes and letter/digit transitions and removes english
+    /// possessives.
+    fn default() -> Self {
+        WordDelimiterTokenFilter {
+            generate_word_parts: true,
+            generate_number_parts: true,
+            catenate_words: false,
+            catenate_numbers: false,
+            catenate_all: false,
+            preserve_original: false,
+            split_on_case_change: true,
+            split_on_numerics: true,
+            stem_english_possessive: true,
+        }
+    }
+}
+
+impl TokenFilter for WordDelimiterTokenFilter {
+    type Tokenizer<T: Tokenizer> = WordDelimiterFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        WordDelimiterFilterWrapper::new(tokenizer, self)
+    }
+}
@@ -0,0 +1,290 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use super::WordDelimiterTokenFilter;
+
+const LOWER: u8 = 0x01;
+const UPPER: u8 = 0x02;
+const DIGIT: u8 = 0x04;
+const DELIMITER: u8 = 0x08;
+/// Letters without case (e.g. CJK) are both lower and upper, so they never split.
+const ALPHA: u8 = LOWER | UPPER;
+
+fn char_type(c: char) -> u8 {
+    if c.is_lowercase() {
+        LOWER
+    } else if c.is_uppercase() {
+        UPPER
+    } else if c.is_alphabetic() {
+        ALPHA
+    } else if c.is_numeric() {
+        DIGIT
+    } else {
+        DELIMITER
+    }
+}
+
+/// A sub-word, as a byte range of the token's text.
+#[derive(Clone, Copy, Debug)]
+struct Part {
+    from: usize,
+    to: usize,
+    number: bool,
+}
+
+/// A token to emit. `from` and `to` are indices of sub-words (`to` is exclusive).
+#[derive(Clone, Debug)]
+struct Candidate {
+    text: String,
+    from: usize,
+    to: usize,
+    byte_from: usize,
+    byte_to: usize,
+}
+
+impl WordDelimiterTokenFilter {
+    fn is_break(&self, last: u8, current: u8) -> bool {
+        if last & current != 0 {
+            return false;
+        }
+        let is_alpha = |t: u8| t & ALPHA != 0;
+        let is_digit = |t: u8| t & DIGIT != 0;
+        if !self.split_on_case_change && is_alpha(last) && is_alpha(current) {
+            return false;
+        }
+        // An upper case letter followed by a lower case letter doesn't split
+        if last & UPPER != 0 && is_alpha(current) {
+            return false;
+        }
+        if !self.split_on_numerics
+            && ((is_alpha(last) && is_digit(current)) || (is_digit(last) && is_alpha(current)))
+        {
+            return false;
+        }
+        true
+    }
+
+    fn generate(&self, number: bool) -> bool {
+        if number {
+            self.generate_number_parts
+        } else {
+            self.generate_word_parts
+        }
+    }
+
+    fn catenate(&self, number: bool) -> bool {
+        if number {
+            self.catenate_numbers
+        } else {
+            self.catenate_words
+        }
+    }
+
+    /// Split the text into sub-words.
+    fn parts(&self, text: &str) -> Vec<Part> {
+        let chars: Vec<(usize, char)> = text
+            .char_indices()
+            .rev()
+            .skip_while(|(_, c)| char_type(*c) == DELIMITER)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+
+        let mut end = chars.len();
+        if self.stem_english_possessive
+            && end >= 3
+            && matches!(chars[end - 1].1, 's' | 'S')
+            && chars[end - 2].1 == '\''
+            && char_type(chars[end - 3].1) & ALPHA != 0
+        {
+            end -= 2;
+        }
+
+        let mut parts = Vec::new();
+        let mut current: Option<(Part, u8)> = None;
+        for &(index, c) in &chars[..end] {
+            let t = char_type(c);
+            current = match current {
+                _ if t == DELIMITER => {
+                    parts.extend(current.map(|(part, _)| part));
+                    None
+                }
+                Some((mut part, last)) if !self.is_break(last, t) => {
+                    part.to = index + c.len_utf8();
+                    Some((part, t))
+                }
+                _ => {
+                    parts.extend(current.map(|(part, _)| part));
+                    let part = Part {
+                        from: index,
+                        to: index + c.len_utf8(),
+                        number: t & DIGIT != 0,
+                    };
+                    Some((part, t))
+                }
+            };
+        }
+        parts.extend(current.map(|(part, _)| part));
+
+        parts
+    }
+
+    /// Tokens to emit for the text, ordered by position then from the longest to the shortest.
+    /// `None` means that the token must be emitted unchanged.
+    fn candidates(&self, text: &str) -> Option<Vec<Candidate>> {
+        let parts = self.parts(text);
+        if parts.len() == 1 && parts[0].from == 0 && parts[0].to == text.len() {
+            return None;
+        }
+        if parts.is_empty() && self.preserve_original {
+            return None;
+        }
+
+        let catenate = |from: usize, to: usize| Candidate {
+            text: parts[from..to]
+                .iter()
+                .map(|part| &text[part.from..part.to])
+                .collect(),
+            from,
+            to,
+            byte_from: parts[from].from,
+            byte_to: parts[to - 1].to,
+        };
+
+        let mut candidates = Vec::new();
+        if self.preserve_original {
+            candidates.push(Candidate {
+                text: text.to_string(),
+                from: 0,
+                to: parts.len(),
+                byte_from: 0,
+                byte_to: text.len(),
+            });
+        }
+        if self.catenate_all && parts.len() > 1 {
+            candidates.push(catenate(0, parts.len()));
+        }
+        // Runs of sub-words of the same kind
+        let mut start = 0;
+        while start < parts.len() {
+            let number = parts[start].number;
+            let end = parts[start..]
+                .iter()
+                .position(|part| part.number != number)
+                .map_or(parts.len(), |len| start + len);
+            if self.catenate(number) && (end - start > 1 || !self.generate(number)) {
+                candidates.push(catenate(start, end));
+            }
+            start = end;
+        }
+        for (index, part) in parts.iter().enumerate() {
+            if self.generate(part.number) {
+                candidates.push(catenate(index, index + 1));
+            }
+        }
+
+        let mut result: Vec<Candidate> = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            let duplicate = result.iter().any(|c| {
+                c.from == candidate.from && c.to == candidate.to && c.text == candidate.text
+            });
+            if !duplicate {
+                result.push(candidate);
+            }
+        }
+        result.sort_by_key(|c| (c.from, Reverse(c.to)));
+
+        Some(result)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WordDelimiterTokenStream<T> {
+    tail: T,
+    config: WordDelimiterTokenFilter,
+    /// Tokens generated from the current token that are not emitted yet.
+    buffer: VecDeque<Token>,
+    token: Token,
+    /// Number of positions added by splitting, it shifts
+    /// the position of every following token.
+    position_shift: usize,
+}
+
+impl<T> WordDelimiterTokenStream<T> {
+    pub(crate) fn new(tail: T, config: WordDelimiterTokenFilter) -> Self {
+        Self {
+            tail,
+            config,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+            position_shift: 0,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for WordDelimiterTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.buffer.pop_front() {
+                self.token = token;
+                return true;
+            }
+
+            if !self.tail.advance() {
+                return false;
+            }
+
+            let token = self.tail.token();
+            let position = token.position + self.position_shift;
+            let Some(candidates) = self.config.candidates(&token.text) else {
+                self.token.clone_from(token);
+                self.token.position = position;
+                return true;
+            };
+            if candidates.is_empty() {
+                continue;
+            }
+
+            // Sub-words that are not emitted don't take a position
+            let mut nodes: Vec<usize> = candidates.iter().flat_map(|c| [c.from, c.to]).collect();
+            nodes.sort_unstable();
+            nodes.dedup();
+            let rank = |node: usize| nodes.binary_search(&node).unwrap_or_default();
+
+            // Offsets can only be adjusted if they still match the text
+            let adjust = token.offset_to.checked_sub(token.offset_from) == Some(token.text.len());
+            for candidate in candidates {
+                let (offset_from, offset_to) = if adjust {
+                    (
+                        token.offset_from + candidate.byte_from,
+                        token.offset_from + candidate.byte_to,
+                    )
+                } else {
+                    (token.offset_from, token.offset_to)
+                };
+                self.buffer.push_back(Token {
+                    offset_from,
+                    offset_to,
+                    position: position + rank(candidate.from),
+                    text: candidate.text,
+                    position_length: rank(candidate.to) - rank(candidate.from),
+                });
+            }
+            self.position_shift += nodes.len() - 2;
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -0,0 +1,27 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::{WordDelimiterTokenFilter, WordDelimiterTokenStream};
+
+#[derive(Clone, Debug)]
+pub struct WordDelimiterFilterWrapper<T> {
+    config: WordDelimiterTokenFilter,
+    inner: T,
+}
+
+impl<T> WordDelimiterFilterWrapper<T> {
+    pub(crate) fn new(inner: T, config: WordDelimiterTokenFilter) -> Self {
+        Self { config, inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for WordDelimiterFilterWrapper<T> {
+    type TokenStream<'a> = WordDelimiterTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        WordDelimiterTokenStream::new(self.inner.token_stream(text), self.config)
+    }
+}
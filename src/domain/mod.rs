@@ -0,0 +1,188 @@
+//! This module provides a token filter that reduces hostnames to their registrable
+//! domain, using the [public suffix list](https://publicsuffix.org/) and the
+//! [publicsuffix crate](https://docs.rs/publicsuffix/latest/publicsuffix/).
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+//! use tantivy_analysis_contrib::domain::{List, RegistrableDomainTokenFilter};
+//!
+//! let list: List = "// ===BEGIN ICANN DOMAINS===\ncom\nuk\nco.uk\n// ===END ICANN DOMAINS===".parse()?;
+//! let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+//!    .filter(RegistrableDomainTokenFilter::new(list))
+//!    .build();
+//! let mut token_stream = tmp.token_stream("foo.bar.co.uk www.example.com");
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "bar.co.uk".to_string());
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "example.com".to_string());
+//!
+//! assert_eq!(None, token_stream.next());
+//! #     Ok(())
+//! # }
+//! ```
+pub use publicsuffix::{Error, List};
+pub use token_filter::RegistrableDomainTokenFilter;
+use token_stream::RegistrableDomainTokenStream;
+use wrapper::RegistrableDomainFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    const LIST: &str = "// ===BEGIN ICANN DOMAINS===
+com
+uk
+ac.uk
+co.uk
+jp
+*.kawasaki.jp
+!city.kawasaki.jp
+// ===END ICANN DOMAINS===";
+
+    fn token_stream_helper(text: &str) -> Vec<Token> {
+        let list: List = LIST.parse().unwrap();
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(RegistrableDomainTokenFilter::new(list))
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_multi_level_suffix() {
+        let result = token_stream_helper("foo.bar.co.uk bar.co.uk www.ox.ac.uk");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 13,
+                position: 0,
+                text: "bar.co.uk".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 14,
+                offset_to: 23,
+                position: 1,
+                text: "bar.co.uk".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 24,
+                offset_to: 36,
+                position: 2,
+                text: "ox.ac.uk".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_com() {
+        let result = token_stream_helper("www.example.com example.com a.b.c.example.com");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 15,
+                position: 0,
+                text: "example.com".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 16,
+                offset_to: 27,
+                position: 1,
+                text: "example.com".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 28,
+                offset_to: 45,
+                position: 2,
+                text: "example.com".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_bare_suffix() {
+        let result = token_stream_helper("com co.uk uk");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "com".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 9,
+                position: 1,
+                text: "co.uk".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 12,
+                position: 2,
+                text: "uk".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wildcard_and_exception() {
+        let result = token_stream_helper("www.foo.bar.kawasaki.jp www.city.kawasaki.jp");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 23,
+                position: 0,
+                text: "foo.bar.kawasaki.jp".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 24,
+                offset_to: 44,
+                position: 1,
+                text: "city.kawasaki.jp".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_not_a_hostname() {
+        let result = token_stream_helper("hello");
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 5,
+            position: 0,
+            text: "hello".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use publicsuffix::List;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::RegistrableDomainFilterWrapper;
+
+/// A token filter that reduces hostnames to their registrable domain using the
+/// [public suffix list](https://publicsuffix.org/) (e.g. `foo.bar.co.uk` becomes
+/// `bar.co.uk` and `www.example.com` becomes `example.com`). It allows grouping
+/// tokens by site.
+///
+/// Tokens that have no registrable domain (e.g. a bare suffix such as `com` or `co.uk`)
+/// are left untouched.
+///
+/// The list is case-sensitive, so tokens should be lowercased before this filter.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy_analysis_contrib::domain::{List, RegistrableDomainTokenFilter};
+///
+/// // Usually the list is downloaded from https://publicsuffix.org/list/public_suffix_list.dat
+/// let list: List = "// ===BEGIN ICANN DOMAINS===\ncom\nuk\nco.uk\n// ===END ICANN DOMAINS===".parse()?;
+/// let filter = RegistrableDomainTokenFilter::new(list);
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RegistrableDomainTokenFilter {
+    list: Arc<List>,
+}
+
+impl RegistrableDomainTokenFilter {
+    /// Construct a new registrable domain token filter.
+    ///
+    /// # Parameters :
+    ///
+    /// * `list` : the public suffix list.
+    pub fn new(list: List) -> Self {
+        Self {
+            list: Arc::new(list),
+        }
+    }
+}
+
+impl TokenFilter for RegistrableDomainTokenFilter {
+    type Tokenizer<T: Tokenizer> = RegistrableDomainFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        RegistrableDomainFilterWrapper::new(tokenizer, self.list)
+    }
+}
@@ -0,0 +1,45 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use publicsuffix::{List, Psl};
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct RegistrableDomainTokenStream<'a, T> {
+    tail: T,
+    list: &'a List,
+}
+
+impl<'a, T> RegistrableDomainTokenStream<'a, T> {
+    pub(crate) fn new(tail: T, list: &'a List) -> Self {
+        Self { tail, list }
+    }
+}
+
+impl<T: TokenStream> TokenStream for RegistrableDomainTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let text = &self.tail.token().text;
+        // The registrable domain is always a suffix of the hostname, it starts after a '.'
+        let start = self
+            .list
+            .domain(text.as_bytes())
+            .map(|domain| text.len() - domain.as_bytes().len());
+        if let Some(start) = start {
+            self.tail.token_mut().text.replace_range(..start, "");
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,30 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use std::sync::Arc;
+
+use publicsuffix::List;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::RegistrableDomainTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct RegistrableDomainFilterWrapper<T> {
+    list: Arc<List>,
+    inner: T,
+}
+
+impl<T> RegistrableDomainFilterWrapper<T> {
+    pub(crate) fn new(inner: T, list: Arc<List>) -> Self {
+        Self { list, inner }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for RegistrableDomainFilterWrapper<T> {
+    type TokenStream<'a> = RegistrableDomainTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        RegistrableDomainTokenStream::new(self.inner.token_stream(text), &self.list)
+    }
+}
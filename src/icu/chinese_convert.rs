@@ -0,0 +1,138 @@
+//! This module provides a filter that converts between simplified and traditional Chinese.
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::{Direction, Error, ICUTransformTokenFilter};
+
+/// Conversion to apply on Chinese tokens.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChineseConversion {
+    /// Convert simplified Chinese into traditional Chinese.
+    SimplifiedToTraditional,
+    /// Convert traditional Chinese into simplified Chinese.
+    TraditionalToSimplified,
+}
+
+impl ChineseConversion {
+    fn compound_id(&self) -> &'static str {
+        match self {
+            ChineseConversion::SimplifiedToTraditional => "Simplified-Traditional",
+            ChineseConversion::TraditionalToSimplified => "Traditional-Simplified",
+        }
+    }
+}
+
+/// This [TokenFilter] converts tokens between simplified and traditional Chinese.
+/// It is a thin wrapper over [ICUTransformTokenFilter] using ICU's
+/// `Simplified-Traditional` and `Traditional-Simplified` transforms.
+///
+/// Note that ICU converts character by character. Some characters map to several
+/// characters depending on the word they belong to (e.g. `发` is either `發` or `髮`),
+/// in that case the most common one is chosen. If you need phrase-level accuracy,
+/// consider converting text with a dictionary-based tool before indexing.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::icu::{ChineseConversion, ChineseConvertTokenFilter};
+///
+/// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+///    .filter(ChineseConvertTokenFilter::new(ChineseConversion::TraditionalToSimplified)?)
+///    .build();
+/// let mut token_stream = tmp.token_stream("簡化字");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "简化字".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChineseConvertTokenFilter {
+    transform: ICUTransformTokenFilter,
+}
+
+impl ChineseConvertTokenFilter {
+    /// Construct a new Chinese conversion filter.
+    ///
+    /// # Parameters :
+    ///
+    /// * `conversion` : conversion to apply
+    pub fn new(conversion: ChineseConversion) -> Result<Self, Error> {
+        let transform = ICUTransformTokenFilter::new(
+            conversion.compound_id().to_string(),
+            None,
+            Direction::Forward,
+        )?;
+        Ok(Self { transform })
+    }
+}
+
+impl TokenFilter for ChineseConvertTokenFilter {
+    type Tokenizer<T: Tokenizer> = <ICUTransformTokenFilter as TokenFilter>::Tokenizer<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        self.transform.transform(token_stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, conversion: ChineseConversion) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(ChineseConvertTokenFilter::new(conversion).unwrap())
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_traditional_to_simplified() {
+        let tokens = token_stream_helper("簡化字", ChineseConversion::TraditionalToSimplified);
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 9,
+            position: 0,
+            text: "简化字".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_simplified_to_traditional() {
+        let tokens = token_stream_helper("简化字", ChineseConversion::SimplifiedToTraditional);
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 9,
+            position: 0,
+            text: "簡化字".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_already_traditional() {
+        let tokens = token_stream_helper("簡化字", ChineseConversion::SimplifiedToTraditional);
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 9,
+            position: 0,
+            text: "簡化字".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(tokens, expected);
+    }
+}
@@ -0,0 +1,315 @@
+//! Direct calls to ICU, for what the `rust_icu_*` crates don't provide. It is the only
+//! module with unsafe code : each ICU handle is wrapped in a type with a safe API, and
+//! each `Send`/`Sync` implementation tells what ICU guarantees for its handle.
+#![allow(unsafe_code)]
+
+use std::ffi::CStr;
+use std::ptr::NonNull;
+
+use rust_icu_sys as sys;
+use rust_icu_sys::versioned_function;
+
+use super::{to_capacity, Error};
+
+/// A normalizer instance got from `unorm2_getInstance`. Instances are owned by ICU,
+/// they must not be closed.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct NormalizerInstance {
+    rep: NonNull<sys::UNormalizer2>,
+}
+
+// SAFETY: `unorm2_getInstance` returns a singleton that ICU loads once, caches for the
+// whole process and gives to every caller, whatever its thread. ICU's `Normalizer2`
+// instances are immutable, normalizing only reads them, and they are never closed.
+unsafe impl Send for NormalizerInstance {}
+unsafe impl Sync for NormalizerInstance {}
+
+impl NormalizerInstance {
+    /// Instance of the data called `name`, from the data `package` or, if there
+    /// is none, from ICU's own data.
+    pub(crate) fn get(
+        package: Option<&CStr>,
+        name: &CStr,
+        mode: sys::UNormalization2Mode,
+    ) -> Result<Self, Error> {
+        let mut status = sys::UErrorCode::U_ZERO_ERROR;
+        // SAFETY: `package` and `name` are valid C strings and `status` a valid pointer
+        // for the duration of the call. A null package name means ICU's own data.
+        let rep = unsafe {
+            versioned_function!(unorm2_getInstance)(
+                package.map_or(std::ptr::null(), CStr::as_ptr),
+                name.as_ptr(),
+                mode,
+                &mut status,
+            )
+        };
+        Error::ok_or_warning(status)?;
+        NonNull::new(rep as *mut sys::UNormalizer2)
+            .map(|rep| Self { rep })
+            .ok_or(Error::Sys(sys::UErrorCode::U_ILLEGAL_ARGUMENT_ERROR))
+    }
+
+    pub(crate) fn normalize(&self, text: &str) -> Result<String, Error> {
+        normalize(self.rep, text)
+    }
+}
+
+/// A unicode set, frozen once opened. It is closed on drop.
+#[derive(Debug)]
+pub(crate) struct FrozenSet {
+    rep: NonNull<sys::USet>,
+}
+
+// SAFETY: a `USet` isn't tied to the thread that opened it. `uset_freeze` makes it
+// immutable, and ICU documents a frozen set as thread-safe until it is closed : only
+// `Drop` closes it, through an exclusive reference.
+unsafe impl Send for FrozenSet {}
+unsafe impl Sync for FrozenSet {}
+
+impl FrozenSet {
+    /// Set of a pattern like `[^[:Han:]]`.
+    pub(crate) fn new(pattern: &str) -> Result<Self, Error> {
+        let pattern: Vec<sys::UChar> = pattern.encode_utf16().collect();
+        let mut status = sys::UErrorCode::U_ZERO_ERROR;
+        // SAFETY: `pattern` is valid for its length and `status` a valid pointer for
+        // the duration of the call.
+        let rep = unsafe {
+            versioned_function!(uset_openPattern)(
+                pattern.as_ptr(),
+                to_capacity(pattern.len())?,
+                &mut status,
+            )
+        };
+        // Closed on drop if the pattern is wrong
+        let set = NonNull::new(rep).map(|rep| Self { rep });
+        Error::ok_or_warning(status)?;
+        let set = set.ok_or(Error::Sys(sys::UErrorCode::U_ILLEGAL_ARGUMENT_ERROR))?;
+        // SAFETY: the set was opened above and isn't shared yet.
+        unsafe { versioned_function!(uset_freeze)(set.rep.as_ptr()) };
+        Ok(set)
+    }
+
+    pub(crate) fn contains(&self, ch: char) -> bool {
+        // SAFETY: the set is open and frozen, it is only read.
+        unsafe { versioned_function!(uset_contains)(self.rep.as_ptr(), ch as sys::UChar32) != 0 }
+    }
+}
+
+impl Drop for FrozenSet {
+    fn drop(&mut self) {
+        // SAFETY: the set was opened in `new` and is only closed here.
+        unsafe { versioned_function!(uset_close)(self.rep.as_ptr()) };
+    }
+}
+
+/// A normalizer that leaves the code points outside a unicode set untouched
+/// (ICU's `FilteredNormalizer2`). It is closed on drop, before its set.
+#[derive(Debug)]
+pub(crate) struct FilteredInstance {
+    rep: NonNull<sys::UNormalizer2>,
+    // ICU doesn't copy the set, it must live as long as the normalizer
+    set: FrozenSet,
+}
+
+// SAFETY: ICU documents a `FilteredNormalizer2` as immutable, normalizing only reads
+// it and its set, which is frozen (see `FrozenSet`). Neither is tied to the thread
+// that opened it, and both are only closed on drop.
+unsafe impl Send for FilteredInstance {}
+unsafe impl Sync for FilteredInstance {}
+
+impl FilteredInstance {
+    pub(crate) fn new(base: NormalizerInstance, set: FrozenSet) -> Result<Self, Error> {
+        let mut status = sys::UErrorCode::U_ZERO_ERROR;
+        // SAFETY: `base` is an instance owned by ICU and `set` is open, `status` is a
+        // valid pointer for the duration of the call.
+        let rep = unsafe {
+            versioned_function!(unorm2_openFiltered)(
+                base.rep.as_ptr(),
+                set.rep.as_ptr(),
+                &mut status,
+            )
+        };
+        // Closed on drop, before the set, if ICU failed
+        let filtered = NonNull::new(rep).map(|rep| Self { rep, set });
+        Error::ok_or_warning(status)?;
+        filtered.ok_or(Error::Sys(sys::UErrorCode::U_ILLEGAL_ARGUMENT_ERROR))
+    }
+
+    pub(crate) fn normalize(&self, text: &str) -> Result<String, Error> {
+        normalize(self.rep, text)
+    }
+
+    /// Whether `ch` is normalized, rather than left untouched.
+    pub(crate) fn contains(&self, ch: char) -> bool {
+        self.set.contains(ch)
+    }
+}
+
+impl Drop for FilteredInstance {
+    fn drop(&mut self) {
+        // SAFETY: the normalizer was opened in `new` and is only closed here. The set
+        // it uses is a field, dropped after it.
+        unsafe { versioned_function!(unorm2_close)(self.rep.as_ptr()) };
+    }
+}
+
+/// Normalizes `text` with an ICU normalizer.
+fn normalize(rep: NonNull<sys::UNormalizer2>, text: &str) -> Result<String, Error> {
+    let src: Vec<sys::UChar> = text.encode_utf16().collect();
+    let src_len = to_capacity(src.len())?;
+    let mut dest: Vec<sys::UChar> = vec![0; src.len()];
+
+    loop {
+        let mut status = sys::UErrorCode::U_ZERO_ERROR;
+        let capacity = to_capacity(dest.len())?;
+        // SAFETY: `rep` is an open normalizer, `src` and `dest` are valid for their
+        // length, ICU writes at most `capacity` units into `dest`.
+        let len = unsafe {
+            versioned_function!(unorm2_normalize)(
+                rep.as_ptr(),
+                src.as_ptr(),
+                src_len,
+                dest.as_mut_ptr(),
+                capacity,
+                &mut status,
+            )
+        };
+        let len = usize::try_from(len).unwrap_or_default();
+        if status == sys::UErrorCode::U_BUFFER_OVERFLOW_ERROR && len > dest.len() {
+            // Decompositions can be longer than the text, retry with the required size
+            dest.resize(len, 0);
+            continue;
+        }
+        Error::ok_or_warning(status)?;
+        dest.truncate(len);
+        return String::from_utf16(&dest).map_err(Error::wrapper);
+    }
+}
+
+/// Script of a code point (`uscript_getScript`).
+pub(crate) fn script(c: char) -> Result<sys::UScriptCode, Error> {
+    let mut status = sys::UErrorCode::U_ZERO_ERROR;
+    // SAFETY: `status` is a valid pointer for the duration of the call.
+    let script = unsafe { versioned_function!(uscript_getScript)(c as sys::UChar32, &mut status) };
+    Error::ok_or_warning(status)?;
+    Ok(script)
+}
+
+/// Whether a code point has a binary property (`u_hasBinaryProperty`).
+pub(crate) fn has_binary_property(c: char, property: sys::UProperty) -> bool {
+    // SAFETY: the function only reads its arguments.
+    unsafe { versioned_function!(u_hasBinaryProperty)(c as sys::UChar32, property) != 0 }
+}
+
+/// An ICU transliterator instance. It is closed on drop.
+#[derive(Debug)]
+pub(crate) struct TransliteratorInstance {
+    rep: NonNull<sys::UTransliterator>,
+}
+
+// SAFETY: a `UTransliterator` isn't tied to the thread that opened it, and it is only
+// closed on drop. Through a shared reference, it can only be cloned : `utrans_clone`
+// calls the const `Transliterator::clone`, and ICU's const functions can be called
+// from several threads at once. Transliterating modifies it, it needs an exclusive
+// reference.
+unsafe impl Send for TransliteratorInstance {}
+unsafe impl Sync for TransliteratorInstance {}
+
+impl TransliteratorInstance {
+    /// Compile a transform. If ICU fails to parse it, `parse_error` tells where.
+    pub(crate) fn open(
+        compound_id: &str,
+        rules: Option<&str>,
+        direction: sys::UTransDirection,
+        parse_error: &mut sys::UParseError,
+    ) -> Result<Self, Error> {
+        let id: Vec<sys::UChar> = compound_id.encode_utf16().collect();
+        let rules: Option<Vec<sys::UChar>> = rules.map(|rules| rules.encode_utf16().collect());
+        let (rules_ptr, rules_len) = match &rules {
+            Some(rules) => (rules.as_ptr(), to_capacity(rules.len())?),
+            None => (std::ptr::null(), 0),
+        };
+        let mut status = sys::UErrorCode::U_ZERO_ERROR;
+        // SAFETY: `id` and `rules` are valid for their length, `parse_error` and
+        // `status` are valid pointers for the duration of the call.
+        let rep = unsafe {
+            versioned_function!(utrans_openU)(
+                id.as_ptr(),
+                to_capacity(id.len())?,
+                direction,
+                rules_ptr,
+                rules_len,
+                parse_error,
+                &mut status,
+            )
+        };
+        // Closed on drop if ICU failed
+        let instance = NonNull::new(rep).map(|rep| Self { rep });
+        Error::ok_or_warning(status)?;
+        instance.ok_or(Error::Sys(sys::UErrorCode::U_ILLEGAL_ARGUMENT_ERROR))
+    }
+
+    /// Clone the ICU instance, rules are not compiled again.
+    pub(crate) fn try_clone(&self) -> Result<Self, Error> {
+        let mut status = sys::UErrorCode::U_ZERO_ERROR;
+        // SAFETY: `self.rep` is an open transliterator and `status` a valid pointer
+        // for the duration of the call.
+        let rep = unsafe { versioned_function!(utrans_clone)(self.rep.as_ptr(), &mut status) };
+        let instance = NonNull::new(rep).map(|rep| Self { rep });
+        Error::ok_or_warning(status)?;
+        // ICU only fails to clone when it runs out of memory
+        instance.ok_or(Error::Sys(sys::UErrorCode::U_MEMORY_ALLOCATION_ERROR))
+    }
+
+    /// ICU instance, to tell clones apart.
+    #[cfg(test)]
+    pub(crate) fn as_ptr(&self) -> *const sys::UTransliterator {
+        self.rep.as_ptr().cast_const()
+    }
+
+    /// Transliterate `text`.
+    pub(crate) fn transliterate(&mut self, text: &str) -> Result<String, Error> {
+        let src: Vec<sys::UChar> = text.encode_utf16().collect();
+        let src_len = to_capacity(src.len())?;
+        let mut buffer = src.clone();
+
+        loop {
+            let mut len = src_len;
+            let mut limit = src_len;
+            let mut status = sys::UErrorCode::U_ZERO_ERROR;
+            let capacity = to_capacity(buffer.len())?;
+            // SAFETY: `self.rep` is an open transliterator, only used here. `buffer` is
+            // valid for `capacity` units and starts with the `len` units of the text,
+            // ICU transliterates it in place without writing more than `capacity` units.
+            unsafe {
+                versioned_function!(utrans_transUChars)(
+                    self.rep.as_ptr(),
+                    buffer.as_mut_ptr(),
+                    &mut len,
+                    capacity,
+                    0,
+                    &mut limit,
+                    &mut status,
+                )
+            };
+            if status == sys::UErrorCode::U_BUFFER_OVERFLOW_ERROR && len > capacity {
+                // Transliterated text is longer than the text, start again from the
+                // text with the required size
+                buffer.clone_from(&src);
+                buffer.resize(usize::try_from(len).unwrap_or_default(), 0);
+                continue;
+            }
+            Error::ok_or_warning(status)?;
+            buffer.truncate(usize::try_from(len).unwrap_or_default());
+            return String::from_utf16(&buffer).map_err(Error::wrapper);
+        }
+    }
+}
+
+impl Drop for TransliteratorInstance {
+    fn drop(&mut self) {
+        // SAFETY: the transliterator was opened in `open` or `try_clone` and is only
+        // closed here.
+        unsafe { versioned_function!(utrans_close)(self.rep.as_ptr()) };
+    }
+}
@@ -0,0 +1,198 @@
+//! This module provides a filter that folds accents, case and other diacritics.
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::{Error, ICUNormalizer2TokenFilter};
+
+/// This [TokenFilter] folds text : it removes accents and diacritics, folds case,
+/// applies compatibility normalization and removes default ignorable code points in
+/// one pass. It is close to
+/// [Lucene's ICUFoldingFilter](https://lucene.apache.org/core/9_0_0/analysis/icu/org/apache/lucene/analysis/icu/ICUFoldingFilter.html).
+///
+/// Lucene folds with its own `utr30` normalization data, which ICU doesn't ship.
+/// This filter relies on [ICUNormalizer2TokenFilter] instead : the text is decomposed
+/// with ICU's `nfkc_cf` data (compatibility forms, case folding and removal of default
+/// ignorable code points), nonspacing marks are removed, then what's left is composed
+/// again (NFC). Normalizers are built once and shared by all clones of the filter.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::icu::ICUFoldingTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+///    .filter(ICUFoldingTokenFilter::new()?)
+///    .build();
+/// let mut token_stream = tmp.token_stream("Résumé");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "resume".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// Use [ICUFoldingTokenFilterBuilder] to restrict the folding to characters of a
+/// [unicode set](https://unicode-org.github.io/icu/userguide/strings/unicodeset.html).
+/// Characters outside the set are left untouched.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::icu::ICUFoldingTokenFilterBuilder;
+///
+/// let filter = ICUFoldingTokenFilterBuilder::default()
+///     .unicode_set_filter("[^åäöÅÄÖ]")
+///     .build()?;
+/// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+///    .filter(filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("Résumé på svenska");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "resume på svenska".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ICUFoldingTokenFilter {
+    normalizer: ICUNormalizer2TokenFilter,
+}
+
+impl ICUFoldingTokenFilter {
+    /// Construct a new folding filter that applies on all characters.
+    pub fn new() -> Result<Self, Error> {
+        ICUFoldingTokenFilterBuilder::default().build()
+    }
+}
+
+impl TokenFilter for ICUFoldingTokenFilter {
+    type Tokenizer<T: Tokenizer> = <ICUNormalizer2TokenFilter as TokenFilter>::Tokenizer<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        self.normalizer.transform(token_stream)
+    }
+}
+
+/// Builder for [ICUFoldingTokenFilter].
+#[derive(Clone, Debug, Default)]
+pub struct ICUFoldingTokenFilterBuilder {
+    unicode_set_filter: Option<String>,
+}
+
+impl ICUFoldingTokenFilterBuilder {
+    /// Only fold characters that belong to this
+    /// [unicode set](https://unicode-org.github.io/icu/userguide/strings/unicodeset.html)
+    /// (e.g. `[^åäöÅÄÖ]` to keep Swedish letters untouched).
+    pub fn unicode_set_filter(mut self, unicode_set_filter: &str) -> Self {
+        self.unicode_set_filter = Some(unicode_set_filter.to_string());
+        self
+    }
+
+    /// Build the [ICUFoldingTokenFilter]. It fails if the unicode set is invalid.
+    pub fn build(self) -> Result<ICUFoldingTokenFilter, Error> {
+        let normalizer = ICUNormalizer2TokenFilter::folding(self.unicode_set_filter.as_deref())?;
+
+        Ok(ICUFoldingTokenFilter { normalizer })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, filter: ICUFoldingTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_folding() {
+        let tokens = token_stream_helper("Résumé ﬁ Ruß", ICUFoldingTokenFilter::new().unwrap());
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "resume".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 12,
+                position: 1,
+                text: "fi".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 13,
+                offset_to: 17,
+                position: 2,
+                text: "russ".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_default_ignorable() {
+        let tokens = token_stream_helper("soft\u{00AD}ware", ICUFoldingTokenFilter::new().unwrap());
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 10,
+            position: 0,
+            text: "software".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_unicode_set_filter() {
+        let filter = ICUFoldingTokenFilterBuilder::default()
+            .unicode_set_filter("[^åäöÅÄÖ]")
+            .build()
+            .unwrap();
+        let tokens = token_stream_helper("Résumé Åsa", filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 8,
+                position: 0,
+                text: "resume".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 13,
+                position: 1,
+                text: "Åsa".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_invalid_unicode_set_filter() {
+        let result = ICUFoldingTokenFilterBuilder::default()
+            .unicode_set_filter("[^åäö")
+            .build();
+        assert!(result.is_err());
+    }
+}
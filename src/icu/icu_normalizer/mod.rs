@@ -1,16 +1,19 @@
-use rust_icu_unorm2::UNormalizer;
+use normalizer::{Form, Normalizer};
 pub use token_filter::ICUNormalizer2TokenFilter;
 use token_stream::ICUNormalizer2TokenStream;
 use wrapper::ICUNormalizer2FilterWrapper;
 
-use super::Error;
-
+mod normalizer;
 mod token_filter;
 mod token_stream;
 mod wrapper;
 
 /// Normalization algorithms (see [Wikipedia](https://en.wikipedia.org/wiki/Unicode_equivalence#Normalization)).
+///
+/// More modes may be added, so matching on it needs a wildcard arm.
 #[derive(Clone, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Mode {
     /// Normalization Form Canonical Composition.
     NFC,
@@ -22,21 +25,27 @@ pub enum Mode {
     NFKD,
     /// Normalization Form Compatibility Composition with casefolding.
     NFKCCasefold,
+    /// Fast C Contiguous, a "safe" NFC. It composes like NFC but only characters
+    /// that are next to each other : a mark is not composed with its base if other
+    /// marks are in between. Sequences whose rendering could change are left as is.
+    /// See [Unicode Technical Note #5](https://www.unicode.org/notes/tn5/#FCC).
+    FCC,
+    /// Fast C or D, the text is only modified as much as needed to be in the
+    /// [FCD](https://www.unicode.org/notes/tn5/#FCD) form, which is neither
+    /// composed nor decomposed.
+    FCD,
 }
 
-impl TryFrom<Mode> for UNormalizer {
-    type Error = Error;
-
-    fn try_from(tp: Mode) -> Result<Self, Self::Error> {
-        let normalizer = match tp {
-            Mode::NFC => UNormalizer::new_nfc()?,
-            Mode::NFD => UNormalizer::new_nfd()?,
-            Mode::NFKC => UNormalizer::new_nfkc()?,
-            Mode::NFKD => UNormalizer::new_nfkd()?,
-            Mode::NFKCCasefold => UNormalizer::new_nfkc_casefold()?,
-        };
-        Ok(normalizer)
-    }
+/// Mode of a normalizer loaded by name, see
+/// [from_name](ICUNormalizer2TokenFilter::from_name) and
+/// [from_package](ICUNormalizer2TokenFilter::from_package).
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompositionMode {
+    /// Decompose then recompose, like NFC.
+    Compose,
+    /// Only decompose, like NFD.
+    Decompose,
 }
 
 #[cfg(test)]
@@ -77,6 +86,25 @@ mod tests {
         tokens
     }
 
+    fn token_stream_helper_keep_original(text: &str, tp: Mode) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(
+                ICUNormalizer2TokenFilter::new(tp)
+                    .unwrap()
+                    .keep_original(true),
+            )
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
     #[test]
     fn test_default() {
         let tokens = token_stream_helper("This is a test", Mode::NFKCCasefold);
@@ -208,4 +236,217 @@ mod tests {
 
         assert_eq!(expected, tokens);
     }
+
+    #[test]
+    fn test_keep_original() {
+        let tokens = token_stream_helper_keep_original("Café test", Mode::NFKCCasefold);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "café".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "Café".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 10,
+                position: 1,
+                text: "test".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(expected, tokens);
+    }
+
+    #[test]
+    fn test_keep_original_unchanged() {
+        let tokens = token_stream_helper_keep_original("café test", Mode::NFKCCasefold);
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "café".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 10,
+                position: 1,
+                text: "test".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(expected, tokens);
+    }
+
+    #[test]
+    fn test_drop_empty() {
+        // The combining grapheme joiner is removed by NFKC casefold
+        let text = "a \u{034F} b";
+
+        let tokens = token_stream_helper(text, Mode::NFKCCasefold);
+        let texts: Vec<&str> = tokens.iter().map(|token| token.text.as_str()).collect();
+        assert_eq!(texts, vec!["a", "", "b"]);
+
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(
+                ICUNormalizer2TokenFilter::new(Mode::NFKCCasefold)
+                    .unwrap()
+                    .drop_empty(true),
+            )
+            .build();
+        let mut token_stream = a.token_stream(text);
+        let mut tokens = vec![];
+        token_stream.process(&mut |token: &Token| tokens.push(token.clone()));
+
+        let expected: Vec<Token> = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 1,
+                position: 0,
+                text: "a".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 6,
+                position: 2,
+                text: "b".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(expected, tokens);
+    }
+
+    #[test]
+    fn test_fcc() {
+        // The grave below doesn't block the grave accent, NFC composes the grave
+        // with the `a` whereas FCC only composes adjacent characters
+        let text = "a\u{0316}\u{0300}";
+
+        let tokens = token_stream_helper_raw(text, Mode::NFC);
+        assert_eq!(tokens[0].text, "\u{00E0}\u{0316}");
+
+        let tokens = token_stream_helper_raw(text, Mode::FCC);
+        assert_eq!(tokens[0].text, text);
+
+        // Adjacent characters are composed
+        let tokens = token_stream_helper_raw("e\u{0301}", Mode::FCC);
+        assert_eq!(tokens[0].text, "\u{00E9}");
+    }
+
+    #[test]
+    fn test_fcd() {
+        // Already in FCD form, it is neither composed nor decomposed
+        let tokens = token_stream_helper_raw("\u{00E9}e\u{0301}", Mode::FCD);
+        assert_eq!(tokens[0].text, "\u{00E9}e\u{0301}");
+    }
+
+    #[test]
+    fn test_from_name() -> Result<(), Box<dyn Error>> {
+        let text = "Ruß ＡＢＣ Ǆ";
+        let expected = token_stream_helper(text, Mode::NFKCCasefold);
+
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(ICUNormalizer2TokenFilter::from_name(
+                "nfkc_cf",
+                CompositionMode::Compose,
+            )?)
+            .build();
+        let mut token_stream = a.token_stream(text);
+        let mut tokens = vec![];
+        token_stream.process(&mut |token: &Token| tokens.push(token.clone()));
+
+        assert_eq!(tokens, expected);
+        assert_eq!(tokens[0].text, "russ");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_unknown_name() {
+        let result = ICUNormalizer2TokenFilter::from_name("unknown", CompositionMode::Compose);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_unknown_package() {
+        let result = ICUNormalizer2TokenFilter::from_package(
+            "/does/not/exist/package",
+            "utr30",
+            CompositionMode::Compose,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filter() -> Result<(), Box<dyn Error>> {
+        // The Kangxi radical `⼀` is a Han character, NFKC maps it to `一`
+        let text = "ｆｕｌｌ\u{2F00}";
+
+        let tokens = token_stream_helper_raw(text, Mode::NFKC);
+        assert_eq!(tokens[0].text, "full\u{4E00}");
+
+        let mut a = TextAnalyzer::builder(RawTokenizer::default())
+            .filter(ICUNormalizer2TokenFilter::new_with_filter(
+                Mode::NFKC,
+                "[^[:Han:]]",
+            )?)
+            .build();
+        let mut token_stream = a.token_stream(text);
+        let token = token_stream.next().expect("A token should be present.");
+        assert_eq!(token.text, "full\u{2F00}");
+        assert_eq!(None, token_stream.next());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_clones() -> Result<(), Box<dyn Error>> {
+        // The filtered normalizer is built once and shared by clones, on any thread
+        let analyzer = TextAnalyzer::builder(RawTokenizer::default())
+            .filter(ICUNormalizer2TokenFilter::new_with_filter(
+                Mode::NFKC,
+                "[^[:Han:]]",
+            )?)
+            .build();
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let mut analyzer = analyzer.clone();
+                std::thread::spawn(move || {
+                    let mut token_stream = analyzer.token_stream("ｆｕｌｌ\u{2F00}");
+                    token_stream.next().map(|token| token.text.clone())
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), Some("full\u{2F00}".to_string()));
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<(), Box<dyn Error>> {
+        let mode: Mode = serde_json::from_str(r#""NFKCCasefold""#)?;
+        assert_eq!(serde_json::to_string(&mode)?, r#""NFKCCasefold""#);
+
+        let result = token_stream_helper("Ｈｅｌｌｏ", mode);
+        assert_eq!(result[0].text, "hello".to_string());
+
+        Ok(())
+    }
 }
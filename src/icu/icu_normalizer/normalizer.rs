@@ -0,0 +1,187 @@
+//! Normalizers. They are got directly from ICU, so that FCC and FCD instances, filtered
+//! normalizers and folding are available, and so that they can be built once and shared
+//! by token streams of all threads.
+
+use std::ffi::CString;
+use std::sync::Arc;
+
+use rust_icu_sys as sys;
+use rust_icu_uchar::char_type;
+
+use super::super::ffi::{FilteredInstance, FrozenSet, NormalizerInstance};
+use super::super::Error;
+use super::{CompositionMode, Mode};
+
+/// Data and mode of the normalizer of an [ICUNormalizer2TokenFilter](super::ICUNormalizer2TokenFilter).
+#[derive(Clone, Debug)]
+pub(crate) enum Form {
+    /// One of the built-in modes.
+    Mode(Mode),
+    /// An instance loaded by the name of its data, like `nfkc_cf`, from ICU's own
+    /// data or from a data package.
+    Named {
+        package: Option<String>,
+        name: String,
+        mode: CompositionMode,
+    },
+}
+
+/// ICU normalizer of an [ICUNormalizer2TokenFilter](super::ICUNormalizer2TokenFilter).
+/// It is built once, clones share the same ICU instances.
+#[derive(Clone, Debug)]
+pub(crate) enum Normalizer {
+    /// Any [Form].
+    Raw(RawNormalizer),
+    /// Any form, restricted to the code points of a unicode set.
+    Filtered(Arc<FilteredNormalizer>),
+    /// Folding of case, compatibility forms and diacritics.
+    Folding(Arc<Folding>),
+}
+
+impl Normalizer {
+    pub(crate) fn normalize(&self, text: &str) -> Result<String, Error> {
+        match self {
+            Normalizer::Raw(normalizer) => normalizer.normalize(text),
+            Normalizer::Filtered(normalizer) => normalizer.normalize(text),
+            Normalizer::Folding(folding) => folding.normalize(text),
+        }
+    }
+
+    /// Whether `ch` is normalized, rather than left untouched by a unicode set.
+    fn contains(&self, ch: char) -> bool {
+        match self {
+            Normalizer::Raw(_) => true,
+            Normalizer::Filtered(normalizer) => normalizer.contains(ch),
+            Normalizer::Folding(folding) => folding.decompose.contains(ch),
+        }
+    }
+
+    /// Normalizer for a [Form] that only normalizes the code points of `unicode_set`
+    /// (a pattern like `[^[:Han:]]`), if any.
+    pub(crate) fn new(form: &Form, unicode_set: Option<&str>) -> Result<Self, Error> {
+        let base = match form {
+            Form::Mode(mode) => RawNormalizer::from_mode(*mode)?,
+            Form::Named {
+                package,
+                name,
+                mode,
+            } => RawNormalizer::from_name(package.as_deref(), name, *mode)?,
+        };
+        let normalizer = match unicode_set {
+            Some(unicode_set) => {
+                Normalizer::Filtered(Arc::new(FilteredNormalizer::new(base, unicode_set)?))
+            }
+            None => Normalizer::Raw(base),
+        };
+        Ok(normalizer)
+    }
+
+    /// Folding normalizer that only folds the code points of `unicode_set`, if any.
+    pub(crate) fn folding(unicode_set: Option<&str>) -> Result<Self, Error> {
+        let decompose = Form::Named {
+            package: None,
+            name: "nfkc_cf".to_string(),
+            mode: CompositionMode::Decompose,
+        };
+        Ok(Normalizer::Folding(Arc::new(Folding {
+            decompose: Normalizer::new(&decompose, unicode_set)?,
+            compose: Normalizer::new(&Form::Mode(Mode::NFC), unicode_set)?,
+        })))
+    }
+}
+
+/// ICU doesn't have Lucene's `utr30` folding data, folding is done in three steps :
+/// * compatibility decomposition with case folding (`nfkc_cf` data), which also
+///   removes default ignorable code points,
+/// * removal of nonspacing marks, the decomposed diacritics, if they are in the
+///   unicode set,
+/// * canonical composition of what's left.
+#[derive(Debug)]
+pub(crate) struct Folding {
+    decompose: Normalizer,
+    compose: Normalizer,
+}
+
+impl Folding {
+    fn normalize(&self, text: &str) -> Result<String, Error> {
+        let decomposed = self.decompose.normalize(text)?;
+        let stripped: String = decomposed
+            .chars()
+            .filter(|&ch| {
+                char_type(ch as sys::UChar32) != sys::UCharCategory::U_NON_SPACING_MARK
+                    || !self.decompose.contains(ch)
+            })
+            .collect();
+        self.compose.normalize(&stripped)
+    }
+}
+
+/// Name given to ICU.
+fn c_string(name: &str) -> Result<CString, Error> {
+    CString::new(name).map_err(|_| Error::Sys(sys::UErrorCode::U_ILLEGAL_ARGUMENT_ERROR))
+}
+
+/// A normalizer instance got from ICU data and a mode.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RawNormalizer {
+    instance: NormalizerInstance,
+}
+
+impl RawNormalizer {
+    /// Instance for a [Mode].
+    fn from_mode(mode: Mode) -> Result<Self, Error> {
+        use sys::UNormalization2Mode::*;
+
+        let (name, mode) = match mode {
+            Mode::NFC => ("nfc", UNORM2_COMPOSE),
+            Mode::NFD => ("nfc", UNORM2_DECOMPOSE),
+            Mode::NFKC => ("nfkc", UNORM2_COMPOSE),
+            Mode::NFKD => ("nfkc", UNORM2_DECOMPOSE),
+            Mode::NFKCCasefold => ("nfkc_cf", UNORM2_COMPOSE),
+            Mode::FCC => ("nfc", UNORM2_COMPOSE_CONTIGUOUS),
+            Mode::FCD => ("nfc", UNORM2_FCD),
+        };
+        let instance = NormalizerInstance::get(None, &c_string(name)?, mode)?;
+        Ok(Self { instance })
+    }
+
+    /// Instance of the data called `name`, from the data `package` or ICU's own data.
+    fn from_name(package: Option<&str>, name: &str, mode: CompositionMode) -> Result<Self, Error> {
+        let package = package.map(c_string).transpose()?;
+        let name = c_string(name)?;
+        let mode = match mode {
+            CompositionMode::Compose => sys::UNormalization2Mode::UNORM2_COMPOSE,
+            CompositionMode::Decompose => sys::UNormalization2Mode::UNORM2_DECOMPOSE,
+        };
+        let instance = NormalizerInstance::get(package.as_deref(), &name, mode)?;
+        Ok(Self { instance })
+    }
+
+    fn normalize(&self, text: &str) -> Result<String, Error> {
+        self.instance.normalize(text)
+    }
+}
+
+/// A normalizer that leaves the code points outside a unicode set untouched
+/// (ICU's `FilteredNormalizer2`). Unlike [RawNormalizer], it is owned and closed
+/// on drop.
+#[derive(Debug)]
+pub(crate) struct FilteredNormalizer {
+    instance: FilteredInstance,
+}
+
+impl FilteredNormalizer {
+    fn new(base: RawNormalizer, unicode_set: &str) -> Result<Self, Error> {
+        let set = FrozenSet::new(unicode_set)?;
+        let instance = FilteredInstance::new(base.instance, set)?;
+        Ok(Self { instance })
+    }
+
+    fn normalize(&self, text: &str) -> Result<String, Error> {
+        self.instance.normalize(text)
+    }
+
+    fn contains(&self, ch: char) -> bool {
+        self.instance.contains(ch)
+    }
+}
@@ -1,16 +1,16 @@
-use rust_icu_unorm2::UNormalizer;
 use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
 
 use super::super::Error;
-use super::{ICUNormalizer2FilterWrapper, Mode};
+use super::{CompositionMode, Form, ICUNormalizer2FilterWrapper, Mode, Normalizer};
 
 /// [TokenFilter] that converts text into a normal form.
-/// It supports all [Google's unicode normalization](https://docs.rs/rust_icu_unorm2/2.0.0/rust_icu_unorm2/struct.UNormalizer.html) using [Mode]:
+/// It supports all [ICU's unicode normalization](https://unicode-org.github.io/icu/userguide/transforms/normalization/) using [Mode]:
 /// * NFC
 /// * NFD
 /// * NFKC
 /// * NFKD
 /// * NFKC casefold
+/// * FCC and FCD
 ///
 /// See Wikipedia's [unicode normalization](https://en.wikipedia.org/wiki/Unicode_equivalence#Normalization) or
 /// [Unicode documentation](https://www.unicode.org/reports/tr15/) for more information.
@@ -26,6 +26,29 @@ use super::{ICUNormalizer2FilterWrapper, Mode};
 /// # }
 /// ```
 ///
+/// Use [new_with_filter](ICUNormalizer2TokenFilter::new_with_filter) to only normalize
+/// the code points of a [unicode set](https://unicode-org.github.io/icu/userguide/strings/unicodeset.html),
+/// here everything but Han characters :
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy_analysis_contrib::icu::{ICUNormalizer2TokenFilter, Mode};
+///
+/// let normalizer = ICUNormalizer2TokenFilter::new_with_filter(Mode::NFKC, "[^[:Han:]]")?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// Normalization data can also be loaded by name with
+/// [from_name](ICUNormalizer2TokenFilter::from_name) :
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy_analysis_contrib::icu::{CompositionMode, ICUNormalizer2TokenFilter};
+///
+/// let normalizer = ICUNormalizer2TokenFilter::from_name("nfkc_cf", CompositionMode::Compose)?;
+/// #     Ok(())
+/// # }
+/// ```
+///
 /// # Example
 ///
 /// Here is an example showing which tokens are produce
@@ -48,9 +71,37 @@ use super::{ICUNormalizer2FilterWrapper, Mode};
 /// #     Ok(())
 /// # }
 /// ```
-#[derive(Clone, Copy, Debug)]
+///
+/// Use [keep_original](ICUNormalizer2TokenFilter::keep_original) to also emit the original
+/// token, at the same position, when normalization changed it.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::icu::{ICUNormalizer2TokenFilter, Mode};
+///
+/// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+///    .filter(ICUNormalizer2TokenFilter::new(Mode::NFKCCasefold)?.keep_original(true))
+///    .build();
+/// let mut token_stream = tmp.token_stream("Café");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "café".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Café".to_string());
+/// assert_eq!(token.position, 0);
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
 pub struct ICUNormalizer2TokenFilter {
-    mode: Mode,
+    /// Built once, clones share the same ICU instances
+    normalizer: Normalizer,
+    keep_original: bool,
+    drop_empty: bool,
 }
 
 impl ICUNormalizer2TokenFilter {
@@ -60,14 +111,106 @@ impl ICUNormalizer2TokenFilter {
     ///
     /// * `mode` : Normalization algorithm.
     pub fn new(mode: Mode) -> Result<Self, Error> {
-        let _ = UNormalizer::try_from(mode)?;
-        Ok(mode.into())
+        Self::from_form(Form::Mode(mode))
+    }
+
+    /// Construct a new normalizer 2 token filter that only normalizes the code points
+    /// of a unicode set, the others are left untouched. It maps to ICU's `FilteredNormalizer2`.
+    ///
+    /// # Parameters :
+    ///
+    /// * `mode` : Normalization algorithm.
+    /// * `unicode_set` : pattern of the code points to normalize, for example `[^[:Han:]]`
+    ///   to leave Han characters untouched.
+    ///
+    /// The filtered normalizer is built here, once, and shared by all clones of the filter.
+    pub fn new_with_filter(mode: Mode, unicode_set: &str) -> Result<Self, Error> {
+        Ok(Normalizer::new(&Form::Mode(mode), Some(unicode_set))?.into())
+    }
+
+    /// Construct a new normalizer 2 token filter from the name of normalization data
+    /// built into ICU, rather than one of the built-in [Mode]. ICU provides `nfc`,
+    /// `nfkc` and `nfkc_cf`. Use [from_package](ICUNormalizer2TokenFilter::from_package)
+    /// for other data, like Lucene's `utr30`.
+    ///
+    /// # Parameters :
+    ///
+    /// * `name` : name of the data, for example `nfkc_cf` for [Mode::NFKCCasefold].
+    /// * `mode` : whether to compose or only decompose.
+    ///
+    /// It fails if ICU can't load the data.
+    pub fn from_name(name: &str, mode: CompositionMode) -> Result<Self, Error> {
+        Self::from_form(Form::Named {
+            package: None,
+            name: name.to_string(),
+            mode,
+        })
+    }
+
+    /// Construct a new normalizer 2 token filter from normalization data of an ICU
+    /// data package, for example data built with ICU's `gennorm2` tool.
+    ///
+    /// # Parameters :
+    ///
+    /// * `package` : the data package, as expected by ICU's `unorm2_getInstance`. It
+    ///   can be the path of the package (e.g. `/path/to/data/mypackage`), then ICU
+    ///   looks for the `.nrm` file in it.
+    /// * `name` : name of the data, for example `utr30` for `utr30.nrm`.
+    /// * `mode` : whether to compose or only decompose.
+    ///
+    /// It fails if ICU can't load the data.
+    pub fn from_package(package: &str, name: &str, mode: CompositionMode) -> Result<Self, Error> {
+        Self::from_form(Form::Named {
+            package: Some(package.to_string()),
+            name: name.to_string(),
+            mode,
+        })
+    }
+
+    /// Folding filter of [ICUFoldingTokenFilter](crate::icu::ICUFoldingTokenFilter).
+    pub(crate) fn folding(unicode_set: Option<&str>) -> Result<Self, Error> {
+        Ok(Normalizer::folding(unicode_set)?.into())
+    }
+
+    fn from_form(form: Form) -> Result<Self, Error> {
+        Ok(Normalizer::new(&form, None)?.into())
+    }
+
+    /// Also emit the original token, at the same position, when normalization
+    /// changed its text. The normalized token comes first.
+    ///
+    /// Default is `false`.
+    pub fn keep_original(mut self, keep_original: bool) -> Self {
+        self.keep_original = keep_original;
+        self
+    }
+
+    /// Remove tokens whose text is empty once normalized, for example a lone
+    /// combining grapheme joiner (`U+034F`) with [Mode::NFKCCasefold]. Removed
+    /// tokens leave a gap in positions, like stop words.
+    ///
+    /// Default is `false` : such tokens are emitted with an empty text.
+    pub fn drop_empty(mut self, drop_empty: bool) -> Self {
+        self.drop_empty = drop_empty;
+        self
+    }
+}
+
+impl TryFrom<Mode> for ICUNormalizer2TokenFilter {
+    type Error = Error;
+
+    fn try_from(mode: Mode) -> Result<Self, Self::Error> {
+        Self::new(mode)
     }
 }
 
-impl From<Mode> for ICUNormalizer2TokenFilter {
-    fn from(mode: Mode) -> Self {
-        ICUNormalizer2TokenFilter { mode }
+impl From<Normalizer> for ICUNormalizer2TokenFilter {
+    fn from(normalizer: Normalizer) -> Self {
+        ICUNormalizer2TokenFilter {
+            normalizer,
+            keep_original: false,
+            drop_empty: false,
+        }
     }
 }
 
@@ -75,6 +218,11 @@ impl TokenFilter for ICUNormalizer2TokenFilter {
     type Tokenizer<T: Tokenizer> = ICUNormalizer2FilterWrapper<T>;
 
     fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
-        ICUNormalizer2FilterWrapper::new(token_stream, self.mode)
+        ICUNormalizer2FilterWrapper::new(
+            token_stream,
+            self.normalizer,
+            self.keep_original,
+            self.drop_empty,
+        )
     }
 }
@@ -3,38 +3,62 @@
 
 use std::mem;
 
-use rust_icu_unorm2::UNormalizer;
 use tantivy_tokenizer_api::{Token, TokenStream};
 
+use super::Normalizer;
+
 #[derive(Debug)]
 pub struct ICUNormalizer2TokenStream<T> {
-    normalizer: UNormalizer,
+    normalizer: Normalizer,
     tail: T,
     temp: String,
+    keep_original: bool,
+    drop_empty: bool,
+    original: Option<String>,
 }
 
 impl<T> ICUNormalizer2TokenStream<T> {
-    pub(crate) fn new(tail: T, normalizer: UNormalizer) -> Self {
+    pub(crate) fn new(
+        tail: T,
+        normalizer: Normalizer,
+        keep_original: bool,
+        drop_empty: bool,
+    ) -> Self {
         Self {
             normalizer,
             tail,
             temp: String::with_capacity(100),
+            keep_original,
+            drop_empty,
+            original: None,
         }
     }
 }
 
 impl<T: TokenStream> TokenStream for ICUNormalizer2TokenStream<T> {
     fn advance(&mut self) -> bool {
-        let result = self.tail.advance();
-        if !result {
-            return false;
+        if let Some(original) = self.original.take() {
+            self.tail.token_mut().text = original;
+            return true;
         }
 
-        if let Ok(t) = self.normalizer.normalize(&self.tail.token().text) {
-            self.temp = t;
-            mem::swap(&mut self.tail.token_mut().text, &mut self.temp);
+        while self.tail.advance() {
+            if let Ok(t) = self.normalizer.normalize(&self.tail.token().text) {
+                self.temp = t;
+                mem::swap(&mut self.tail.token_mut().text, &mut self.temp);
+                if self.keep_original && self.temp != self.tail.token().text {
+                    self.original = Some(mem::take(&mut self.temp));
+                }
+            }
+
+            if !self.drop_empty || !self.tail.token().text.is_empty() {
+                return true;
+            }
+            // The original is dropped with the token
+            self.original = None;
         }
-        result
+
+        false
     }
 
     fn token(&self) -> &Token {
@@ -4,17 +4,29 @@
 
 use tantivy_tokenizer_api::Tokenizer;
 
-use super::{ICUNormalizer2TokenStream, Mode};
+use super::{ICUNormalizer2TokenStream, Normalizer};
 
 #[derive(Debug, Clone)]
 pub struct ICUNormalizer2FilterWrapper<T> {
-    mode: Mode,
+    normalizer: Normalizer,
+    keep_original: bool,
+    drop_empty: bool,
     inner: T,
 }
 
 impl<T> ICUNormalizer2FilterWrapper<T> {
-    pub(crate) fn new(inner: T, mode: Mode) -> Self {
-        Self { mode, inner }
+    pub(crate) fn new(
+        inner: T,
+        normalizer: Normalizer,
+        keep_original: bool,
+        drop_empty: bool,
+    ) -> Self {
+        Self {
+            normalizer,
+            keep_original,
+            drop_empty,
+            inner,
+        }
     }
 }
 
@@ -22,10 +34,11 @@ impl<T: Tokenizer> Tokenizer for ICUNormalizer2FilterWrapper<T> {
     type TokenStream<'a> = ICUNormalizer2TokenStream<T::TokenStream<'a>>;
 
     fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
-        // It's safe to unwrap here, we check that its work in token filter's new method
         ICUNormalizer2TokenStream::new(
             self.inner.token_stream(text),
-            self.mode.try_into().expect("Can't convert into normalizer"),
+            self.normalizer.clone(),
+            self.keep_original,
+            self.drop_empty,
         )
     }
 }
@@ -0,0 +1,30 @@
+//! Emoji detection. It's a port of Lucene's `BreakIteratorWrapper` check.
+
+use rust_icu_sys as sys;
+
+use super::super::ffi::has_binary_property;
+
+/// Characters that have the emoji property but that are emoji only if followed by a
+/// variation selector or a keycap (e.g. `#`, digits, `©`).
+fn is_emoji_rk(c: char) -> bool {
+    matches!(c, '#' | '*' | '0'..='9' | '©' | '®' | '™' | '〰' | '〽')
+}
+
+/// Check if a segment returned by the break iterator is an emoji or an emoji sequence
+/// (ZWJ sequences, regional indicators, keycaps, tag sequences, ...).
+pub(crate) fn is_emoji(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c)
+            if has_binary_property(c, sys::UProperty::UCHAR_EMOJI)
+                || has_binary_property(c, sys::UProperty::UCHAR_EXTENDED_PICTOGRAPHIC) =>
+        {
+            if is_emoji_rk(c) {
+                matches!(chars.next(), Some('\u{FE0F}') | Some('\u{20E3}'))
+            } else {
+                true
+            }
+        }
+        _ => false,
+    }
+}
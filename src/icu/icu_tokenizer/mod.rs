@@ -1,21 +1,43 @@
 //! This module provides a tokenizer that uses the same rules to break string into words.
-use token_stream::ICUTokenizerTokenStream;
-pub use tokenizer::ICUTokenizer;
+use std::path::PathBuf;
 
+pub(crate) use script::char_script;
+use thiserror::Error;
+pub(crate) use token_stream::ICUTokenizerTokenStream;
+pub use token_stream::ICUTokenizerWithScriptTokenStream;
+pub use tokenizer::{ICURulesTokenizer, ICUTokenizer, ICUTokenizerWithScript};
+
+mod emoji;
+mod script;
 mod token_stream;
 mod tokenizer;
 
 /// Default rules, copy from Lucene's binary rules
 const DEFAULT_RULES: &str = include_str!("breaking_rules/Default.rbbi");
 
-/*
 /// Myanmar rules, copy from Lucene's binary rules
-const MYANMAR_SYLLABLE_RULES: &str = std::include_str!("breaking_rules/MyanmarSyllable.rbbi");
-*/
+const MYANMAR_SYLLABLE_RULES: &str = include_str!("breaking_rules/MyanmarSyllable.rbbi");
+
+/// Errors raised when loading custom breaking rules.
+#[derive(Error, Debug)]
+pub enum ICURulesError {
+    /// The rules file can't be read.
+    #[error("Can't read breaking rules from '{}': {source}", .path.display())]
+    Io {
+        /// Path of the rules file.
+        path: PathBuf,
+        /// Cause of the failure.
+        source: std::io::Error,
+    },
+    /// ICU can't compile the rules. It contains the ICU error.
+    #[error("Invalid breaking rules: {0}")]
+    InvalidRules(#[from] super::Error),
+}
 
 #[cfg(test)]
 mod tests {
-    use tantivy::tokenizer::{Token, TokenStream};
+    use rust_icu_sys::UScriptCode;
+    use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
 
     /// Same tests as Lucene ICU tokenizer might be enough
     use super::*;
@@ -57,6 +79,50 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    /// Tripwire for quadratic behaviour while breaking large documents.
+    #[test]
+    fn test_huge_doc_performance() {
+        let words = 200_000;
+        let mut huge_doc = String::with_capacity(words * 13);
+        for i in 0..words {
+            huge_doc.push_str("testing ");
+            huge_doc.push_str(&(i % 10_000).to_string());
+            huge_doc.push(' ');
+        }
+
+        let start = std::time::Instant::now();
+        let tokenizer = &mut ICUTokenizerTokenStream::new(huge_doc.as_str());
+        let count = tokenizer.count();
+        let elapsed = start.elapsed();
+
+        assert_eq!(count, words * 2);
+        assert!(
+            elapsed < std::time::Duration::from_secs(10),
+            "Tokenizing {} bytes took {:?}",
+            huge_doc.len(),
+            elapsed
+        );
+    }
+
+    #[test]
+    fn test_script() {
+        let mut token_stream = ICUTokenizerWithScriptTokenStream::new("hello мир 123");
+        let mut result = vec![];
+        while token_stream.advance() {
+            result.push((
+                token_stream.token().text.clone(),
+                token_stream.last_script(),
+            ));
+        }
+        let expected = vec![
+            ("hello".to_string(), Some(UScriptCode::USCRIPT_LATIN)),
+            ("мир".to_string(), Some(UScriptCode::USCRIPT_CYRILLIC)),
+            ("123".to_string(), Some(UScriptCode::USCRIPT_COMMON)),
+        ];
+        assert_eq!(result, expected);
+        assert_eq!(token_stream.last_script(), None);
+    }
+
     #[test]
     fn test_armenian() {
         let tokenizer = &mut ICUTokenizerTokenStream::new("Վիքիպեդիայի 13 միլիոն հոդվածները (4,600` հայերեն վիքիպեդիայում) գրվել են կամավորների կողմից ու համարյա բոլոր հոդվածները կարող է խմբագրել ցանկաց մարդ ով կարող է բացել Վիքիպեդիայի կայքը։");
@@ -1182,6 +1248,7 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    /// Myanmar is broken into syllables, as opposed to dictionary break of သက်ဝင်|လှုပ်ရှား|စေ|ပြီး
     #[test]
     fn test_myanmar() {
         let tokenizer = &mut ICUTokenizerTokenStream::new("သက်ဝင်လှုပ်ရှားစေပြီး");
@@ -1189,29 +1256,43 @@ mod tests {
         let expected = vec![
             Token {
                 offset_from: 0,
-                offset_to: 6,
+                offset_to: 3,
                 position: 0,
-                text: "သက်ဝင်".to_string(),
+                text: "သက်".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 3,
+                offset_to: 6,
+                position: 1,
+                text: "ဝင်".to_string(),
                 position_length: 1,
             },
             Token {
                 offset_from: 6,
+                offset_to: 11,
+                position: 2,
+                text: "လှုပ်".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 11,
                 offset_to: 15,
-                position: 1,
-                text: "လှုပ်ရှား".to_string(),
+                position: 3,
+                text: "ရှား".to_string(),
                 position_length: 1,
             },
             Token {
                 offset_from: 15,
                 offset_to: 17,
-                position: 2,
+                position: 4,
                 text: "စေ".to_string(),
                 position_length: 1,
             },
             Token {
                 offset_from: 17,
                 offset_to: 21,
-                position: 3,
+                position: 5,
                 text: "ပြီး".to_string(),
                 position_length: 1,
             },
@@ -1219,6 +1300,142 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    /// Simple tests from "A Rule-based Syllable Segmentation of Myanmar Text"
+    #[test]
+    fn test_myanmar_syllable_c() {
+        let tokenizer = &mut ICUTokenizerTokenStream::new("ကက");
+        let result: Vec<Token> = tokenizer.collect();
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 1,
+                position: 0,
+                text: "က".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 1,
+                offset_to: 2,
+                position: 1,
+                text: "က".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_myanmar_syllable_cf() {
+        let tokenizer = &mut ICUTokenizerTokenStream::new("ကံကံ");
+        let result: Vec<Token> = tokenizer.collect();
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 2,
+                position: 0,
+                text: "ကံ".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 4,
+                position: 1,
+                text: "ကံ".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_myanmar_syllable_cca() {
+        let tokenizer = &mut ICUTokenizerTokenStream::new("ကင်ကင်");
+        let result: Vec<Token> = tokenizer.collect();
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "ကင်".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 3,
+                offset_to: 6,
+                position: 1,
+                text: "ကင်".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_myanmar_syllable_cv() {
+        let tokenizer = &mut ICUTokenizerTokenStream::new("ကာကာ");
+        let result: Vec<Token> = tokenizer.collect();
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 2,
+                position: 0,
+                text: "ကာ".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 2,
+                offset_to: 4,
+                position: 1,
+                text: "ကာ".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_builtin_rules_compile() {
+        assert!(script::BreakRules::Default.compiled().is_some());
+        assert!(script::BreakRules::MyanmarSyllable.compiled().is_some());
+    }
+
+    #[test]
+    fn test_myanmar_mixed_scripts() {
+        let tokenizer = &mut ICUTokenizerTokenStream::new("hello ကက world");
+        let result: Vec<Token> = tokenizer.collect();
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 5,
+                position: 0,
+                text: "hello".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 6,
+                offset_to: 7,
+                position: 1,
+                text: "က".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 8,
+                position: 2,
+                text: "က".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 14,
+                position: 3,
+                text: "world".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_thai() {
         let tokenizer =
@@ -2140,7 +2357,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn test_emoji() {
         let tokenizer = &mut ICUTokenizerTokenStream::new("💩 💩💩");
         let result: Vec<Token> = tokenizer.collect();
@@ -2171,7 +2387,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn test_emoji_sequence() {
         let tokenizer = &mut ICUTokenizerTokenStream::new("👩‍❤️‍👩");
         let result: Vec<Token> = tokenizer.collect();
@@ -2186,7 +2401,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn test_emoji_sequence_with_modifier() {
         let tokenizer = &mut ICUTokenizerTokenStream::new("👨🏼‍⚕️");
         let result: Vec<Token> = tokenizer.collect();
@@ -2201,7 +2415,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn test_emoji_regional_indicator() {
         let tokenizer = &mut ICUTokenizerTokenStream::new("🇺🇸🇺🇸");
         let result: Vec<Token> = tokenizer.collect();
@@ -2225,7 +2438,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn test_emoji_variation_sequence() {
         let tokenizer = &mut ICUTokenizerTokenStream::new("#️⃣");
         let result: Vec<Token> = tokenizer.collect();
@@ -2233,14 +2445,13 @@ mod tests {
             offset_from: 0,
             offset_to: 3,
             position: 0,
-            text: "3️⃣".to_string(),
+            text: "#️⃣".to_string(),
             position_length: 1,
         }];
         assert_eq!(result, expected);
     }
 
     #[test]
-    #[ignore]
     fn test_emoji_tag_sequence() {
         let tokenizer = &mut ICUTokenizerTokenStream::new("🏴󠁧󠁢󠁥󠁮󠁧󠁿");
         let result: Vec<Token> = tokenizer.collect();
@@ -2255,7 +2466,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn test_emoji_tokenization() {
         let tokenizer = &mut ICUTokenizerTokenStream::new("poo💩poo");
         let result: Vec<Token> = tokenizer.collect();
@@ -2318,4 +2528,66 @@ mod tests {
         ];
         assert_eq!(result, expected);
     }
+
+    fn texts<T: TokenStream>(mut token_stream: T) -> Vec<String> {
+        let mut result = Vec::new();
+        while token_stream.advance() {
+            result.push(token_stream.token().text.clone());
+        }
+        result
+    }
+
+    #[test]
+    fn test_custom_rules_file() -> Result<(), ICURulesError> {
+        let mut tokenizer = ICURulesTokenizer::from_file("./test_assets/rbbi/hyphenated.rbbi")?;
+        let result = texts(tokenizer.token_stream("Wi-Fi and e-mail, 42"));
+        assert_eq!(result, vec!["Wi-Fi", "and", "e-mail", "42"]);
+
+        // Default rules split hyphenated words
+        let result = texts(ICUTokenizer.token_stream("Wi-Fi and e-mail, 42"));
+        assert_eq!(result, vec!["Wi", "Fi", "and", "e", "mail", "42"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_rules_offsets() -> Result<(), ICURulesError> {
+        let mut tokenizer = ICURulesTokenizer::from_file("./test_assets/rbbi/hyphenated.rbbi")?;
+        let mut token_stream = tokenizer.token_stream("été x-y");
+        let mut result = Vec::new();
+        while token_stream.advance() {
+            result.push(token_stream.token().clone());
+        }
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "été".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 7,
+                position: 1,
+                text: "x-y".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let result = ICURulesTokenizer::new("$Alnum = [\\p{L};");
+        assert!(matches!(result, Err(ICURulesError::InvalidRules(_))));
+    }
+
+    #[test]
+    fn test_missing_rules_file() {
+        let result = ICURulesTokenizer::from_file("./test_assets/rbbi/missing.rbbi");
+        assert!(matches!(result, Err(ICURulesError::Io { .. })));
+    }
 }
@@ -0,0 +1,181 @@
+//! Script detection of tokens.
+
+use std::sync::OnceLock;
+
+use rust_icu_sys as sys;
+use rust_icu_ubrk::UBreakIterator;
+
+use super::super::{ffi, Error};
+
+/// Breaking rules compiled by ICU. Break iterators are opened from the compiled
+/// rules, so rules are not compiled again for each text.
+pub(crate) struct CompiledRules(Vec<u8>);
+
+impl std::fmt::Debug for CompiledRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CompiledRules").field(&self.0.len()).finish()
+    }
+}
+
+impl CompiledRules {
+    /// Compile the source of the rules. It fails if the rules are invalid.
+    pub(crate) fn compile(rules: &str) -> Result<Self, Error> {
+        let breaking_iterator = UBreakIterator::try_new_rules(rules, "")?;
+        Ok(Self(breaking_iterator.get_binary_rules()?))
+    }
+
+    /// Open a break iterator on `text`.
+    pub(crate) fn iterator(&self, text: &str) -> Result<UBreakIterator, Error> {
+        UBreakIterator::try_new_binary_rules(&self.0, text)
+    }
+}
+
+/// Rules used to break a run of text into words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BreakRules {
+    /// [Default rules](super::DEFAULT_RULES). They also handle scripts that need a
+    /// dictionary, like Khmer, Lao or Thai.
+    Default,
+    /// [Myanmar syllable rules](super::MYANMAR_SYLLABLE_RULES).
+    MyanmarSyllable,
+}
+
+impl BreakRules {
+    /// Rules to apply on a script. It's [None] for `Common` and `Inherited` as
+    /// they don't decide rules on their own.
+    fn from_script(script: sys::UScriptCode) -> Option<Self> {
+        match script {
+            sys::UScriptCode::USCRIPT_COMMON | sys::UScriptCode::USCRIPT_INHERITED => None,
+            sys::UScriptCode::USCRIPT_MYANMAR => Some(BreakRules::MyanmarSyllable),
+            _ => Some(BreakRules::Default),
+        }
+    }
+
+    /// Get the rules source.
+    pub(crate) fn rules(self) -> &'static str {
+        match self {
+            BreakRules::Default => super::DEFAULT_RULES,
+            BreakRules::MyanmarSyllable => super::MYANMAR_SYLLABLE_RULES,
+        }
+    }
+
+    /// Get the compiled rules. They are compiled once and shared by every tokenizer.
+    /// It's [None] only if ICU can't compile them, which tests rule out.
+    pub(crate) fn compiled(self) -> Option<&'static CompiledRules> {
+        static DEFAULT: OnceLock<Option<CompiledRules>> = OnceLock::new();
+        static MYANMAR_SYLLABLE: OnceLock<Option<CompiledRules>> = OnceLock::new();
+
+        let compiled = match self {
+            BreakRules::Default => &DEFAULT,
+            BreakRules::MyanmarSyllable => &MYANMAR_SYLLABLE,
+        };
+        compiled
+            .get_or_init(|| CompiledRules::compile(self.rules()).ok())
+            .as_ref()
+    }
+}
+
+/// A run of text that is broken with the same rules.
+#[derive(Debug)]
+pub(crate) struct ScriptRun<'a> {
+    pub(crate) text: &'a str,
+    /// Offset, in UTF-16 code units like ICU, of the run in the whole text.
+    pub(crate) offset: usize,
+    pub(crate) rules: BreakRules,
+}
+
+/// Split a text into runs of [ScriptRun]. `Common` and `Inherited` characters (spaces,
+/// punctuation, digits, ...) are kept in the current run.
+#[derive(Debug)]
+pub(crate) struct ScriptRuns<'a> {
+    text: &'a str,
+    offset: usize,
+    /// Split the text by script, otherwise the whole text is a single run.
+    split: bool,
+}
+
+impl<'a> ScriptRuns<'a> {
+    /// The whole text as a single run.
+    pub(crate) fn single(text: &'a str) -> Self {
+        ScriptRuns {
+            text,
+            offset: 0,
+            split: false,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScriptRuns<'a> {
+    fn from(text: &'a str) -> Self {
+        ScriptRuns {
+            text,
+            offset: 0,
+            split: true,
+        }
+    }
+}
+
+impl<'a> Iterator for ScriptRuns<'a> {
+    type Item = ScriptRun<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.text.is_empty() {
+            return None;
+        }
+
+        if !self.split {
+            let run = std::mem::take(&mut self.text);
+            return Some(ScriptRun {
+                text: run,
+                offset: self.offset,
+                rules: BreakRules::Default,
+            });
+        }
+
+        let mut rules = None;
+        let mut end = self.text.len();
+        let mut length = 0;
+        for (index, c) in self.text.char_indices() {
+            match (rules, BreakRules::from_script(char_script(c))) {
+                (None, Some(char_rules)) => rules = Some(char_rules),
+                (Some(run_rules), Some(char_rules)) if run_rules != char_rules => {
+                    end = index;
+                    break;
+                }
+                _ => {}
+            }
+            length += c.len_utf16();
+        }
+
+        let (run, rest) = self.text.split_at(end);
+        let offset = self.offset;
+        self.text = rest;
+        self.offset += length;
+
+        Some(ScriptRun {
+            text: run,
+            offset,
+            rules: rules.unwrap_or(BreakRules::Default),
+        })
+    }
+}
+
+/// Get the script of a character. [Common](sys::UScriptCode::USCRIPT_COMMON) is
+/// returned if ICU fails.
+pub(crate) fn char_script(c: char) -> sys::UScriptCode {
+    ffi::script(c).unwrap_or(sys::UScriptCode::USCRIPT_COMMON)
+}
+
+/// Get the script of a token : the script of the first character that
+/// is neither [Common](sys::UScriptCode::USCRIPT_COMMON) nor
+/// [Inherited](sys::UScriptCode::USCRIPT_INHERITED), like Lucene does.
+/// If there is no such character, it's `Common`.
+pub(crate) fn token_script(text: &str) -> sys::UScriptCode {
+    text.chars()
+        .map(char_script)
+        .find(|script| {
+            *script != sys::UScriptCode::USCRIPT_COMMON
+                && *script != sys::UScriptCode::USCRIPT_INHERITED
+        })
+        .unwrap_or(sys::UScriptCode::USCRIPT_COMMON)
+}
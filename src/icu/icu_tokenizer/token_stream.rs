@@ -1,17 +1,34 @@
-use std::str::Chars;
-
+use rust_icu_sys::UScriptCode;
 use rust_icu_ubrk::UBreakIterator;
 use tantivy_tokenizer_api::{Token, TokenStream};
 
+use super::emoji::is_emoji;
+use super::script::{token_script, BreakRules, CompiledRules, ScriptRuns};
+
+/// Break text into words. Text is first split into runs of scripts, then each
+/// run is broken using the rules of its script.
+///
+/// Like ICU, indexes are in UTF-16 code units.
 struct ICUBreakingWord<'a> {
-    text: Chars<'a>,
-    default_breaking_iterator: UBreakIterator,
+    runs: ScriptRuns<'a>,
+    /// Rules that replace the rules of the scripts.
+    custom_rules: Option<&'a CompiledRules>,
+    /// Text of the current run.
+    text: Utf16Cursor<'a>,
+    offset: usize,
+    /// Break iterators already opened, one for each rules. They are reused
+    /// for the following runs broken with the same rules.
+    breaking_iterators: Vec<(BreakRules, UBreakIterator)>,
+    /// Index of the break iterator of the current run.
+    current: Option<usize>,
 }
 
 impl std::fmt::Debug for ICUBreakingWord<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ICUBreakingWord")
-            .field("text", &self.text)
+            .field("runs", &self.runs)
+            .field("text", &self.text.text)
+            .field("offset", &self.offset)
             .finish()
     }
 }
@@ -19,47 +36,145 @@ impl std::fmt::Debug for ICUBreakingWord<'_> {
 impl<'a> From<&'a str> for ICUBreakingWord<'a> {
     fn from(text: &'a str) -> Self {
         ICUBreakingWord {
-            text: text.chars(),
-            default_breaking_iterator: UBreakIterator::try_new_rules(super::DEFAULT_RULES, text)
-                .expect("Can't read default rules."),
+            runs: ScriptRuns::from(text),
+            custom_rules: None,
+            text: Utf16Cursor::new(""),
+            offset: 0,
+            breaking_iterators: Vec::new(),
+            current: None,
         }
     }
 }
 
-impl Iterator for ICUBreakingWord<'_> {
-    type Item = (String, usize, usize);
+/// Converts the UTF-16 indexes given by ICU into substrings of the text. As break
+/// indexes only move forward, the cursor keeps the last position it reached, so the
+/// text is walked once instead of from its start for every segment.
+#[derive(Clone, Copy, Debug)]
+struct Utf16Cursor<'a> {
+    text: &'a str,
+    /// Byte index of the cursor.
+    byte: usize,
+    /// UTF-16 index of the cursor.
+    unit: usize,
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
+impl<'a> Utf16Cursor<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            byte: 0,
+            unit: 0,
+        }
+    }
+
+    /// Move the cursor to the UTF-16 `index` and get the byte index.
+    fn seek(&mut self, index: usize) -> usize {
+        if index < self.unit {
+            // Never happens with a break iterator, but stay correct
+            *self = Self::new(self.text);
+        }
+        for c in self.text[self.byte..].chars() {
+            if self.unit >= index {
+                break;
+            }
+            self.unit += c.len_utf16();
+            self.byte += c.len_utf8();
+        }
+
+        self.byte
+    }
+
+    /// Get the substring between two UTF-16 indexes. The cursor stays at `start`,
+    /// as the next segment starts at `end` or after it.
+    fn slice(&mut self, start: usize, end: usize) -> &'a str {
+        let from = self.seek(start);
+        let mut end_cursor = *self;
+        let to = end_cursor.seek(end);
+        &self.text[from..to]
+    }
+}
+
+impl<'a> ICUBreakingWord<'a> {
+    /// Break the whole text with the given rules.
+    fn with_rules(text: &'a str, rules: &'a CompiledRules) -> Self {
+        ICUBreakingWord {
+            runs: ScriptRuns::single(text),
+            custom_rules: Some(rules),
+            text: Utf16Cursor::new(""),
+            offset: 0,
+            breaking_iterators: Vec::new(),
+            current: None,
+        }
+    }
+
+    /// Index of the break iterator of `rules`, set on `text`. The iterator is opened
+    /// the first time the rules are needed. It's [None] if ICU fails, then the run
+    /// is skipped rather than failing the whole indexing.
+    fn breaking_iterator(&mut self, rules: BreakRules, text: &str) -> Option<usize> {
+        if let Some(index) = self
+            .breaking_iterators
+            .iter()
+            .position(|(r, _)| *r == rules)
+        {
+            self.breaking_iterators[index].1.set_text(text).ok()?;
+            return Some(index);
+        }
+
+        let compiled = self.custom_rules.or_else(|| rules.compiled())?;
+        let breaking_iterator = compiled.iterator(text).ok()?;
+        self.breaking_iterators.push((rules, breaking_iterator));
+        Some(self.breaking_iterators.len() - 1)
+    }
+
+    /// Get the next word of the current run. Indexes are relative to the run.
+    fn next_word(
+        breaking_iterator: &mut UBreakIterator,
+        text: &mut Utf16Cursor,
+    ) -> Option<(String, usize, usize)> {
         // It is a port in Rust of Lucene algorithm
         let mut cont = true;
-        let mut start = self.default_breaking_iterator.current();
-        let mut end = self.default_breaking_iterator.next();
+        let mut start = breaking_iterator.current();
+        let mut end = breaking_iterator.next();
         while cont && end.is_some() {
-            if end.is_some() && self.default_breaking_iterator.get_rule_status() == 0 {
-                start = end.unwrap();
-                end = self.default_breaking_iterator.next();
+            if let Some(index) = end {
+                // Emoji have no rule status, but we keep them like Lucene does
+                if breaking_iterator.get_rule_status() == 0
+                    && !is_emoji(text.slice(start as usize, index as usize))
+                {
+                    start = index;
+                    end = breaking_iterator.next();
+                }
             }
             if let Some(index) = end {
-                cont = !self
-                    .text
-                    .clone()
-                    .take(index as usize)
-                    .skip(start as usize)
-                    .any(char::is_alphanumeric);
+                let word = text.slice(start as usize, index as usize);
+                cont = !(word.chars().any(char::is_alphanumeric) || is_emoji(word));
             }
         }
 
-        match end {
-            None => None,
-            Some(index) => {
-                let substring: String = self
-                    .text
-                    .clone()
-                    .take(index as usize)
-                    .skip(start as usize)
-                    .collect();
-                Some((substring, start as usize, index as usize))
+        end.map(|index| {
+            let substring = text.slice(start as usize, index as usize).to_string();
+            (substring, start as usize, index as usize)
+        })
+    }
+}
+
+impl Iterator for ICUBreakingWord<'_> {
+    type Item = (String, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(index) = self.current {
+                let breaking_iterator = &mut self.breaking_iterators[index].1;
+                if let Some((word, start, end)) = Self::next_word(breaking_iterator, &mut self.text)
+                {
+                    return Some((word, start + self.offset, end + self.offset));
+                }
             }
+
+            let run = self.runs.next()?;
+            self.text = Utf16Cursor::new(run.text);
+            self.offset = run.offset;
+            self.current = self.breaking_iterator(run.rules, run.text);
         }
     }
 }
@@ -77,6 +192,14 @@ impl<'a> ICUTokenizerTokenStream<'a> {
             token: Token::default(),
         }
     }
+
+    /// Token stream that breaks the text with the given compiled rules.
+    pub(crate) fn with_rules(text: &'a str, rules: &'a CompiledRules) -> Self {
+        ICUTokenizerTokenStream {
+            breaking_word: ICUBreakingWord::with_rules(text, rules),
+            token: Token::default(),
+        }
+    }
 }
 
 impl TokenStream for ICUTokenizerTokenStream<'_> {
@@ -103,3 +226,47 @@ impl TokenStream for ICUTokenizerTokenStream<'_> {
         &mut self.token
     }
 }
+
+/// Token stream of [ICUTokenizerWithScript](super::ICUTokenizerWithScript). It
+/// works like the [ICUTokenizer](super::ICUTokenizer)'s one, but it also keeps the
+/// script of the last token.
+#[derive(Debug)]
+pub struct ICUTokenizerWithScriptTokenStream<'a> {
+    inner: ICUTokenizerTokenStream<'a>,
+    script: Option<UScriptCode>,
+}
+
+impl<'a> ICUTokenizerWithScriptTokenStream<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self {
+            inner: ICUTokenizerTokenStream::new(text),
+            script: None,
+        }
+    }
+
+    /// Script of the token the stream is on. It is [None] before the first
+    /// call to [advance](TokenStream::advance) or once the stream is exhausted.
+    pub fn last_script(&self) -> Option<UScriptCode> {
+        self.script
+    }
+}
+
+impl TokenStream for ICUTokenizerWithScriptTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        let result = self.inner.advance();
+        self.script = if result {
+            Some(token_script(&self.inner.token().text))
+        } else {
+            None
+        };
+        result
+    }
+
+    fn token(&self) -> &Token {
+        self.inner.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.inner.token_mut()
+    }
+}
@@ -1,8 +1,19 @@
+use std::path::Path;
+use std::sync::Arc;
+
 use tantivy_tokenizer_api::Tokenizer;
 
-use super::ICUTokenizerTokenStream;
+use super::script::CompiledRules;
+use super::{ICURulesError, ICUTokenizerTokenStream, ICUTokenizerWithScriptTokenStream};
 
 /// ICU [Tokenizer]. It does not (yet ?) work as Lucene's counterpart.
+///
+/// Text is split into runs of scripts and each run is broken with rules specific
+/// to its script. Myanmar is broken into syllables, other scripts use the default rules.
+/// Emoji and emoji sequences are kept as tokens.
+///
+/// Offsets are in UTF-16 code units, as Lucene's.
+///
 /// Getting a tokenizer is simple :
 /// ```rust
 /// use tantivy_analysis_contrib::icu::ICUTokenizer;
@@ -56,3 +67,113 @@ impl Tokenizer for ICUTokenizer {
         ICUTokenizerTokenStream::new(text)
     }
 }
+
+/// ICU [Tokenizer] that also computes the script of each token. It produces the same
+/// tokens as [ICUTokenizer].
+///
+/// As a [Token](tantivy_tokenizer_api::Token) can't carry the script, it is available
+/// through [last_script](ICUTokenizerWithScriptTokenStream::last_script) of the token
+/// stream. It allows, for example, to apply a different stemming on Latin and Cyrillic
+/// tokens of the same document. The script of a token is the script of its first character
+/// that is neither `Common` nor `Inherited`.
+///
+/// ```rust
+/// use tantivy::tokenizer::{TokenStream, Tokenizer};
+/// use tantivy_analysis_contrib::icu::{ICUTokenizerWithScript, UScriptCode};
+///
+/// let mut tokenizer = ICUTokenizerWithScript;
+/// let mut token_stream = tokenizer.token_stream("hello мир");
+///
+/// assert!(token_stream.advance());
+/// assert_eq!(token_stream.token().text, "hello".to_string());
+/// assert_eq!(token_stream.last_script(), Some(UScriptCode::USCRIPT_LATIN));
+///
+/// assert!(token_stream.advance());
+/// assert_eq!(token_stream.token().text, "мир".to_string());
+/// assert_eq!(token_stream.last_script(), Some(UScriptCode::USCRIPT_CYRILLIC));
+///
+/// assert!(!token_stream.advance());
+/// assert_eq!(token_stream.last_script(), None);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ICUTokenizerWithScript;
+
+impl Tokenizer for ICUTokenizerWithScript {
+    type TokenStream<'a> = ICUTokenizerWithScriptTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        ICUTokenizerWithScriptTokenStream::new(text)
+    }
+}
+
+/// ICU [Tokenizer] that breaks text with custom rules instead of the rules of
+/// [ICUTokenizer]. Rules use the
+/// [ICU break rules syntax](https://unicode-org.github.io/icu/userguide/boundaryanalysis/break-rules.html)
+/// and are usually stored in a `.rbbi` file, so they can be changed without changing
+/// the code.
+///
+/// The whole text is broken with the custom rules, whatever its scripts. Like with
+/// [ICUTokenizer], a segment is a token if its rule has a non-zero status (e.g. `{200}`),
+/// if it is an emoji or if it contains a letter or a digit. Offsets are in UTF-16 code units.
+///
+/// Rules are compiled once, when the tokenizer is created, so invalid rules are reported
+/// at that time. Clones of the tokenizer share the compiled rules.
+///
+/// # Example
+///
+/// These rules keep hyphenated words together, where [ICUTokenizer] would split them.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::icu::ICURulesTokenizer;
+///
+/// let rules = r"
+/// $Alnum = [\p{L}\p{N}];
+/// $Alnum+ ([\-] $Alnum+)* {200};
+/// ";
+/// let mut tmp = TextAnalyzer::builder(ICURulesTokenizer::new(rules)?).build();
+/// let mut token_stream = tmp.token_stream("wi-fi rocks");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "wi-fi".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "rocks".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ICURulesTokenizer {
+    rules: Arc<CompiledRules>,
+}
+
+impl ICURulesTokenizer {
+    /// Construct a new [ICURulesTokenizer] from the source of the rules.
+    pub fn new(rules: &str) -> Result<Self, ICURulesError> {
+        // Rules are compiled once, errors are reported now rather than while tokenizing
+        Ok(Self {
+            rules: Arc::new(CompiledRules::compile(rules)?),
+        })
+    }
+
+    /// Construct a new [ICURulesTokenizer] from a rules file, usually a `.rbbi` file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ICURulesError> {
+        let path = path.as_ref();
+        let rules = std::fs::read_to_string(path).map_err(|source| ICURulesError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::new(&rules)
+    }
+}
+
+impl Tokenizer for ICURulesTokenizer {
+    type TokenStream<'a> = ICUTokenizerTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        ICUTokenizerTokenStream::with_rules(text, &self.rules)
+    }
+}
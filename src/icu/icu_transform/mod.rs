@@ -1,14 +1,18 @@
 use rust_icu_sys as sys;
+use thiserror::Error;
 pub use token_filter::ICUTransformTokenFilter;
 use token_stream::ICUTransformTokenStream;
+use transliterator::Transliterator;
 use wrapper::ICUTransformFilterWrapper;
 
 mod token_filter;
 mod token_stream;
+mod transliterator;
 mod wrapper;
 
 /// Direction
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     /// Forward
     Forward,
@@ -25,9 +29,25 @@ impl From<Direction> for sys::UTransDirection {
     }
 }
 
+/// Why ICU can't compile a transform, with the position of the error in the rules
+/// (or in the compound id if there are no rules).
+#[derive(Error, Debug)]
+#[error(
+    "Can't compile transform '{compound_id}' ({code:?}) at offset {offset}, after '{pre_context}' and before '{post_context}'"
+)]
+struct TransformSyntaxError {
+    compound_id: String,
+    code: sys::UErrorCode,
+    offset: i32,
+    pre_context: String,
+    post_context: String,
+}
+
 #[cfg(test)]
 mod tests {
-    use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+    use tantivy::tokenizer::{
+        RawTokenizer, TextAnalyzer, Token, TokenFilter, TokenStream, Tokenizer, WhitespaceTokenizer,
+    };
 
     use super::*;
 
@@ -53,6 +73,39 @@ mod tests {
         tokens
     }
 
+    fn token_stream_helper_split(
+        text: &str,
+        compound_id: &str,
+        split_on_whitespace: bool,
+    ) -> Vec<Token> {
+        token_stream_helper_options(text, compound_id, split_on_whitespace, false)
+    }
+
+    fn token_stream_helper_options(
+        text: &str,
+        compound_id: &str,
+        split_on_whitespace: bool,
+        keep_original: bool,
+    ) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(
+                ICUTransformTokenFilter::new(compound_id.to_string(), None, Direction::Forward)
+                    .unwrap()
+                    .split_on_whitespace(split_on_whitespace)
+                    .keep_original(keep_original),
+            )
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
     #[test]
     fn test_basic_functionality() {
         let tokens =
@@ -200,4 +253,224 @@ mod tests {
         }];
         assert_eq!(tokens, expected);
     }
+
+    #[test]
+    fn test_no_split() {
+        let tokens = token_stream_helper_split("中国 人", "Han-Latin", false);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "zhōng guó".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 10,
+                position: 1,
+                text: "rén".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_split() {
+        let tokens = token_stream_helper_split("中国 人", "Han-Latin", true);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "zhōng".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 1,
+                text: "guó".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 10,
+                position: 2,
+                text: "rén".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_keep_original() {
+        let tokens = token_stream_helper_options("中国 abc", "Han-Latin; Latin-ASCII", false, true);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "中国".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "zhong guo".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 10,
+                position: 1,
+                text: "abc".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_keep_original_split() {
+        let tokens = token_stream_helper_options("中国 人", "Han-Latin", true, true);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "中国".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "zhōng".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 1,
+                text: "guó".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 10,
+                position: 2,
+                text: "人".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 10,
+                position: 2,
+                text: "rén".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        // The set is never closed
+        let result = ICUTransformTokenFilter::new(
+            "test".to_string(),
+            Some("a > b; [c > d;".to_string()),
+            Direction::Forward,
+        );
+        let error = result.expect_err("Rules should be invalid.");
+        let message = error.to_string();
+        assert!(message.contains("'test'"), "{message}");
+        assert!(message.contains("at offset"), "{message}");
+    }
+
+    #[test]
+    fn test_invalid_compound_id() {
+        let result =
+            ICUTransformTokenFilter::new("Not-A-Transform".to_string(), None, Direction::Forward);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_transliterator_is_shared() {
+        let filter =
+            ICUTransformTokenFilter::new("Any-Latin".to_string(), None, Direction::Forward)
+                .unwrap();
+        let mut wrapper = filter.clone().transform(RawTokenizer::default());
+        assert!(filter.transform.shares_compiled(&wrapper.transform));
+
+        let first = {
+            let mut token_stream = wrapper.token_stream("Αλφα");
+            assert!(token_stream.advance());
+            token_stream.transform.instance()
+        };
+        let second = {
+            let mut token_stream = wrapper.token_stream("βητα");
+            assert!(token_stream.advance());
+            token_stream.transform.instance()
+        };
+        assert!(first.is_some());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_clones_in_threads() {
+        let analyzer = TextAnalyzer::builder(RawTokenizer::default())
+            .filter(
+                ICUTransformTokenFilter::new("Greek-Latin".to_string(), None, Direction::Forward)
+                    .unwrap(),
+            )
+            .build();
+
+        // Each clone has its own transliterator
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let mut analyzer = analyzer.clone();
+                std::thread::spawn(move || {
+                    let mut result = vec![];
+                    for _ in 0..100 {
+                        let mut token_stream = analyzer.token_stream("Κατάλογος");
+                        token_stream.process(&mut |token: &Token| result.push(token.text.clone()));
+                    }
+                    result
+                })
+            })
+            .collect();
+        for handle in handles {
+            let result = handle.join().unwrap();
+            assert_eq!(result, vec!["Katálogos".to_string(); 100]);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let filter: ICUTransformTokenFilter = serde_json::from_str(
+            r#"{"compound_id": "Any-Latin; NFD; [:Nonspacing Mark:] Remove; NFC", "direction": "Forward"}"#,
+        )
+        .unwrap();
+        let json = serde_json::to_string(&filter).unwrap();
+        let filter: ICUTransformTokenFilter = serde_json::from_str(&json).unwrap();
+
+        let mut a = TextAnalyzer::builder(RawTokenizer::default())
+            .filter(filter)
+            .build();
+        let mut token_stream = a.token_stream("中国");
+        let mut result = vec![];
+        token_stream.process(&mut |token: &Token| result.push(token.text.clone()));
+        assert_eq!(result, vec!["zhong guo"]);
+
+        // The transliterator is compiled when deserializing
+        let result = serde_json::from_str::<ICUTransformTokenFilter>(
+            r#"{"compound_id": "Not-A-Transform", "direction": "Forward"}"#,
+        );
+        assert!(result.is_err());
+    }
 }
@@ -1,8 +1,7 @@
-use rust_icu_utrans as utrans;
 use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
 
 use super::super::Error;
-use super::{Direction, ICUTransformFilterWrapper};
+use super::{Direction, ICUTransformFilterWrapper, Transliterator};
 
 /// This [TokenFilter] allow to transform text into another,
 /// for example, to performe transliteration.
@@ -20,6 +19,11 @@ use super::{Direction, ICUTransformFilterWrapper};
 /// # }
 /// ```
 ///
+/// The transliterator is compiled once, when the filter is created, and shared by all
+/// clones of the filter and of analyzers that contain it. Each clone makes its own copy
+/// of the compiled transliterator the first time it transforms a token, so indexing
+/// threads don't wait for each other. Token streams of an analyzer reuse that copy.
+///
 /// # Example
 ///
 /// Here is an example of transform that converts greek letters into latin letters
@@ -44,14 +48,64 @@ use super::{Direction, ICUTransformFilterWrapper};
 /// assert_eq!(None, token_stream.next());
 /// #     Ok(())
 /// # }
+/// ```
+///
+/// Some transforms (e.g. `Han-Latin`) output several words separated by spaces. By default,
+/// the transformed text is kept as a single token, use
+/// [split_on_whitespace](ICUTransformTokenFilter::split_on_whitespace) to emit each word
+/// as a separate token at consecutive positions.
+///
+/// Use [keep_original](ICUTransformTokenFilter::keep_original) to also index the
+/// original token, at the same position as the transformed one, so that both scripts
+/// match.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::icu::{Direction, ICUTransformTokenFilter};
+///
+/// let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+///    .filter(ICUTransformTokenFilter::new(
+///       "Han-Latin".to_string(),
+///       None,
+///       Direction::Forward
+///    )?.split_on_whitespace(true))
+///    .build();
+/// let mut token_stream = tmp.token_stream("中国");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "zhōng".to_string());
+/// assert_eq!(token.position, 0);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "guó".to_string());
+/// assert_eq!(token.position, 1);
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// With the `serde` feature, the filter is serialized as its `compound_id`, `rules`
+/// and `direction`, plus its options. The transliterator is compiled again when it
+/// is deserialized, which fails if the transform is invalid.
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "TransformConfig", into = "TransformConfig")
+)]
 pub struct ICUTransformTokenFilter {
-    /// [Compound transform](https://unicode-org.github.io/icu/userguide/transforms/general/#compound-ids)
+    /// Compiled transliterator
+    pub(super) transform: Transliterator,
+    /// Transform the transliterator was compiled from, kept to describe the filter
     compound_id: String,
-    /// Custom transform [rules](https://unicode-org.github.io/icu/userguide/transforms/general/rules.html)
     rules: Option<String>,
-    /// Direction
     direction: Direction,
+    /// Split transformed text on whitespaces
+    split_on_whitespace: bool,
+    /// Emit the original token before the transformed one
+    keep_original: bool,
 }
 
 impl ICUTransformTokenFilter {
@@ -62,26 +116,111 @@ impl ICUTransformTokenFilter {
     /// * `compound_id` : [Compound transform](https://unicode-org.github.io/icu/userguide/transforms/general/#compound-ids)
     /// * `rules` : Custom transform [rules](https://unicode-org.github.io/icu/userguide/transforms/general/rules.html)
     /// * `direction` : Direction
+    ///
+    /// The transliterator is compiled here, so an unknown compound id or invalid rules
+    /// are reported by an error that contains ICU's error code and where parsing failed.
     pub fn new(
         compound_id: String,
         rules: Option<String>,
         direction: Direction,
     ) -> Result<Self, Error> {
-        let _ =
-            utrans::UTransliterator::new(compound_id.as_str(), rules.as_deref(), direction.into())?;
+        let transform = Transliterator::new(&compound_id, rules.as_deref(), direction)?;
 
         Ok(Self {
+            transform,
             compound_id,
             rules,
             direction,
+            split_on_whitespace: false,
+            keep_original: false,
         })
     }
+
+    /// [Compound transform](https://unicode-org.github.io/icu/userguide/transforms/general/#compound-ids)
+    /// of the filter.
+    pub fn compound_id(&self) -> &str {
+        &self.compound_id
+    }
+
+    /// Custom transform rules of the filter, if any.
+    pub fn rules(&self) -> Option<&str> {
+        self.rules.as_deref()
+    }
+
+    /// Direction of the transform.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Split the transformed text on whitespaces. Each part is emitted as a token
+    /// at consecutive positions, positions of the following tokens are shifted
+    /// accordingly. All parts keep the offsets of the original token.
+    ///
+    /// Default is `false`, the transformed text is kept as one token.
+    pub fn split_on_whitespace(mut self, split_on_whitespace: bool) -> Self {
+        self.split_on_whitespace = split_on_whitespace;
+        self
+    }
+
+    /// Emit the original token, then the transformed one at the same position. If the
+    /// transform doesn't change the token, it is emitted only once.
+    ///
+    /// Default is `false`, only the transformed token is emitted.
+    pub fn keep_original(mut self, keep_original: bool) -> Self {
+        self.keep_original = keep_original;
+        self
+    }
+}
+
+/// Serialized form of an [ICUTransformTokenFilter].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TransformConfig {
+    compound_id: String,
+    #[serde(default)]
+    rules: Option<String>,
+    direction: Direction,
+    #[serde(default)]
+    split_on_whitespace: bool,
+    #[serde(default)]
+    keep_original: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TransformConfig> for ICUTransformTokenFilter {
+    type Error = Error;
+
+    fn try_from(config: TransformConfig) -> Result<Self, Self::Error> {
+        Ok(
+            Self::new(config.compound_id, config.rules, config.direction)?
+                .split_on_whitespace(config.split_on_whitespace)
+                .keep_original(config.keep_original),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ICUTransformTokenFilter> for TransformConfig {
+    fn from(filter: ICUTransformTokenFilter) -> Self {
+        Self {
+            compound_id: filter.compound_id,
+            rules: filter.rules,
+            direction: filter.direction,
+            split_on_whitespace: filter.split_on_whitespace,
+            keep_original: filter.keep_original,
+        }
+    }
 }
 
 impl TokenFilter for ICUTransformTokenFilter {
     type Tokenizer<T: Tokenizer> = ICUTransformFilterWrapper<T>;
 
     fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
-        ICUTransformFilterWrapper::new(token_stream, self.compound_id, self.rules, self.direction)
+        ICUTransformFilterWrapper::new(
+            token_stream,
+            self.transform,
+            self.split_on_whitespace,
+            self.keep_original,
+        )
     }
 }
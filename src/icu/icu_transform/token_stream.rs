@@ -1,37 +1,85 @@
 //! Module that contains the [TokenStream] implementation. It's this that
 //! do the real job.
 
+use std::collections::VecDeque;
 use std::mem;
 
-use rust_icu_utrans as utrans;
 use tantivy_tokenizer_api::{Token, TokenStream};
 
+use super::Transliterator;
+
 #[derive(Debug)]
-pub struct ICUTransformTokenStream<T> {
-    transform: utrans::UTransliterator,
+pub struct ICUTransformTokenStream<'a, T> {
+    pub(super) transform: &'a mut Transliterator,
     tail: T,
     temp: String,
+    split_on_whitespace: bool,
+    keep_original: bool,
+    /// Transformed text to emit after the original token.
+    transformed: Option<String>,
+    /// Remaining parts of the transformed token when splitting.
+    parts: VecDeque<String>,
+    /// Number of positions added by splitting, it shifts
+    /// the position of every following token.
+    position_shift: usize,
 }
 
-impl<T> ICUTransformTokenStream<T> {
-    pub(crate) fn new(tail: T, transform: utrans::UTransliterator) -> Self {
+impl<'a, T> ICUTransformTokenStream<'a, T> {
+    pub(crate) fn new(
+        tail: T,
+        transform: &'a mut Transliterator,
+        split_on_whitespace: bool,
+        keep_original: bool,
+    ) -> Self {
         Self {
             transform,
             tail,
             temp: String::with_capacity(100),
+            split_on_whitespace,
+            keep_original,
+            transformed: None,
+            parts: VecDeque::new(),
+            position_shift: 0,
         }
     }
 }
 
-impl<T: TokenStream> TokenStream for ICUTransformTokenStream<T> {
+impl<T: TokenStream> TokenStream for ICUTransformTokenStream<'_, T> {
     fn advance(&mut self) -> bool {
+        if let Some(transformed) = self.transformed.take() {
+            self.tail.token_mut().text = transformed;
+            return true;
+        }
+
+        if let Some(part) = self.parts.pop_front() {
+            let token = self.tail.token_mut();
+            token.text = part;
+            token.position += 1;
+            self.position_shift += 1;
+            return true;
+        }
+
         let result = self.tail.advance();
         if !result {
             return false;
         }
-        if let Ok(t) = self.transform.transliterate(&self.tail.token().text) {
+        self.tail.token_mut().position += self.position_shift;
+
+        let transformed = self.transform.transliterate(&self.tail.token().text);
+        if let Ok(t) = transformed {
             self.temp = t;
-            mem::swap(&mut self.tail.token_mut().text, &mut self.temp);
+            if self.split_on_whitespace {
+                self.parts = self.temp.split_whitespace().map(String::from).collect();
+                // If the transformed text contains only whitespaces, it is kept as is.
+                if let Some(first) = self.parts.pop_front() {
+                    self.temp = first;
+                }
+            }
+            if !self.keep_original {
+                mem::swap(&mut self.tail.token_mut().text, &mut self.temp);
+            } else if self.temp != self.tail.token().text {
+                self.transformed = Some(mem::take(&mut self.temp));
+            }
         }
         result
     }
@@ -0,0 +1,92 @@
+//! Transliterators. `rust_icu_utrans` doesn't give ICU's parse error and clones of its
+//! transliterator share the same ICU instance, so transliterators are opened and cloned
+//! directly from ICU, through the `ffi` module.
+
+use std::sync::Arc;
+
+use rust_icu_sys as sys;
+
+use super::super::ffi::TransliteratorInstance;
+use super::super::Error;
+use super::{Direction, TransformSyntaxError};
+
+/// Context of a parse error, it is nul-terminated.
+fn context(units: &[sys::UChar]) -> String {
+    let len = units
+        .iter()
+        .position(|&unit| unit == 0)
+        .unwrap_or(units.len());
+    String::from_utf16_lossy(&units[..len])
+}
+
+/// A compiled ICU transliterator. The compiled transliterator is shared by all clones,
+/// each clone transliterates with its own copy of it, made the first time it is used
+/// (rules are not compiled again), so clones can be used from different threads.
+#[derive(Debug)]
+pub(crate) struct Transliterator {
+    compiled: Arc<TransliteratorInstance>,
+    instance: Option<TransliteratorInstance>,
+}
+
+impl Transliterator {
+    /// Compile the transform. If it fails, the error tells where parsing failed.
+    pub(crate) fn new(
+        compound_id: &str,
+        rules: Option<&str>,
+        direction: Direction,
+    ) -> Result<Self, Error> {
+        let mut parse_error = sys::UParseError {
+            line: 0,
+            offset: 0,
+            preContext: [0; 16],
+            postContext: [0; 16],
+        };
+        let compiled =
+            TransliteratorInstance::open(compound_id, rules, direction.into(), &mut parse_error)
+                .map_err(|error| match error {
+                    Error::Sys(code) => Error::wrapper(TransformSyntaxError {
+                        compound_id: compound_id.to_string(),
+                        code,
+                        offset: parse_error.offset,
+                        pre_context: context(&parse_error.preContext),
+                        post_context: context(&parse_error.postContext),
+                    }),
+                    error => error,
+                })?;
+        Ok(Self {
+            compiled: Arc::new(compiled),
+            instance: None,
+        })
+    }
+
+    /// Whether both transliterators share the same compiled transliterator.
+    #[cfg(test)]
+    pub(crate) fn shares_compiled(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.compiled, &other.compiled)
+    }
+
+    /// ICU instance used to transliterate, if it has been used.
+    #[cfg(test)]
+    pub(crate) fn instance(&self) -> Option<*const sys::UTransliterator> {
+        self.instance.as_ref().map(TransliteratorInstance::as_ptr)
+    }
+
+    /// Transliterate `text`.
+    pub(crate) fn transliterate(&mut self, text: &str) -> Result<String, Error> {
+        let instance = match self.instance.take() {
+            Some(instance) => instance,
+            None => self.compiled.try_clone()?,
+        };
+        self.instance.insert(instance).transliterate(text)
+    }
+}
+
+impl Clone for Transliterator {
+    fn clone(&self) -> Self {
+        // The clone makes its own instance when it is first used
+        Self {
+            compiled: Arc::clone(&self.compiled),
+            instance: None,
+        }
+    }
+}
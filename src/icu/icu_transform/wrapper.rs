@@ -2,47 +2,43 @@
 //! it's mostly here to give to the bottom component of the analysis
 //! stack (which is a [Tokenizer]) the text to parse.
 
-use rust_icu_utrans as utrans;
 use tantivy_tokenizer_api::Tokenizer;
 
-use super::{Direction, ICUTransformTokenStream};
+use super::{ICUTransformTokenStream, Transliterator};
 
 #[derive(Debug, Clone)]
 pub struct ICUTransformFilterWrapper<T> {
-    compound_id: String,
-    rules: Option<String>,
-    direction: Direction,
+    pub(super) transform: Transliterator,
+    split_on_whitespace: bool,
+    keep_original: bool,
     inner: T,
 }
 
 impl<T> ICUTransformFilterWrapper<T> {
     pub(crate) fn new(
         inner: T,
-        compound_id: String,
-        rules: Option<String>,
-        direction: Direction,
+        transform: Transliterator,
+        split_on_whitespace: bool,
+        keep_original: bool,
     ) -> Self {
         Self {
-            compound_id,
-            rules,
-            direction,
+            transform,
+            split_on_whitespace,
+            keep_original,
             inner,
         }
     }
 }
 
 impl<T: Tokenizer> Tokenizer for ICUTransformFilterWrapper<T> {
-    type TokenStream<'a> = ICUTransformTokenStream<T::TokenStream<'a>>;
+    type TokenStream<'a> = ICUTransformTokenStream<'a, T::TokenStream<'a>>;
 
     fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
-        // unwrap work, we checked in token filter's new method.
-        let transform = utrans::UTransliterator::new(
-            self.compound_id.as_str(),
-            self.rules.as_deref(),
-            self.direction.into(),
+        ICUTransformTokenStream::new(
+            self.inner.token_stream(text),
+            &mut self.transform,
+            self.split_on_whitespace,
+            self.keep_original,
         )
-        .expect("Can't create transliterator");
-
-        ICUTransformTokenStream::new(self.inner.token_stream(text), transform)
     }
 }
@@ -1,10 +1,31 @@
 //! This module contains all ICU-related components.
+//!
+//! With the `register` feature, the `register` module registers default-configured
+//! components into a tantivy `TokenizerManager` by name.
+mod chinese_convert;
+mod ffi;
+mod icu_folding;
 mod icu_normalizer;
 mod icu_tokenizer;
 mod icu_transform;
+#[cfg(feature = "register")]
+pub mod register;
+mod script_routing;
 
 pub use rust_icu_common::Error;
+pub use rust_icu_sys::UScriptCode;
 
-pub use crate::icu::icu_normalizer::{ICUNormalizer2TokenFilter, Mode};
-pub use crate::icu::icu_tokenizer::ICUTokenizer;
+pub use crate::icu::chinese_convert::{ChineseConversion, ChineseConvertTokenFilter};
+pub use crate::icu::icu_folding::{ICUFoldingTokenFilter, ICUFoldingTokenFilterBuilder};
+pub use crate::icu::icu_normalizer::{CompositionMode, ICUNormalizer2TokenFilter, Mode};
+pub use crate::icu::icu_tokenizer::{
+    ICURulesError, ICURulesTokenizer, ICUTokenizer, ICUTokenizerWithScript,
+    ICUTokenizerWithScriptTokenStream,
+};
 pub use crate::icu::icu_transform::{Direction, ICUTransformTokenFilter};
+pub use crate::icu::script_routing::{ScriptRoutingTokenStream, ScriptRoutingTokenizer};
+
+/// Length of a buffer as expected by ICU.
+fn to_capacity(len: usize) -> Result<i32, Error> {
+    i32::try_from(len).map_err(|_| Error::Sys(rust_icu_sys::UErrorCode::U_ILLEGAL_ARGUMENT_ERROR))
+}
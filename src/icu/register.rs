@@ -0,0 +1,107 @@
+//! Registration of default-configured ICU components into a tantivy
+//! [TokenizerManager], so that analyzers can be chosen by name, for example from
+//! a configuration file.
+//!
+//! The names are :
+//!
+//! | Name             | Analyzer                                                          |
+//! |------------------|-------------------------------------------------------------------|
+//! | `icu_tokenizer`  | [ICUTokenizer]                                                    |
+//! | `icu_normalizer` | [ICUTokenizer] and [ICUNormalizer2TokenFilter] with [NFKCCasefold] |
+//! | `icu_folding`    | [ICUTokenizer] and [ICUFoldingTokenFilter]                        |
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use tantivy::tokenizer::TokenizerManager;
+//! use tantivy_analysis_contrib::icu::register;
+//!
+//! let manager = TokenizerManager::default();
+//! register::register_all(&manager)?;
+//!
+//! let mut analyzer = manager.get("icu_folding").expect("icu_folding should be registered");
+//! let mut token_stream = analyzer.token_stream("Crème Brûlée");
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "creme".to_string());
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "brulee".to_string());
+//!
+//! assert_eq!(None, token_stream.next());
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! [ICUTokenizer]: crate::icu::ICUTokenizer
+//! [ICUNormalizer2TokenFilter]: crate::icu::ICUNormalizer2TokenFilter
+//! [NFKCCasefold]: crate::icu::Mode::NFKCCasefold
+//! [ICUFoldingTokenFilter]: crate::icu::ICUFoldingTokenFilter
+
+use tantivy::tokenizer::{TextAnalyzer, TokenizerManager};
+
+use crate::icu::{Error, ICUFoldingTokenFilter, ICUNormalizer2TokenFilter, ICUTokenizer, Mode};
+
+/// Names of the registered analyzers.
+pub const NAMES: [&str; 3] = ["icu_tokenizer", "icu_normalizer", "icu_folding"];
+
+/// Register all [NAMES] into `manager`. An analyzer already registered with the
+/// same name is replaced.
+///
+/// It fails if ICU can't load the normalization or folding data, nothing is
+/// registered then.
+pub fn register_all(manager: &TokenizerManager) -> Result<(), Error> {
+    let normalizer = ICUNormalizer2TokenFilter::new(Mode::NFKCCasefold)?;
+    let folding = ICUFoldingTokenFilter::new()?;
+
+    manager.register("icu_tokenizer", ICUTokenizer);
+    manager.register(
+        "icu_normalizer",
+        TextAnalyzer::builder(ICUTokenizer)
+            .filter(normalizer)
+            .build(),
+    );
+    manager.register(
+        "icu_folding",
+        TextAnalyzer::builder(ICUTokenizer).filter(folding).build(),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::Token;
+
+    use super::*;
+
+    fn texts(manager: &TokenizerManager, name: &str, text: &str) -> Vec<String> {
+        let mut analyzer = manager.get(name).expect("Analyzer should be registered");
+        let mut token_stream = analyzer.token_stream(text);
+
+        let mut tokens = vec![];
+        token_stream.process(&mut |token: &Token| tokens.push(token.text.clone()));
+        tokens
+    }
+
+    #[test]
+    fn test_register_all() -> Result<(), Error> {
+        let manager = TokenizerManager::default();
+        register_all(&manager)?;
+
+        for name in NAMES {
+            assert!(manager.get(name).is_some(), "{name}");
+        }
+
+        assert_eq!(
+            texts(&manager, "icu_tokenizer", "Hello World"),
+            vec!["Hello", "World"]
+        );
+        assert_eq!(
+            texts(&manager, "icu_normalizer", "ＨＥＬＬＯ"),
+            vec!["hello"]
+        );
+        assert_eq!(texts(&manager, "icu_folding", "Crème"), vec!["creme"]);
+
+        Ok(())
+    }
+}
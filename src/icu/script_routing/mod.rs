@@ -0,0 +1,122 @@
+//! This module provides a tokenizer that routes runs of scripts to different tokenizers.
+pub use token_stream::ScriptRoutingTokenStream;
+pub use tokenizer::ScriptRoutingTokenizer;
+
+mod token_stream;
+mod tokenizer;
+
+#[cfg(test)]
+mod tests {
+    use rust_icu_sys::UScriptCode;
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(
+        text: &str,
+        tokenizer: ScriptRoutingTokenizer<WhitespaceTokenizer>,
+    ) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(tokenizer).build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn token(text: &str, offset_from: usize, offset_to: usize, position: usize) -> Token {
+        Token {
+            offset_from,
+            offset_to,
+            position,
+            text: text.to_string(),
+            position_length: 1,
+        }
+    }
+
+    #[test]
+    fn test_thai_then_english() {
+        let result = token_stream_helper(
+            "การที่ได้ต้องแสดงว่างานดี is Thai",
+            ScriptRoutingTokenizer::new(WhitespaceTokenizer::default()),
+        );
+        let expected = vec![
+            token("การ", 0, 9, 0),
+            token("ที่", 9, 18, 1),
+            token("ได้", 18, 27, 2),
+            token("ต้อง", 27, 39, 3),
+            token("แสดง", 39, 51, 4),
+            token("ว่า", 51, 60, 5),
+            token("งาน", 60, 69, 6),
+            token("ดี", 69, 75, 7),
+            token("is", 76, 78, 8),
+            token("Thai", 79, 83, 9),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_english_then_thai() {
+        let result = token_stream_helper(
+            "Thai: การที่ได้ต้องแสดงว่างานดี",
+            ScriptRoutingTokenizer::new(WhitespaceTokenizer::default()),
+        );
+        let expected = vec![
+            token("Thai:", 0, 5, 0),
+            token("การ", 6, 15, 1),
+            token("ที่", 15, 24, 2),
+            token("ได้", 24, 33, 3),
+            token("ต้อง", 33, 45, 4),
+            token("แสดง", 45, 57, 5),
+            token("ว่า", 57, 66, 6),
+            token("งาน", 66, 75, 7),
+            token("ดี", 75, 81, 8),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_not_routed() {
+        // Without routing, the whitespace tokenizer keeps the Thai sentence as one token.
+        let result = token_stream_helper(
+            "การที่ได้ต้องแสดงว่างานดี is Thai",
+            ScriptRoutingTokenizer::with_scripts(
+                WhitespaceTokenizer::default(),
+                [UScriptCode::USCRIPT_LATIN],
+            ),
+        );
+        let expected = vec![
+            token("การที่ได้ต้องแสดงว่างานดี", 0, 75, 0),
+            token("is", 76, 78, 1),
+            token("Thai", 79, 83, 2),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_only_default() {
+        let result = token_stream_helper(
+            "Hello, world 42",
+            ScriptRoutingTokenizer::new(WhitespaceTokenizer::default()),
+        );
+        let expected = vec![
+            token("Hello,", 0, 6, 0),
+            token("world", 7, 12, 1),
+            token("42", 13, 15, 2),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_empty() {
+        let result = token_stream_helper(
+            "",
+            ScriptRoutingTokenizer::new(WhitespaceTokenizer::default()),
+        );
+        assert!(result.is_empty());
+    }
+}
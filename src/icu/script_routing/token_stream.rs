@@ -0,0 +1,116 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use rust_icu_sys::UScriptCode;
+use tantivy_tokenizer_api::{Token, TokenStream, Tokenizer};
+
+use crate::icu::icu_tokenizer::{char_script, ICUTokenizerTokenStream};
+
+/// Token stream of [ScriptRoutingTokenizer](super::ScriptRoutingTokenizer).
+#[derive(Debug)]
+pub struct ScriptRoutingTokenStream<'a, D> {
+    text: &'a str,
+    scripts: &'a [UScriptCode],
+    default: &'a mut D,
+    /// Start, in bytes, of the next run.
+    start: usize,
+    /// Position of the first token of the next run.
+    position: usize,
+    buffer: VecDeque<Token>,
+    token: Token,
+}
+
+impl<'a, D: Tokenizer> ScriptRoutingTokenStream<'a, D> {
+    pub(crate) fn new(text: &'a str, scripts: &'a [UScriptCode], default: &'a mut D) -> Self {
+        Self {
+            text,
+            scripts,
+            default,
+            start: 0,
+            position: 0,
+            buffer: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+
+    /// Find the end of the run starting at `self.start` and whether it is routed.
+    fn next_run(&self) -> (usize, bool) {
+        let mut routed = None;
+        for (index, c) in self.text[self.start..].char_indices() {
+            let script = char_script(c);
+            if script == UScriptCode::USCRIPT_COMMON || script == UScriptCode::USCRIPT_INHERITED {
+                continue;
+            }
+            let is_routed = self.scripts.contains(&script);
+            match routed {
+                Some(current) if current != is_routed => return (self.start + index, current),
+                _ => routed = Some(is_routed),
+            }
+        }
+
+        (self.text.len(), routed.unwrap_or(false))
+    }
+
+    /// Tokenize the next run and put its tokens in the buffer.
+    fn fill(&mut self) {
+        let (end, routed) = self.next_run();
+        let run = &self.text[self.start..end];
+
+        if routed {
+            // ICU offsets are in UTF-16 code units, map them to bytes.
+            let mut bytes = Vec::with_capacity(run.len() + 1);
+            for (index, c) in run.char_indices() {
+                bytes.extend(std::iter::repeat(index).take(c.len_utf16()));
+            }
+            bytes.push(run.len());
+
+            let mut stream = ICUTokenizerTokenStream::new(run);
+            while stream.advance() {
+                let mut token = stream.token().clone();
+                token.offset_from = bytes[token.offset_from];
+                token.offset_to = bytes[token.offset_to];
+                self.buffer.push_back(token);
+            }
+        } else {
+            let mut stream = self.default.token_stream(run);
+            while stream.advance() {
+                self.buffer.push_back(stream.token().clone());
+            }
+        }
+
+        let mut next_position = self.position;
+        for token in self.buffer.iter_mut() {
+            token.offset_from += self.start;
+            token.offset_to += self.start;
+            token.position += self.position;
+            next_position = next_position.max(token.position + 1);
+        }
+        self.position = next_position;
+        self.start = end;
+    }
+}
+
+impl<D: Tokenizer> TokenStream for ScriptRoutingTokenStream<'_, D> {
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(token) = self.buffer.pop_front() {
+                self.token = token;
+                return true;
+            }
+            if self.start >= self.text.len() {
+                return false;
+            }
+            self.fill();
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -0,0 +1,113 @@
+use rust_icu_sys::UScriptCode;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::ScriptRoutingTokenStream;
+
+/// Scripts written without spaces between words, that need ICU dictionaries to be
+/// split into words.
+const DICTIONARY_SCRIPTS: [UScriptCode; 7] = [
+    UScriptCode::USCRIPT_THAI,
+    UScriptCode::USCRIPT_LAO,
+    UScriptCode::USCRIPT_KHMER,
+    UScriptCode::USCRIPT_MYANMAR,
+    UScriptCode::USCRIPT_HAN,
+    UScriptCode::USCRIPT_HIRAGANA,
+    UScriptCode::USCRIPT_KATAKANA,
+];
+
+/// A [Tokenizer] that routes runs of text to a tokenizer depending on their script.
+///
+/// Text is split into runs of routed and not routed scripts. Routed runs are broken
+/// with the same rules as the [ICUTokenizer](crate::icu::ICUTokenizer) (dictionaries
+/// for Thai, Lao, Khmer, CJK, syllables for Myanmar...), other runs are tokenized
+/// with the given tokenizer. `Common` and `Inherited` characters (spaces, punctuation,
+/// digits, combining marks...) belong to the run they appear in.
+///
+/// By default, routed scripts are Thai, Lao, Khmer, Myanmar, Han, Hiragana and
+/// Katakana. They can be changed with [with_scripts](ScriptRoutingTokenizer::with_scripts).
+///
+/// Tokens of all runs are merged in order. Offsets are in bytes (unlike the
+/// [ICUTokenizer](crate::icu::ICUTokenizer)) and positions follow each other
+/// across runs. The given tokenizer is expected to produce byte offsets, as tantivy's
+/// tokenizers do.
+///
+/// # Example
+///
+/// ```rust
+/// use tantivy::tokenizer::{TextAnalyzer, WhitespaceTokenizer};
+/// use tantivy_analysis_contrib::icu::ScriptRoutingTokenizer;
+///
+/// let mut tmp =
+///     TextAnalyzer::builder(ScriptRoutingTokenizer::new(WhitespaceTokenizer::default())).build();
+/// let mut token_stream = tmp.token_stream("การที่ได้ต้องแสดงว่างานดี is Thai");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "การ".to_string());
+/// assert_eq!(token.offset_from, 0);
+/// assert_eq!(token.offset_to, 9);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ที่".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ได้".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ต้อง".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "แสดง".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ว่า".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "งาน".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ดี".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "is".to_string());
+/// assert_eq!(token.offset_from, 76);
+/// assert_eq!(token.offset_to, 78);
+/// assert_eq!(token.position, 8);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Thai".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScriptRoutingTokenizer<D> {
+    scripts: Vec<UScriptCode>,
+    default: D,
+}
+
+impl<D> ScriptRoutingTokenizer<D> {
+    /// Construct a [ScriptRoutingTokenizer] that routes scripts needing a dictionary
+    /// to ICU rules and everything else to `default`.
+    pub fn new(default: D) -> Self {
+        Self::with_scripts(default, DICTIONARY_SCRIPTS)
+    }
+
+    /// Construct a [ScriptRoutingTokenizer] that routes the given scripts to ICU
+    /// rules and everything else to `default`.
+    /// # Parameters :
+    /// * `default`: tokenizer for runs of scripts that are not routed
+    /// * `scripts`: scripts to break with ICU rules
+    pub fn with_scripts(default: D, scripts: impl IntoIterator<Item = UScriptCode>) -> Self {
+        Self {
+            scripts: scripts.into_iter().collect(),
+            default,
+        }
+    }
+}
+
+impl<D: Tokenizer> Tokenizer for ScriptRoutingTokenizer<D> {
+    type TokenStream<'a> = ScriptRoutingTokenStream<'a, D>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        ScriptRoutingTokenStream::new(text, &self.scripts, &mut self.default)
+    }
+}
@@ -5,10 +5,16 @@
 //!     * [ICUTokenizer](crate::icu::ICUTokenizer) that is an equivalent
 //! of [Lucene's ICUTokenizer](https://lucene.apache.org/core/9_0_0/analysis/icu/org/apache/lucene/analysis/icu/segmentation/ICUTokenizer.html)
 //! without support of emojis.
+//!     * [ICUTokenizerWithScript](crate::icu::ICUTokenizerWithScript) that also gives the script of each token.
+//!     * [ICURulesTokenizer](crate::icu::ICURulesTokenizer) that breaks text with custom rules, for example from a `.rbbi` file.
+//!     * [ScriptRoutingTokenizer](crate::icu::ScriptRoutingTokenizer) that breaks Thai, CJK... with ICU rules and everything else with another tokenizer.
 //!     * [ICUNormalizer2TokenFilter](crate::icu::ICUNormalizer2TokenFilter) that normalize text. It is an equivalent of
 //! [Lucene's ICUNormalizer2Filter](https://lucene.apache.org/core/9_0_0/analysis/icu/org/apache/lucene/analysis/icu/ICUNormalizer2Filter.html).
 //!     * [ICUTransformTokenFilter](crate::icu::ICUTransformTokenFilter) which is an equivalent of
 //! [Lucene's ICUTransformFilter](https://lucene.apache.org/core/9_0_0/analysis/icu/org/apache/lucene/analysis/icu/ICUNormalizer2Filter.html)
+//!     * [ICUFoldingTokenFilter](crate::icu::ICUFoldingTokenFilter) that folds accents, case and diacritics. It is close to
+//! [Lucene's ICUFoldingFilter](https://lucene.apache.org/core/9_0_0/analysis/icu/org/apache/lucene/analysis/icu/ICUFoldingFilter.html)
+//!     * [ChineseConvertTokenFilter](crate::icu::ChineseConvertTokenFilter) that converts between simplified and traditional Chinese
 //! * Commons components :
 //!     * [PathTokenizer](crate::commons::PathTokenizer) which tokenize a hierarchical path (equivalent of
 //! [PathHierarchyTokenizer](https://lucene.apache.org/core/9_1_0/analysis/common/org/apache/lucene/analysis/path/PathHierarchyTokenizer.html) and
@@ -23,8 +29,77 @@
 //! [ElisionFilter](https://lucene.apache.org/core/9_1_0/analysis/common/org/apache/lucene/analysis/util/ElisionFilter.html)
 //!     * [EdgeNgramTokenFilter](crate::commons::EdgeNgramTokenFilter) that generate ngram prefixes of tokens, see
 //! [EdgeNGramTokenFilter](https://lucene.apache.org/core/9_1_0/analysis/common/org/apache/lucene/analysis/ngram/EdgeNGramTokenFilter.html)
+//!     * [GreekNormalizationTokenFilter](crate::commons::GreekNormalizationTokenFilter) that lowercases and removes accents from Greek tokens, see
+//! [GreekLowerCaseFilter](https://lucene.apache.org/core/9_1_0/analysis/common/org/apache/lucene/analysis/el/GreekLowerCaseFilter.html)
+//!     * [CyrillicYoTokenFilter](crate::commons::CyrillicYoTokenFilter) that replaces the Cyrillic `ё` with `е`
+//!     * [PositionRangeTokenFilter](crate::commons::PositionRangeTokenFilter) that applies a token filter only on tokens within a range of positions
+//!     * [EmailNormalizeTokenFilter](crate::commons::EmailNormalizeTokenFilter) that normalizes email addresses (domain case, plus-tags, dots)
+//!     * [CodepointRangeTokenFilter](crate::commons::CodepointRangeTokenFilter) that removes tokens with characters outside a range of codepoints
+//!     * [CompoundJoinTokenFilter](crate::commons::CompoundJoinTokenFilter) that joins adjacent tokens that form a known compound word
+//!     * [PatternStopTokenFilter](crate::commons::PatternStopTokenFilter) that removes tokens matching any of a list of regular expressions
+//!     * [TrimTokenFilter](crate::commons::TrimTokenFilter) that is an equivalent of [Lucene's TrimFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/miscellaneous/TrimFilter.html)
+//!     * [StopTokenFilter](crate::commons::StopTokenFilter) that is an equivalent of [Lucene's StopFilter](https://lucene.apache.org/core/9_0_0/core/org/apache/lucene/analysis/StopFilter.html)
+//!     * [VietnameseToneNormalizeTokenFilter](crate::commons::VietnameseToneNormalizeTokenFilter) that normalizes the placement of Vietnamese tone marks (`hòa` and `hoà`)
+//!     * [ASCIIFoldingTokenFilter](crate::commons::ASCIIFoldingTokenFilter) that is an equivalent of [Lucene's ASCIIFoldingFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/miscellaneous/ASCIIFoldingFilter.html)
+//!     * [ScriptReverseTokenFilter](crate::commons::ScriptReverseTokenFilter) that reverses tokens of a given script (e.g. CJK) and leaves the others unchanged
+//!     * [NGramTokenFilter](crate::commons::NGramTokenFilter) that is an equivalent of [Lucene's NGramTokenFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/ngram/NGramTokenFilter.html)
+//!     * [KeywordMarkerTokenFilter](crate::commons::KeywordMarkerTokenFilter) that protects keywords from another filter, close to [Lucene's KeywordMarkerFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/miscellaneous/KeywordMarkerFilter.html)
+//!     * [MarkupStripTokenFilter](crate::commons::MarkupStripTokenFilter) that removes markdown and wiki markup from tokens (`**bold**` becomes `bold`)
+//!     * [HybridTokenFilter](crate::commons::HybridTokenFilter) that emits both stemmed terms and edge-ngrams of tokens, for relevance and autocomplete in one field
+//!     * [WordDelimiterTokenFilter](crate::commons::WordDelimiterTokenFilter) that is modeled on [Lucene's WordDelimiterGraphFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/miscellaneous/WordDelimiterGraphFilter.html)
+//!     * [CharLimitTokenFilter](crate::commons::CharLimitTokenFilter) that limits the cumulative number of characters of emitted tokens
+//!     * [ShingleTokenFilter](crate::commons::ShingleTokenFilter) that is an equivalent of [Lucene's ShingleFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/shingle/ShingleFilter.html)
+//!     * [CommonGramsTokenFilter](crate::commons::CommonGramsTokenFilter) that is an equivalent of [Lucene's CommonGramsFilter](https://lucene.apache.org/core/9_0_0/analysis/common/org/apache/lucene/analysis/commongrams/CommonGramsFilter.html) and CommonGramsQueryFilter
+//!     * [SuperscriptDigitTokenFilter](crate::commons::SuperscriptDigitTokenFilter) that replaces superscript, subscript and circled digits by ASCII digits.
+//!     * [PatternReplaceTokenFilter](crate::commons::PatternReplaceTokenFilter) that rewrites tokens with a regular expression.
+//!     * [PatternTokenizer](crate::commons::PatternTokenizer) that splits text, or captures tokens, with a regular expression.
+//!     * [BoundedDistinctTokenFilter](crate::commons::BoundedDistinctTokenFilter) that removes duplicated tokens using a bounded amount of memory.
+//!     * [SocialTokenFilter](crate::commons::SocialTokenFilter) that normalizes hashtags and mentions, and splits hashtags into words.
+//!     * [KeywordTokenizer](crate::commons::KeywordTokenizer) that emits the whole text as a single token.
+//!     * [CharGroupTokenizer](crate::commons::CharGroupTokenizer) that splits text on any character of a group.
+//!     * [QuoteBracketTokenFilter](crate::commons::QuoteBracketTokenFilter) that canonicalizes quotation marks and brackets.
+//!     * [UrlEmailTokenizer](crate::commons::UrlEmailTokenizer) that keeps URLs and email addresses as single tokens.
+//!     * [DecimalDigitTokenFilter](crate::commons::DecimalDigitTokenFilter) that replaces Unicode decimal digits by ASCII digits.
+//!     * [SearchFoldTokenFilter](crate::commons::SearchFoldTokenFilter) that folds width, accents and case in a single pass.
+//!     * [LowerCaseTokenFilter](crate::commons::LowerCaseTokenFilter) and [UpperCaseTokenFilter](crate::commons::UpperCaseTokenFilter) that lowercase or uppercase tokens using Unicode rules.
+//!     * [ThaiNormalizationTokenFilter](crate::commons::ThaiNormalizationTokenFilter) that decomposes sara am and puts Thai combining marks in canonical order.
+//!     * [CapitalizationTokenFilter](crate::commons::CapitalizationTokenFilter) that capitalizes words, with keep words and length rules.
+//!     * [BomStripTokenFilter](crate::commons::BomStripTokenFilter) that removes a byte order mark at the start of the first token.
+//!     * [AbbreviationExpandTokenFilter](crate::commons::AbbreviationExpandTokenFilter) that replaces abbreviations with their full form, possibly of several words.
+//!     * [ArchaicLatinTokenFilter](crate::commons::ArchaicLatinTokenFilter) that maps archaic Latin forms (long s, ligatures...) to their modern equivalent.
+//!     * [DistinctSpansTokenFilter](crate::commons::DistinctSpansTokenFilter) that emits each term once and records the offsets of all its occurrences.
+//!     * [PunctuationRunTokenFilter](crate::commons::PunctuationRunTokenFilter) that shortens or drops runs of the same punctuation character (`!!!` becomes `!`).
+//!     * [MathAlphanumericTokenFilter](crate::commons::MathAlphanumericTokenFilter) that replaces mathematical alphanumeric symbols (`𝓱𝓮𝓵𝓵𝓸`) by their base letters and digits.
+//!     * [EnclosedAlphanumericTokenFilter](crate::commons::EnclosedAlphanumericTokenFilter) that replaces enclosed and fullwidth letters and numbers (`Ⓐ`, `⒜`, `①`, `Ａ`) by their plain form.
+//!     * [BudgetTokenFilter](crate::commons::BudgetTokenFilter) that protects indexing from pathological inputs by stopping the token stream once a budget of tokens or bytes is exhausted.
+//!     * [DictionaryLemmatizeTokenFilter](crate::commons::DictionaryLemmatizeTokenFilter) that replaces inflected forms with their lemma using a dictionary.
+//!     * [CurrencyTokenFilter](crate::commons::CurrencyTokenFilter) that normalizes currency and unit symbols, like `€` into `eur`.
+//!     * [DictionaryCompoundWordTokenFilter](crate::commons::DictionaryCompoundWordTokenFilter) that splits compound words into the dictionary words they contain.
+//!     * [SentinelTokenFilter](crate::commons::SentinelTokenFilter) that switches another token filter on and off when it meets a sentinel token, to control the analysis from the text.
+//!     * [KeywordNormalizeTokenFilter](crate::commons::KeywordNormalizeTokenFilter) that normalizes whitespaces and case of keyword values, with an [OffsetMap](crate::commons::OffsetMap) for highlighting
 //! * Phonetic :
 //!     * [PhoneticTokenFilter](crate::phonetic::PhoneticTokenFilter) a token filter to apply phonetic algorithm on tokens.
+//!     * [MultiPhoneticTokenFilter](crate::phonetic::MultiPhoneticTokenFilter) a token filter to apply several phonetic algorithms on tokens.
+//!     * [name_search_analyzer](crate::phonetic::name_search_analyzer) an analysis chain for person names (folding, elision and phonetic codes), with `commons` feature.
+//! * Phone (not included by default) :
+//!     * [PhoneNormalizeTokenFilter](crate::phone::PhoneNormalizeTokenFilter) a token filter that normalizes phone numbers into E.164 form.
+//! * Domain (not included by default) :
+//!     * [RegistrableDomainTokenFilter](crate::domain::RegistrableDomainTokenFilter) a token filter that reduces hostnames to their registrable domain using the public suffix list.
+//! * Pinyin (not included by default) :
+//!     * [PinyinTokenFilter](crate::pinyin::PinyinTokenFilter) a token filter that converts Han characters into pinyin, with or without tones.
+//! * Graphemes (not included by default) :
+//!     * [GraphemeSplitTokenFilter](crate::commons::GraphemeSplitTokenFilter) a token filter that splits tokens into grapheme clusters, keeping emoji sequences and Indic clusters whole.
+//!     * [LengthCountMode::Graphemes](crate::commons::LengthCountMode::Graphemes) and [EdgeNgramTokenFilter::with_graphemes](crate::commons::EdgeNgramTokenFilter::with_graphemes) to count grapheme clusters.
+//!
+//! # Empty input
+//!
+//! All components follow the same contract for empty input :
+//! * tokenizers emit no token for an empty text, except [KeywordTokenizer](crate::commons::KeywordTokenizer)
+//!   which, like tantivy's `RawTokenizer`, emits the whole text as a single token even if it is empty,
+//! * token filters emit no token if they receive none,
+//! * token filters emit an empty token unchanged, unless removing tokens is what they do (for example
+//!   [LengthTokenFilter](crate::commons::LengthTokenFilter), n-grams, or [TrimTokenFilter](crate::commons::TrimTokenFilter)
+//!   that removes tokens that are empty once trimmed). They never turn it into a non-empty token.
 //!
 //! # Example
 //!
@@ -151,7 +226,13 @@ extern crate derive_builder;
 
 #[cfg(feature = "commons")]
 pub mod commons;
+#[cfg(feature = "domain")]
+pub mod domain;
 #[cfg(feature = "icu")]
 pub mod icu;
+#[cfg(feature = "phone")]
+pub mod phone;
 #[cfg(feature = "phonetic")]
 pub mod phonetic;
+#[cfg(feature = "pinyin")]
+pub mod pinyin;
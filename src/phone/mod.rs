@@ -0,0 +1,146 @@
+//! This module provides a token filter that normalizes phone numbers into
+//! their [E.164](https://en.wikipedia.org/wiki/E.164) form, using the
+//! [phonenumber crate](https://docs.rs/phonenumber/latest/phonenumber/).
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token};
+//! use tantivy_analysis_contrib::phone::PhoneNormalizeTokenFilter;
+//!
+//! let mut tmp = TextAnalyzer::builder(RawTokenizer::default())
+//!    .filter(PhoneNormalizeTokenFilter::new("US")?)
+//!    .build();
+//! let mut token_stream = tmp.token_stream("(650) 253-0000");
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "+16502530000".to_string());
+//!
+//! assert_eq!(None, token_stream.next());
+//! #     Ok(())
+//! # }
+//! ```
+use thiserror::Error;
+pub use token_filter::PhoneNormalizeTokenFilter;
+use token_stream::PhoneNormalizeTokenStream;
+use wrapper::PhoneNormalizeFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+/// Errors from phone number filter.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The default region is not a known region code.
+    #[error("Unknown region '{0}'")]
+    UnknownRegion(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{RawTokenizer, TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, region: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(RawTokenizer::default())
+            .filter(PhoneNormalizeTokenFilter::new(region).unwrap())
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn token_stream_helper_whitespace(text: &str, region: &str) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(PhoneNormalizeTokenFilter::new(region).unwrap())
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_formats() {
+        for text in [
+            "(650) 253-0000",
+            "650-253-0000",
+            "650.253.0000",
+            "+1 650 253 0000",
+            "+16502530000",
+        ] {
+            let result = token_stream_helper(text, "US");
+            let expected = vec![Token {
+                offset_from: 0,
+                offset_to: text.len(),
+                position: 0,
+                text: "+16502530000".to_string(),
+                position_length: 1,
+            }];
+            assert_eq!(result, expected, "Fail to normalize '{text}'");
+        }
+    }
+
+    #[test]
+    fn test_default_region() {
+        let result = token_stream_helper("01 42 68 53 00", "FR");
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 14,
+            position: 0,
+            text: "+33142685300".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_not_phone_number() {
+        let result = token_stream_helper_whitespace("call 123 +16502530000", "US");
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "call".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 5,
+                offset_to: 8,
+                position: 1,
+                text: "123".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 9,
+                offset_to: 21,
+                position: 2,
+                text: "+16502530000".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_unknown_region() {
+        let result = PhoneNormalizeTokenFilter::new("Unknown");
+        assert_eq!(
+            result.unwrap_err(),
+            Error::UnknownRegion("Unknown".to_string())
+        );
+    }
+}
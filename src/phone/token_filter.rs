@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+use phonenumber::country::Id;
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::{Error, PhoneNormalizeFilterWrapper};
+
+/// A token filter that replaces tokens that are valid phone numbers with their
+/// [E.164](https://en.wikipedia.org/wiki/E.164) form (e.g. `(650) 253-0000`
+/// becomes `+16502530000`). Other tokens are left untouched.
+///
+/// Numbers without a country calling code are parsed using the default region.
+///
+/// Note that phone numbers usually contain spaces, so it should be used with a
+/// tokenizer that keeps them in one token (e.g. [RawTokenizer](tantivy::tokenizer::RawTokenizer)).
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy_analysis_contrib::phone::PhoneNormalizeTokenFilter;
+///
+/// let filter = PhoneNormalizeTokenFilter::new("FR")?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PhoneNormalizeTokenFilter {
+    default_region: Id,
+}
+
+impl PhoneNormalizeTokenFilter {
+    /// Construct a new phone number token filter.
+    ///
+    /// # Parameters :
+    ///
+    /// * `default_region` : two letters region code (e.g. `US`, `FR`) used to parse
+    ///   numbers that do not start with a country calling code.
+    pub fn new(default_region: &str) -> Result<Self, Error> {
+        let default_region = Id::from_str(default_region)
+            .map_err(|_| Error::UnknownRegion(default_region.to_string()))?;
+        Ok(Self { default_region })
+    }
+}
+
+impl TokenFilter for PhoneNormalizeTokenFilter {
+    type Tokenizer<T: Tokenizer> = PhoneNormalizeFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        PhoneNormalizeFilterWrapper::new(token_stream, self.default_region)
+    }
+}
@@ -0,0 +1,50 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use phonenumber::country::Id;
+use phonenumber::Mode;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct PhoneNormalizeTokenStream<T> {
+    tail: T,
+    default_region: Id,
+}
+
+impl<T> PhoneNormalizeTokenStream<T> {
+    pub(crate) fn new(tail: T, default_region: Id) -> Self {
+        Self {
+            tail,
+            default_region,
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for PhoneNormalizeTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let text = &self.tail.token().text;
+        if !text.chars().any(|c| c.is_ascii_digit()) {
+            return true;
+        }
+
+        if let Ok(number) = phonenumber::parse(Some(self.default_region), text) {
+            if number.is_valid() {
+                self.tail.token_mut().text = number.format().mode(Mode::E164).to_string();
+            }
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,31 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use phonenumber::country::Id;
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::PhoneNormalizeTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct PhoneNormalizeFilterWrapper<T> {
+    default_region: Id,
+    inner: T,
+}
+
+impl<T> PhoneNormalizeFilterWrapper<T> {
+    pub(crate) fn new(inner: T, default_region: Id) -> Self {
+        Self {
+            default_region,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for PhoneNormalizeFilterWrapper<T> {
+    type TokenStream<'a> = PhoneNormalizeTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        PhoneNormalizeTokenStream::new(self.inner.token_stream(text), self.default_region)
+    }
+}
@@ -32,22 +32,41 @@
 //!
 //! Every parameter of [PhoneticAlgorithm]'s variant is typed to try to make it clear what is their purpose.
 //! Most of them are [Option] allowing to use default values.
+//!
+//! [PhoneticAlgorithm::from_name] (or [str::parse]) builds an algorithm from its name and
+//! parameters, for configuration driven analysis chains.
+//!
+//! To apply several algorithms on the same tokens, use [MultiPhoneticTokenFilter].
+//!
+//! When codes are injected as synonyms, the original token comes before its codes. This
+//! can be changed with [InjectOrder]. Codes are at the position of their token, unless
+//! [PositionIncrement::Increment] is set with [PhoneticTokenFilter::builder].
+//!
+//! With the `commons` feature, [name_search_analyzer] builds a whole analysis chain for
+//! person names, combining folding, elision and phonetic codes.
 
+pub use multi::MultiPhoneticTokenFilter;
+#[cfg(feature = "commons")]
+pub use name_search::{name_search_analyzer, NameLanguage};
 pub use rphonetic::{BMError, LanguageSet, NameType, PhoneticError, RuleType};
 use rphonetic::{
-    Caverphone1, Caverphone2, Cologne, ConfigFiles, DaitchMokotoffSoundex,
-    DaitchMokotoffSoundexBuilder, DoubleMetaphone, MatchRatingApproach, Metaphone, Nysiis, Phonex,
-    RefinedSoundex, Soundex, DEFAULT_US_ENGLISH_MAPPING_SOUNDEX,
+    BeiderMorse, BeiderMorseBuilder, Caverphone1, Caverphone2, Cologne, ConfigFiles,
+    DaitchMokotoffSoundex, DaitchMokotoffSoundexBuilder, DoubleMetaphone, MatchRatingApproach,
+    Metaphone, Nysiis, Phonex, RefinedSoundex, Soundex, DEFAULT_US_ENGLISH_MAPPING_SOUNDEX,
 };
 use thiserror::Error;
-pub use token_filter::PhoneticTokenFilter;
+pub use token_filter::{PhoneticTokenFilter, PhoneticTokenFilterBuilder};
 use token_stream::{
     BeiderMorseTokenStream, DaitchMokotoffTokenStream, DoubleMetaphoneTokenStream,
-    GenericPhoneticTokenStream,
+    GenericPhoneticTokenStream, PositionIncrementTokenStream, SourceCountTokenStream,
 };
 pub use types::*;
 use wrapper::PhoneticFilterWrapper;
 
+mod multi;
+#[cfg(feature = "commons")]
+mod name_search;
+mod parse;
 mod token_filter;
 mod token_stream;
 mod types;
@@ -59,6 +78,37 @@ pub enum Error {
     /// Fail to create the encoder. It contains the rphonetic error.
     #[error("{0}")]
     AlgorithmError(#[from] PhoneticError),
+    /// The name given to [PhoneticAlgorithm::from_name] is unknown.
+    #[error("Unknown phonetic algorithm '{0}'")]
+    UnknownAlgorithm(String),
+    /// The parameter isn't supported by the algorithm.
+    #[error("Unknown parameter '{parameter}' for phonetic algorithm '{algorithm}'")]
+    UnknownParameter {
+        /// Name of the algorithm.
+        algorithm: String,
+        /// Name of the parameter.
+        parameter: String,
+    },
+    /// A parameter required by the algorithm is missing.
+    #[error("Missing parameter '{parameter}' for phonetic algorithm '{algorithm}'")]
+    MissingParameter {
+        /// Name of the algorithm.
+        algorithm: String,
+        /// Name of the parameter.
+        parameter: String,
+    },
+    /// The value of a parameter can't be parsed.
+    #[error(
+        "Invalid value '{value}' for parameter '{parameter}' of phonetic algorithm '{algorithm}'"
+    )]
+    InvalidParameter {
+        /// Name of the algorithm.
+        algorithm: String,
+        /// Name of the parameter.
+        parameter: String,
+        /// Value that can't be parsed.
+        value: String,
+    },
 }
 
 /// These are different algorithms from [rphonetic crate](https://docs.rs/rphonetic/1.0.0/rphonetic/).
@@ -67,6 +117,7 @@ pub enum Error {
 ///
 /// Parameters are mostly wrapper to make clearer what they mean.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PhoneticAlgorithm {
     /// [BeiderMorse](rphonetic::BeiderMorse) algorithm.
     ///
@@ -81,6 +132,10 @@ pub enum PhoneticAlgorithm {
     ///
     /// You have to provide a set of languages. They must be supported by your rule files. If the list
     /// is empty, the encoder will try to guess languages.
+    ///
+    /// With the `serde` feature, this variant is skipped as it refers to rule files loaded
+    /// at runtime : serializing it fails and it can't be deserialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
     BeiderMorse(
         &'static ConfigFiles,
         Option<NameType>,
@@ -98,7 +153,23 @@ pub enum PhoneticAlgorithm {
     /// [DaitchMokotoffSoundex] algorithm. You will need to provide the encoder's
     /// rules as a string.
     ///
-    DaitchMokotoffSoundex(DMRule, Folding, Branching),
+    /// Codes are truncated to [MaxCodeLength]. If `None` is provided, codes keep their
+    /// 6 digits. Codes that become identical once truncated are only emitted once.
+    ///
+    /// Codes are emitted in the order of the rules' branches, and only the first
+    /// [MaxBranches] of them are kept. This avoids index bloat with names that have
+    /// many branches.
+    ///
+    /// With [JoinBranches], branches are joined into a single token using the
+    /// given separator instead of being emitted as one token per branch.
+    DaitchMokotoffSoundex(
+        DMRule,
+        Folding,
+        Branching,
+        MaxBranches,
+        MaxCodeLength,
+        JoinBranches,
+    ),
     /// [DoubleMetaphone] algorithm. The integer is maximum length of generated codes.
     /// If `None` is provided, then the default maximum code length will apply.
     ///
@@ -134,19 +205,19 @@ pub enum PhoneticAlgorithm {
 // when calling build() on DaitchMokotoffSoundexBuilder.
 #[derive(Clone, Debug)]
 pub(crate) enum EncoderAlgorithm {
-    // We will recreate the BeiderMorse as it has a lifetime, and it could be in the phonetic token filter...
-    BeiderMorse(
-        &'static ConfigFiles,
-        Option<NameType>,
-        Option<RuleType>,
-        Option<bool>,
-        Option<usize>,
-        Option<LanguageSet>,
-    ),
+    // Config files are 'static, so the encoder is built once. The integer is the
+    // maximum number of phonemes.
+    BeiderMorse(BeiderMorse<'static>, usize, Option<LanguageSet>),
     Caverphone1(Caverphone1),
     Caverphone2(Caverphone2),
     Cologne(Cologne),
-    DaitchMokotoffSoundex(DaitchMokotoffSoundex, bool),
+    DaitchMokotoffSoundex(
+        DaitchMokotoffSoundex,
+        bool,
+        Option<usize>,
+        Option<usize>,
+        Option<String>,
+    ),
     DoubleMetaphone(DoubleMetaphone, bool),
     MatchRatingApproach(MatchRatingApproach),
     Metaphone(Metaphone),
@@ -187,12 +258,22 @@ impl TryFrom<&PhoneticAlgorithm> for EncoderAlgorithm {
                 } else {
                     Some(languages_set)
                 };
+                let mut builder = BeiderMorseBuilder::new(config_files);
+                if let Some(name_type) = name_type {
+                    builder = builder.name_type(*name_type);
+                }
+                if let Some(rule_type) = rule_type {
+                    builder = builder.rule_type(*rule_type);
+                }
+                if let Some(concat) = concat.0 {
+                    builder = builder.concat(concat);
+                }
+                if let Some(max_phonemes) = max_phonames.0 {
+                    builder = builder.max_phonemes(max_phonemes);
+                }
                 Ok(EncoderAlgorithm::BeiderMorse(
-                    config_files,
-                    *name_type,
-                    *rule_type,
-                    concat.0,
-                    max_phonames.0,
+                    builder.build(),
+                    max_phonames.0.unwrap_or(20),
                     languages_set,
                 ))
             }
@@ -200,7 +281,14 @@ impl TryFrom<&PhoneticAlgorithm> for EncoderAlgorithm {
             PhoneticAlgorithm::Caverphone2 => Ok(EncoderAlgorithm::Caverphone2(Caverphone2)),
             PhoneticAlgorithm::Cologne => Ok(EncoderAlgorithm::Cologne(Cologne)),
             #[cfg(feature = "embedded_dm")]
-            PhoneticAlgorithm::DaitchMokotoffSoundex(rules, ascii_folding, branching) => {
+            PhoneticAlgorithm::DaitchMokotoffSoundex(
+                rules,
+                ascii_folding,
+                branching,
+                max_branches,
+                code_length,
+                join_branches,
+            ) => {
                 let encoder = match &rules.0 {
                     None => DaitchMokotoffSoundexBuilder::default()
                         .ascii_folding(ascii_folding.0)
@@ -212,16 +300,29 @@ impl TryFrom<&PhoneticAlgorithm> for EncoderAlgorithm {
                 Ok(EncoderAlgorithm::DaitchMokotoffSoundex(
                     encoder,
                     branching.0,
+                    max_branches.0,
+                    code_length.0,
+                    join_branches.0.clone(),
                 ))
             }
             #[cfg(not(feature = "embedded_dm"))]
-            PhoneticAlgorithm::DaitchMokotoffSoundex(rules, ascii_folding, branching) => {
+            PhoneticAlgorithm::DaitchMokotoffSoundex(
+                rules,
+                ascii_folding,
+                branching,
+                max_branches,
+                code_length,
+                join_branches,
+            ) => {
                 let encoder = DaitchMokotoffSoundexBuilder::with_rules(rules.0.as_str())
                     .ascii_folding(ascii_folding.0)
                     .build()?;
                 Ok(EncoderAlgorithm::DaitchMokotoffSoundex(
                     encoder,
                     branching.0,
+                    max_branches.0,
+                    code_length.0,
+                    join_branches.0.clone(),
                 ))
             }
             PhoneticAlgorithm::DoubleMetaphone(max_code_length, use_alternate) => {
@@ -0,0 +1,227 @@
+pub use token_filter::MultiPhoneticTokenFilter;
+use token_stream::MultiPhoneticTokenStream;
+use wrapper::MultiPhoneticFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use lazy_static::lazy_static;
+    use rphonetic::{ConfigFiles, RuleType};
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+    use crate::phonetic::{
+        Alternate, Concat, Error, InjectOrder, Mapping, MaxCodeLength, MaxPhonemeNumber,
+        PhoneticAlgorithm, PhoneticTokenFilter, SpecialHW,
+    };
+
+    lazy_static! {
+        static ref CONFIG_FILES: ConfigFiles =
+            ConfigFiles::new(&PathBuf::from("./test_assets/bm-cc-rules")).unwrap();
+    }
+
+    fn token_stream_helper(text: &str, token_filter: MultiPhoneticTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(token_filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    fn algorithms() -> Vec<PhoneticAlgorithm> {
+        vec![
+            PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None)),
+            PhoneticAlgorithm::Metaphone(MaxCodeLength(None)),
+        ]
+    }
+
+    #[test]
+    fn test_inject() -> Result<(), Error> {
+        let token_filter: MultiPhoneticTokenFilter = (algorithms(), true).try_into()?;
+        let result = token_stream_helper("Robert Rupert", token_filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "Robert".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "R163".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "RBRT".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 13,
+                position: 1,
+                text: "Rupert".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 13,
+                position: 1,
+                text: "R163".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 7,
+                offset_to: 13,
+                position: 1,
+                text: "RPRT".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_inject_code_first() -> Result<(), Error> {
+        let token_filter = MultiPhoneticTokenFilter::try_from((algorithms(), true))?
+            .with_inject_order(InjectOrder::CodeFirst);
+        let result: Vec<String> = token_stream_helper("Robert Rupert", token_filter)
+            .into_iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(
+            result,
+            vec!["R163", "RBRT", "Robert", "R163", "RPRT", "Rupert"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_inject() -> Result<(), Error> {
+        let token_filter: MultiPhoneticTokenFilter = (algorithms(), false).try_into()?;
+        let result = token_stream_helper("Robert", token_filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "R163".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 6,
+                position: 0,
+                text: "RBRT".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_inject_code_equal_to_token() -> Result<(), Error> {
+        // Metaphone code of "RBRT" is the token itself, it must be kept
+        let algorithms = vec![
+            PhoneticAlgorithm::Metaphone(MaxCodeLength(None)),
+            PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None)),
+        ];
+        let token_filter: MultiPhoneticTokenFilter = (algorithms, false).try_into()?;
+        let result = token_stream_helper("RBRT", token_filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "RBRT".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 4,
+                position: 0,
+                text: "R163".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dedupe() -> Result<(), Error> {
+        let algorithms = vec![
+            PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None)),
+            PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(Some(false))),
+        ];
+        let token_filter: MultiPhoneticTokenFilter = (algorithms, false).try_into()?;
+        let result = token_stream_helper("Robert", token_filter);
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 6,
+            position: 0,
+            text: "R163".to_string(),
+            position_length: 1,
+        }];
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_same_codes_as_phonetic_filter() -> Result<(), Error> {
+        let algorithms = vec![
+            PhoneticAlgorithm::BeiderMorse(
+                &CONFIG_FILES,
+                None,
+                Some(RuleType::Exact),
+                Concat(None),
+                MaxPhonemeNumber(None),
+                vec![],
+            ),
+            PhoneticAlgorithm::DoubleMetaphone(MaxCodeLength(None), Alternate(true)),
+            PhoneticAlgorithm::Phonex(MaxCodeLength(None)),
+        ];
+        let text = "Angelo Schmidt Robert";
+        for algorithm in algorithms {
+            let multi = MultiPhoneticTokenFilter::try_from((vec![algorithm.clone()], false))?;
+            let single = PhoneticTokenFilter::try_from((algorithm, false))?;
+            let mut expected: Vec<Token> = vec![];
+            for token in crate::phonetic::tests::token_stream_helper(text, single) {
+                if !expected.contains(&token) {
+                    expected.push(token);
+                }
+            }
+            assert_eq!(token_stream_helper(text, multi), expected);
+        }
+
+        Ok(())
+    }
+}
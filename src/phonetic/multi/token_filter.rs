@@ -0,0 +1,111 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::MultiPhoneticFilterWrapper;
+use crate::phonetic::{EncoderAlgorithm, Error, InjectOrder, PhoneticAlgorithm};
+
+/// This token filter applies several phonetic algorithms on each token. All
+/// codes are emitted at the same position as the original token, so a field
+/// can be searched with, for example, Soundex and Metaphone at the same time.
+///
+/// Identical codes produced by different algorithms are only emitted once.
+///
+/// ```rust
+/// # fn main() -> Result<(), tantivy_analysis_contrib::phonetic::Error> {
+/// use tantivy_analysis_contrib::phonetic::{Mapping, MaxCodeLength, MultiPhoneticTokenFilter, PhoneticAlgorithm, SpecialHW};
+///
+/// let algorithms = vec![
+///     PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None)),
+///     PhoneticAlgorithm::Metaphone(MaxCodeLength(None)),
+/// ];
+/// let token_filter = MultiPhoneticTokenFilter::try_from(algorithms)?;
+/// #    Ok(())
+/// # }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), tantivy_analysis_contrib::phonetic::Error> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::phonetic::{Mapping, MaxCodeLength, MultiPhoneticTokenFilter, PhoneticAlgorithm, SpecialHW};
+///
+/// let algorithms = vec![
+///     PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None)),
+///     PhoneticAlgorithm::Metaphone(MaxCodeLength(None)),
+/// ];
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(MultiPhoneticTokenFilter::try_from((algorithms, true))?)
+///    .build();
+/// let mut token_stream = tmp.token_stream("Robert");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "Robert".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "R163".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "RBRT".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultiPhoneticTokenFilter {
+    algorithms: Vec<EncoderAlgorithm>,
+    inject: bool,
+    order: InjectOrder,
+}
+
+impl MultiPhoneticTokenFilter {
+    /// Set the order of the original token and the codes when codes are injected.
+    /// It has no effect if codes replace the original token.
+    pub fn with_inject_order(mut self, order: InjectOrder) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+impl TokenFilter for MultiPhoneticTokenFilter {
+    type Tokenizer<T: Tokenizer> = MultiPhoneticFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
+        MultiPhoneticFilterWrapper::new(token_stream, self.algorithms, self.inject, self.order)
+    }
+}
+
+/// Get the token filter from several [PhoneticAlgorithm]. Codes are emitted
+/// in the order of the algorithms.
+///
+/// The boolean indicates if encoded values should be treated as synonyms (`true`), in
+/// this case the original token will be present, or if they should replace (`false`) the
+/// original token.
+impl TryFrom<(Vec<PhoneticAlgorithm>, bool)> for MultiPhoneticTokenFilter {
+    type Error = Error;
+
+    fn try_from((value, inject): (Vec<PhoneticAlgorithm>, bool)) -> Result<Self, Self::Error> {
+        let algorithms = value
+            .iter()
+            .map(EncoderAlgorithm::try_from)
+            .collect::<Result<Vec<EncoderAlgorithm>, Error>>()?;
+        Ok(Self {
+            algorithms,
+            inject,
+            order: InjectOrder::default(),
+        })
+    }
+}
+
+/// Get the token filter from several [PhoneticAlgorithm]. Codes are emitted
+/// in the order of the algorithms.
+///
+/// Encoded values will be added as synonyms; that means the original
+/// token will be present.
+impl TryFrom<Vec<PhoneticAlgorithm>> for MultiPhoneticTokenFilter {
+    type Error = Error;
+
+    fn try_from(value: Vec<PhoneticAlgorithm>) -> Result<Self, Self::Error> {
+        (value, true).try_into()
+    }
+}
@@ -0,0 +1,82 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+use crate::phonetic::{EncoderAlgorithm, InjectOrder};
+
+#[derive(Debug)]
+pub struct MultiPhoneticTokenStream<'a, T> {
+    tail: T,
+    algorithms: &'a [EncoderAlgorithm],
+    inject: bool,
+    order: InjectOrder,
+    codes: VecDeque<String>,
+}
+
+impl<'a, T> MultiPhoneticTokenStream<'a, T> {
+    pub(crate) fn new(
+        tail: T,
+        algorithms: &'a [EncoderAlgorithm],
+        inject: bool,
+        order: InjectOrder,
+    ) -> Self {
+        Self {
+            tail,
+            algorithms,
+            inject,
+            order,
+            codes: VecDeque::with_capacity(10),
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for MultiPhoneticTokenStream<'_, T> {
+    fn advance(&mut self) -> bool {
+        if let Some(code) = self.codes.pop_front() {
+            self.tail.token_mut().text = code;
+            return true;
+        }
+
+        if !self.tail.advance() {
+            return false;
+        }
+        if self.tail.token().text.is_empty() {
+            return true;
+        }
+
+        for algorithm in self.algorithms {
+            // A failing encoder gives no code
+            let codes = algorithm.codes(&self.tail.token().text).unwrap_or_default();
+            for code in codes {
+                // When injecting, a code equal to the token would duplicate it
+                let original = self.inject && code == self.tail.token().text;
+                if !original && !self.codes.contains(&code) {
+                    self.codes.push_back(code);
+                }
+            }
+        }
+
+        if !self.inject || self.order == InjectOrder::CodeFirst {
+            if let Some(code) = self.codes.pop_front() {
+                let original = std::mem::replace(&mut self.tail.token_mut().text, code);
+                if self.inject {
+                    // The original token goes after the codes
+                    self.codes.push_back(original);
+                }
+            }
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
@@ -0,0 +1,45 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::MultiPhoneticTokenStream;
+use crate::phonetic::{EncoderAlgorithm, InjectOrder};
+
+#[derive(Clone, Debug)]
+pub struct MultiPhoneticFilterWrapper<T> {
+    algorithms: Vec<EncoderAlgorithm>,
+    inject: bool,
+    order: InjectOrder,
+    inner: T,
+}
+
+impl<T> MultiPhoneticFilterWrapper<T> {
+    pub(crate) fn new(
+        inner: T,
+        algorithms: Vec<EncoderAlgorithm>,
+        inject: bool,
+        order: InjectOrder,
+    ) -> Self {
+        Self {
+            algorithms,
+            inject,
+            order,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for MultiPhoneticFilterWrapper<T> {
+    type TokenStream<'a> = MultiPhoneticTokenStream<'a, T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        MultiPhoneticTokenStream::new(
+            self.inner.token_stream(text),
+            &self.algorithms,
+            self.inject,
+            self.order,
+        )
+    }
+}
@@ -0,0 +1,193 @@
+//! A ready to use analysis chain for person names.
+
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::{Error, PhoneticAlgorithm, PhoneticTokenFilter};
+use crate::commons::{
+    CharGroupTokenizerBuilder, ElisionTokenFilter, QuoteBracketTokenFilter, SearchFoldTokenFilter,
+};
+
+/// Characters that separate the parts of a name.
+const NAME_SEPARATORS: [char; 11] = [' ', '\t', '\r', '\n', '-', ',', '.', ';', '/', '(', ')'];
+
+/// Language of the names, it decides which elided particles are removed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NameLanguage {
+    /// No particle is removed, apostrophes are only dropped (`O'Brien` gives `obrien`).
+    /// It suits English, German, Spanish, Dutch... names.
+    #[default]
+    Generic,
+    /// `d'` and `l'` are removed (`d'Estaing` gives `estaing`).
+    French,
+    /// `d'`, `l'`, `dall'`, `dell'`... are removed (`Dell'Acqua` gives `acqua`).
+    Italian,
+    /// `d'`, `l'`, `n'` and `s'` are removed (`d'Ors` gives `ors`).
+    Catalan,
+}
+
+impl NameLanguage {
+    fn elisions(self) -> &'static [&'static str] {
+        match self {
+            NameLanguage::Generic => &[],
+            NameLanguage::French => &["d", "l"],
+            NameLanguage::Italian => &["d", "l", "dall", "dell", "nell", "sull"],
+            NameLanguage::Catalan => &["d", "l", "n", "s"],
+        }
+    }
+}
+
+/// Build an analysis chain tuned for searching person names.
+///
+/// Names are split on whitespaces, hyphens and punctuation, typographic apostrophes
+/// are replaced by `'`, then accents, width and case are folded with a
+/// [SearchFoldTokenFilter]. Elided particles of the `language` are removed, remaining
+/// apostrophes are dropped and finally each part is replaced by its phonetic code
+/// with `algorithm`. So `Seán O’Brien` matches `sean obrien`, and spelling variants
+/// such as `Smyth` match `Smith` through their codes.
+///
+/// Codes replace the parts, rather than being added as synonyms, so that the same
+/// analyzer can be used at query time : a query parser builds a phrase query from
+/// tokens at the same position, which would require the exact spelling.
+///
+/// The result is a [Tokenizer], wrap it into a `TextAnalyzer` to register it
+/// into an index.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use tantivy::tokenizer::TextAnalyzer;
+/// use tantivy_analysis_contrib::phonetic::{
+///     name_search_analyzer, MaxCodeLength, NameLanguage, PhoneticAlgorithm,
+/// };
+///
+/// let algorithm = PhoneticAlgorithm::Metaphone(MaxCodeLength(None));
+/// let mut analyzer = TextAnalyzer::from(name_search_analyzer(algorithm, NameLanguage::French)?);
+/// let mut token_stream = analyzer.token_stream("Giscard d'Estaing");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "JSKR".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ESTN".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #     Ok(())
+/// # }
+/// ```
+pub fn name_search_analyzer(
+    algorithm: PhoneticAlgorithm,
+    language: NameLanguage,
+) -> Result<impl Tokenizer, Error> {
+    let tokenizer = CharGroupTokenizerBuilder::default()
+        .split_chars(NAME_SEPARATORS.to_vec())
+        .build()
+        .expect("Name separators should be valid");
+    let phonetic = PhoneticTokenFilter::try_from((algorithm, false))?;
+
+    let tokenizer = QuoteBracketTokenFilter::new(false).transform(tokenizer);
+    let tokenizer = SearchFoldTokenFilter.transform(tokenizer);
+    let tokenizer = ElisionTokenFilter::from_iter_str(language.elisions().iter().copied(), true)
+        .transform(tokenizer);
+    let tokenizer = QuoteBracketTokenFilter::new(true).transform(tokenizer);
+    Ok(phonetic.transform(tokenizer))
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::collector::Count;
+    use tantivy::query::QueryParser;
+    use tantivy::schema::{IndexRecordOption, SchemaBuilder, TextFieldIndexing, TextOptions};
+    use tantivy::tokenizer::{TextAnalyzer, Token};
+    use tantivy::{doc, Index, ReloadPolicy};
+
+    use super::*;
+    use crate::phonetic::MaxCodeLength;
+
+    fn analyzer(language: NameLanguage) -> TextAnalyzer {
+        let algorithm = PhoneticAlgorithm::Metaphone(MaxCodeLength(None));
+        TextAnalyzer::from(name_search_analyzer(algorithm, language).unwrap())
+    }
+
+    fn terms(text: &str, language: NameLanguage) -> Vec<String> {
+        let mut a = analyzer(language);
+        let mut token_stream = a.token_stream(text);
+
+        let mut terms = vec![];
+        token_stream.process(&mut |token: &Token| terms.push(token.text.clone()));
+        terms
+    }
+
+    #[test]
+    fn test_normalization() {
+        assert_eq!(
+            terms("Seán O’Brien", NameLanguage::Generic),
+            terms("sean obrien", NameLanguage::Generic)
+        );
+        assert_eq!(
+            terms("Jean-Pierre", NameLanguage::Generic),
+            terms("jean pierre", NameLanguage::Generic)
+        );
+        assert_eq!(
+            terms("Dell'Acqua", NameLanguage::Italian),
+            terms("acqua", NameLanguage::Italian)
+        );
+
+        // Without elision, the particle stays in the name
+        assert_eq!(
+            terms("Dell'Acqua", NameLanguage::Generic),
+            terms("dellacqua", NameLanguage::Generic)
+        );
+        assert_ne!(
+            terms("Dell'Acqua", NameLanguage::Generic),
+            terms("acqua", NameLanguage::Generic)
+        );
+    }
+
+    #[test]
+    fn test_search_names() -> Result<(), Box<dyn std::error::Error>> {
+        const ANALYSIS_NAME: &str = "names";
+
+        let options = TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(ANALYSIS_NAME)
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        );
+        let mut schema = SchemaBuilder::new();
+        let field = schema.add_text_field("name", options);
+        let index = Index::create_in_ram(schema.build());
+        index
+            .tokenizers()
+            .register(ANALYSIS_NAME, analyzer(NameLanguage::French));
+
+        let mut index_writer = index.writer(15_000_000)?;
+        index_writer.add_document(doc!(field => "Seán O’Brien"))?;
+        index_writer.add_document(doc!(field => "Valéry Giscard d'Estaing"))?;
+        index_writer.add_document(doc!(field => "Jean-Pierre Smith"))?;
+        index_writer.commit()?;
+
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()?;
+        let searcher = reader.searcher();
+        let query_parser = QueryParser::for_index(&index, vec![field]);
+
+        for (query, expected) in [
+            ("sean", 1),
+            ("obrien", 1),
+            ("obrian", 1),
+            ("valery", 1),
+            ("estaing", 1),
+            ("jean", 1),
+            ("smyth", 1),
+            ("dupont", 0),
+        ] {
+            let query = query_parser.parse_query(query)?;
+            let count = searcher.search(&query, &Count)?;
+            assert_eq!(count, expected, "{query:?}");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,408 @@
+//! Construction of a [PhoneticAlgorithm] from its name and parameters, for
+//! configuration driven analysis chains.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[cfg(feature = "embedded_bm")]
+use rphonetic::{ConfigFiles, NameType, RuleType};
+
+use super::{
+    Alternate, Branching, DMRule, Error, Folding, JoinBranches, Mapping, MaxBranches,
+    MaxCodeLength, PhoneticAlgorithm, SpecialHW, Strict,
+};
+#[cfg(feature = "embedded_bm")]
+use super::{Concat, MaxPhonemeNumber};
+
+const MAX_CODE_LENGTH: &str = "max_code_length";
+
+/// Supported parameters of each algorithm, [None] if the algorithm is unknown.
+fn supported_parameters(name: &str) -> Option<&'static [&'static str]> {
+    let parameters: &[&str] = match name {
+        "beider_morse" => &[
+            "name_type",
+            "rule_type",
+            "concat",
+            "max_phonemes",
+            "languages",
+        ],
+        "caverphone1" | "caverphone2" | "cologne" | "match_rating_approach" => &[],
+        "daitch_mokotoff" => &[
+            "rules",
+            "folding",
+            "branching",
+            "max_branches",
+            MAX_CODE_LENGTH,
+            "join_branches",
+        ],
+        "double_metaphone" => &[MAX_CODE_LENGTH, "alternate"],
+        "metaphone" | "phonex" => &[MAX_CODE_LENGTH],
+        "nysiis" => &["strict"],
+        "refined_soundex" => &["mapping"],
+        "soundex" => &["mapping", "special_hw"],
+        _ => return None,
+    };
+    Some(parameters)
+}
+
+/// Parameters of an algorithm, with errors that tell which algorithm and parameter fail.
+struct Parameters<'a> {
+    algorithm: &'a str,
+    parameters: &'a HashMap<String, String>,
+}
+
+impl Parameters<'_> {
+    fn get(&self, parameter: &str) -> Option<&str> {
+        self.parameters.get(parameter).map(String::as_str)
+    }
+
+    fn invalid(&self, parameter: &str, value: &str) -> Error {
+        Error::InvalidParameter {
+            algorithm: self.algorithm.to_string(),
+            parameter: parameter.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    fn parse<T: FromStr>(&self, parameter: &str) -> Result<Option<T>, Error> {
+        self.get(parameter)
+            .map(|value| value.parse().map_err(|_| self.invalid(parameter, value)))
+            .transpose()
+    }
+
+    fn mapping(&self) -> Result<Mapping, Error> {
+        let Some(value) = self.get("mapping") else {
+            return Ok(Mapping(None));
+        };
+        let mapping: Vec<char> = value.chars().collect();
+        let mapping = mapping
+            .try_into()
+            .map_err(|_| self.invalid("mapping", value))?;
+        Ok(Mapping(Some(mapping)))
+    }
+
+    #[cfg(feature = "embedded_dm")]
+    fn dm_rule(&self) -> Result<DMRule, Error> {
+        Ok(DMRule(self.get("rules").map(str::to_string)))
+    }
+
+    #[cfg(not(feature = "embedded_dm"))]
+    fn dm_rule(&self) -> Result<DMRule, Error> {
+        match self.get("rules") {
+            Some(rules) => Ok(DMRule(rules.to_string())),
+            None => Err(Error::MissingParameter {
+                algorithm: self.algorithm.to_string(),
+                parameter: "rules".to_string(),
+            }),
+        }
+    }
+
+    #[cfg(feature = "embedded_bm")]
+    fn beider_morse(&self) -> Result<PhoneticAlgorithm, Error> {
+        static CONFIG_FILES: std::sync::OnceLock<ConfigFiles> = std::sync::OnceLock::new();
+
+        let name_type = match self.get("name_type") {
+            None => None,
+            Some("ash" | "ashkenazi") => Some(NameType::Ashkenazi),
+            Some("gen" | "generic") => Some(NameType::Generic),
+            Some("sep" | "sephardic") => Some(NameType::Sephardic),
+            Some(value) => return Err(self.invalid("name_type", value)),
+        };
+        let rule_type = match self.get("rule_type") {
+            None => None,
+            Some("approx") => Some(RuleType::Approx),
+            Some("exact") => Some(RuleType::Exact),
+            Some(value) => return Err(self.invalid("rule_type", value)),
+        };
+        let languages = self
+            .get("languages")
+            .map(|languages| {
+                languages
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|language| !language.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(PhoneticAlgorithm::BeiderMorse(
+            CONFIG_FILES.get_or_init(ConfigFiles::default),
+            name_type,
+            rule_type,
+            Concat(self.parse("concat")?),
+            MaxPhonemeNumber(self.parse("max_phonemes")?),
+            languages,
+        ))
+    }
+
+    #[cfg(not(feature = "embedded_bm"))]
+    fn beider_morse(&self) -> Result<PhoneticAlgorithm, Error> {
+        // Rule files can't be given as a parameter
+        Err(Error::MissingParameter {
+            algorithm: self.algorithm.to_string(),
+            parameter: "config_files".to_string(),
+        })
+    }
+}
+
+impl PhoneticAlgorithm {
+    /// Construct a [PhoneticAlgorithm] from its name and parameters, for example from a
+    /// configuration file. Names are case-insensitive, parameters are the ones of the
+    /// variant, in snake case, and missing parameters take their default value.
+    ///
+    /// | Name | Parameters |
+    /// |------|------------|
+    /// | `beider_morse` | `name_type` (`ash`, `gen` or `sep`), `rule_type` (`approx` or `exact`), `concat`, `max_phonemes`, `languages` (comma separated) |
+    /// | `caverphone1`, `caverphone2`, `cologne`, `match_rating_approach` | |
+    /// | `daitch_mokotoff` | `rules`, `folding` (default `true`), `branching` (default `true`), `max_branches`, `max_code_length`, `join_branches` |
+    /// | `double_metaphone` | `max_code_length`, `alternate` (default `false`) |
+    /// | `metaphone`, `phonex` | `max_code_length` |
+    /// | `nysiis` | `strict` |
+    /// | `refined_soundex` | `mapping` (26 characters) |
+    /// | `soundex` | `mapping` (26 characters), `special_hw` |
+    ///
+    /// `beider_morse` needs the `embedded_bm` feature, as rule files can't be given as a
+    /// parameter : it uses the embedded rules. `daitch_mokotoff` requires `rules` unless
+    /// the `embedded_dm` feature is enabled.
+    ///
+    /// It fails if the name or a parameter is unknown, if a required parameter is missing
+    /// or if a value can't be parsed.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), tantivy_analysis_contrib::phonetic::Error> {
+    /// use std::collections::HashMap;
+    ///
+    /// use tantivy_analysis_contrib::phonetic::{PhoneticAlgorithm, PhoneticTokenFilter};
+    ///
+    /// let parameters = HashMap::from([("max_code_length".to_string(), "4".to_string())]);
+    /// let algorithm = PhoneticAlgorithm::from_name("double_metaphone", &parameters)?;
+    /// let token_filter = PhoneticTokenFilter::try_from(algorithm)?;
+    ///
+    /// // Without parameters
+    /// let algorithm: PhoneticAlgorithm = "soundex".parse()?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn from_name(name: &str, parameters: &HashMap<String, String>) -> Result<Self, Error> {
+        let name = name.to_lowercase();
+        let supported =
+            supported_parameters(&name).ok_or_else(|| Error::UnknownAlgorithm(name.clone()))?;
+        if let Some(parameter) = parameters
+            .keys()
+            .find(|parameter| !supported.contains(&parameter.as_str()))
+        {
+            return Err(Error::UnknownParameter {
+                algorithm: name,
+                parameter: parameter.clone(),
+            });
+        }
+
+        let parameters = Parameters {
+            algorithm: &name,
+            parameters,
+        };
+        let algorithm = match name.as_str() {
+            "beider_morse" => parameters.beider_morse()?,
+            "caverphone1" => PhoneticAlgorithm::Caverphone1,
+            "caverphone2" => PhoneticAlgorithm::Caverphone2,
+            "cologne" => PhoneticAlgorithm::Cologne,
+            "daitch_mokotoff" => PhoneticAlgorithm::DaitchMokotoffSoundex(
+                parameters.dm_rule()?,
+                Folding(parameters.parse("folding")?.unwrap_or(true)),
+                Branching(parameters.parse("branching")?.unwrap_or(true)),
+                MaxBranches(parameters.parse("max_branches")?),
+                MaxCodeLength(parameters.parse(MAX_CODE_LENGTH)?),
+                JoinBranches(parameters.get("join_branches").map(str::to_string)),
+            ),
+            "double_metaphone" => PhoneticAlgorithm::DoubleMetaphone(
+                MaxCodeLength(parameters.parse(MAX_CODE_LENGTH)?),
+                Alternate(parameters.parse("alternate")?.unwrap_or(false)),
+            ),
+            "match_rating_approach" => PhoneticAlgorithm::MatchRatingApproach,
+            "metaphone" => {
+                PhoneticAlgorithm::Metaphone(MaxCodeLength(parameters.parse(MAX_CODE_LENGTH)?))
+            }
+            "nysiis" => PhoneticAlgorithm::Nysiis(Strict(parameters.parse("strict")?)),
+            "phonex" => {
+                PhoneticAlgorithm::Phonex(MaxCodeLength(parameters.parse(MAX_CODE_LENGTH)?))
+            }
+            "refined_soundex" => PhoneticAlgorithm::RefinedSoundex(parameters.mapping()?),
+            "soundex" => PhoneticAlgorithm::Soundex(
+                parameters.mapping()?,
+                SpecialHW(parameters.parse("special_hw")?),
+            ),
+            _ => unreachable!("Parameters of unknown algorithms are rejected before"),
+        };
+
+        Ok(algorithm)
+    }
+}
+
+/// Construct a [PhoneticAlgorithm] from its name, with default parameters. See
+/// [PhoneticAlgorithm::from_name].
+impl FromStr for PhoneticAlgorithm {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name, &HashMap::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters(parameters: &[(&str, &str)]) -> HashMap<String, String> {
+        parameters
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_names() -> Result<(), Error> {
+        assert!(matches!(
+            "Caverphone1".parse()?,
+            PhoneticAlgorithm::Caverphone1
+        ));
+        assert!(matches!("cologne".parse()?, PhoneticAlgorithm::Cologne));
+        assert!(matches!(
+            "metaphone".parse()?,
+            PhoneticAlgorithm::Metaphone(MaxCodeLength(None))
+        ));
+        assert!(matches!(
+            "double_metaphone".parse()?,
+            PhoneticAlgorithm::DoubleMetaphone(MaxCodeLength(None), Alternate(false))
+        ));
+        assert!(matches!(
+            "SOUNDEX".parse()?,
+            PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parameters() -> Result<(), Error> {
+        let algorithm = PhoneticAlgorithm::from_name(
+            "double_metaphone",
+            &parameters(&[("max_code_length", "8"), ("alternate", "true")]),
+        )?;
+        assert!(matches!(
+            algorithm,
+            PhoneticAlgorithm::DoubleMetaphone(MaxCodeLength(Some(8)), Alternate(true))
+        ));
+
+        let algorithm =
+            PhoneticAlgorithm::from_name("nysiis", &parameters(&[("strict", "false")]))?;
+        assert!(matches!(
+            algorithm,
+            PhoneticAlgorithm::Nysiis(Strict(Some(false)))
+        ));
+
+        let mapping = "01230120022455012623010202";
+        let algorithm = PhoneticAlgorithm::from_name(
+            "soundex",
+            &parameters(&[("mapping", mapping), ("special_hw", "false")]),
+        )?;
+        let PhoneticAlgorithm::Soundex(Mapping(Some(result)), SpecialHW(Some(false))) = algorithm
+        else {
+            panic!("Unexpected algorithm {algorithm:?}");
+        };
+        assert_eq!(String::from_iter(result), mapping);
+
+        let algorithm = PhoneticAlgorithm::from_name(
+            "daitch_mokotoff",
+            &parameters(&[("rules", "\"a\" \"0\" \"\" \"\""), ("max_branches", "2")]),
+        )?;
+        assert!(matches!(
+            algorithm,
+            PhoneticAlgorithm::DaitchMokotoffSoundex(
+                _,
+                Folding(true),
+                Branching(true),
+                MaxBranches(Some(2)),
+                MaxCodeLength(None),
+                JoinBranches(None),
+            )
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            "eudex".parse::<PhoneticAlgorithm>().unwrap_err(),
+            Error::UnknownAlgorithm("eudex".to_string())
+        );
+        assert_eq!(
+            PhoneticAlgorithm::from_name("metaphone", &parameters(&[("alternate", "true")]))
+                .unwrap_err(),
+            Error::UnknownParameter {
+                algorithm: "metaphone".to_string(),
+                parameter: "alternate".to_string(),
+            }
+        );
+        assert_eq!(
+            PhoneticAlgorithm::from_name("metaphone", &parameters(&[("max_code_length", "-1")]))
+                .unwrap_err(),
+            Error::InvalidParameter {
+                algorithm: "metaphone".to_string(),
+                parameter: "max_code_length".to_string(),
+                value: "-1".to_string(),
+            }
+        );
+        assert_eq!(
+            PhoneticAlgorithm::from_name("soundex", &parameters(&[("mapping", "0123")]))
+                .unwrap_err(),
+            Error::InvalidParameter {
+                algorithm: "soundex".to_string(),
+                parameter: "mapping".to_string(),
+                value: "0123".to_string(),
+            }
+        );
+    }
+
+    #[cfg(not(feature = "embedded_bm"))]
+    #[test]
+    fn test_beider_morse_without_rules() {
+        assert_eq!(
+            "beider_morse".parse::<PhoneticAlgorithm>().unwrap_err(),
+            Error::MissingParameter {
+                algorithm: "beider_morse".to_string(),
+                parameter: "config_files".to_string(),
+            }
+        );
+    }
+
+    #[cfg(feature = "embedded_bm")]
+    #[test]
+    fn test_beider_morse() -> Result<(), Error> {
+        let algorithm = PhoneticAlgorithm::from_name(
+            "beider_morse",
+            &parameters(&[("rule_type", "exact"), ("languages", "any, common")]),
+        )?;
+        let PhoneticAlgorithm::BeiderMorse(_, None, Some(RuleType::Exact), _, _, languages) =
+            algorithm
+        else {
+            panic!("Unexpected algorithm {algorithm:?}");
+        };
+        assert_eq!(languages, vec!["any", "common"]);
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "embedded_dm"))]
+    #[test]
+    fn test_daitch_mokotoff_without_rules() {
+        assert_eq!(
+            "daitch_mokotoff".parse::<PhoneticAlgorithm>().unwrap_err(),
+            Error::MissingParameter {
+                algorithm: "daitch_mokotoff".to_string(),
+                parameter: "rules".to_string(),
+            }
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
 
-use super::{EncoderAlgorithm, Error, PhoneticAlgorithm, PhoneticFilterWrapper};
+use super::{
+    EncoderAlgorithm, Error, InjectOrder, PhoneticAlgorithm, PhoneticFilterWrapper,
+    PositionIncrement,
+};
 
 /// This the phonetic token filter.
 /// It generates a token according
@@ -23,17 +26,140 @@ use super::{EncoderAlgorithm, Error, PhoneticAlgorithm, PhoneticFilterWrapper};
 /// #    Ok(())
 /// # }
 /// ```
+///
+/// When codes are injected, the original token comes first. Use
+/// [with_inject_order](PhoneticTokenFilter::with_inject_order) to put codes first :
+///
+/// ```rust
+/// # fn main() -> Result<(), tantivy_analysis_contrib::phonetic::Error> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::phonetic::{InjectOrder, MaxCodeLength, PhoneticAlgorithm, PhoneticTokenFilter};
+///
+/// let algorithm = PhoneticAlgorithm::Metaphone(MaxCodeLength(None));
+/// let token_filter = PhoneticTokenFilter::try_from(algorithm)?.with_inject_order(InjectOrder::CodeFirst);
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(token_filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("ccc");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "KKK".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ccc".to_string());
+///
+/// assert_eq!(None, token_stream.next());
+/// #    Ok(())
+/// # }
+/// ```
+///
+/// [PhoneticTokenFilter::builder] sets all options, including the [PositionIncrement] of
+/// codes :
+///
+/// ```rust
+/// # fn main() -> Result<(), tantivy_analysis_contrib::phonetic::Error> {
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::phonetic::{MaxCodeLength, PhoneticAlgorithm, PhoneticTokenFilter, PositionIncrement};
+///
+/// let algorithm = PhoneticAlgorithm::Metaphone(MaxCodeLength(None));
+/// let token_filter = PhoneticTokenFilter::builder(algorithm)
+///     .inject(true)
+///     .position_increment(PositionIncrement::Increment)
+///     .build()?;
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(token_filter)
+///    .build();
+/// let mut token_stream = tmp.token_stream("ccc");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "ccc".to_string());
+/// assert_eq!(token.position, 0);
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "KKK".to_string());
+/// assert_eq!(token.position, 1);
+///
+/// assert_eq!(None, token_stream.next());
+/// #    Ok(())
+/// # }
+/// ```
+///
+/// If the encoder panics on a token, the document is still indexed : the token is kept
+/// as is when codes are injected, otherwise it is removed. Tokens that don't give any code
+/// (numbers, punctuation...) are kept as is.
 #[derive(Clone, Debug)]
 pub struct PhoneticTokenFilter {
     algorithm: EncoderAlgorithm,
     inject: bool,
+    order: InjectOrder,
+    position_increment: PositionIncrement,
+}
+
+impl PhoneticTokenFilter {
+    /// Start building a [PhoneticTokenFilter] for the given algorithm. Codes are
+    /// injected by default.
+    pub fn builder(algorithm: PhoneticAlgorithm) -> PhoneticTokenFilterBuilder {
+        PhoneticTokenFilterBuilder {
+            algorithm,
+            inject: true,
+            order: InjectOrder::default(),
+            position_increment: PositionIncrement::default(),
+        }
+    }
+
+    /// Set the order of the original token and its codes when codes are injected.
+    /// It has no effect if codes replace the original token.
+    pub fn with_inject_order(mut self, order: InjectOrder) -> Self {
+        self.order = order;
+        self
+    }
 }
 
 impl TokenFilter for PhoneticTokenFilter {
     type Tokenizer<T: Tokenizer> = PhoneticFilterWrapper<T>;
 
     fn transform<T: Tokenizer>(self, token_stream: T) -> Self::Tokenizer<T> {
-        PhoneticFilterWrapper::new(token_stream, self.algorithm, self.inject)
+        PhoneticFilterWrapper::new(token_stream, self.algorithm, self.inject, self.order)
+            .with_position_increment(self.position_increment)
+    }
+}
+
+/// Builder for [PhoneticTokenFilter], see [PhoneticTokenFilter::builder].
+#[derive(Clone, Debug)]
+pub struct PhoneticTokenFilterBuilder {
+    algorithm: PhoneticAlgorithm,
+    inject: bool,
+    order: InjectOrder,
+    position_increment: PositionIncrement,
+}
+
+impl PhoneticTokenFilterBuilder {
+    /// Indicate if codes are treated as synonyms (`true`), in this case the original
+    /// token is kept, or if they replace (`false`) the original token.
+    pub fn inject(mut self, inject: bool) -> Self {
+        self.inject = inject;
+        self
+    }
+
+    /// Set the order of the original token and its codes when codes are injected, as
+    /// [PhoneticTokenFilter::with_inject_order] does.
+    pub fn inject_order(mut self, order: InjectOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Set the position of codes relative to their token.
+    pub fn position_increment(mut self, position_increment: PositionIncrement) -> Self {
+        self.position_increment = position_increment;
+        self
+    }
+
+    /// Build the [PhoneticTokenFilter]. It fails if the algorithm can't be initialized.
+    pub fn build(self) -> Result<PhoneticTokenFilter, Error> {
+        let mut filter = PhoneticTokenFilter::try_from((self.algorithm, self.inject))?
+            .with_inject_order(self.order);
+        filter.position_increment = self.position_increment;
+        Ok(filter)
     }
 }
 
@@ -62,7 +188,12 @@ impl TryFrom<(&PhoneticAlgorithm, bool)> for PhoneticTokenFilter {
 
     fn try_from((value, inject): (&PhoneticAlgorithm, bool)) -> Result<Self, Self::Error> {
         let algorithm: EncoderAlgorithm = value.try_into()?;
-        Ok(Self { algorithm, inject })
+        Ok(Self {
+            algorithm,
+            inject,
+            order: InjectOrder::default(),
+            position_increment: PositionIncrement::default(),
+        })
     }
 }
 
@@ -92,6 +223,8 @@ impl TryFrom<&PhoneticAlgorithm> for PhoneticTokenFilter {
         Ok(Self {
             algorithm,
             inject: true,
+            order: InjectOrder::default(),
+            position_increment: PositionIncrement::default(),
         })
     }
 }
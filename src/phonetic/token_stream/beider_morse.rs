@@ -3,21 +3,26 @@ use std::collections::VecDeque;
 use rphonetic::{BeiderMorse, Encoder, LanguageSet};
 use tantivy_tokenizer_api::{Token, TokenStream};
 
+use super::try_encode;
+use crate::phonetic::InjectOrder;
+
 pub(crate) struct BeiderMorseTokenStream<'a, T> {
     tail: T,
-    encoder: BeiderMorse<'a>,
+    encoder: &'a BeiderMorse<'static>,
     codes: VecDeque<String>,
-    languages: Option<LanguageSet>,
+    languages: Option<&'a LanguageSet>,
     inject: bool,
+    order: InjectOrder,
 }
 
 impl<'a, T> BeiderMorseTokenStream<'a, T> {
     pub(crate) fn new(
         tail: T,
-        encoder: BeiderMorse<'a>,
+        encoder: &'a BeiderMorse<'static>,
         max_phonemes: usize,
-        languages: Option<LanguageSet>,
+        languages: Option<&'a LanguageSet>,
         inject: bool,
+        order: InjectOrder,
     ) -> Self {
         Self {
             tail,
@@ -25,6 +30,7 @@ impl<'a, T> BeiderMorseTokenStream<'a, T> {
             codes: VecDeque::with_capacity(max_phonemes),
             languages,
             inject,
+            order,
         }
     }
 }
@@ -39,40 +45,29 @@ impl<T: TokenStream> TokenStream for BeiderMorseTokenStream<'_, T> {
                 return true;
             }
 
-            let encoded = match &self.languages {
+            let encoded = try_encode(|| match self.languages {
                 None => self.encoder.encode(&self.tail.token().text),
                 Some(languages) => self
                     .encoder
                     .encode_with_languages(&self.tail.token().text, languages),
-            };
-            let mut start_token = 0;
-            let mut end_token = 0;
-            let mut start = true;
-            // "Simple" parsing of potentially nested (...|...|...)-(...|...|...)
-            for (index, ch) in encoded.char_indices() {
-                if ch != '(' && ch != ')' && ch != '-' && ch != '|' {
-                    if start {
-                        start_token = index;
-                        end_token = index;
-                        start = false;
-                    } else {
-                        end_token += 1;
-                    }
-                } else if start_token < end_token {
-                    self.codes
-                        .push_back(encoded[start_token..=end_token].to_string());
-                    start_token = end_token;
-                    start = true;
+            });
+            let Some(encoded) = encoded else {
+                // The encoder failed, the original token is only kept if injecting
+                if self.inject {
+                    return true;
                 }
-            }
-
-            // Handle last code
-            if start_token < end_token {
-                self.codes
-                    .push_back(encoded[start_token..=end_token].to_string());
-            }
+                continue;
+            };
+            self.codes.extend(codes(&encoded));
 
             if self.inject || encoded.is_empty() {
+                if self.order == InjectOrder::CodeFirst {
+                    // The original token goes after the codes
+                    if let Some(code) = self.codes.pop_front() {
+                        let original = std::mem::replace(&mut self.tail.token_mut().text, code);
+                        self.codes.push_back(original);
+                    }
+                }
                 return true;
             }
         }
@@ -96,6 +91,36 @@ impl<T: TokenStream> TokenStream for BeiderMorseTokenStream<'_, T> {
     }
 }
 
+/// Codes of an encoded value. "Simple" parsing of potentially nested
+/// `(...|...|...)-(...|...|...)`.
+pub(crate) fn codes(encoded: &str) -> Vec<String> {
+    let mut codes = Vec::new();
+    let mut start_token = 0;
+    let mut end_token = 0;
+    let mut start = true;
+    for (index, ch) in encoded.char_indices() {
+        if ch != '(' && ch != ')' && ch != '-' && ch != '|' {
+            if start {
+                start_token = index;
+                end_token = index;
+                start = false;
+            } else {
+                end_token += 1;
+            }
+        } else if start_token < end_token {
+            codes.push(encoded[start_token..=end_token].to_string());
+            start_token = end_token;
+            start = true;
+        }
+    }
+
+    // Handle last code
+    if start_token < end_token {
+        codes.push(encoded[start_token..=end_token].to_string());
+    }
+    codes
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
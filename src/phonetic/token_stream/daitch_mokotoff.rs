@@ -3,32 +3,52 @@ use std::collections::VecDeque;
 use rphonetic::DaitchMokotoffSoundex;
 use tantivy_tokenizer_api::{Token, TokenStream};
 
-pub(crate) struct DaitchMokotoffTokenStream<T> {
+use super::try_encode;
+use crate::phonetic::InjectOrder;
+
+pub(crate) struct DaitchMokotoffTokenStream<'a, T> {
     tail: T,
-    encoder: DaitchMokotoffSoundex,
+    encoder: &'a DaitchMokotoffSoundex,
     branching: bool,
+    max_branches: Option<usize>,
+    code_length: Option<usize>,
+    separator: Option<&'a str>,
     codes: VecDeque<String>,
     inject: bool,
+    order: InjectOrder,
 }
 
-impl<T> DaitchMokotoffTokenStream<T> {
+impl<'a, T> DaitchMokotoffTokenStream<'a, T> {
     pub(crate) fn new(
         tail: T,
-        encoder: DaitchMokotoffSoundex,
+        encoder: &'a DaitchMokotoffSoundex,
         branching: bool,
+        code_length: Option<usize>,
+        separator: Option<&'a str>,
         inject: bool,
+        order: InjectOrder,
     ) -> Self {
         Self {
             tail,
             encoder,
             branching,
+            max_branches: None,
+            code_length,
+            separator,
             codes: VecDeque::with_capacity(10),
             inject,
+            order,
         }
     }
+
+    /// Keep at most `max_branches` codes per token, [None] keeps them all.
+    pub(crate) fn with_max_branches(mut self, max_branches: Option<usize>) -> Self {
+        self.max_branches = max_branches;
+        self
+    }
 }
 
-impl<T: TokenStream> TokenStream for DaitchMokotoffTokenStream<T> {
+impl<T: TokenStream> TokenStream for DaitchMokotoffTokenStream<'_, T> {
     fn advance(&mut self) -> bool {
         while self.codes.is_empty() {
             let result = self.tail.advance();
@@ -39,15 +59,28 @@ impl<T: TokenStream> TokenStream for DaitchMokotoffTokenStream<T> {
                 return true;
             }
 
-            self.codes = self
-                .encoder
-                .inner_soundex(&self.tail.token().text, self.branching)
-                .iter()
-                .filter(|v| !v.is_empty())
-                .cloned()
-                .collect();
+            let encoded = try_encode(|| {
+                self.encoder
+                    .inner_soundex(&self.tail.token().text, self.branching)
+            });
+            let Some(encoded) = encoded else {
+                // The encoder failed, the original token is only kept if injecting
+                if self.inject {
+                    return true;
+                }
+                continue;
+            };
+
+            self.codes = codes(encoded, self.code_length, self.max_branches, self.separator).into();
 
             if self.inject {
+                if self.order == InjectOrder::CodeFirst {
+                    // The original token goes after the codes
+                    if let Some(code) = self.codes.pop_front() {
+                        let original = std::mem::replace(&mut self.tail.token_mut().text, code);
+                        self.codes.push_back(original);
+                    }
+                }
                 return true;
             }
         }
@@ -71,13 +104,42 @@ impl<T: TokenStream> TokenStream for DaitchMokotoffTokenStream<T> {
     }
 }
 
+/// Codes of the branches of a token : cut to `code_length`, deduplicated, at most
+/// `max_branches` of them and joined with `separator`, if any.
+pub(crate) fn codes(
+    encoded: Vec<String>,
+    code_length: Option<usize>,
+    max_branches: Option<usize>,
+    separator: Option<&str>,
+) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::new();
+    for code in encoded {
+        let code = match code_length {
+            Some(code_length) => code.chars().take(code_length).collect(),
+            None => code,
+        };
+        if !code.is_empty() && !codes.contains(&code) {
+            codes.push(code);
+        }
+    }
+    if let Some(max_branches) = max_branches {
+        codes.truncate(max_branches.max(1));
+    }
+
+    match separator {
+        Some(separator) if !codes.is_empty() => vec![codes.join(separator)],
+        _ => codes,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tantivy::tokenizer::Token;
 
     use crate::phonetic::tests::{token_stream_helper, token_stream_helper_raw};
     use crate::phonetic::{
-        Branching, DMRule, Error, Folding, PhoneticAlgorithm, PhoneticTokenFilter,
+        Branching, DMRule, Error, Folding, JoinBranches, MaxBranches, MaxCodeLength,
+        PhoneticAlgorithm, PhoneticTokenFilter,
     };
 
     const RULES: &str = include_str!("../../../test_assets/dm-cc-rules/dmrules.txt");
@@ -89,12 +151,18 @@ mod tests {
             DMRule(Some(RULES.to_string())),
             Folding(true),
             Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(None),
+            JoinBranches(None),
         );
         #[cfg(not(feature = "embedded_dm"))]
         let algorithm = PhoneticAlgorithm::DaitchMokotoffSoundex(
             DMRule(RULES.to_string()),
             Folding(true),
             Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(None),
+            JoinBranches(None),
         );
 
         let token_filter: PhoneticTokenFilter = (algorithm, true).try_into()?;
@@ -205,12 +273,18 @@ mod tests {
             DMRule(Some(RULES.to_string())),
             Folding(true),
             Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(None),
+            JoinBranches(None),
         );
         #[cfg(not(feature = "embedded_dm"))]
         let algorithm = PhoneticAlgorithm::DaitchMokotoffSoundex(
             DMRule(RULES.to_string()),
             Folding(true),
             Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(None),
+            JoinBranches(None),
         );
         let token_filter: PhoneticTokenFilter = (algorithm, false).try_into()?;
 
@@ -286,6 +360,132 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_branch_per_token_code_length() -> Result<(), Error> {
+        #[cfg(feature = "embedded_dm")]
+        let algorithm = PhoneticAlgorithm::DaitchMokotoffSoundex(
+            DMRule(Some(RULES.to_string())),
+            Folding(true),
+            Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(Some(2)),
+            JoinBranches(None),
+        );
+        #[cfg(not(feature = "embedded_dm"))]
+        let algorithm = PhoneticAlgorithm::DaitchMokotoffSoundex(
+            DMRule(RULES.to_string()),
+            Folding(true),
+            Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(Some(2)),
+            JoinBranches(None),
+        );
+
+        let token_filter: PhoneticTokenFilter = (algorithm, false).try_into()?;
+        let result = token_stream_helper("ccc easgasg", token_filter);
+        // 45 and 54 come from two branches each but are only emitted once
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "40".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "45".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "54".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "50".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 11,
+                position: 1,
+                text: "04".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_joined_branches() -> Result<(), Error> {
+        #[cfg(feature = "embedded_dm")]
+        let algorithm = PhoneticAlgorithm::DaitchMokotoffSoundex(
+            DMRule(Some(RULES.to_string())),
+            Folding(true),
+            Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(None),
+            JoinBranches(Some(",".to_string())),
+        );
+        #[cfg(not(feature = "embedded_dm"))]
+        let algorithm = PhoneticAlgorithm::DaitchMokotoffSoundex(
+            DMRule(RULES.to_string()),
+            Folding(true),
+            Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(None),
+            JoinBranches(Some(",".to_string())),
+        );
+
+        let token_filter: PhoneticTokenFilter = (algorithm, true).try_into()?;
+        let result = token_stream_helper("ccc easgasg", token_filter);
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "ccc".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 0,
+                offset_to: 3,
+                position: 0,
+                text: "400000,450000,454000,540000,545000,500000".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 11,
+                position: 1,
+                text: "easgasg".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 4,
+                offset_to: 11,
+                position: 1,
+                text: "045450".to_string(),
+                position_length: 1,
+            },
+        ];
+
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_empty_term() -> Result<(), Error> {
         #[cfg(feature = "embedded_dm")]
@@ -293,12 +493,18 @@ mod tests {
             DMRule(Some(RULES.to_string())),
             Folding(true),
             Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(None),
+            JoinBranches(None),
         );
         #[cfg(not(feature = "embedded_dm"))]
         let algorithm = PhoneticAlgorithm::DaitchMokotoffSoundex(
             DMRule(RULES.to_string()),
             Folding(true),
             Branching(true),
+            MaxBranches(None),
+            MaxCodeLength(None),
+            JoinBranches(None),
         );
 
         let token_filter: PhoneticTokenFilter = (algorithm, false).try_into()?;
@@ -316,4 +522,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_max_branches() -> Result<(), Error> {
+        let algorithm = |max_branches: Option<usize>| {
+            #[cfg(feature = "embedded_dm")]
+            let rules = DMRule(Some(RULES.to_string()));
+            #[cfg(not(feature = "embedded_dm"))]
+            let rules = DMRule(RULES.to_string());
+            PhoneticAlgorithm::DaitchMokotoffSoundex(
+                rules,
+                Folding(true),
+                Branching(true),
+                MaxBranches(max_branches),
+                MaxCodeLength(None),
+                JoinBranches(None),
+            )
+        };
+        let codes = |max_branches: Option<usize>| -> Result<Vec<String>, Error> {
+            let token_filter: PhoneticTokenFilter = (algorithm(max_branches), false).try_into()?;
+            Ok(token_stream_helper("Rosochowaciec", token_filter)
+                .into_iter()
+                .map(|token| token.text)
+                .collect())
+        };
+
+        // "Rosochowaciec" has 2 branches for "ch" and 2 for each "c"
+        assert_eq!(
+            codes(None)?,
+            vec!["944744", "944745", "944754", "944755", "945744", "945745", "945754", "945755"]
+        );
+        assert_eq!(codes(Some(3))?, vec!["944744", "944745", "944754"]);
+        // At least one code is kept
+        assert_eq!(codes(Some(0))?, vec!["944744"]);
+
+        Ok(())
+    }
 }
@@ -1,20 +1,25 @@
 use rphonetic::DoubleMetaphone;
 use tantivy_tokenizer_api::{Token, TokenStream};
 
+use super::try_encode;
+use crate::phonetic::InjectOrder;
+
 pub(crate) struct DoubleMetaphoneTokenStream<T> {
     tail: T,
     encoder: DoubleMetaphone,
     codes: Vec<String>,
     inject: bool,
+    order: InjectOrder,
 }
 
 impl<T> DoubleMetaphoneTokenStream<T> {
-    pub(crate) fn new(tail: T, encoder: DoubleMetaphone, inject: bool) -> Self {
+    pub(crate) fn new(tail: T, encoder: DoubleMetaphone, inject: bool, order: InjectOrder) -> Self {
         Self {
             tail,
             encoder,
             codes: Vec::with_capacity(10),
             inject,
+            order,
         }
     }
 }
@@ -32,7 +37,15 @@ impl<T: TokenStream> TokenStream for DoubleMetaphoneTokenStream<T> {
                     return true;
                 }
 
-                let encoded = self.encoder.double_metaphone(&self.tail.token().text);
+                let encoded = try_encode(|| self.encoder.double_metaphone(&self.tail.token().text));
+                let Some(encoded) = encoded else {
+                    // The encoder failed, the original token is only kept if injecting
+                    if self.inject {
+                        return true;
+                    }
+                    result = false;
+                    continue;
+                };
                 let primary = encoded.primary();
                 let alternate = encoded.alternate();
                 if primary.is_empty() && alternate.is_empty() && self.inject {
@@ -67,6 +80,12 @@ impl<T: TokenStream> TokenStream for DoubleMetaphoneTokenStream<T> {
                     result = true;
                 }
             }
+            if self.inject && self.order == InjectOrder::CodeFirst {
+                // Codes are popped from the end, the original token goes to the front
+                let code = self.codes.pop().unwrap();
+                let original = std::mem::replace(&mut self.tail.token_mut().text, code);
+                self.codes.insert(0, original);
+            }
             result
         } else {
             self.tail.token_mut().text = self.codes.pop().unwrap();
@@ -1,19 +1,29 @@
 use rphonetic::Encoder;
 use tantivy_tokenizer_api::{Token, TokenStream};
 
+use super::try_encode;
+use crate::phonetic::InjectOrder;
+
 pub(crate) struct GenericPhoneticTokenStream<T> {
     tail: T,
     encoder: Box<dyn Encoder>,
     inject: bool,
+    order: InjectOrder,
     backup: Option<String>,
 }
 
 impl<T> GenericPhoneticTokenStream<T> {
-    pub(crate) fn new(tail: T, encoder: Box<dyn Encoder>, inject: bool) -> Self {
+    pub(crate) fn new(
+        tail: T,
+        encoder: Box<dyn Encoder>,
+        inject: bool,
+        order: InjectOrder,
+    ) -> Self {
         Self {
             tail,
             encoder,
             inject,
+            order,
             backup: None,
         }
     }
@@ -35,7 +45,13 @@ impl<T: TokenStream> TokenStream for GenericPhoneticTokenStream<T> {
             if !tail_result {
                 return false;
             }
-            let token = self.encoder.encode(&self.tail.token().text);
+            let Some(token) = try_encode(|| self.encoder.encode(&self.tail.token().text)) else {
+                // The encoder failed, the original token is only kept if injecting
+                if self.inject {
+                    return true;
+                }
+                continue;
+            };
 
             if self.tail.token().text.is_empty() || token.is_empty() {
                 return true;
@@ -47,8 +63,13 @@ impl<T: TokenStream> TokenStream for GenericPhoneticTokenStream<T> {
             } else if !token.is_empty() {
                 // Otherwise, if token isn't empty
                 if self.inject {
-                    // We back it up if inject
-                    self.backup = Some(token)
+                    // We back up what comes second if inject
+                    self.backup = Some(match self.order {
+                        InjectOrder::OriginalFirst => token,
+                        InjectOrder::CodeFirst => {
+                            std::mem::replace(&mut self.tail.token_mut().text, token)
+                        }
+                    })
                 } else {
                     // Otherwise we replace original token
                     self.tail.token_mut().text = token;
@@ -71,12 +92,16 @@ impl<T: TokenStream> TokenStream for GenericPhoneticTokenStream<T> {
 
 #[cfg(test)]
 mod tests {
-    use tantivy::tokenizer::Token;
+    use rphonetic::Encoder;
+    use tantivy::tokenizer::{Token, Tokenizer, WhitespaceTokenizer};
+    use tantivy_tokenizer_api::TokenStream;
+
+    use super::GenericPhoneticTokenStream;
 
     use crate::phonetic::tests::{token_stream_helper, token_stream_helper_raw};
     use crate::phonetic::{
-        Alternate, Error, Mapping, MaxCodeLength, PhoneticAlgorithm, PhoneticTokenFilter,
-        SpecialHW, Strict,
+        Alternate, Error, InjectOrder, Mapping, MaxCodeLength, PhoneticAlgorithm,
+        PhoneticTokenFilter, SpecialHW, Strict,
     };
 
     #[test]
@@ -150,6 +175,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_metaphone_inject_order() -> Result<(), Error> {
+        let token = |text: &str, offset_from, offset_to, position| Token {
+            offset_from,
+            offset_to,
+            position,
+            text: text.to_string(),
+            position_length: 1,
+        };
+
+        let algorithm = PhoneticAlgorithm::Metaphone(MaxCodeLength(None));
+        let token_filter = PhoneticTokenFilter::try_from(&algorithm)?
+            .with_inject_order(InjectOrder::OriginalFirst);
+        let result = token_stream_helper("aaa ccc", token_filter);
+        let expected = vec![
+            token("aaa", 0, 3, 0),
+            token("A", 0, 3, 0),
+            token("ccc", 4, 7, 1),
+            token("KKK", 4, 7, 1),
+        ];
+        assert_eq!(result, expected);
+
+        let token_filter =
+            PhoneticTokenFilter::try_from(&algorithm)?.with_inject_order(InjectOrder::CodeFirst);
+        let result = token_stream_helper("aaa ccc", token_filter);
+        let expected = vec![
+            token("A", 0, 3, 0),
+            token("aaa", 0, 3, 0),
+            token("KKK", 4, 7, 1),
+            token("ccc", 4, 7, 1),
+        ];
+        assert_eq!(result, expected);
+
+        // Order has no effect without inject
+        let token_filter = PhoneticTokenFilter::try_from((&algorithm, false))?
+            .with_inject_order(InjectOrder::CodeFirst);
+        let result = token_stream_helper("aaa ccc", token_filter);
+        let expected = vec![token("A", 0, 3, 0), token("KKK", 4, 7, 1)];
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_metaphone_not_inject() -> Result<(), Error> {
         let algorithm = PhoneticAlgorithm::Metaphone(MaxCodeLength(None));
@@ -964,4 +1032,69 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<(), Error> {
+        let algorithms: Vec<PhoneticAlgorithm> = serde_json::from_str(
+            r#"[
+                {"Metaphone": null},
+                {"DoubleMetaphone": [4, true]},
+                {"Nysiis": false},
+                {"Soundex": [null, true]}
+            ]"#,
+        )
+        .unwrap();
+        let json = serde_json::to_string(&algorithms).unwrap();
+        let algorithms: Vec<PhoneticAlgorithm> = serde_json::from_str(&json).unwrap();
+
+        let expected = vec![
+            PhoneticAlgorithm::Metaphone(MaxCodeLength(None)),
+            PhoneticAlgorithm::DoubleMetaphone(MaxCodeLength(Some(4)), Alternate(true)),
+            PhoneticAlgorithm::Nysiis(Strict(Some(false))),
+            PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(Some(true))),
+        ];
+        for (algorithm, expected) in algorithms.into_iter().zip(expected) {
+            let result = token_stream_helper("Thompson Smith", algorithm.try_into()?);
+            let expected = token_stream_helper("Thompson Smith", expected.try_into()?);
+            assert_eq!(result, expected);
+        }
+
+        Ok(())
+    }
+
+    /// Encoder that panics on a pathological token.
+    struct PanickingEncoder;
+
+    impl Encoder for PanickingEncoder {
+        fn encode(&self, s: &str) -> String {
+            assert_ne!(s, "boom", "Pathological token");
+            s.to_uppercase()
+        }
+    }
+
+    fn panicking_helper(text: &str, inject: bool) -> Vec<String> {
+        let mut tokenizer = WhitespaceTokenizer::default();
+        let mut token_stream = GenericPhoneticTokenStream::new(
+            tokenizer.token_stream(text),
+            Box::new(PanickingEncoder),
+            inject,
+            InjectOrder::OriginalFirst,
+        );
+
+        let mut tokens = vec![];
+        while token_stream.advance() {
+            tokens.push(token_stream.token().text.clone());
+        }
+        tokens
+    }
+
+    #[test]
+    fn test_encoder_panic() {
+        assert_eq!(panicking_helper("a boom b", false), vec!["A", "B"]);
+        assert_eq!(
+            panicking_helper("a boom b", true),
+            vec!["a", "A", "boom", "b", "B"]
+        );
+    }
 }
@@ -1,12 +1,22 @@
 //! Module that contains the [TokenStream] implementation. It's this that
 //! do the real job.
 
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
 pub(crate) use beider_morse::BeiderMorseTokenStream;
 pub(crate) use daitch_mokotoff::DaitchMokotoffTokenStream;
 pub(crate) use double_metaphone::DoubleMetaphoneTokenStream;
 pub(crate) use generic::GenericPhoneticTokenStream;
+pub(crate) use position_increment::{PositionIncrementTokenStream, SourceCountTokenStream};
 
-mod beider_morse;
-mod daitch_mokotoff;
+pub(crate) mod beider_morse;
+pub(crate) mod daitch_mokotoff;
 mod double_metaphone;
 mod generic;
+mod position_increment;
+
+/// Run an encoder on a token. A panic of the encoder gives [None], so a
+/// pathological token doesn't fail the whole document.
+pub(crate) fn try_encode<R>(encode: impl FnOnce() -> R) -> Option<R> {
+    catch_unwind(AssertUnwindSafe(encode)).ok()
+}
@@ -0,0 +1,208 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+/// Count the tokens that come from the tokenizer, so [PositionIncrementTokenStream] knows
+/// when a token comes from the same source as the previous one.
+pub(crate) struct SourceCountTokenStream<T> {
+    tail: T,
+    count: Rc<Cell<usize>>,
+}
+
+impl<T> SourceCountTokenStream<T> {
+    pub(crate) fn new(tail: T, count: Rc<Cell<usize>>) -> Self {
+        Self { tail, count }
+    }
+}
+
+impl<T: TokenStream> TokenStream for SourceCountTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        let result = self.tail.advance();
+        if result {
+            self.count.set(self.count.get() + 1);
+        }
+        result
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
+
+/// Give its own position to each token that comes from the same source as the
+/// previous one.
+pub(crate) struct PositionIncrementTokenStream<T> {
+    tail: T,
+    count: Rc<Cell<usize>>,
+    /// Number of source tokens when the previous token was emitted.
+    previous: usize,
+    /// Positions added by codes so far.
+    shift: usize,
+    token: Token,
+}
+
+impl<T> PositionIncrementTokenStream<T> {
+    pub(crate) fn new(tail: T, count: Rc<Cell<usize>>) -> Self {
+        Self {
+            tail,
+            count,
+            previous: 0,
+            shift: 0,
+            token: Token::default(),
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for PositionIncrementTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+
+        let count = self.count.get();
+        if count == self.previous {
+            self.shift += 1;
+        }
+        self.previous = count;
+
+        self.token.clone_from(self.tail.token());
+        self.token.position += self.shift;
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::phonetic::tests::token_stream_helper;
+    use crate::phonetic::{
+        Alternate, Error, InjectOrder, Mapping, MaxCodeLength, PhoneticAlgorithm,
+        PhoneticTokenFilter, PositionIncrement, SpecialHW,
+    };
+
+    fn positions(
+        text: &str,
+        algorithm: PhoneticAlgorithm,
+        inject: bool,
+        position_increment: PositionIncrement,
+    ) -> Result<Vec<(String, usize)>, Error> {
+        let token_filter = PhoneticTokenFilter::builder(algorithm)
+            .inject(inject)
+            .position_increment(position_increment)
+            .build()?;
+        Ok(token_stream_helper(text, token_filter)
+            .into_iter()
+            .map(|token| (token.text, token.position))
+            .collect())
+    }
+
+    fn expected(tokens: &[(&str, usize)]) -> Vec<(String, usize)> {
+        tokens
+            .iter()
+            .map(|(text, position)| (text.to_string(), *position))
+            .collect()
+    }
+
+    fn text_offset(source: &str) -> usize {
+        "ccc Schmidt 123".find(source).unwrap()
+    }
+
+    #[test]
+    fn test_same_position() -> Result<(), Error> {
+        let algorithms = vec![
+            PhoneticAlgorithm::Metaphone(MaxCodeLength(None)),
+            PhoneticAlgorithm::DoubleMetaphone(MaxCodeLength(None), Alternate(true)),
+            PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None)),
+        ];
+
+        for algorithm in algorithms {
+            let name = format!("{algorithm:?}");
+            let token_filter = PhoneticTokenFilter::builder(algorithm).build()?;
+            let tokens = token_stream_helper("ccc Schmidt 123", token_filter);
+
+            // Injected codes are at the position of their source token
+            let sources = ["ccc", "Schmidt", "123"];
+            for token in &tokens {
+                let source = sources
+                    .iter()
+                    .position(|source| text_offset(source) == token.offset_from)
+                    .expect("Token should come from a source token");
+                assert_eq!(token.position, source, "\n{name}: {token:?}");
+            }
+            assert!(tokens.len() > sources.len(), "\n{name}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_increment_inject() -> Result<(), Error> {
+        let result = positions(
+            "ccc hello",
+            PhoneticAlgorithm::Metaphone(MaxCodeLength(None)),
+            true,
+            PositionIncrement::Increment,
+        )?;
+        assert_eq!(
+            result,
+            expected(&[("ccc", 0), ("KKK", 1), ("hello", 2), ("HL", 3)])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_increment_alternate() -> Result<(), Error> {
+        let result = positions(
+            "Schmidt ccc",
+            PhoneticAlgorithm::DoubleMetaphone(MaxCodeLength(None), Alternate(true)),
+            false,
+            PositionIncrement::Increment,
+        )?;
+        assert_eq!(result, expected(&[("XMT", 0), ("SMT", 1), ("KK", 2)]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_increment_not_inject() -> Result<(), Error> {
+        let result = positions(
+            "ccc hello",
+            PhoneticAlgorithm::Metaphone(MaxCodeLength(None)),
+            false,
+            PositionIncrement::Increment,
+        )?;
+        assert_eq!(result, expected(&[("KKK", 0), ("HL", 1)]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_inject_order() -> Result<(), Error> {
+        let algorithm = PhoneticAlgorithm::Metaphone(MaxCodeLength(None));
+        let built = PhoneticTokenFilter::builder(algorithm.clone())
+            .inject_order(InjectOrder::CodeFirst)
+            .build()?;
+        let set =
+            PhoneticTokenFilter::try_from(algorithm)?.with_inject_order(InjectOrder::CodeFirst);
+
+        let result = token_stream_helper("ccc hello", built);
+        assert_eq!(result, token_stream_helper("ccc hello", set));
+        let texts: Vec<&str> = result.iter().map(|token| token.text.as_str()).collect();
+        assert_eq!(texts, vec!["KKK", "ccc", "HL", "hello"]);
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 ///
 /// If `None` is provided, then the phonetic encoder will choose its default.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaxCodeLength(pub Option<usize>);
 
 /// If a text contains multiple words, they all get encode if `true` otherwise
@@ -9,12 +10,14 @@ pub struct MaxCodeLength(pub Option<usize>);
 ///
 /// If `None` is provided, it will be `true`.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Concat(pub Option<bool>);
 
 /// Allow setting the maximum length in [BeiderMorse](super::PhoneticAlgorithm::BeiderMorse).
 ///
 /// If `None` it will use 20.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaxPhonemeNumber(pub Option<usize>);
 
 /// This is Daitch-Mokotoff rules. They will be parsed.
@@ -23,6 +26,7 @@ pub struct MaxPhonemeNumber(pub Option<usize>);
 /// They can be provided using feature `embedded_dm`.
 #[cfg(not(feature = "embedded_dm"))]
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DMRule(pub String);
 
 /// This is Daitch-Mokotoff rules. They will be parsed.
@@ -31,26 +35,46 @@ pub struct DMRule(pub String);
 /// If `None` is provided, then the embedded rules will be used.
 #[cfg(feature = "embedded_dm")]
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DMRule(pub Option<String>);
 
 /// Boolean to apply folding (`true`) in Daitch-Mokotoff.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Folding(pub bool);
 
 /// Boolean to allow (`true`) or disallow (`false`) branching
 /// for Daitch-Mokotoff.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Branching(pub bool);
 
+/// Maximum number of Daitch-Mokotoff codes emitted per token. Identical codes are
+/// only counted once, and the first branches are kept, in the order given by the rules.
+///
+/// If `None` is provided, all branches are emitted. At least one code is always kept.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxBranches(pub Option<usize>);
+
+/// Separator used to join Daitch-Mokotoff branches into a single token.
+///
+/// If `None` is provided, each branch is emitted as its own token.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JoinBranches(pub Option<String>);
+
 /// This boolean allows generating alternate code, in double metaphone,
 /// if different from primary.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Alternate(pub bool);
 
 /// This boolean indicates if Nysiis algorithm should be strict or not.
 ///
 /// Default to `true`.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Strict(pub Option<bool>);
 
 /// This is the mapping for each latin letter for Soundex and Refined
@@ -58,10 +82,43 @@ pub struct Strict(pub Option<bool>);
 ///
 /// The default is [DEFAULT_US_ENGLISH_MAPPING_SOUNDEX](super::DEFAULT_US_ENGLISH_MAPPING_SOUNDEX).
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mapping(pub Option<[char; 26]>);
 
 /// Indicate, for Soundex, if `H` and `W` should be treated as silence.
 ///
 /// Default to `true`.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpecialHW(pub Option<bool>);
+
+/// Order of the original token and its codes when codes are injected as synonyms.
+///
+/// Default to [OriginalFirst](InjectOrder::OriginalFirst), that is what all phonetic
+/// token filters have always done. Codes of a token keep their order relative to each
+/// other, only the original token is moved.
+#[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InjectOrder {
+    /// The original token comes first, followed by its codes.
+    #[default]
+    OriginalFirst,
+    /// Codes come first, followed by the original token.
+    CodeFirst,
+}
+
+/// Position of the codes relative to the token they come from.
+///
+/// Default to [Same](PositionIncrement::Same) : codes are at the position of their token,
+/// so a phrase query matches either the original or the phonetic form. With
+/// [Increment](PositionIncrement::Increment), each code gets its own position, after the
+/// previous token, and positions of the following tokens are shifted accordingly.
+#[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PositionIncrement {
+    /// Codes are at the position of their token.
+    #[default]
+    Same,
+    /// Each code is at the position following the previous token.
+    Increment,
+}
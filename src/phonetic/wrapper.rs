@@ -2,12 +2,17 @@
 //! it's mostly here to give to the bottom component of the analysis
 //! stack (which is a [Tokenizer]) the text to parse.
 
-use rphonetic::{BeiderMorseBuilder, Encoder, Phonex};
+use std::cell::Cell;
+use std::rc::Rc;
+
+use rphonetic::{Encoder, Phonex};
 use tantivy_tokenizer_api::{TokenStream, Tokenizer};
 
+use super::token_stream::{beider_morse, daitch_mokotoff, try_encode};
 use super::{
     BeiderMorseTokenStream, DaitchMokotoffTokenStream, DoubleMetaphoneTokenStream,
-    EncoderAlgorithm, GenericPhoneticTokenStream,
+    EncoderAlgorithm, GenericPhoneticTokenStream, InjectOrder, PositionIncrement,
+    PositionIncrementTokenStream, SourceCountTokenStream,
 };
 
 /// Phonex wrapper to handle the case only '0'.
@@ -32,139 +37,215 @@ impl Encoder for PhonexWrapper {
 pub struct PhoneticFilterWrapper<T> {
     algorithm: EncoderAlgorithm,
     inject: bool,
+    order: InjectOrder,
+    position_increment: PositionIncrement,
     inner: T,
 }
 
 impl<T> PhoneticFilterWrapper<T> {
-    pub(crate) fn new(inner: T, algorithm: EncoderAlgorithm, inject: bool) -> Self {
+    pub(crate) fn new(
+        inner: T,
+        algorithm: EncoderAlgorithm,
+        inject: bool,
+        order: InjectOrder,
+    ) -> Self {
         Self {
             algorithm,
             inject,
+            order,
+            position_increment: PositionIncrement::default(),
             inner,
         }
     }
+
+    pub(crate) fn with_position_increment(mut self, position_increment: PositionIncrement) -> Self {
+        self.position_increment = position_increment;
+        self
+    }
 }
 
 impl<T: Tokenizer> Tokenizer for PhoneticFilterWrapper<T> {
     type TokenStream<'a> = Box<dyn TokenStream + 'a>;
 
     fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
-        match &self.algorithm {
-            // Beider Morse
-            EncoderAlgorithm::BeiderMorse(
-                config_files,
-                name_type,
-                rule_type,
-                concat,
-                max_phonemes,
-                languages_set,
-            ) => {
-                let mut builder = BeiderMorseBuilder::new(config_files);
-                if let Some(name_type) = name_type {
-                    builder = builder.name_type(*name_type);
-                }
-                if let Some(rule_type) = rule_type {
-                    builder = builder.rule_type(*rule_type);
-                }
-                if let Some(concat) = concat {
-                    builder = builder.concat(*concat);
-                }
-                if let Some(max_phonemes) = max_phonemes {
-                    builder = builder.max_phonemes(*max_phonemes);
-                }
-
-                let max_phonemes = match max_phonemes {
-                    Some(max_phonemes) => *max_phonemes,
-                    None => 20,
-                };
-                let encoder = builder.build();
-                Box::new(BeiderMorseTokenStream::new(
-                    self.inner.token_stream(text),
-                    encoder,
-                    max_phonemes,
-                    languages_set.clone(),
-                    self.inject,
-                ))
+        let tail = self.inner.token_stream(text);
+        match self.position_increment {
+            PositionIncrement::Same => {
+                encoded_stream(&self.algorithm, tail, self.inject, self.order)
             }
-            // Caverphone1
-            EncoderAlgorithm::Caverphone1(encoder) => Box::new(GenericPhoneticTokenStream::new(
-                self.inner.token_stream(text),
-                Box::new(*encoder),
-                self.inject,
-            )),
-            // Caverphone2
-            EncoderAlgorithm::Caverphone2(encoder) => Box::new(GenericPhoneticTokenStream::new(
-                self.inner.token_stream(text),
-                Box::new(*encoder),
-                self.inject,
+            PositionIncrement::Increment => {
+                let count = Rc::new(Cell::new(0));
+                let tail = SourceCountTokenStream::new(tail, count.clone());
+                let stream = encoded_stream(&self.algorithm, tail, self.inject, self.order);
+                Box::new(PositionIncrementTokenStream::new(stream, count))
+            }
+        }
+    }
+}
+
+/// Stream of the codes of `tail`'s tokens.
+fn encoded_stream<'a, S: TokenStream + 'a>(
+    algorithm: &'a EncoderAlgorithm,
+    tail: S,
+    inject: bool,
+    order: InjectOrder,
+) -> Box<dyn TokenStream + 'a> {
+    match algorithm {
+        // Beider Morse
+        EncoderAlgorithm::BeiderMorse(encoder, max_phonemes, languages_set) => {
+            Box::new(BeiderMorseTokenStream::new(
+                tail,
+                encoder,
+                *max_phonemes,
+                languages_set.as_ref(),
+                inject,
+                order,
+            ))
+        }
+        // Caverphone1
+        EncoderAlgorithm::Caverphone1(encoder) => Box::new(GenericPhoneticTokenStream::new(
+            tail,
+            Box::new(*encoder),
+            inject,
+            order,
+        )),
+        // Caverphone2
+        EncoderAlgorithm::Caverphone2(encoder) => Box::new(GenericPhoneticTokenStream::new(
+            tail,
+            Box::new(*encoder),
+            inject,
+            order,
+        )),
+        // Cologne
+        EncoderAlgorithm::Cologne(encoder) => Box::new(GenericPhoneticTokenStream::new(
+            tail,
+            Box::new(*encoder),
+            inject,
+            order,
+        )),
+        // Daitch Mokotoff
+        EncoderAlgorithm::DaitchMokotoffSoundex(
+            encoder,
+            branching,
+            max_branches,
+            code_length,
+            separator,
+        ) => Box::new(
+            DaitchMokotoffTokenStream::new(
+                tail,
+                encoder,
+                *branching,
+                *code_length,
+                separator.as_deref(),
+                inject,
+                order,
+            )
+            .with_max_branches(*max_branches),
+        ),
+        // Double Metaphone
+        EncoderAlgorithm::DoubleMetaphone(encoder, use_alternate) => match use_alternate {
+            // Alternate: if true, use specific token filter, otherwise, use generic
+            true => Box::new(DoubleMetaphoneTokenStream::new(
+                tail, *encoder, inject, order,
             )),
-            // Cologne
-            EncoderAlgorithm::Cologne(encoder) => Box::new(GenericPhoneticTokenStream::new(
-                self.inner.token_stream(text),
+            false => Box::new(GenericPhoneticTokenStream::new(
+                tail,
                 Box::new(*encoder),
-                self.inject,
+                inject,
+                order,
             )),
-            // Daitch Mokotoff
-            EncoderAlgorithm::DaitchMokotoffSoundex(encoder, branching) => {
-                Box::new(DaitchMokotoffTokenStream::new(
-                    self.inner.token_stream(text),
-                    encoder.clone(),
-                    *branching,
-                    self.inject,
-                ))
+        },
+        // Match Rating Approach
+        EncoderAlgorithm::MatchRatingApproach(encoder) => Box::new(
+            GenericPhoneticTokenStream::new(tail, Box::new(*encoder), inject, order),
+        ),
+        // Metaphone
+        EncoderAlgorithm::Metaphone(encoder) => Box::new(GenericPhoneticTokenStream::new(
+            tail,
+            Box::new(*encoder),
+            inject,
+            order,
+        )),
+        // Nysiis
+        EncoderAlgorithm::Nysiis(encoder) => Box::new(GenericPhoneticTokenStream::new(
+            tail,
+            Box::new(*encoder),
+            inject,
+            order,
+        )),
+        // Phonex
+        EncoderAlgorithm::Phonex(encoder) => Box::new(GenericPhoneticTokenStream::new(
+            tail,
+            Box::new(PhonexWrapper(*encoder)),
+            inject,
+            order,
+        )),
+        // Refined Soundex
+        EncoderAlgorithm::RefinedSoundex(encoder) => Box::new(GenericPhoneticTokenStream::new(
+            tail,
+            Box::new(*encoder),
+            inject,
+            order,
+        )),
+        // Soundex
+        EncoderAlgorithm::Soundex(encoder) => Box::new(GenericPhoneticTokenStream::new(
+            tail,
+            Box::new(*encoder),
+            inject,
+            order,
+        )),
+    }
+}
+
+impl EncoderAlgorithm {
+    /// Codes of `text`, as the token stream of the algorithm emits them when it
+    /// doesn't inject the original token. It's [None] if the encoder failed.
+    pub(crate) fn codes(&self, text: &str) -> Option<Vec<String>> {
+        let codes = match self {
+            EncoderAlgorithm::BeiderMorse(encoder, _, languages_set) => {
+                let encoded = try_encode(|| match languages_set {
+                    None => encoder.encode(text),
+                    Some(languages_set) => encoder.encode_with_languages(text, languages_set),
+                })?;
+                beider_morse::codes(&encoded)
             }
-            // Double Metaphone
-            EncoderAlgorithm::DoubleMetaphone(encoder, use_alternate) => match use_alternate {
-                // Alternate: if true, use specific token filter, otherwise, use generic
-                true => Box::new(DoubleMetaphoneTokenStream::new(
-                    self.inner.token_stream(text),
-                    *encoder,
-                    self.inject,
-                )),
-                false => Box::new(GenericPhoneticTokenStream::new(
-                    self.inner.token_stream(text),
-                    Box::new(*encoder),
-                    self.inject,
-                )),
-            },
-            // Match Rating Approach
-            EncoderAlgorithm::MatchRatingApproach(encoder) => {
-                Box::new(GenericPhoneticTokenStream::new(
-                    self.inner.token_stream(text),
-                    Box::new(*encoder),
-                    self.inject,
-                ))
+            EncoderAlgorithm::DaitchMokotoffSoundex(
+                encoder,
+                branching,
+                max_branches,
+                code_length,
+                separator,
+            ) => {
+                let encoded = try_encode(|| encoder.inner_soundex(text, *branching))?;
+                daitch_mokotoff::codes(encoded, *code_length, *max_branches, separator.as_deref())
             }
-            // Metaphone
-            EncoderAlgorithm::Metaphone(encoder) => Box::new(GenericPhoneticTokenStream::new(
-                self.inner.token_stream(text),
-                Box::new(*encoder),
-                self.inject,
-            )),
-            // Nysiis
-            EncoderAlgorithm::Nysiis(encoder) => Box::new(GenericPhoneticTokenStream::new(
-                self.inner.token_stream(text),
-                Box::new(*encoder),
-                self.inject,
-            )),
-            // Phonex
-            EncoderAlgorithm::Phonex(encoder) => Box::new(GenericPhoneticTokenStream::new(
-                self.inner.token_stream(text),
-                Box::new(PhonexWrapper(*encoder)),
-                self.inject,
-            )),
-            // Refined Soundex
-            EncoderAlgorithm::RefinedSoundex(encoder) => Box::new(GenericPhoneticTokenStream::new(
-                self.inner.token_stream(text),
-                Box::new(*encoder),
-                self.inject,
-            )),
-            // Soundex
-            EncoderAlgorithm::Soundex(encoder) => Box::new(GenericPhoneticTokenStream::new(
-                self.inner.token_stream(text),
-                Box::new(*encoder),
-                self.inject,
-            )),
-        }
+            EncoderAlgorithm::DoubleMetaphone(encoder, true) => {
+                let encoded = try_encode(|| encoder.double_metaphone(text))?;
+                let mut codes = Vec::with_capacity(2);
+                for code in [encoded.primary(), encoded.alternate()] {
+                    if !code.is_empty() && !codes.contains(&code) {
+                        codes.push(code);
+                    }
+                }
+                codes
+            }
+            EncoderAlgorithm::Caverphone1(encoder) => encode(encoder, text)?,
+            EncoderAlgorithm::Caverphone2(encoder) => encode(encoder, text)?,
+            EncoderAlgorithm::Cologne(encoder) => encode(encoder, text)?,
+            EncoderAlgorithm::DoubleMetaphone(encoder, false) => encode(encoder, text)?,
+            EncoderAlgorithm::MatchRatingApproach(encoder) => encode(encoder, text)?,
+            EncoderAlgorithm::Metaphone(encoder) => encode(encoder, text)?,
+            EncoderAlgorithm::Nysiis(encoder) => encode(encoder, text)?,
+            EncoderAlgorithm::Phonex(encoder) => encode(&PhonexWrapper(*encoder), text)?,
+            EncoderAlgorithm::RefinedSoundex(encoder) => encode(encoder, text)?,
+            EncoderAlgorithm::Soundex(encoder) => encode(encoder, text)?,
+        };
+        Some(codes)
     }
 }
+
+/// Code of `text` for encoders that give a single code, if it isn't empty.
+fn encode(encoder: &impl Encoder, text: &str) -> Option<Vec<String>> {
+    let code = try_encode(|| encoder.encode(text))?;
+    Some((!code.is_empty()).then_some(code).into_iter().collect())
+}
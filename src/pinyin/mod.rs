@@ -0,0 +1,95 @@
+//! This module provides a token filter that converts Han characters into pinyin,
+//! using the [pinyin crate](https://docs.rs/pinyin/latest/pinyin/). It helps to match
+//! Chinese names, with or without tones, or by their initials.
+//!
+//! ```rust
+//! use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+//! use tantivy_analysis_contrib::pinyin::PinyinTokenFilter;
+//!
+//! let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+//!    .filter(PinyinTokenFilter::default().with_tone(true))
+//!    .build();
+//! let mut token_stream = tmp.token_stream("王小明");
+//!
+//! let token = token_stream.next().expect("A token should be present.");
+//! assert_eq!(token.text, "wángxiǎomíng".to_string());
+//!
+//! assert_eq!(None, token_stream.next());
+//! ```
+pub use token_filter::PinyinTokenFilter;
+use token_stream::PinyinTokenStream;
+use wrapper::PinyinFilterWrapper;
+
+mod token_filter;
+mod token_stream;
+mod wrapper;
+
+#[cfg(test)]
+mod tests {
+    use tantivy::tokenizer::{TextAnalyzer, Token, WhitespaceTokenizer};
+
+    use super::*;
+
+    fn token_stream_helper(text: &str, filter: PinyinTokenFilter) -> Vec<Token> {
+        let mut a = TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter)
+            .build();
+
+        let mut token_stream = a.token_stream(text);
+
+        let mut tokens = vec![];
+        let mut add_token = |token: &Token| {
+            tokens.push(token.clone());
+        };
+        token_stream.process(&mut add_token);
+        tokens
+    }
+
+    #[test]
+    fn test_without_tone() {
+        let result = token_stream_helper("王小明 li", PinyinTokenFilter::default());
+        let expected = vec![
+            Token {
+                offset_from: 0,
+                offset_to: 9,
+                position: 0,
+                text: "wangxiaoming".to_string(),
+                position_length: 1,
+            },
+            Token {
+                offset_from: 10,
+                offset_to: 12,
+                position: 1,
+                text: "li".to_string(),
+                position_length: 1,
+            },
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_with_tone() {
+        let result = token_stream_helper("王小明", PinyinTokenFilter::default().with_tone(true));
+        let expected = vec![Token {
+            offset_from: 0,
+            offset_to: 9,
+            position: 0,
+            text: "wángxiǎomíng".to_string(),
+            position_length: 1,
+        }];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_abbreviation_and_original() {
+        let filter = PinyinTokenFilter::default()
+            .with_abbreviation(true)
+            .keep_original(true);
+        let result = token_stream_helper("张三3", filter);
+        let texts: Vec<&str> = result.iter().map(|token| token.text.as_str()).collect();
+        assert_eq!(texts, vec!["zhangsan3", "zs3", "张三3"]);
+        assert!(result
+            .iter()
+            .all(|token| token.position == 0 && token.offset_to == 7));
+    }
+}
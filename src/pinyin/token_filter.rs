@@ -0,0 +1,82 @@
+use tantivy_tokenizer_api::{TokenFilter, Tokenizer};
+
+use super::PinyinFilterWrapper;
+
+/// A token filter that converts Han characters into their
+/// [pinyin](https://en.wikipedia.org/wiki/Pinyin) syllables, using the table of the
+/// [pinyin crate](https://docs.rs/pinyin/latest/pinyin/). It is mostly useful to match
+/// Chinese names whatever the characters used to write them (e.g. `张三` becomes `zhangsan`).
+///
+/// Syllables of a token are joined : `张三` gives a single `zhangsan` token. Other
+/// characters are kept, and tokens without Han characters are left untouched. When a
+/// character has several readings, the most common one is used.
+///
+/// Options allow to :
+/// * keep the tone marks (`zhāngsān`), see [with_tone](PinyinTokenFilter::with_tone),
+/// * also emit the first letters of the syllables (`zs`), see
+///   [with_abbreviation](PinyinTokenFilter::with_abbreviation),
+/// * also emit the original token, see [keep_original](PinyinTokenFilter::keep_original).
+///
+/// Tokens emitted for a token are all at its position, in this order : pinyin,
+/// abbreviation and original.
+///
+/// ```rust
+/// use tantivy::tokenizer::{WhitespaceTokenizer, TextAnalyzer, Token};
+/// use tantivy_analysis_contrib::pinyin::PinyinTokenFilter;
+///
+/// let mut tmp = TextAnalyzer::builder(WhitespaceTokenizer::default())
+///    .filter(PinyinTokenFilter::default().with_abbreviation(true))
+///    .build();
+/// let mut token_stream = tmp.token_stream("张三");
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "zhangsan".to_string());
+///
+/// let token = token_stream.next().expect("A token should be present.");
+/// assert_eq!(token.text, "zs".to_string());
+/// assert_eq!(token.position, 0);
+///
+/// assert_eq!(None, token_stream.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PinyinTokenFilter {
+    tone: bool,
+    abbreviation: bool,
+    keep_original: bool,
+}
+
+impl PinyinTokenFilter {
+    /// Keep tone marks on syllables (`zhāngsān`) instead of stripping them (`zhangsan`).
+    ///
+    /// Default is `false`.
+    pub fn with_tone(mut self, tone: bool) -> Self {
+        self.tone = tone;
+        self
+    }
+
+    /// Also emit the first letter of each syllable, joined (`zs` for `张三`). It is
+    /// emitted after the pinyin token, at the same position.
+    ///
+    /// Default is `false`.
+    pub fn with_abbreviation(mut self, abbreviation: bool) -> Self {
+        self.abbreviation = abbreviation;
+        self
+    }
+
+    /// Also emit the original token, at the same position, when it contains Han
+    /// characters. It is emitted last.
+    ///
+    /// Default is `false`.
+    pub fn keep_original(mut self, keep_original: bool) -> Self {
+        self.keep_original = keep_original;
+        self
+    }
+}
+
+impl TokenFilter for PinyinTokenFilter {
+    type Tokenizer<T: Tokenizer> = PinyinFilterWrapper<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        PinyinFilterWrapper::new(tokenizer, self.tone, self.abbreviation, self.keep_original)
+    }
+}
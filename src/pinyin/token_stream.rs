@@ -0,0 +1,86 @@
+//! Module that contains the [TokenStream] implementation. It's this that
+//! do the real job.
+
+use std::collections::VecDeque;
+
+use ::pinyin::ToPinyin;
+use tantivy_tokenizer_api::{Token, TokenStream};
+
+#[derive(Clone, Debug)]
+pub struct PinyinTokenStream<T> {
+    tail: T,
+    tone: bool,
+    abbreviation: bool,
+    keep_original: bool,
+    /// Texts of the tokens to emit at the position of the current token.
+    pending: VecDeque<String>,
+    token: Token,
+}
+
+impl<T> PinyinTokenStream<T> {
+    pub(crate) fn new(tail: T, tone: bool, abbreviation: bool, keep_original: bool) -> Self {
+        Self {
+            tail,
+            tone,
+            abbreviation,
+            keep_original,
+            pending: VecDeque::new(),
+            token: Token::default(),
+        }
+    }
+}
+
+impl<T: TokenStream> TokenStream for PinyinTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if let Some(text) = self.pending.pop_front() {
+            self.token.text = text;
+            return true;
+        }
+
+        if !self.tail.advance() {
+            return false;
+        }
+
+        self.token.clone_from(self.tail.token());
+        // Avoid allocating when there's nothing to convert, which is the most common case.
+        if !self.token.text.chars().any(|ch| ch.to_pinyin().is_some()) {
+            return true;
+        }
+
+        let mut full = String::with_capacity(self.token.text.len() * 2);
+        let mut abbreviation = String::new();
+        for ch in self.token.text.chars() {
+            match ch.to_pinyin() {
+                Some(pinyin) if self.tone => full.push_str(pinyin.with_tone()),
+                Some(pinyin) => full.push_str(pinyin.plain()),
+                None => full.push(ch),
+            }
+            if self.abbreviation {
+                match ch.to_pinyin() {
+                    Some(pinyin) => abbreviation.push_str(pinyin.first_letter()),
+                    None => abbreviation.push(ch),
+                }
+            }
+        }
+
+        if self.abbreviation {
+            self.pending.push_back(abbreviation);
+        }
+        if self.keep_original {
+            self.pending
+                .push_back(std::mem::replace(&mut self.token.text, full));
+        } else {
+            self.token.text = full;
+        }
+
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
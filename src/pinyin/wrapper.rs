@@ -0,0 +1,39 @@
+//! Module that contains the `wrapper`. From what I understand
+//! it's mostly here to give to the bottom component of the analysis
+//! stack (which is a [Tokenizer]) the text to parse.
+
+use tantivy_tokenizer_api::Tokenizer;
+
+use super::PinyinTokenStream;
+
+#[derive(Clone, Debug)]
+pub struct PinyinFilterWrapper<T> {
+    tone: bool,
+    abbreviation: bool,
+    keep_original: bool,
+    inner: T,
+}
+
+impl<T> PinyinFilterWrapper<T> {
+    pub(crate) fn new(inner: T, tone: bool, abbreviation: bool, keep_original: bool) -> Self {
+        Self {
+            tone,
+            abbreviation,
+            keep_original,
+            inner,
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for PinyinFilterWrapper<T> {
+    type TokenStream<'a> = PinyinTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        PinyinTokenStream::new(
+            self.inner.token_stream(text),
+            self.tone,
+            self.abbreviation,
+            self.keep_original,
+        )
+    }
+}
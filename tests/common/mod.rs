@@ -0,0 +1,77 @@
+//! Helpers shared by integration tests.
+// Each test only uses some of them
+#![allow(dead_code)]
+
+use std::collections::BTreeSet;
+
+use tantivy::collector::TopDocs;
+use tantivy::query::{Query, QueryParser};
+use tantivy::schema::{
+    Field, IndexRecordOption, SchemaBuilder, TextFieldIndexing, TextOptions, Value,
+};
+use tantivy::tokenizer::TextAnalyzer;
+use tantivy::{doc, Index, ReloadPolicy, TantivyDocument, TantivyError};
+
+pub const ANALYSIS_NAME: &str = "test";
+
+/// Index `documents` in a single field analyzed by `analyzer`.
+pub fn index(analyzer: TextAnalyzer, documents: &[&str]) -> Result<(Index, Field), TantivyError> {
+    let options = TextOptions::default()
+        .set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(ANALYSIS_NAME)
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        )
+        .set_stored();
+    let mut schema = SchemaBuilder::new();
+    let field = schema.add_text_field("field", options);
+
+    let index = Index::create_in_ram(schema.build());
+    index.tokenizers().register(ANALYSIS_NAME, analyzer);
+
+    let mut index_writer = index.writer(15_000_000)?;
+    for document in documents {
+        index_writer.add_document(doc!(field => *document))?;
+    }
+    index_writer.commit()?;
+
+    Ok((index, field))
+}
+
+/// Values of documents that match `query`.
+pub fn search(
+    index: &Index,
+    field: Field,
+    query: &dyn Query,
+) -> Result<BTreeSet<String>, TantivyError> {
+    let reader = index
+        .reader_builder()
+        .reload_policy(ReloadPolicy::Manual)
+        .try_into()?;
+    let searcher = reader.searcher();
+
+    let mut result = BTreeSet::new();
+    for (_, doc_address) in searcher.search(query, &TopDocs::with_limit(10))? {
+        let document = searcher.doc::<TantivyDocument>(doc_address)?;
+        if let Some(value) = document.get_first(field).and_then(|v| v.as_str()) {
+            result.insert(value.to_string());
+        }
+    }
+
+    Ok(result)
+}
+
+/// Values of documents that match `query`, parsed with the index's analyzer.
+pub fn parse_and_search(
+    index: &Index,
+    field: Field,
+    query: &str,
+) -> Result<BTreeSet<String>, Box<dyn std::error::Error>> {
+    let query = QueryParser::for_index(index, vec![field]).parse_query(query)?;
+    Ok(search(index, field, &query)?)
+}
+
+/// Build a set of expected values.
+pub fn values(values: &[&str]) -> BTreeSet<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
@@ -0,0 +1,440 @@
+//! Empty input contract, checked on every public component :
+//! * tokenizers emit no token for an empty text, except [KeywordTokenizer] that emits
+//!   the whole text as a token even if it is empty, like [RawTokenizer],
+//! * token filters emit no token when they receive none,
+//! * token filters given an empty token (e.g. from [RawTokenizer]) either emit it
+//!   unchanged, or remove it when removing tokens is what they do (length, n-grams...).
+//!   They never turn it into a non-empty token.
+#![cfg(feature = "commons")]
+
+use std::num::NonZeroUsize;
+
+use tantivy::tokenizer::{
+    RawTokenizer, TextAnalyzer, Token, TokenFilter, Tokenizer, WhitespaceTokenizer,
+};
+use tantivy_analysis_contrib::commons::*;
+
+/// What a filter does with an empty token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Empty {
+    Kept,
+    Removed,
+}
+
+fn tokens(mut analyzer: TextAnalyzer) -> Vec<Token> {
+    let mut token_stream = analyzer.token_stream("");
+    let mut tokens = vec![];
+    token_stream.process(&mut |token: &Token| tokens.push(token.clone()));
+    tokens
+}
+
+fn assert_tokenizer<T: Tokenizer>(name: &str, tokenizer: T) {
+    let tokens = tokens(TextAnalyzer::builder(tokenizer).build());
+    assert_eq!(tokens, vec![], "{name} emits tokens for an empty text");
+}
+
+fn assert_whole_text_tokenizer<T: Tokenizer>(name: &str, tokenizer: T) {
+    let tokens = tokens(TextAnalyzer::builder(tokenizer).build());
+    let expected = vec![Token {
+        offset_from: 0,
+        offset_to: 0,
+        position: 0,
+        text: String::new(),
+        position_length: 1,
+    }];
+    assert_eq!(tokens, expected, "{name} doesn't emit an empty token");
+}
+
+fn assert_filter<F: TokenFilter + Clone>(name: &str, filter: F, expected: Empty) {
+    let no_token = tokens(
+        TextAnalyzer::builder(WhitespaceTokenizer::default())
+            .filter(filter.clone())
+            .build(),
+    );
+    assert_eq!(no_token, vec![], "{name} emits tokens out of nothing");
+
+    let empty_token = tokens(
+        TextAnalyzer::builder(RawTokenizer::default())
+            .filter(filter)
+            .build(),
+    );
+    let expected = match expected {
+        Empty::Kept => vec![Token {
+            offset_from: 0,
+            offset_to: 0,
+            position: 0,
+            text: String::new(),
+            position_length: 1,
+        }],
+        Empty::Removed => vec![],
+    };
+    assert_eq!(
+        empty_token, expected,
+        "{name} doesn't handle an empty token"
+    );
+}
+
+fn set(keys: &[&str]) -> Set<Vec<u8>> {
+    build_set(keys.iter().copied()).unwrap()
+}
+
+fn size(size: usize) -> NonZeroUsize {
+    NonZeroUsize::new(size).unwrap()
+}
+
+#[test]
+fn test_commons_tokenizers() -> Result<(), Box<dyn std::error::Error>> {
+    assert_tokenizer("CharGroupTokenizer", CharGroupTokenizer::default());
+    assert_whole_text_tokenizer("KeywordTokenizer", KeywordTokenizer::default());
+    assert_tokenizer("PathTokenizer", PathTokenizer::default());
+    assert_tokenizer(
+        "PathTokenizer (reverse)",
+        PathTokenizerBuilder::default().reverse(true).build()?,
+    );
+    assert_tokenizer(
+        "PathTokenizer (bidirectional)",
+        PathTokenizerBuilder::default()
+            .bidirectional(true)
+            .build()?,
+    );
+    assert_tokenizer("PatternTokenizer", PatternTokenizer::new(r"\s+", -1)?);
+    assert_tokenizer(
+        "PatternTokenizer (group)",
+        PatternTokenizer::new(r"\w+", 0)?,
+    );
+    assert_tokenizer("UrlEmailTokenizer", UrlEmailTokenizer);
+
+    Ok(())
+}
+
+#[test]
+fn test_commons_filters() -> Result<(), Box<dyn std::error::Error>> {
+    use Empty::*;
+
+    assert_filter(
+        "AbbreviationExpandTokenFilter",
+        AbbreviationExpandTokenFilter::new([("St", "Saint")], true),
+        Kept,
+    );
+    assert_filter(
+        "ArchaicLatinTokenFilter",
+        ArchaicLatinTokenFilter::default(),
+        Kept,
+    );
+    assert_filter(
+        "ASCIIFoldingTokenFilter",
+        ASCIIFoldingTokenFilter::new(true),
+        Kept,
+    );
+    assert_filter("BomStripTokenFilter", BomStripTokenFilter::default(), Kept);
+    assert_filter(
+        "BoundedDistinctTokenFilter",
+        BoundedDistinctTokenFilter::new(size(10)),
+        Kept,
+    );
+    assert_filter("BudgetTokenFilter", BudgetTokenFilter::new(10), Kept);
+    assert_filter(
+        "CapitalizationTokenFilter",
+        CapitalizationTokenFilter::default(),
+        Kept,
+    );
+    assert_filter("LowerCaseTokenFilter", LowerCaseTokenFilter, Kept);
+    assert_filter("UpperCaseTokenFilter", UpperCaseTokenFilter, Kept);
+    assert_filter(
+        "CharLimitTokenFilter",
+        CharLimitTokenFilter::new(10, false),
+        Kept,
+    );
+    assert_filter(
+        "CodepointRangeTokenFilter",
+        CodepointRangeTokenFilter::new(0x20, 0x7E, false),
+        Kept,
+    );
+    assert_filter(
+        "CommonGramsTokenFilter",
+        CommonGramsTokenFilter::from_iter_str(vec!["the"], false)?,
+        Kept,
+    );
+    assert_filter(
+        "CompoundJoinTokenFilter",
+        CompoundJoinTokenFilter::from_iter_str(vec!["newyork"], false),
+        Kept,
+    );
+    assert_filter(
+        "DictionaryCompoundWordTokenFilter",
+        DictionaryCompoundWordTokenFilter::new(set(&["ball", "foot"])),
+        Kept,
+    );
+    assert_filter("CurrencyTokenFilter", CurrencyTokenFilter::default(), Kept);
+    assert_filter("CyrillicYoTokenFilter", CyrillicYoTokenFilter, Kept);
+    assert_filter("DecimalDigitTokenFilter", DecimalDigitTokenFilter, Kept);
+    assert_filter(
+        "DistinctSpansTokenFilter",
+        DistinctSpansTokenFilter::new(SpanSink::new()),
+        Kept,
+    );
+    assert_filter(
+        "EdgeNgramTokenFilter",
+        EdgeNgramTokenFilter::new(size(1), None, false)?,
+        Removed,
+    );
+    assert_filter(
+        "ElisionTokenFilter",
+        ElisionTokenFilter::from_iter_str(vec!["l"], false),
+        Kept,
+    );
+    assert_filter(
+        "EmailNormalizeTokenFilter",
+        EmailNormalizeTokenFilter::default(),
+        Kept,
+    );
+    assert_filter(
+        "EnclosedAlphanumericTokenFilter",
+        EnclosedAlphanumericTokenFilter,
+        Kept,
+    );
+    assert_filter(
+        "GreekNormalizationTokenFilter",
+        GreekNormalizationTokenFilter,
+        Kept,
+    );
+    assert_filter(
+        "HybridTokenFilter",
+        HybridTokenFilter::new(
+            LowerCaseTokenFilter,
+            EdgeNgramTokenFilter::new(size(1), None, false)?,
+        ),
+        Kept,
+    );
+    assert_filter(
+        "KeywordMarkerTokenFilter",
+        KeywordMarkerTokenFilter::protect(set(&["the"])).around(ReverseTokenFilter::default()),
+        Kept,
+    );
+    assert_filter(
+        "DictionaryLemmatizeTokenFilter",
+        DictionaryLemmatizeTokenFilter::from_pairs([("mice", "mouse")])?,
+        Kept,
+    );
+    assert_filter(
+        "LengthTokenFilter",
+        LengthTokenFilter::new(Some(1), None),
+        Removed,
+    );
+    assert_filter(
+        "LengthTokenFilter (no min)",
+        LengthTokenFilter::new(None, Some(5)),
+        Kept,
+    );
+    assert_filter(
+        "LimitTokenCountFilter",
+        LimitTokenCountFilter::new(10),
+        Kept,
+    );
+    // Tokens that are empty once stripped or trimmed are removed
+    assert_filter("MarkupStripTokenFilter", MarkupStripTokenFilter, Removed);
+    assert_filter(
+        "MathAlphanumericTokenFilter",
+        MathAlphanumericTokenFilter,
+        Kept,
+    );
+    assert_filter(
+        "NGramTokenFilter",
+        NGramTokenFilter::new(size(1), None, false)?,
+        Removed,
+    );
+    assert_filter(
+        "PatternReplaceTokenFilter",
+        PatternReplaceTokenFilter::new("a", "b", true)?,
+        Kept,
+    );
+    assert_filter(
+        "PatternStopTokenFilter",
+        PatternStopTokenFilter::new(["^[0-9]+$"])?,
+        Kept,
+    );
+    assert_filter(
+        "PositionRangeTokenFilter",
+        PositionRangeTokenFilter::new(0, 10, ReverseTokenFilter::default()),
+        Kept,
+    );
+    assert_filter(
+        "PunctuationRunTokenFilter",
+        PunctuationRunTokenFilter::default(),
+        Kept,
+    );
+    assert_filter(
+        "QuoteBracketTokenFilter",
+        QuoteBracketTokenFilter::new(true),
+        Kept,
+    );
+    assert_filter("ReverseTokenFilter", ReverseTokenFilter::default(), Kept);
+    assert_filter(
+        "ScriptReverseTokenFilter",
+        ScriptReverseTokenFilter::new(['\u{4E00}'..='\u{9FFF}']),
+        Kept,
+    );
+    assert_filter("SearchFoldTokenFilter", SearchFoldTokenFilter, Kept);
+    assert_filter(
+        "SentinelTokenFilter",
+        SentinelTokenFilter::new("__raw__").around(ReverseTokenFilter::default()),
+        Kept,
+    );
+    assert_filter(
+        "ShingleTokenFilter",
+        ShingleTokenFilter::new(2, 2, true)?,
+        Kept,
+    );
+    assert_filter("SocialTokenFilter", SocialTokenFilter::new(true), Kept);
+    assert_filter(
+        "StopTokenFilter",
+        StopTokenFilter::from_iter_str(vec!["the"], false)?,
+        Kept,
+    );
+    assert_filter(
+        "SuperscriptDigitTokenFilter",
+        SuperscriptDigitTokenFilter::new(true),
+        Kept,
+    );
+    assert_filter(
+        "ThaiNormalizationTokenFilter",
+        ThaiNormalizationTokenFilter,
+        Kept,
+    );
+    assert_filter("TrimTokenFilter", TrimTokenFilter, Removed);
+    assert_filter(
+        "KeywordNormalizeTokenFilter",
+        KeywordNormalizeTokenFilter,
+        Removed,
+    );
+    assert_filter(
+        "VietnameseToneNormalizeTokenFilter",
+        VietnameseToneNormalizeTokenFilter,
+        Kept,
+    );
+    assert_filter(
+        "WordDelimiterTokenFilter",
+        WordDelimiterTokenFilter::default(),
+        Removed,
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "icu")]
+#[test]
+fn test_icu() -> Result<(), Box<dyn std::error::Error>> {
+    use tantivy_analysis_contrib::icu::*;
+    use Empty::*;
+
+    assert_tokenizer("ICUTokenizer", ICUTokenizer);
+    assert_tokenizer("ICUTokenizerWithScript", ICUTokenizerWithScript);
+    assert_tokenizer("ICURulesTokenizer", ICURulesTokenizer::new("[a-z]+;")?);
+    assert_tokenizer(
+        "ScriptRoutingTokenizer",
+        ScriptRoutingTokenizer::new(WhitespaceTokenizer::default()),
+    );
+
+    assert_filter(
+        "ICUNormalizer2TokenFilter",
+        ICUNormalizer2TokenFilter::new(Mode::NFKCCasefold)?,
+        Kept,
+    );
+    assert_filter(
+        "ICUNormalizer2TokenFilter (drop empty)",
+        ICUNormalizer2TokenFilter::new(Mode::NFKCCasefold)?.drop_empty(true),
+        Removed,
+    );
+    assert_filter(
+        "ICUTransformTokenFilter",
+        ICUTransformTokenFilter::new("Any-Latin".to_string(), None, Direction::Forward)?,
+        Kept,
+    );
+    assert_filter("ICUFoldingTokenFilter", ICUFoldingTokenFilter::new()?, Kept);
+    assert_filter(
+        "ChineseConvertTokenFilter",
+        ChineseConvertTokenFilter::new(ChineseConversion::TraditionalToSimplified)?,
+        Kept,
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "phonetic")]
+#[test]
+fn test_phonetic() -> Result<(), Box<dyn std::error::Error>> {
+    use tantivy_analysis_contrib::phonetic::*;
+    use Empty::*;
+
+    let algorithm = PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None));
+    for inject in [true, false] {
+        assert_filter(
+            "PhoneticTokenFilter",
+            PhoneticTokenFilter::try_from((algorithm.clone(), inject))?,
+            Kept,
+        );
+    }
+    assert_filter(
+        "MultiPhoneticTokenFilter",
+        MultiPhoneticTokenFilter::try_from(vec![
+            algorithm,
+            PhoneticAlgorithm::Metaphone(MaxCodeLength(None)),
+        ])?,
+        Kept,
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "phone")]
+#[test]
+fn test_phone() -> Result<(), Box<dyn std::error::Error>> {
+    use tantivy_analysis_contrib::phone::PhoneNormalizeTokenFilter;
+
+    assert_filter(
+        "PhoneNormalizeTokenFilter",
+        PhoneNormalizeTokenFilter::new("FR")?,
+        Empty::Kept,
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "domain")]
+#[test]
+fn test_domain() -> Result<(), Box<dyn std::error::Error>> {
+    use tantivy_analysis_contrib::domain::{List, RegistrableDomainTokenFilter};
+
+    let list: List = "// ===BEGIN ICANN DOMAINS===\ncom\n// ===END ICANN DOMAINS===".parse()?;
+    assert_filter(
+        "RegistrableDomainTokenFilter",
+        RegistrableDomainTokenFilter::new(list),
+        Empty::Kept,
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "pinyin")]
+#[test]
+fn test_pinyin() {
+    use tantivy_analysis_contrib::pinyin::PinyinTokenFilter;
+
+    assert_filter(
+        "PinyinTokenFilter",
+        PinyinTokenFilter::default()
+            .with_abbreviation(true)
+            .keep_original(true),
+        Empty::Kept,
+    );
+}
+
+#[cfg(feature = "graphemes")]
+#[test]
+fn test_graphemes() {
+    assert_filter(
+        "GraphemeSplitTokenFilter",
+        GraphemeSplitTokenFilter,
+        Empty::Removed,
+    );
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "icu")]
+
+mod common;
+
+use std::thread;
+
+use tantivy::tokenizer::{TextAnalyzer, Token};
+use tantivy_analysis_contrib::icu::{ICUNormalizer2TokenFilter, ICUTokenizer, Mode};
+
+use crate::common::{index, parse_and_search, values};
+
+fn analyzer() -> Result<TextAnalyzer, Box<dyn std::error::Error>> {
+    Ok(TextAnalyzer::builder(ICUTokenizer)
+        .filter(ICUNormalizer2TokenFilter::new(Mode::NFKCCasefold)?)
+        .build())
+}
+
+fn tokens(analyzer: &mut TextAnalyzer, text: &str) -> Vec<Token> {
+    let mut token_stream = analyzer.token_stream(text);
+    let mut tokens = vec![];
+    token_stream.process(&mut |token: &Token| tokens.push(token.clone()));
+    tokens
+}
+
+#[test]
+fn test_normalized_terms() -> Result<(), Box<dyn std::error::Error>> {
+    let (index, field) = index(
+        analyzer()?,
+        &[
+            "Ｔｈｅ Ｑｕｉｃｋ Ｆｏｘ",
+            "Straße nach Köln",
+            "another document",
+        ],
+    )?;
+
+    assert_eq!(
+        parse_and_search(&index, field, "QUICK")?,
+        values(&["Ｔｈｅ Ｑｕｉｃｋ Ｆｏｘ"])
+    );
+    assert_eq!(
+        parse_and_search(&index, field, "strasse")?,
+        values(&["Straße nach Köln"])
+    );
+    assert_eq!(parse_and_search(&index, field, "fuchs")?, values(&[]));
+
+    Ok(())
+}
+
+#[test]
+fn test_phrase() -> Result<(), Box<dyn std::error::Error>> {
+    let (index, field) = index(analyzer()?, &["Ｔｈｅ Ｑｕｉｃｋ Ｆｏｘ", "fox quick"])?;
+
+    assert_eq!(
+        parse_and_search(&index, field, "\"quick fox\"")?,
+        values(&["Ｔｈｅ Ｑｕｉｃｋ Ｆｏｘ"])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_clone_across_threads() -> Result<(), Box<dyn std::error::Error>> {
+    let text = "Ｔｈｅ Ｑｕｉｃｋ Ｆｏｘ";
+    let mut analyzer = analyzer()?;
+    let expected = tokens(&mut analyzer, text);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let mut analyzer = analyzer.clone();
+            thread::spawn(move || tokens(&mut analyzer, text))
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().expect("Thread should not panic"), expected);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "commons")]
+
+mod common;
+
+use tantivy::query::{PhraseQuery, TermQuery};
+use tantivy::schema::IndexRecordOption;
+use tantivy::tokenizer::TextAnalyzer;
+use tantivy::Term;
+use tantivy_analysis_contrib::commons::{PathTokenizer, PathTokenizerBuilder};
+
+use crate::common::{index, search, values};
+
+const DOCUMENTS: [&str; 3] = ["/usr/local/bin", "/usr/share/doc", "/var/local"];
+
+#[test]
+fn test_ancestor() -> Result<(), Box<dyn std::error::Error>> {
+    let (index, field) = index(TextAnalyzer::from(PathTokenizer::default()), &DOCUMENTS)?;
+
+    let query =
+        |path: &str| TermQuery::new(Term::from_field_text(field, path), IndexRecordOption::Basic);
+
+    assert_eq!(
+        search(&index, field, &query("/usr"))?,
+        values(&["/usr/local/bin", "/usr/share/doc"])
+    );
+    assert_eq!(
+        search(&index, field, &query("/usr/local"))?,
+        values(&["/usr/local/bin"])
+    );
+    // Levels are only matched from the root
+    assert_eq!(search(&index, field, &query("/local"))?, values(&[]));
+
+    Ok(())
+}
+
+#[test]
+fn test_positions() -> Result<(), Box<dyn std::error::Error>> {
+    let path_tokenizer = PathTokenizerBuilder::default().positions(true).build()?;
+    let (index, field) = index(TextAnalyzer::from(path_tokenizer), &DOCUMENTS)?;
+
+    let query = |paths: &[&str]| {
+        PhraseQuery::new(
+            paths
+                .iter()
+                .map(|path| Term::from_field_text(field, path))
+                .collect(),
+        )
+    };
+
+    // Consecutive levels are at consecutive positions
+    assert_eq!(
+        search(&index, field, &query(&["/usr", "/usr/local"]))?,
+        values(&["/usr/local/bin"])
+    );
+    assert_eq!(
+        search(&index, field, &query(&["/usr", "/usr/local/bin"]))?,
+        values(&[])
+    );
+
+    Ok(())
+}
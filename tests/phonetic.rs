@@ -0,0 +1,70 @@
+#![cfg(feature = "phonetic")]
+
+mod common;
+
+use tantivy::tokenizer::{TextAnalyzer, WhitespaceTokenizer};
+use tantivy_analysis_contrib::phonetic::{
+    Mapping, PhoneticAlgorithm, PhoneticTokenFilter, SpecialHW,
+};
+
+use crate::common::{index, parse_and_search, values};
+
+const DOCUMENTS: [&str; 3] = ["Robert Smith", "Smith Robert", "Ashcraft Tymczak"];
+
+fn analyzer(inject: bool) -> Result<TextAnalyzer, Box<dyn std::error::Error>> {
+    let algorithm = PhoneticAlgorithm::Soundex(Mapping(None), SpecialHW(None));
+    let token_filter: PhoneticTokenFilter = (algorithm, inject).try_into()?;
+    Ok(TextAnalyzer::builder(WhitespaceTokenizer::default())
+        .filter(token_filter)
+        .build())
+}
+
+#[test]
+fn test_sounds_like() -> Result<(), Box<dyn std::error::Error>> {
+    let (index, field) = index(analyzer(false)?, &DOCUMENTS)?;
+
+    assert_eq!(
+        parse_and_search(&index, field, "Rupert")?,
+        values(&["Robert Smith", "Smith Robert"])
+    );
+    assert_eq!(
+        parse_and_search(&index, field, "Ashcroft")?,
+        values(&["Ashcraft Tymczak"])
+    );
+    assert_eq!(
+        parse_and_search(&index, field, "Tymczyk")?,
+        values(&["Ashcraft Tymczak"])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_phrase() -> Result<(), Box<dyn std::error::Error>> {
+    let (index, field) = index(analyzer(false)?, &DOCUMENTS)?;
+
+    assert_eq!(
+        parse_and_search(&index, field, "\"Rupert Smyth\"")?,
+        values(&["Robert Smith"])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_phrase_inject() -> Result<(), Box<dyn std::error::Error>> {
+    // Injected codes share the position of the original token, so the query's codes
+    // don't shift the positions of the following words
+    let (index, field) = index(analyzer(true)?, &DOCUMENTS)?;
+
+    assert_eq!(
+        parse_and_search(&index, field, "\"Robert Smith\"")?,
+        values(&["Robert Smith"])
+    );
+    assert_eq!(
+        parse_and_search(&index, field, "Robert")?,
+        values(&["Robert Smith", "Smith Robert"])
+    );
+
+    Ok(())
+}